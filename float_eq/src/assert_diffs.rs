@@ -0,0 +1,456 @@
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, DiffsProbe, Mismatch, ReportTol, UlpsTol,
+};
+use core::fmt;
+use core::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+/// How a failing assert describes the values being compared.
+///
+/// Only collections such as `Vec` and `HashMap` may be described concisely,
+/// other types always use the full message. The default is to list up to 10
+/// failing values, which may be changed via [`set_assert_message`] or by
+/// setting the `FLOAT_EQ_ASSERT_MESSAGE` environment variable to either the
+/// maximum number of values to list or `full`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssertMessage {
+    /// List the individual values that failed the check, up to `limit` of them.
+    Concise {
+        /// Maximum number of failing values to list.
+        limit: usize,
+    },
+    /// Print the whole of both values, along with their differences and the
+    /// tolerances used.
+    Full,
+}
+
+impl AssertMessage {
+    const DEFAULT: AssertMessage = AssertMessage::Concise { limit: 10 };
+
+    fn from_env() -> AssertMessage {
+        match std::env::var("FLOAT_EQ_ASSERT_MESSAGE") {
+            Ok(value) if value.trim().eq_ignore_ascii_case("full") => AssertMessage::Full,
+            Ok(value) => value
+                .trim()
+                .parse()
+                .map(|limit| AssertMessage::Concise { limit })
+                .unwrap_or(AssertMessage::DEFAULT),
+            Err(_) => AssertMessage::DEFAULT,
+        }
+    }
+}
+
+// How failing asserts describe values: UNSET until first read, then FULL or
+// CONCISE, in which case the limit is stored separately so that any usize is
+// representable.
+const UNSET: u8 = 0;
+const FULL: u8 = 1;
+const CONCISE: u8 = 2;
+static ASSERT_MESSAGE: AtomicU8 = AtomicU8::new(UNSET);
+static ASSERT_MESSAGE_LIMIT: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets how failing asserts describe the values being compared, for every
+/// thread in the program.
+///
+/// # Examples
+/// ```
+/// use float_eq::{assert_message, set_assert_message, AssertMessage};
+///
+/// set_assert_message(AssertMessage::Full);
+/// assert_eq!(assert_message(), AssertMessage::Full);
+/// ```
+pub fn set_assert_message(message: AssertMessage) {
+    let mode = match message {
+        AssertMessage::Full => FULL,
+        AssertMessage::Concise { limit } => {
+            ASSERT_MESSAGE_LIMIT.store(limit, Ordering::Relaxed);
+            CONCISE
+        }
+    };
+    ASSERT_MESSAGE.store(mode, Ordering::Release);
+}
+
+/// How failing asserts describe the values being compared.
+///
+/// See [`AssertMessage`] for the default.
+pub fn assert_message() -> AssertMessage {
+    match ASSERT_MESSAGE.load(Ordering::Acquire) {
+        UNSET => {
+            let message = AssertMessage::from_env();
+            set_assert_message(message);
+            message
+        }
+        FULL => AssertMessage::Full,
+        _ => AssertMessage::Concise {
+            limit: ASSERT_MESSAGE_LIMIT.load(Ordering::Relaxed),
+        },
+    }
+}
+
+/// The individual values that failed a check, used by asserts to describe
+/// failures in collections concisely.
+///
+/// Values are added via [`AssertFloatEqDiffs::debug_diffs`] and
+/// [`AssertFloatEqAllDiffs::debug_all_diffs`]. Every failure is counted, but only
+/// the first `limit` of them are kept.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssertDiffs {
+    limit: usize,
+    count: usize,
+    diffs: Vec<String>,
+}
+
+impl AssertDiffs {
+    /// An empty list of failures that keeps at most `limit` of them.
+    pub fn new(limit: usize) -> Self {
+        AssertDiffs {
+            limit,
+            count: 0,
+            diffs: Vec::new(),
+        }
+    }
+
+    /// Records a failure of the value at `path`, for example `[2].re`, which
+    /// is empty for the values being compared themselves.
+    pub fn push(&mut self, path: &str, diff: fmt::Arguments<'_>) {
+        self.count += 1;
+        if self.diffs.len() < self.limit {
+            let path = if path.is_empty() { "<root>" } else { path };
+            self.diffs.push(format!("{}: {}", path, diff));
+        }
+    }
+
+    /// Records `a` and `b` as a single failure at `path` if they fail the
+    /// check, along with their differences and the effective tolerance.
+    pub fn push_failure<A, B>(&mut self, path: &str, a: &A, b: &B, tol: ReportTol<'_, A::Tol>)
+    where
+        A: ?Sized + AssertFloatEq<B> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+    {
+        let mut push = |t: &dyn fmt::Debug| self.push_values(path, a, b, t);
+        match tol {
+            ReportTol::Abs(t) if !a.eq_abs(b, t) => push(&a.debug_abs_tol(b, t)),
+            ReportTol::Rmax(t) if !a.eq_rmax(b, t) => push(&a.debug_rmax_tol(b, t)),
+            ReportTol::Rmin(t) if !a.eq_rmin(b, t) => push(&a.debug_rmin_tol(b, t)),
+            ReportTol::R1st(t) if !a.eq_r1st(b, t) => push(&a.debug_r1st_tol(b, t)),
+            ReportTol::R2nd(t) if !a.eq_r2nd(b, t) => push(&a.debug_r2nd_tol(b, t)),
+            ReportTol::AtolRtol(at, rt) if !a.eq_atol_rtol(b, at, rt) => {
+                push(&a.debug_atol_rtol_tol(b, at, rt))
+            }
+            ReportTol::Ulps(t) if !a.eq_ulps(b, t) => push(&a.debug_ulps_tol(b, t)),
+            _ => {}
+        }
+    }
+
+    /// Records `a` and `b` as a single failure at `path` if they fail the
+    /// `_all` check, along with their differences and the effective tolerance.
+    pub fn push_all_failure<A, B>(
+        &mut self,
+        path: &str,
+        a: &A,
+        b: &B,
+        tol: ReportTol<'_, A::AllTol>,
+    ) where
+        A: ?Sized + AssertFloatEq<B> + AssertFloatEqAll<B> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+    {
+        let mut push = |t: &dyn fmt::Debug| self.push_values(path, a, b, t);
+        match tol {
+            ReportTol::Abs(t) if !a.eq_abs_all(b, t) => push(&a.debug_abs_all_tol(b, t)),
+            ReportTol::Rmax(t) if !a.eq_rmax_all(b, t) => push(&a.debug_rmax_all_tol(b, t)),
+            ReportTol::Rmin(t) if !a.eq_rmin_all(b, t) => push(&a.debug_rmin_all_tol(b, t)),
+            ReportTol::R1st(t) if !a.eq_r1st_all(b, t) => push(&a.debug_r1st_all_tol(b, t)),
+            ReportTol::R2nd(t) if !a.eq_r2nd_all(b, t) => push(&a.debug_r2nd_all_tol(b, t)),
+            ReportTol::AtolRtol(at, rt) if !a.eq_atol_rtol_all(b, at, rt) => {
+                push(&a.debug_atol_rtol_all_tol(b, at, rt))
+            }
+            ReportTol::Ulps(t) if !a.eq_ulps_all(b, t) => push(&a.debug_ulps_all_tol(b, t)),
+            _ => {}
+        }
+    }
+
+    fn push_values<A, B>(&mut self, path: &str, a: &A, b: &B, tol: &dyn fmt::Debug)
+    where
+        A: ?Sized + AssertFloatEq<B> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+    {
+        self.push(
+            path,
+            format_args!(
+                "left: `{:?}`, right: `{:?}`, abs_diff: `{:?}`, ulps_diff: `{:?}`, t: `{:?}`",
+                a,
+                b,
+                a.debug_abs_diff(b),
+                a.debug_ulps_diff(b),
+                tol,
+            ),
+        );
+    }
+
+    /// The total number of failures recorded.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The failures that were kept, in the order they were recorded.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.diffs.iter().map(String::as_str)
+    }
+}
+
+impl fmt::Display for AssertDiffs {
+    /// Lists the failures that were kept, one per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} failure{}",
+            self.count,
+            if self.count == 1 { "" } else { "s" }
+        )?;
+        if self.diffs.len() < self.count {
+            write!(f, ", showing the first {}", self.diffs.len())?;
+        }
+        write!(f, ":")?;
+        for diff in &self.diffs {
+            write!(f, "\n           {}", diff)?;
+        }
+        Ok(())
+    }
+}
+
+/// Debug context for when an assert fails, which lists the individual values
+/// that failed the check rather than the whole of both values. Requires the
+/// `std` feature.
+///
+/// This trait is used by [`assert_float_eq!`] when the [`AssertMessage`] is
+/// `Concise`. Types that do not implement it are always described by the full
+/// message.
+///
+/// To implement this trait over a new type, see [How to compare custom types].
+///
+/// [How to compare custom types]: https://jtempest.github.io/float_eq-rs/book/how_to/compare_custom_types.html
+pub trait AssertFloatEqDiffs<Rhs: ?Sized = Self>: AssertFloatEq<Rhs> {
+    /// Records the individual values that fail a check in `diffs`, so that a
+    /// failing assert may list only those.
+    ///
+    /// Returns `true` if the values were itemised, in which case the assert
+    /// will display `diffs` rather than the whole of both values. This is done
+    /// by collections, which call `debug_diffs` on each of their elements in
+    /// turn, appending the index or key of the element to `path`.
+    ///
+    /// Other types should record each of their fields in the same way and
+    /// return `false`, or if they have no fields record themselves as a single
+    /// failure via [`AssertDiffs::push_failure`].
+    fn debug_diffs(
+        &self,
+        other: &Rhs,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool;
+}
+
+/// Debug context for when an assert using an `all` check fails, which lists
+/// the individual values that failed the check. Requires the `std` feature.
+///
+/// See [`AssertFloatEqDiffs`].
+pub trait AssertFloatEqAllDiffs<Rhs: ?Sized = Self>: AssertFloatEqAll<Rhs> {
+    /// Records the individual values that fail an `_all` check in `diffs`, so
+    /// that a failing assert may list only those.
+    ///
+    /// See [`AssertFloatEqDiffs::debug_diffs`]. Types without fields may record
+    /// themselves via [`AssertDiffs::push_all_failure`].
+    fn debug_all_diffs(
+        &self,
+        other: &Rhs,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool;
+}
+
+/// Helper for describing a pair of collections element by element, which
+/// records a mismatch if their lengths differ. If `tol` is finite, as it is for
+/// per-element tolerances, then it must also have the same length.
+pub(crate) fn diff_elements<A, B, T, F>(
+    path: &str,
+    left: A,
+    right: B,
+    tol: T,
+    diffs: &mut AssertDiffs,
+    mut func: F,
+) where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator,
+    T: Iterator,
+    F: FnMut(A::Item, B::Item, T::Item, &str, &mut AssertDiffs),
+{
+    let (len, right_len) = (left.len(), right.len());
+    if len != right_len {
+        let mismatch = Mismatch::Len {
+            left: len,
+            right: right_len,
+        };
+        diffs.push(path, format_args!("{}", mismatch));
+        return;
+    }
+    if let Some(tol_len) = tol.size_hint().1 {
+        if tol_len != len {
+            let mismatch = Mismatch::TolLen { len, tol: tol_len };
+            diffs.push(path, format_args!("{}", mismatch));
+            return;
+        }
+    }
+
+    for (i, ((a, b), t)) in left.zip(right).zip(tol).enumerate() {
+        func(a, b, t, &format!("{}[{}]", path, i), diffs);
+    }
+}
+
+// Asserts only itemise the failures of values whose types implement the diffs
+// traits, which is decided by autoref specialisation. The methods of these
+// traits take a `DiffsProbe` by reference, so are found before those of
+// `NoDiffsCmp` that take it by double reference, but only if their impl's
+// bounds hold. Each gives the function that itemises the failures of a check.
+type DiffsFn<A, B, T> = fn(&A, &B, &T) -> Option<AssertDiffs>;
+
+#[doc(hidden)]
+pub trait FloatEqDiffsCmp<A: ?Sized + AssertFloatEqDiffs<B>, B: ?Sized> {
+    #[inline]
+    fn abs(&self) -> DiffsFn<A, B, A::Tol> {
+        |a, b, tol| diffs(a, b, ReportTol::Abs(tol))
+    }
+
+    #[inline]
+    fn rel(&self) -> DiffsFn<A, B, A::Tol> {
+        |a, b, tol| diffs(a, b, ReportTol::Rmax(tol))
+    }
+
+    #[inline]
+    fn rmax(&self) -> DiffsFn<A, B, A::Tol> {
+        |a, b, tol| diffs(a, b, ReportTol::Rmax(tol))
+    }
+
+    #[inline]
+    fn rmin(&self) -> DiffsFn<A, B, A::Tol> {
+        |a, b, tol| diffs(a, b, ReportTol::Rmin(tol))
+    }
+
+    #[inline]
+    fn r1st(&self) -> DiffsFn<A, B, A::Tol> {
+        |a, b, tol| diffs(a, b, ReportTol::R1st(tol))
+    }
+
+    #[inline]
+    fn r2nd(&self) -> DiffsFn<A, B, A::Tol> {
+        |a, b, tol| diffs(a, b, ReportTol::R2nd(tol))
+    }
+
+    #[inline]
+    fn ulps(&self) -> DiffsFn<A, B, UlpsTol<A::Tol>> {
+        |a, b, tol| diffs(a, b, ReportTol::Ulps(tol))
+    }
+
+    #[inline]
+    fn atol_rtol(&self) -> DiffsFn<A, B, (A::Tol, A::Tol)>
+    where
+        A::Tol: Sized,
+    {
+        |a, b, tol| diffs(a, b, ReportTol::AtolRtol(&tol.0, &tol.1))
+    }
+}
+
+impl<A: ?Sized + AssertFloatEqDiffs<B>, B: ?Sized> FloatEqDiffsCmp<A, B> for DiffsProbe<A, B> {}
+
+#[doc(hidden)]
+pub trait FloatEqAllDiffsCmp<A: ?Sized + AssertFloatEqAllDiffs<B>, B: ?Sized> {
+    #[inline]
+    fn abs_all(&self) -> DiffsFn<A, B, A::AllTol> {
+        |a, b, tol| diffs_all(a, b, ReportTol::Abs(tol))
+    }
+
+    #[inline]
+    fn rel_all(&self) -> DiffsFn<A, B, A::AllTol> {
+        |a, b, tol| diffs_all(a, b, ReportTol::Rmax(tol))
+    }
+
+    #[inline]
+    fn rmax_all(&self) -> DiffsFn<A, B, A::AllTol> {
+        |a, b, tol| diffs_all(a, b, ReportTol::Rmax(tol))
+    }
+
+    #[inline]
+    fn rmin_all(&self) -> DiffsFn<A, B, A::AllTol> {
+        |a, b, tol| diffs_all(a, b, ReportTol::Rmin(tol))
+    }
+
+    #[inline]
+    fn r1st_all(&self) -> DiffsFn<A, B, A::AllTol> {
+        |a, b, tol| diffs_all(a, b, ReportTol::R1st(tol))
+    }
+
+    #[inline]
+    fn r2nd_all(&self) -> DiffsFn<A, B, A::AllTol> {
+        |a, b, tol| diffs_all(a, b, ReportTol::R2nd(tol))
+    }
+
+    #[inline]
+    fn ulps_all(&self) -> DiffsFn<A, B, UlpsTol<A::AllTol>> {
+        |a, b, tol| diffs_all(a, b, ReportTol::Ulps(tol))
+    }
+
+    #[inline]
+    fn atol_rtol_all(&self) -> DiffsFn<A, B, (A::AllTol, A::AllTol)>
+    where
+        A::AllTol: Sized,
+    {
+        |a, b, tol| diffs_all(a, b, ReportTol::AtolRtol(&tol.0, &tol.1))
+    }
+}
+
+impl<A: ?Sized + AssertFloatEqAllDiffs<B>, B: ?Sized> FloatEqAllDiffsCmp<A, B>
+    for DiffsProbe<A, B>
+{
+}
+
+pub(crate) fn diffs<A, B>(a: &A, b: &B, tol: ReportTol<'_, A::Tol>) -> Option<AssertDiffs>
+where
+    A: ?Sized + AssertFloatEqDiffs<B>,
+    B: ?Sized,
+{
+    match assert_message() {
+        AssertMessage::Concise { limit } => {
+            let mut diffs = AssertDiffs::new(limit);
+            if a.debug_diffs(b, tol, "", &mut diffs) {
+                Some(diffs)
+            } else {
+                None
+            }
+        }
+        AssertMessage::Full => None,
+    }
+}
+
+pub(crate) fn diffs_all<A, B>(a: &A, b: &B, tol: ReportTol<'_, A::AllTol>) -> Option<AssertDiffs>
+where
+    A: ?Sized + AssertFloatEqAllDiffs<B>,
+    B: ?Sized,
+{
+    match assert_message() {
+        AssertMessage::Concise { limit } => {
+            let mut diffs = AssertDiffs::new(limit);
+            if a.debug_all_diffs(b, tol, "", &mut diffs) {
+                Some(diffs)
+            } else {
+                None
+            }
+        }
+        AssertMessage::Full => None,
+    }
+}
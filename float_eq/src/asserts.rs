@@ -0,0 +1,193 @@
+use crate::check::{assert_checks, AllCheck, EachCheck};
+use crate::{AssertFloatEq, AssertFloatEqAll, Check, DebugUlpsDiff, FloatEqUlpsTol, UlpsTol};
+use core::fmt;
+
+// Each function wraps the equivalent assert, so that the messages are the same
+// and `#[track_caller]` reports the location of the function's caller. Since the
+// assert is made in a generic context, failures are never itemised.
+macro_rules! impl_assert_fns {
+    ($(
+        $check:ident $check_all:ident, $desc:literal,
+        tol: $tol:ty, all_tol: $all_tol:ty, where [$($bound:tt)*] [$($all_bound:tt)*],
+        $eq:ident, $ne:ident, $eq_all:ident, $ne_all:ident;
+    )+) => {$(
+        #[doc = concat!("Asserts that two values are equal, using ", $desc, ".")]
+        ///
+        #[doc = concat!("Equivalent to `assert_float_eq!(a, b, ", stringify!($check), " <= tol)`, except")]
+        /// that failures are always described by the full message.
+        #[track_caller]
+        pub fn $eq<A, B>(a: &A, b: &B, tol: &$tol)
+        where
+            A: ?Sized + AssertFloatEq<B> + fmt::Debug,
+            B: ?Sized + fmt::Debug,
+            DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+            UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+            $($bound)*
+        {
+            crate::assert_float_eq!(*a, *b, $check <= *tol)
+        }
+
+        #[doc = concat!("Asserts that two values are not equal, using ", $desc, ".")]
+        ///
+        #[doc = concat!("Equivalent to `assert_float_ne!(a, b, ", stringify!($check), " <= tol)`.")]
+        #[track_caller]
+        pub fn $ne<A, B>(a: &A, b: &B, tol: &$tol)
+        where
+            A: ?Sized + AssertFloatEq<B> + fmt::Debug,
+            B: ?Sized + fmt::Debug,
+            DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+            UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+            $($bound)*
+        {
+            crate::assert_float_ne!(*a, *b, $check <= *tol)
+        }
+
+        #[doc = concat!(
+            "Asserts that two values are equal, using ", $desc,
+            " with the same tolerance for every field."
+        )]
+        ///
+        #[doc = concat!("Equivalent to `assert_float_eq!(a, b, ", stringify!($check_all), " <= tol)`, except")]
+        /// that failures are always described by the full message.
+        #[track_caller]
+        pub fn $eq_all<A, B>(a: &A, b: &B, tol: &$all_tol)
+        where
+            A: ?Sized + AssertFloatEq<B> + AssertFloatEqAll<B> + fmt::Debug,
+            B: ?Sized + fmt::Debug,
+            DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+            UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+            $($all_bound)*
+        {
+            crate::assert_float_eq!(*a, *b, $check_all <= *tol)
+        }
+
+        #[doc = concat!(
+            "Asserts that two values are not equal, using ", $desc,
+            " with the same tolerance for every field."
+        )]
+        ///
+        #[doc = concat!("Equivalent to `assert_float_ne!(a, b, ", stringify!($check_all), " <= tol)`.")]
+        #[track_caller]
+        pub fn $ne_all<A, B>(a: &A, b: &B, tol: &$all_tol)
+        where
+            A: ?Sized + AssertFloatEq<B> + AssertFloatEqAll<B> + fmt::Debug,
+            B: ?Sized + fmt::Debug,
+            DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+            UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+            $($all_bound)*
+        {
+            crate::assert_float_ne!(*a, *b, $check_all <= *tol)
+        }
+    )+};
+}
+
+impl_assert_fns! {
+    abs abs_all, "an absolute tolerance comparison",
+    tol: A::Tol, all_tol: A::AllTol, where [] [],
+    assert_eq_abs, assert_ne_abs, assert_eq_abs_all, assert_ne_abs_all;
+
+    rmax rmax_all, "a relative tolerance comparison scaled to the larger value",
+    tol: A::Tol, all_tol: A::AllTol, where [] [],
+    assert_eq_rmax, assert_ne_rmax, assert_eq_rmax_all, assert_ne_rmax_all;
+
+    rmin rmin_all, "a relative tolerance comparison scaled to the smaller value",
+    tol: A::Tol, all_tol: A::AllTol, where [] [],
+    assert_eq_rmin, assert_ne_rmin, assert_eq_rmin_all, assert_ne_rmin_all;
+
+    r1st r1st_all, "a relative tolerance comparison scaled to the first value",
+    tol: A::Tol, all_tol: A::AllTol, where [] [],
+    assert_eq_r1st, assert_ne_r1st, assert_eq_r1st_all, assert_ne_r1st_all;
+
+    r2nd r2nd_all, "a relative tolerance comparison scaled to the second value",
+    tol: A::Tol, all_tol: A::AllTol, where [] [],
+    assert_eq_r2nd, assert_ne_r2nd, assert_eq_r2nd_all, assert_ne_r2nd_all;
+
+    atol_rtol atol_rtol_all, "an absolute and relative tolerance comparison, given an `(atol, rtol)` pair",
+    tol: (A::Tol, A::Tol), all_tol: (A::AllTol, A::AllTol), where [A::Tol: Sized,] [A::AllTol: Sized,],
+    assert_eq_atol_rtol, assert_ne_atol_rtol, assert_eq_atol_rtol_all, assert_ne_atol_rtol_all;
+
+    ulps ulps_all, "an ULPs comparison",
+    tol: UlpsTol<A::Tol>, all_tol: UlpsTol<A::AllTol>, where [] [],
+    assert_eq_ulps, assert_ne_ulps, assert_eq_ulps_all, assert_ne_ulps_all;
+}
+
+/// Asserts that two values are equal, using any one of a list of checks chosen
+/// at runtime.
+///
+/// Equivalent to listing the checks in `assert_float_eq!(a, b, check1 <= tol1,
+/// check2 <= tol2, ...)`, except that failures are always described by the full
+/// message. To require every check to succeed, as in an `all(...)` group, use
+/// [`CheckSet::All`](crate::CheckSet::All).
+///
+/// ```
+/// # use float_eq::{assert_eq_checks, Check};
+/// let checks = [Check::Abs(0.000_1), Check::Ulps(4)];
+/// assert_eq_checks(&1.0_f32, &1.000_000_5_f32, &checks);
+/// ```
+#[track_caller]
+pub fn assert_eq_checks<A, B, T>(a: &A, b: &B, checks: &[Check<T>])
+where
+    A: ?Sized + AssertFloatEq<B, Tol = T> + fmt::Debug,
+    B: ?Sized + fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+    UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    assert_checks::<EachCheck, _, _, _>(false, checks, false, a, b)
+}
+
+/// Asserts that two values are not equal, using a list of checks chosen at
+/// runtime, all of which must fail.
+///
+/// Equivalent to listing the checks in `assert_float_ne!(a, b, check1 <= tol1,
+/// check2 <= tol2, ...)`.
+#[track_caller]
+pub fn assert_ne_checks<A, B, T>(a: &A, b: &B, checks: &[Check<T>])
+where
+    A: ?Sized + AssertFloatEq<B, Tol = T> + fmt::Debug,
+    B: ?Sized + fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+    UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    assert_checks::<EachCheck, _, _, _>(true, checks, false, a, b)
+}
+
+/// Asserts that two values are equal, using any one of a list of checks chosen
+/// at runtime, each with the same tolerance for every field.
+///
+/// Equivalent to listing the checks in `assert_float_eq!(a, b, check1_all <= tol1,
+/// check2_all <= tol2, ...)`, except that failures are always described by the
+/// full message.
+#[track_caller]
+pub fn assert_eq_checks_all<A, B, T>(a: &A, b: &B, checks: &[Check<T>])
+where
+    A: ?Sized + AssertFloatEq<B> + AssertFloatEqAll<B, AllTol = T> + fmt::Debug,
+    B: ?Sized + fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+    UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    assert_checks::<AllCheck, _, _, _>(false, checks, false, a, b)
+}
+
+/// Asserts that two values are not equal, using a list of checks chosen at
+/// runtime, each with the same tolerance for every field, all of which must fail.
+///
+/// Equivalent to listing the checks in `assert_float_ne!(a, b, check1_all <= tol1,
+/// check2_all <= tol2, ...)`.
+#[track_caller]
+pub fn assert_ne_checks_all<A, B, T>(a: &A, b: &B, checks: &[Check<T>])
+where
+    A: ?Sized + AssertFloatEq<B> + AssertFloatEqAll<B, AllTol = T> + fmt::Debug,
+    B: ?Sized + fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+    UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    assert_checks::<AllCheck, _, _, _>(true, checks, false, a, b)
+}
@@ -0,0 +1,424 @@
+//! Records how closely each comparison passes, enabled by the `audit` feature.
+//!
+//! When the `FLOAT_EQ_AUDIT` environment variable holds a path, every check
+//! made by [`float_eq!`] and [`float_ne!`], and so by the assert macros built
+//! on them, appends an [`AuditRecord`] of it to that file as a line of JSON,
+//! whether it passed or not. Each record holds the location of the macro, the
+//! check made and the worst errors found between any of the fields or
+//! elements compared, along with the proportion of the tolerance they used:
+//!
+//! ```text
+//! {"file":"tests/solver.rs","line":12,"column":5,"check":"rmax","passed":true,"abs_diff":2.5e-9,"ulps_diff":11,"rel_diff":1.2e-9,"tol":{"Abs":2e-4},"margin":1.25e-5}
+//! ```
+//!
+//! Records from many test runs may be gathered in the same file. [`summarize`]
+//! combines them by location, so that [`AuditSummary::can_tighten`] finds the
+//! checks whose tolerance is far looser than needed. The `float_eq_audit`
+//! binary, which is built along with the `audit` feature, lists them:
+//!
+//! ```text
+//! FLOAT_EQ_AUDIT=audit.jsonl cargo test --features float_eq/audit
+//! cargo install float_eq --features audit
+//! float_eq_audit audit.jsonl
+//! ```
+//!
+//! Only types that implement [`FloatEqReport`] and [`FloatEqAllReport`] may
+//! be measured, as all of those in float_eq and those derived by
+//! `#[derive_float_eq]` do. Whether they do is decided from the types named
+//! at the macro, so values of other types, and values of a generic type
+//! within generic code even if it is instantiated with a type that supports
+//! reports, are compared as usual and recorded as `unsupported`, with no
+//! differences or margin. Without the environment variable, no reports are
+//! made at all. Checks made by a [`Check`] or [`CheckSet`] are not recorded.
+//!
+//! [`Check`]: crate::Check
+//! [`CheckSet`]: crate::CheckSet
+
+use crate::{
+    EffectiveTol, FloatEqAllReport, FloatEqReport, FloatEqReportCmp, FloatEqUlpsTol, Report,
+    ReportKind,
+};
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::panic::Location;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, Once};
+
+/// The environment variable holding the path that records are appended to.
+pub const AUDIT_PATH_VAR: &str = "FLOAT_EQ_AUDIT";
+
+/// A single check made by [`float_eq!`] or [`float_ne!`].
+///
+/// The errors are the largest found between any of the floating point fields
+/// or elements compared, which may each belong to a different field. They
+/// are `None` if no floating point values were compared, or if they are not
+/// finite, for example if a value was NaN or infinite.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AuditRecord {
+    /// The file containing the macro that made the check.
+    pub file: String,
+    /// The line of the macro.
+    pub line: u32,
+    /// The column of the macro.
+    pub column: u32,
+    /// The name of the check, such as `rmax` or `ulps_all`.
+    pub check: String,
+    /// Whether the check passed.
+    pub passed: bool,
+    /// The largest absolute difference.
+    pub abs_diff: Option<f64>,
+    /// The largest difference in ULPs.
+    pub ulps_diff: Option<u64>,
+    /// The largest absolute difference relative to the larger magnitude of
+    /// the two values.
+    pub rel_diff: Option<f64>,
+    /// The tolerance of the field or element closest to failing, after any
+    /// scaling by a relative check.
+    pub tol: Option<EffectiveTol>,
+    /// The largest proportion of its tolerance used by any field or element,
+    /// which is at most 1 for a check that passed.
+    pub margin: Option<f64>,
+    /// Whether the values compared do not support reports, in which case the
+    /// check could not be measured. Only written to the file if true.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub unsupported: bool,
+}
+
+impl AuditRecord {
+    /// The record of a check made at `location`, from the report of it.
+    pub fn new(location: &Location<'_>, report: &Report) -> Self {
+        let mut record = AuditRecord {
+            file: location.file().to_string(),
+            line: location.line(),
+            column: location.column(),
+            check: report.check.to_string(),
+            passed: report.passed(),
+            abs_diff: None,
+            ulps_diff: None,
+            rel_diff: None,
+            tol: None,
+            margin: None,
+            unsupported: false,
+        };
+        for node in report.nodes() {
+            if let ReportKind::Float(float) = &node.kind {
+                let scale = float.left.abs().max(float.right.abs());
+                let rel_diff = if float.abs_diff == 0.0 {
+                    0.0
+                } else {
+                    float.abs_diff / scale
+                };
+                let margin = match float.tol {
+                    EffectiveTol::Abs(tol) => ratio(float.abs_diff, tol),
+                    EffectiveTol::Ulps(tol) => float
+                        .ulps_diff
+                        .map_or(f64::INFINITY, |diff| ratio(diff as f64, tol as f64)),
+                };
+                record.abs_diff = max(record.abs_diff, Some(float.abs_diff));
+                record.ulps_diff = record.ulps_diff.max(float.ulps_diff);
+                record.rel_diff = max(record.rel_diff, Some(rel_diff));
+                let is_worst = match record.margin {
+                    Some(worst) => margin > worst,
+                    None => true,
+                };
+                if is_worst {
+                    record.margin = Some(margin);
+                    record.tol = Some(float.tol);
+                }
+            }
+        }
+        record.abs_diff = record.abs_diff.filter(|diff| diff.is_finite());
+        record.rel_diff = record.rel_diff.filter(|diff| diff.is_finite());
+        record.margin = record.margin.filter(|margin| margin.is_finite());
+        record.tol = record.tol.filter(|tol| match tol {
+            EffectiveTol::Abs(tol) => tol.is_finite(),
+            EffectiveTol::Ulps(_) => true,
+        });
+        record
+    }
+
+    /// The record of a check made at `location` of values that do not support
+    /// reports, which only notes whether it passed.
+    pub fn unsupported(location: &Location<'_>, check: &str, passed: bool) -> Self {
+        AuditRecord {
+            file: location.file().to_string(),
+            line: location.line(),
+            column: location.column(),
+            check: check.to_string(),
+            passed,
+            abs_diff: None,
+            ulps_diff: None,
+            rel_diff: None,
+            tol: None,
+            margin: None,
+            unsupported: true,
+        }
+    }
+
+    /// Reads the records from a file written by the audit, skipping any blank
+    /// lines.
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Vec<AuditRecord>> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut records = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                records.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(records)
+    }
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+// The proportion of a tolerance used by a difference, where a zero tolerance
+// is entirely used by any difference at all and NaN by none.
+fn ratio(diff: f64, tol: f64) -> f64 {
+    if diff == 0.0 {
+        0.0
+    } else if diff.is_nan() {
+        f64::INFINITY
+    } else {
+        diff / tol
+    }
+}
+
+// The larger of two values, ignoring NaN unless it is the only value.
+fn max(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// The records of every check made by the same macro.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditSummary {
+    /// The file containing the macro.
+    pub file: String,
+    /// The line of the macro.
+    pub line: u32,
+    /// The column of the macro.
+    pub column: u32,
+    /// The name of the check.
+    pub check: String,
+    /// The number of times the check was made.
+    pub count: usize,
+    /// The number of times the check failed.
+    pub failures: usize,
+    /// The number of times the values compared did not support reports, so
+    /// that the check could not be measured.
+    pub unsupported: usize,
+    /// The largest absolute difference of any record.
+    pub abs_diff: Option<f64>,
+    /// The largest difference in ULPs of any record.
+    pub ulps_diff: Option<u64>,
+    /// The largest relative difference of any record.
+    pub rel_diff: Option<f64>,
+    /// The largest proportion of its tolerance used by any record, or `None`
+    /// if it could not be measured for any one of them.
+    pub margin: Option<f64>,
+}
+
+impl AuditSummary {
+    /// Whether the check always passed, using no more than `1 / factor` of
+    /// its tolerance. For example, a factor of `10.0` finds the checks that
+    /// would still have passed with a tolerance an order of magnitude smaller.
+    ///
+    /// This is only a guide, since the values compared by the records may not
+    /// cover every case the check is intended for.
+    pub fn can_tighten(&self, factor: f64) -> bool {
+        self.failures == 0 && matches!(self.margin, Some(margin) if margin * factor <= 1.0)
+    }
+}
+
+/// Combines the records of each check made by the same macro, ordered by their
+/// location.
+pub fn summarize<I>(records: I) -> Vec<AuditSummary>
+where
+    I: IntoIterator<Item = AuditRecord>,
+{
+    let mut summaries: BTreeMap<(String, u32, u32, String), AuditSummary> = BTreeMap::new();
+    for record in records {
+        let key = (
+            record.file.clone(),
+            record.line,
+            record.column,
+            record.check.clone(),
+        );
+        let summary = summaries.entry(key).or_insert_with(|| AuditSummary {
+            file: record.file.clone(),
+            line: record.line,
+            column: record.column,
+            check: record.check.clone(),
+            count: 0,
+            failures: 0,
+            unsupported: 0,
+            abs_diff: None,
+            ulps_diff: None,
+            rel_diff: None,
+            margin: Some(0.0),
+        });
+        summary.count += 1;
+        if !record.passed {
+            summary.failures += 1;
+        }
+        if record.unsupported {
+            summary.unsupported += 1;
+        }
+        summary.abs_diff = max(summary.abs_diff, record.abs_diff);
+        summary.ulps_diff = summary.ulps_diff.max(record.ulps_diff);
+        summary.rel_diff = max(summary.rel_diff, record.rel_diff);
+        summary.margin = summary
+            .margin
+            .zip(record.margin)
+            .map(|(worst, margin)| worst.max(margin));
+    }
+    summaries.into_values().collect()
+}
+
+// The audit file is opened once, on the first check made while auditing.
+static OPEN: Once = Once::new();
+static AUDITING: AtomicBool = AtomicBool::new(false);
+static FILE: Mutex<Option<File>> = Mutex::new(None);
+
+fn audit_file() -> Option<MutexGuard<'static, Option<File>>> {
+    if is_auditing() {
+        // a panic while the lock is held cannot leave the file inconsistent
+        Some(FILE.lock().unwrap_or_else(|err| err.into_inner()))
+    } else {
+        None
+    }
+}
+
+#[doc(hidden)]
+pub fn is_auditing() -> bool {
+    OPEN.call_once(|| {
+        let path = match std::env::var_os(AUDIT_PATH_VAR) {
+            Some(path) => path,
+            None => return,
+        };
+        match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(file) => {
+                *FILE.lock().unwrap_or_else(|err| err.into_inner()) = Some(file);
+                AUDITING.store(true, Ordering::Relaxed);
+            }
+            Err(err) => eprintln!(
+                "float_eq: not auditing, cannot open {:?}: {}",
+                Path::new(&path),
+                err
+            ),
+        }
+    });
+    AUDITING.load(Ordering::Relaxed)
+}
+
+/// Appends the record of a check to the audit file, which is marked as
+/// unsupported if the values compared do not support reports.
+#[doc(hidden)]
+#[track_caller]
+pub fn record(check: &str, passed: bool, report: Option<Report>) {
+    if let Some(mut file) = audit_file() {
+        let record = match report {
+            Some(report) => AuditRecord::new(Location::caller(), &report),
+            None => AuditRecord::unsupported(Location::caller(), check, passed),
+        };
+        if let (Some(file), Ok(mut line)) = (file.as_mut(), serde_json::to_string(&record)) {
+            line.push('\n');
+            let _ = file.write_all(line.as_bytes());
+        }
+    }
+}
+
+/// A check made by [`float_eq!`] while auditing.
+///
+/// Each of the `Audit` traits reports the checks with a given form of
+/// tolerance, and is implemented for checks of values that support reports.
+/// Others fall back to [`AuditUnsupported`], which is implemented for a
+/// reference to any check, so that values are compared as usual and recorded
+/// as unsupported if they cannot be reported, rather than failing to compile.
+#[doc(hidden)]
+pub struct AuditCheck<'a, A: ?Sized, B: ?Sized, T: ?Sized> {
+    a: &'a A,
+    b: &'a B,
+    tol: &'a T,
+}
+
+#[doc(hidden)]
+impl<'a, A: ?Sized, B: ?Sized, T: ?Sized> AuditCheck<'a, A, B, T> {
+    #[inline]
+    pub fn new(a: &'a A, b: &'a B, tol: &'a T) -> Self {
+        AuditCheck { a, b, tol }
+    }
+}
+
+macro_rules! impl_audit_traits {
+    ($(
+        $trait:ident for $tol:ty, where [$($bound:tt)*] { $($check:ident)+ }
+    )+) => {
+        $(
+            #[doc(hidden)]
+            pub trait $trait {
+                $(fn $check(&self) -> Option<Report>;)+
+            }
+
+            impl<A, B, T> $trait for AuditCheck<'_, A, B, $tol>
+            where
+                $($bound)*
+            {
+                $(
+                    #[inline]
+                    fn $check(&self) -> Option<Report> {
+                        Some(FloatEqReportCmp::$check(self.a, self.b, self.tol))
+                    }
+                )+
+            }
+        )+
+
+        #[doc(hidden)]
+        pub trait AuditUnsupported {
+            $($(fn $check(&self) -> Option<Report>;)+)+
+        }
+
+        impl<A: ?Sized, B: ?Sized, T: ?Sized> AuditUnsupported for &AuditCheck<'_, A, B, T> {
+            $($(
+                #[inline]
+                fn $check(&self) -> Option<Report> {
+                    None
+                }
+            )+)+
+        }
+    };
+}
+
+impl_audit_traits! {
+    AuditTol for T, where [
+        A: ?Sized + FloatEqReport<B, Tol = T>, B: ?Sized, T: ?Sized,
+    ] { abs rel rmax rmin r1st r2nd }
+
+    AuditAllTol for T, where [
+        A: ?Sized + FloatEqAllReport<B, AllTol = T>, B: ?Sized, T: ?Sized,
+    ] { abs_all rel_all rmax_all rmin_all r1st_all r2nd_all }
+
+    AuditUlpsTol for T, where [
+        A: ?Sized + FloatEqReport<B>, A::Tol: FloatEqUlpsTol<UlpsTol = T>, B: ?Sized, T: ?Sized,
+    ] { ulps }
+
+    AuditUlpsAllTol for T, where [
+        A: ?Sized + FloatEqAllReport<B>, A::AllTol: FloatEqUlpsTol<UlpsTol = T>, B: ?Sized, T: ?Sized,
+    ] { ulps_all }
+
+    AuditAtolRtol for (T, T), where [
+        A: ?Sized + FloatEqReport<B, Tol = T>, B: ?Sized,
+    ] { atol_rtol }
+
+    AuditAtolRtolAll for (T, T), where [
+        A: ?Sized + FloatEqAllReport<B, AllTol = T>, B: ?Sized,
+    ] { atol_rtol_all }
+}
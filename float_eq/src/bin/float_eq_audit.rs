@@ -0,0 +1,124 @@
+//! Lists the checks recorded by float_eq's `audit` feature whose tolerance
+//! could be tightened.
+//!
+//! ```text
+//! cargo install float_eq --features audit
+//! float_eq_audit <audit.jsonl> [factor]
+//! ```
+//!
+//! Checks are listed if they always passed while using no more than
+//! `1 / factor` of their tolerance, where the factor defaults to 10. Checks of
+//! values that do not support reports cannot be measured, and are counted and
+//! listed separately.
+
+use float_eq::audit::{summarize, AuditRecord, AuditSummary};
+use std::fmt::Display;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: float_eq_audit <audit.jsonl> [factor]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (path, factor) = match args.as_slice() {
+        [path] => (path, 10.0),
+        [path, factor] => match factor.parse::<f64>() {
+            Ok(factor) if factor > 0.0 => (path, factor),
+            _ => {
+                eprintln!("invalid factor `{}`\n{}", factor, USAGE);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let records = match AuditRecord::read(path) {
+        Ok(records) => records,
+        Err(err) => {
+            eprintln!("cannot read {}: {}", path, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let summaries = summarize(records);
+    let loose: Vec<&AuditSummary> = summaries
+        .iter()
+        .filter(|summary| summary.can_tighten(factor))
+        .collect();
+
+    println!(
+        "{} of {} checks could use a tolerance {} times smaller",
+        loose.len(),
+        summaries.len(),
+        factor
+    );
+    if !loose.is_empty() {
+        print_table(&loose);
+    }
+
+    let unsupported: Vec<&AuditSummary> = summaries
+        .iter()
+        .filter(|summary| summary.unsupported > 0)
+        .collect();
+    if !unsupported.is_empty() {
+        println!(
+            "{} checks could not be measured, since the values compared do not support reports",
+            unsupported.len()
+        );
+        for s in unsupported {
+            println!("  {}:{}:{}  {}", s.file, s.line, s.column, s.check);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn print_table(summaries: &[&AuditSummary]) {
+    let header = [
+        "location",
+        "check",
+        "count",
+        "margin",
+        "abs_diff",
+        "ulps_diff",
+        "rel_diff",
+    ];
+    let rows: Vec<[String; 7]> = summaries
+        .iter()
+        .map(|s| {
+            [
+                format!("{}:{}:{}", s.file, s.line, s.column),
+                s.check.clone(),
+                s.count.to_string(),
+                or_dash(s.margin.map(|m| format!("{:.2e}", m))),
+                or_dash(s.abs_diff.map(|d| format!("{:.2e}", d))),
+                or_dash(s.ulps_diff),
+                or_dash(s.rel_diff.map(|d| format!("{:.2e}", d))),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    print_row(&header, &widths);
+    for row in &rows {
+        print_row(row, &widths);
+    }
+}
+
+fn print_row<S: AsRef<str>>(cells: &[S], widths: &[usize]) {
+    let line: Vec<String> = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:<w$}", cell.as_ref(), w = width))
+        .collect();
+    println!("  {}", line.join("  ").trim_end());
+}
+
+fn or_dash<T: Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| v.to_string())
+}
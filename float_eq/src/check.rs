@@ -0,0 +1,862 @@
+use crate::macros::assert_failed_with;
+#[cfg(feature = "std")]
+use crate::{
+    assert_diffs::{diffs, diffs_all},
+    AssertCheckDiffs, AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, FloatEqAllReport,
+    FloatEqReport, Report, ReportTol,
+};
+use crate::{
+    AssertCheckTol, AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll,
+    FloatEqUlpsTol, UlpsTol,
+};
+use core::{fmt, marker::PhantomData};
+
+/// A check of two values, chosen at runtime rather than named in a macro.
+///
+/// Each variant is one of the [comparison algorithms] along with its
+/// tolerance, so that `Check::Rmax(tol)` makes the same comparison as the
+/// `rmax <= tol` in `float_eq!(a, b, rmax <= tol)`. This allows checks to be
+/// stored alongside the values they are used with, such as the cases of a
+/// data-driven test:
+///
+/// ```
+/// # use float_eq::Check;
+/// let cases: [(f64, f64, Check<f64>); 3] = [
+///     (1.0, 1.000_000_1, Check::Abs(0.000_001)),
+///     (1e10, 1.000_000_1e10, Check::Rmax(0.000_001)),
+///     (0.1, 0.100_000_000_000_000_03, Check::Ulps(2)),
+/// ];
+///
+/// for (a, b, check) in &cases {
+///     assert!(check.eq(a, b));
+///     check.assert_eq(a, b);
+/// }
+/// ```
+///
+/// A check may be applied using either the [`FloatEq`] traits, via methods
+/// such as [`eq`](Check::eq), or the [`FloatEqAll`] traits, via methods such as
+/// [`eq_all`](Check::eq_all), in which case `Check::Rmax(tol)` is the same as
+/// `rmax_all <= tol`. Failed asserts print the same full messages as the macros
+/// do, without listing individual failures. To combine several checks, or to
+/// assert with concise messages, see [`CheckSet`].
+///
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        rename_all = "snake_case",
+        bound(
+            serialize = "T: serde::Serialize, UlpsTol<T>: serde::Serialize",
+            deserialize = "T: serde::Deserialize<'de>, UlpsTol<T>: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub enum Check<T: FloatEqUlpsTol>
+where
+    UlpsTol<T>: Sized,
+{
+    /// An [absolute tolerance comparison].
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    Abs(T),
+    /// A [relative tolerance comparison], scaled to the precision of the larger operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    Rmax(T),
+    /// A [relative tolerance comparison], scaled to the precision of the smaller operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    Rmin(T),
+    /// A [relative tolerance comparison], scaled to the precision of the first operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    R1st(T),
+    /// A [relative tolerance comparison], scaled to the precision of the second operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    R2nd(T),
+    /// An [absolute and relative tolerance comparison], with the absolute
+    /// tolerance followed by the relative tolerance.
+    ///
+    /// [absolute and relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-and-relative-tolerance-comparison
+    AtolRtol(T, T),
+    /// An [ULPs comparison].
+    ///
+    /// [ULPs comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    Ulps(UlpsTol<T>),
+}
+
+impl<T: FloatEqUlpsTol> Check<T>
+where
+    UlpsTol<T>: Sized,
+{
+    /// The name of the check's algorithm, for example `"rmax"`.
+    pub fn name(&self) -> &'static str {
+        self.name_in(false)
+    }
+
+    /// The name of the check as used by `FloatEq`, or `FloatEqAll` if `all`.
+    fn name_in(&self, all: bool) -> &'static str {
+        match (self, all) {
+            (Check::Abs(_), false) => "abs",
+            (Check::Abs(_), true) => "abs_all",
+            (Check::Rmax(_), false) => "rmax",
+            (Check::Rmax(_), true) => "rmax_all",
+            (Check::Rmin(_), false) => "rmin",
+            (Check::Rmin(_), true) => "rmin_all",
+            (Check::R1st(_), false) => "r1st",
+            (Check::R1st(_), true) => "r1st_all",
+            (Check::R2nd(_), false) => "r2nd",
+            (Check::R2nd(_), true) => "r2nd_all",
+            (Check::AtolRtol(_, _), false) => "atol_rtol",
+            (Check::AtolRtol(_, _), true) => "atol_rtol_all",
+            (Check::Ulps(_), false) => "ulps",
+            (Check::Ulps(_), true) => "ulps_all",
+        }
+    }
+
+    /// The name of the check as it is labelled in an assert message.
+    fn label(&self, all: bool) -> &'static str {
+        match (self, all) {
+            (Check::Abs(_), false) => "[abs]",
+            (Check::Abs(_), true) => "[abs_all]",
+            (Check::Rmax(_), false) => "[rmax]",
+            (Check::Rmax(_), true) => "[rmax_all]",
+            (Check::Rmin(_), false) => "[rmin]",
+            (Check::Rmin(_), true) => "[rmin_all]",
+            (Check::R1st(_), false) => "[r1st]",
+            (Check::R1st(_), true) => "[r1st_all]",
+            (Check::R2nd(_), false) => "[r2nd]",
+            (Check::R2nd(_), true) => "[r2nd_all]",
+            (Check::AtolRtol(_, _), false) => "[atol_rtol]",
+            (Check::AtolRtol(_, _), true) => "[atol_rtol_all]",
+            (Check::Ulps(_), false) => "[ulps]",
+            (Check::Ulps(_), true) => "[ulps_all]",
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn report_tol(&self) -> ReportTol<'_, T> {
+        match self {
+            Check::Abs(t) => ReportTol::Abs(t),
+            Check::Rmax(t) => ReportTol::Rmax(t),
+            Check::Rmin(t) => ReportTol::Rmin(t),
+            Check::R1st(t) => ReportTol::R1st(t),
+            Check::R2nd(t) => ReportTol::R2nd(t),
+            Check::AtolRtol(at, rt) => ReportTol::AtolRtol(at, rt),
+            Check::Ulps(t) => ReportTol::Ulps(t),
+        }
+    }
+
+    /// Check if two values are equal, using the `FloatEq` traits.
+    ///
+    /// Equivalent to `float_eq!(a, b, name <= tol)`.
+    pub fn eq<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEq<B, Tol = T>,
+        B: ?Sized,
+    {
+        match self {
+            Check::Abs(t) => a.eq_abs(b, t),
+            Check::Rmax(t) => a.eq_rmax(b, t),
+            Check::Rmin(t) => a.eq_rmin(b, t),
+            Check::R1st(t) => a.eq_r1st(b, t),
+            Check::R2nd(t) => a.eq_r2nd(b, t),
+            Check::AtolRtol(at, rt) => a.eq_atol_rtol(b, at, rt),
+            Check::Ulps(t) => a.eq_ulps(b, t),
+        }
+    }
+
+    /// Check if two values are not equal, using the `FloatEq` traits.
+    ///
+    /// Equivalent to `float_ne!(a, b, name <= tol)`.
+    #[inline]
+    pub fn ne<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEq<B, Tol = T>,
+        B: ?Sized,
+    {
+        !self.eq(a, b)
+    }
+
+    /// Check if two values are equal, using the `FloatEqAll` traits.
+    ///
+    /// Equivalent to `float_eq!(a, b, name_all <= tol)`.
+    pub fn eq_all<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEqAll<B, AllTol = T>,
+        B: ?Sized,
+    {
+        match self {
+            Check::Abs(t) => a.eq_abs_all(b, t),
+            Check::Rmax(t) => a.eq_rmax_all(b, t),
+            Check::Rmin(t) => a.eq_rmin_all(b, t),
+            Check::R1st(t) => a.eq_r1st_all(b, t),
+            Check::R2nd(t) => a.eq_r2nd_all(b, t),
+            Check::AtolRtol(at, rt) => a.eq_atol_rtol_all(b, at, rt),
+            Check::Ulps(t) => a.eq_ulps_all(b, t),
+        }
+    }
+
+    /// Check if two values are not equal, using the `FloatEqAll` traits.
+    ///
+    /// Equivalent to `float_ne!(a, b, name_all <= tol)`.
+    #[inline]
+    pub fn ne_all<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEqAll<B, AllTol = T>,
+        B: ?Sized,
+    {
+        !self.eq_all(a, b)
+    }
+
+    /// Asserts that two values are equal, using the `FloatEq` traits.
+    ///
+    /// Equivalent to `assert_float_eq!(a, b, name <= tol)`.
+    #[track_caller]
+    pub fn assert_eq<A, B>(&self, a: &A, b: &B)
+    where
+        A: ?Sized + AssertFloatEq<B, Tol = T> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+    {
+        assert_checks::<EachCheck, _, _, _>(false, core::slice::from_ref(self), false, a, b)
+    }
+
+    /// Asserts that two values are not equal, using the `FloatEq` traits.
+    ///
+    /// Equivalent to `assert_float_ne!(a, b, name <= tol)`.
+    #[track_caller]
+    pub fn assert_ne<A, B>(&self, a: &A, b: &B)
+    where
+        A: ?Sized + AssertFloatEq<B, Tol = T> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+    {
+        assert_checks::<EachCheck, _, _, _>(true, core::slice::from_ref(self), false, a, b)
+    }
+
+    /// Asserts that two values are equal, using the `FloatEqAll` traits.
+    ///
+    /// Equivalent to `assert_float_eq!(a, b, name_all <= tol)`.
+    #[track_caller]
+    pub fn assert_eq_all<A, B>(&self, a: &A, b: &B)
+    where
+        A: ?Sized + AssertFloatEq<B> + AssertFloatEqAll<B, AllTol = T> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+    {
+        assert_checks::<AllCheck, _, _, _>(false, core::slice::from_ref(self), false, a, b)
+    }
+
+    /// Asserts that two values are not equal, using the `FloatEqAll` traits.
+    ///
+    /// Equivalent to `assert_float_ne!(a, b, name_all <= tol)`.
+    #[track_caller]
+    pub fn assert_ne_all<A, B>(&self, a: &A, b: &B)
+    where
+        A: ?Sized + AssertFloatEq<B> + AssertFloatEqAll<B, AllTol = T> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+    {
+        assert_checks::<AllCheck, _, _, _>(true, core::slice::from_ref(self), false, a, b)
+    }
+
+    /// Compares two values using the `FloatEq` traits, returning a [`Report`]
+    /// of the result.
+    ///
+    /// Equivalent to `compare!(a, b, name <= tol)`.
+    #[cfg(feature = "std")]
+    pub fn report<A, B>(&self, a: &A, b: &B) -> Report
+    where
+        A: ?Sized + FloatEqReport<B, Tol = T>,
+        B: ?Sized,
+    {
+        Report {
+            check: self.name_in(false),
+            root: a.report(b, self.report_tol(), String::new()),
+        }
+    }
+
+    /// Compares two values using the `FloatEqAll` traits, returning a
+    /// [`Report`] of the result.
+    ///
+    /// Equivalent to `compare!(a, b, name_all <= tol)`.
+    #[cfg(feature = "std")]
+    pub fn report_all<A, B>(&self, a: &A, b: &B) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B, AllTol = T>,
+        B: ?Sized,
+    {
+        Report {
+            check: self.name_in(true),
+            root: a.report_all(b, self.report_tol(), String::new()),
+        }
+    }
+}
+
+impl<T: FloatEqUlpsTol + Clone> Clone for Check<T>
+where
+    UlpsTol<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Check::Abs(t) => Check::Abs(t.clone()),
+            Check::Rmax(t) => Check::Rmax(t.clone()),
+            Check::Rmin(t) => Check::Rmin(t.clone()),
+            Check::R1st(t) => Check::R1st(t.clone()),
+            Check::R2nd(t) => Check::R2nd(t.clone()),
+            Check::AtolRtol(at, rt) => Check::AtolRtol(at.clone(), rt.clone()),
+            Check::Ulps(t) => Check::Ulps(t.clone()),
+        }
+    }
+}
+
+impl<T: FloatEqUlpsTol + Copy> Copy for Check<T> where UlpsTol<T>: Copy {}
+
+impl<T: FloatEqUlpsTol + PartialEq> PartialEq for Check<T>
+where
+    UlpsTol<T>: PartialEq + Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Check::Abs(a), Check::Abs(b))
+            | (Check::Rmax(a), Check::Rmax(b))
+            | (Check::Rmin(a), Check::Rmin(b))
+            | (Check::R1st(a), Check::R1st(b))
+            | (Check::R2nd(a), Check::R2nd(b)) => a == b,
+            (Check::AtolRtol(aa, ar), Check::AtolRtol(ba, br)) => aa == ba && ar == br,
+            (Check::Ulps(a), Check::Ulps(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T: FloatEqUlpsTol + fmt::Debug> fmt::Debug for Check<T>
+where
+    UlpsTol<T>: fmt::Debug + Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Abs(t) => f.debug_tuple("Abs").field(t).finish(),
+            Check::Rmax(t) => f.debug_tuple("Rmax").field(t).finish(),
+            Check::Rmin(t) => f.debug_tuple("Rmin").field(t).finish(),
+            Check::R1st(t) => f.debug_tuple("R1st").field(t).finish(),
+            Check::R2nd(t) => f.debug_tuple("R2nd").field(t).finish(),
+            Check::AtolRtol(at, rt) => f.debug_tuple("AtolRtol").field(at).field(rt).finish(),
+            Check::Ulps(t) => f.debug_tuple("Ulps").field(t).finish(),
+        }
+    }
+}
+
+/// A combination of [`Check`]s, chosen at runtime.
+///
+/// As with the checks given to the macros, two values are equal under `Any` if
+/// any one of its checks succeeds, and under `All` only if every one of them
+/// does, like an `all(...)` group:
+///
+/// ```
+/// # use float_eq::{Check, CheckSet};
+/// let (a, b, c) = (1.0_f32, 1.000_000_5_f32, 1.000_05_f32);
+///
+/// let any = CheckSet::Any(vec![Check::Abs(0.000_1), Check::Ulps(4)]);
+/// assert!(any.eq(&a, &b));
+/// any.assert_eq(&a, &c);
+///
+/// let all = CheckSet::All(vec![Check::Abs(0.000_1), Check::Ulps(4)]);
+/// assert!(all.ne(&a, &c));
+/// all.assert_eq_all(&[a, 2.0], &[b, 2.0]);
+/// ```
+///
+/// An empty `Any` never succeeds, whereas an empty `All` always does. Failed
+/// asserts list individual failures as the macros do, and so require the
+/// values to implement [`AssertFloatEqDiffs`] or [`AssertFloatEqAllDiffs`].
+#[cfg(feature = "std")]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        rename_all = "snake_case",
+        bound(
+            serialize = "T: serde::Serialize, UlpsTol<T>: serde::Serialize",
+            deserialize = "T: serde::Deserialize<'de>, UlpsTol<T>: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub enum CheckSet<T: FloatEqUlpsTol>
+where
+    UlpsTol<T>: Sized,
+{
+    /// Succeeds if any of the checks succeed, which are made in order.
+    Any(Vec<Check<T>>),
+    /// Succeeds only if all of the checks succeed.
+    All(Vec<Check<T>>),
+}
+
+#[cfg(feature = "std")]
+impl<T: FloatEqUlpsTol> CheckSet<T>
+where
+    UlpsTol<T>: Sized,
+{
+    /// The checks in the set, in order.
+    pub fn checks(&self) -> &[Check<T>] {
+        match self {
+            CheckSet::Any(checks) | CheckSet::All(checks) => checks,
+        }
+    }
+
+    #[inline]
+    fn is_all(&self) -> bool {
+        matches!(self, CheckSet::All(_))
+    }
+
+    fn passes<F: FnMut(&Check<T>) -> bool>(&self, eq: F) -> bool {
+        match self {
+            CheckSet::Any(checks) => checks.iter().any(eq),
+            CheckSet::All(checks) => checks.iter().all(eq),
+        }
+    }
+
+    /// Check if two values are equal, using the `FloatEq` traits.
+    pub fn eq<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEq<B, Tol = T>,
+        B: ?Sized,
+    {
+        self.passes(|check| check.eq(a, b))
+    }
+
+    /// Check if two values are not equal, using the `FloatEq` traits.
+    #[inline]
+    pub fn ne<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEq<B, Tol = T>,
+        B: ?Sized,
+    {
+        !self.eq(a, b)
+    }
+
+    /// Check if two values are equal, using the `FloatEqAll` traits.
+    pub fn eq_all<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEqAll<B, AllTol = T>,
+        B: ?Sized,
+    {
+        self.passes(|check| check.eq_all(a, b))
+    }
+
+    /// Check if two values are not equal, using the `FloatEqAll` traits.
+    #[inline]
+    pub fn ne_all<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: ?Sized + FloatEqAll<B, AllTol = T>,
+        B: ?Sized,
+    {
+        !self.eq_all(a, b)
+    }
+
+    /// Asserts that two values are equal, using the `FloatEq` traits.
+    #[track_caller]
+    pub fn assert_eq<A, B>(&self, a: &A, b: &B)
+    where
+        A: ?Sized + AssertFloatEqDiffs<B, Tol = T> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+    {
+        assert_checks_itemised::<EachCheck, _, _, _>(false, self.checks(), self.is_all(), a, b)
+    }
+
+    /// Asserts that two values are not equal, using the `FloatEq` traits.
+    #[track_caller]
+    pub fn assert_ne<A, B>(&self, a: &A, b: &B)
+    where
+        A: ?Sized + AssertFloatEqDiffs<B, Tol = T> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+    {
+        assert_checks_itemised::<EachCheck, _, _, _>(true, self.checks(), self.is_all(), a, b)
+    }
+
+    /// Asserts that two values are equal, using the `FloatEqAll` traits.
+    #[track_caller]
+    pub fn assert_eq_all<A, B>(&self, a: &A, b: &B)
+    where
+        A: ?Sized + AssertFloatEq<B> + AssertFloatEqAllDiffs<B, AllTol = T> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+    {
+        assert_checks_itemised::<AllCheck, _, _, _>(false, self.checks(), self.is_all(), a, b)
+    }
+
+    /// Asserts that two values are not equal, using the `FloatEqAll` traits.
+    #[track_caller]
+    pub fn assert_ne_all<A, B>(&self, a: &A, b: &B)
+    where
+        A: ?Sized + AssertFloatEq<B> + AssertFloatEqAllDiffs<B, AllTol = T> + fmt::Debug,
+        B: ?Sized + fmt::Debug,
+        DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+        UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+    {
+        assert_checks_itemised::<AllCheck, _, _, _>(true, self.checks(), self.is_all(), a, b)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FloatEqUlpsTol> From<Check<T>> for CheckSet<T>
+where
+    UlpsTol<T>: Sized,
+{
+    #[inline]
+    fn from(check: Check<T>) -> Self {
+        CheckSet::Any(vec![check])
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FloatEqUlpsTol + Clone> Clone for CheckSet<T>
+where
+    UlpsTol<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            CheckSet::Any(checks) => CheckSet::Any(checks.clone()),
+            CheckSet::All(checks) => CheckSet::All(checks.clone()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FloatEqUlpsTol + PartialEq> PartialEq for CheckSet<T>
+where
+    UlpsTol<T>: PartialEq + Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.is_all() == other.is_all() && self.checks() == other.checks()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FloatEqUlpsTol + fmt::Debug> fmt::Debug for CheckSet<T>
+where
+    UlpsTol<T>: fmt::Debug + Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckSet::Any(checks) => f.debug_tuple("Any").field(checks).finish(),
+            CheckSet::All(checks) => f.debug_tuple("All").field(checks).finish(),
+        }
+    }
+}
+
+// The checks of either the `FloatEq` or `FloatEqAll` traits, so that asserts of
+// both may share their messages.
+pub(crate) trait Checks<A: ?Sized, B: ?Sized, T: FloatEqUlpsTol>
+where
+    UlpsTol<T>: Sized,
+{
+    const ALL: bool;
+
+    fn eq(check: &Check<T>, a: &A, b: &B) -> bool;
+
+    fn debug_tol(
+        check: &Check<T>,
+        a: &A,
+        b: &B,
+        f: &mut dyn FnMut(&dyn fmt::Debug) -> fmt::Result,
+    ) -> fmt::Result;
+}
+
+// The checks of a `CheckSet`, whose failures are itemised in the same way as
+// those of the macros.
+#[cfg(feature = "std")]
+pub(crate) trait CheckDiffs<A: ?Sized, B: ?Sized, T: FloatEqUlpsTol>:
+    Checks<A, B, T>
+where
+    UlpsTol<T>: Sized,
+{
+    fn diffs(check: &Check<T>, a: &A, b: &B) -> Option<AssertDiffs>;
+}
+
+pub(crate) struct EachCheck;
+
+impl<A, B, T> Checks<A, B, T> for EachCheck
+where
+    A: ?Sized + AssertFloatEq<B, Tol = T>,
+    B: ?Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+    UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+{
+    const ALL: bool = false;
+
+    #[inline]
+    fn eq(check: &Check<T>, a: &A, b: &B) -> bool {
+        check.eq(a, b)
+    }
+
+    fn debug_tol(
+        check: &Check<T>,
+        a: &A,
+        b: &B,
+        f: &mut dyn FnMut(&dyn fmt::Debug) -> fmt::Result,
+    ) -> fmt::Result {
+        match check {
+            Check::Abs(t) => f(&a.debug_abs_tol(b, t)),
+            Check::Rmax(t) => f(&a.debug_rmax_tol(b, t)),
+            Check::Rmin(t) => f(&a.debug_rmin_tol(b, t)),
+            Check::R1st(t) => f(&a.debug_r1st_tol(b, t)),
+            Check::R2nd(t) => f(&a.debug_r2nd_tol(b, t)),
+            Check::AtolRtol(at, rt) => f(&a.debug_atol_rtol_tol(b, at, rt)),
+            Check::Ulps(t) => f(&a.debug_ulps_tol(b, t)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, T> CheckDiffs<A, B, T> for EachCheck
+where
+    A: ?Sized + AssertFloatEqDiffs<B, Tol = T>,
+    B: ?Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+    UlpsTol<A::DebugTol>: fmt::Debug + Sized,
+{
+    #[inline]
+    fn diffs(check: &Check<T>, a: &A, b: &B) -> Option<AssertDiffs> {
+        diffs(a, b, check.report_tol())
+    }
+}
+
+pub(crate) struct AllCheck;
+
+impl<A, B, T> Checks<A, B, T> for AllCheck
+where
+    A: ?Sized + AssertFloatEqAll<B, AllTol = T>,
+    B: ?Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+    UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+{
+    const ALL: bool = true;
+
+    #[inline]
+    fn eq(check: &Check<T>, a: &A, b: &B) -> bool {
+        check.eq_all(a, b)
+    }
+
+    fn debug_tol(
+        check: &Check<T>,
+        a: &A,
+        b: &B,
+        f: &mut dyn FnMut(&dyn fmt::Debug) -> fmt::Result,
+    ) -> fmt::Result {
+        match check {
+            Check::Abs(t) => f(&a.debug_abs_all_tol(b, t)),
+            Check::Rmax(t) => f(&a.debug_rmax_all_tol(b, t)),
+            Check::Rmin(t) => f(&a.debug_rmin_all_tol(b, t)),
+            Check::R1st(t) => f(&a.debug_r1st_all_tol(b, t)),
+            Check::R2nd(t) => f(&a.debug_r2nd_all_tol(b, t)),
+            Check::AtolRtol(at, rt) => f(&a.debug_atol_rtol_all_tol(b, at, rt)),
+            Check::Ulps(t) => f(&a.debug_ulps_all_tol(b, t)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, T> CheckDiffs<A, B, T> for AllCheck
+where
+    A: ?Sized + AssertFloatEqAllDiffs<B, AllTol = T>,
+    B: ?Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+    UlpsTol<A::AllDebugTol>: fmt::Debug + Sized,
+{
+    #[inline]
+    fn diffs(check: &Check<T>, a: &A, b: &B) -> Option<AssertDiffs> {
+        diffs_all(a, b, check.report_tol())
+    }
+}
+
+/// Panics with the same message as `assert_float_eq!`, or `assert_float_ne!`
+/// if `ne`, unless the checks succeed. If `all`, every check must succeed as
+/// in an `all(...)` group, otherwise any one of them. Failures are not
+/// itemised, as for values that do not implement `AssertFloatEqDiffs`.
+#[track_caller]
+pub(crate) fn assert_checks<C, A, B, T>(ne: bool, checks: &[Check<T>], all: bool, a: &A, b: &B)
+where
+    C: Checks<A, B, T>,
+    A: ?Sized + AssertFloatEq<B> + fmt::Debug,
+    B: ?Sized + fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    if checks_eq::<C, _, _, _>(checks, all, a, b) != ne {
+        return;
+    }
+    checks_failed::<C, _, _, _>(ne, checks, all, a, b, None)
+}
+
+/// As [`assert_checks`], but itemises the failures of an equality assert if
+/// the assert message is concise.
+#[cfg(feature = "std")]
+#[track_caller]
+pub(crate) fn assert_checks_itemised<C, A, B, T>(
+    ne: bool,
+    checks: &[Check<T>],
+    all: bool,
+    a: &A,
+    b: &B,
+) where
+    C: CheckDiffs<A, B, T>,
+    A: ?Sized + AssertFloatEq<B> + fmt::Debug,
+    B: ?Sized + fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    if checks_eq::<C, _, _, _>(checks, all, a, b) != ne {
+        return;
+    }
+    if ne {
+        checks_failed::<C, _, _, _>(ne, checks, all, a, b, None)
+    }
+
+    // failures are only marked in an `all(...)` group, since otherwise every
+    // check failed
+    let diffs: Vec<_> = checks
+        .iter()
+        .map(|check| {
+            let failed = !all || !C::eq(check, a, b);
+            (check.label(C::ALL), failed, C::diffs(check, a, b))
+        })
+        .collect();
+    let diffs = AssertCheckDiffs::new(&diffs);
+    checks_failed::<C, _, _, _>(ne, checks, all, a, b, diffs.as_ref().map(|d| d as _))
+}
+
+fn checks_eq<C, A, B, T>(checks: &[Check<T>], all: bool, a: &A, b: &B) -> bool
+where
+    C: Checks<A, B, T>,
+    A: ?Sized,
+    B: ?Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    if all {
+        checks.iter().all(|check| C::eq(check, a, b))
+    } else {
+        checks.iter().any(|check| C::eq(check, a, b))
+    }
+}
+
+#[track_caller]
+fn checks_failed<C, A, B, T>(
+    ne: bool,
+    checks: &[Check<T>],
+    all: bool,
+    a: &A,
+    b: &B,
+    diffs: Option<&dyn fmt::Display>,
+) -> !
+where
+    C: Checks<A, B, T>,
+    A: ?Sized + AssertFloatEq<B> + fmt::Debug,
+    B: ?Sized + fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: fmt::Debug,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    let header = CheckHeader {
+        ne,
+        checks,
+        all,
+        all_tol: C::ALL,
+    };
+
+    let tols = CheckTols::<C, A, B, T> {
+        checks,
+        mark: all && !ne,
+        a,
+        b,
+        family: PhantomData,
+    };
+
+    assert_failed_with(&header, a, b, &tols, diffs, None)
+}
+
+// The macro invocation that an assert of the checks is equivalent to.
+struct CheckHeader<'a, T: FloatEqUlpsTol>
+where
+    UlpsTol<T>: Sized,
+{
+    ne: bool,
+    checks: &'a [Check<T>],
+    all: bool,
+    all_tol: bool,
+}
+
+impl<T: FloatEqUlpsTol> fmt::Display for CheckHeader<'_, T>
+where
+    UlpsTol<T>: Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = if self.ne { "float_ne" } else { "float_eq" };
+        write!(f, "{}!(left, right, ", name)?;
+        if self.all {
+            f.write_str("all(")?;
+        }
+        for (i, check) in self.checks.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} <= t", check.name_in(self.all_tol))?;
+        }
+        if self.all {
+            f.write_str(")")?;
+        }
+        f.write_str(")")
+    }
+}
+
+// The tolerances of each check, as listed by a full assert message.
+struct CheckTols<'a, C, A: ?Sized, B: ?Sized, T: FloatEqUlpsTol>
+where
+    UlpsTol<T>: Sized,
+{
+    checks: &'a [Check<T>],
+    mark: bool,
+    a: &'a A,
+    b: &'a B,
+    family: PhantomData<C>,
+}
+
+impl<C, A, B, T> fmt::Display for CheckTols<'_, C, A, B, T>
+where
+    C: Checks<A, B, T>,
+    A: ?Sized,
+    B: ?Sized,
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, check) in self.checks.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",\n")?;
+            }
+            let failed = self.mark && !C::eq(check, self.a, self.b);
+            C::debug_tol(check, self.a, self.b, &mut |tol| {
+                AssertCheckTol::new(check.label(C::ALL), failed, &tol).fmt_tol(f)
+            })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::FloatEqError;
+use core::fmt;
+use std::panic::Location;
+use std::sync::{Mutex, MutexGuard};
+
+/// Collects the failures of many checks, so that they may all be reported by a
+/// single panic rather than stopping at the first.
+///
+/// Each result of [`check_float_eq!`] or [`check_float_ne!`] passed to
+/// [`check`](FloatEqChecker::check) is recorded along with the location it was
+/// called from. Calling [`finish`](FloatEqChecker::finish) then panics if any
+/// of them failed, with a table summarising every failure followed by the full
+/// message of each:
+///
+/// ```should_panic
+/// use float_eq::{check_float_eq, FloatEqChecker};
+///
+/// let checker = FloatEqChecker::new();
+/// checker.check(check_float_eq!(1.0_f64, 1.000_000_1, rmax <= 1e-6));
+/// checker.check(check_float_eq!(2.0_f64, 2.5, abs <= 0.1, "pressure"));
+/// checker.check(check_float_eq!(3.0_f64, 3.5_f64, ulps <= 4));
+/// checker.finish(); // panics, listing both failures
+/// ```
+///
+/// Checks may be made from many threads at once through a shared reference,
+/// for example from within a rayon parallel iterator. Failures are listed in
+/// the order of their location in the source, then the order they were made.
+///
+/// If the checker is dropped without calling `finish`, such as when the test
+/// returns early, the summary of any failures is printed to stderr as a warning
+/// rather than panicking, since a panic while dropping may abort the program.
+#[derive(Debug, Default)]
+#[must_use = "failures are only reported by calling `finish`"]
+pub struct FloatEqChecker {
+    state: Mutex<CheckerState>,
+}
+
+#[derive(Debug, Default)]
+struct CheckerState {
+    count: usize,
+    failures: Vec<(&'static Location<'static>, FloatEqError)>,
+}
+
+impl FloatEqChecker {
+    /// A checker that has not yet recorded any checks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the result of a check, returning whether it succeeded.
+    #[track_caller]
+    pub fn check(&self, result: Result<(), FloatEqError>) -> bool {
+        let mut state = self.lock();
+        state.count += 1;
+        match result {
+            Ok(()) => true,
+            Err(err) => {
+                state.failures.push((Location::caller(), err));
+                false
+            }
+        }
+    }
+
+    /// The number of checks recorded so far.
+    pub fn count(&self) -> usize {
+        self.lock().count
+    }
+
+    /// The failures recorded so far, with the location of each check.
+    pub fn failures(&self) -> Vec<(&'static Location<'static>, FloatEqError)> {
+        self.lock().failures.clone()
+    }
+
+    /// Panics with a summary of every failed check, if there were any.
+    pub fn finish(self) {
+        let state = core::mem::take(&mut *self.lock());
+        if !state.failures.is_empty() {
+            panic!("{}", Summary::new(state));
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CheckerState> {
+        // a panic while the lock is held cannot leave the state inconsistent
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl Drop for FloatEqChecker {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            let state = core::mem::take(&mut *self.lock());
+            if !state.failures.is_empty() {
+                eprintln!(
+                    "warning: FloatEqChecker dropped without calling finish\n{}",
+                    Summary::new(state)
+                );
+            }
+        }
+    }
+}
+
+// The panic message of a checker, with failures sorted by their location.
+struct Summary(CheckerState);
+
+impl Summary {
+    fn new(mut state: CheckerState) -> Self {
+        state
+            .failures
+            .sort_by_key(|(location, _)| (location.file(), location.line(), location.column()));
+        Summary(state)
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CheckerState { count, failures } = &self.0;
+        let locations: Vec<String> = failures
+            .iter()
+            .map(|(location, _)| location.to_string())
+            .collect();
+        let width = |header: &str, column: &dyn Fn(usize) -> usize| {
+            (0..failures.len())
+                .map(column)
+                .fold(header.len(), usize::max)
+        };
+        let location_width = width("location", &|i| locations[i].len());
+        let check_width = width("check", &|i| failures[i].1.check().len());
+        let left_width = width("left", &|i| failures[i].1.left().len());
+
+        writeln!(f, "{} of {} float checks failed", failures.len(), count)?;
+        writeln!(
+            f,
+            "  {:<lw$}  {:<cw$}  {:<vw$}  right",
+            "location",
+            "check",
+            "left",
+            lw = location_width,
+            cw = check_width,
+            vw = left_width,
+        )?;
+        for (location, (_, err)) in locations.iter().zip(failures) {
+            writeln!(
+                f,
+                "  {:<lw$}  {:<cw$}  {:<vw$}  {}",
+                location,
+                err.check(),
+                err.left(),
+                err.right(),
+                lw = location_width,
+                cw = check_width,
+                vw = left_width,
+            )?;
+        }
+        for (location, (_, err)) in locations.iter().zip(failures) {
+            write!(f, "\n{}: {}\n", location, err)?;
+        }
+        Ok(())
+    }
+}
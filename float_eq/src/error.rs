@@ -0,0 +1,235 @@
+use core::fmt;
+
+/// The failure of a [`check_float_eq!`] or [`check_float_ne!`].
+///
+/// Holds the same information as the message of the equivalent assert, with
+/// each value formatted using its `Debug` representation. The `Display` impl
+/// produces that message, prefixed by `check failed` rather than
+/// `assertion failed`, so that failures within collections are also listed
+/// concisely unless [`set_assert_message`] asks for the full message:
+///
+/// ```
+/// # use float_eq::check_float_eq;
+/// let err = check_float_eq!(1.0_f32, 1.5, abs <= 0.25, "x = {}", 1).unwrap_err();
+/// assert_eq!(err.check(), "float_eq!(left, right, abs <= t)");
+/// assert_eq!(err.left(), "1.0");
+/// assert_eq!(err.abs_diff(), "0.5");
+/// assert_eq!(err.message(), Some("x = 1"));
+/// assert_eq!(
+///     err.to_string(),
+///     r#"check failed: `float_eq!(left, right, abs <= t)`
+///         left: `1.0`,
+///        right: `1.5`,
+///     abs_diff: `0.5`,
+///    ulps_diff: `Some(4194304)`,
+///      [abs] t: `0.25`: x = 1"#
+/// );
+/// ```
+///
+/// Without the `std` feature, the error is stored without allocating and each
+/// value is truncated to its first 128 bytes, ending in `...` if it was cut
+/// short.
+///
+/// [`set_assert_message`]: fn.set_assert_message.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatEqError {
+    check: &'static str,
+    left: Text,
+    right: Text,
+    abs_diff: Text,
+    ulps_diff: Text,
+    tols: Text,
+    diffs: Option<Text>,
+    message: Option<Text>,
+}
+
+impl FloatEqError {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new<L, R, A, U, T>(
+        check: &'static str,
+        left: &L,
+        right: &R,
+        abs_diff: &A,
+        ulps_diff: &U,
+        tols: &T,
+        diffs: Option<&dyn fmt::Display>,
+        message: Option<fmt::Arguments<'_>>,
+    ) -> Self
+    where
+        L: ?Sized + fmt::Debug,
+        R: ?Sized + fmt::Debug,
+        A: fmt::Debug,
+        U: fmt::Debug,
+        T: fmt::Display,
+    {
+        FloatEqError {
+            check,
+            left: Text::new(format_args!("{:?}", left)),
+            right: Text::new(format_args!("{:?}", right)),
+            abs_diff: Text::new(format_args!("{:?}", abs_diff)),
+            ulps_diff: Text::new(format_args!("{:?}", ulps_diff)),
+            tols: Text::new(format_args!("{}", tols)),
+            diffs: diffs.map(|diffs| Text::new(format_args!("{}", diffs))),
+            message: message.map(Text::new),
+        }
+    }
+
+    /// The check that failed, as written in the assert message, such as
+    /// `float_eq!(left, right, rmax <= t, ulps <= t)`.
+    pub fn check(&self) -> &'static str {
+        self.check
+    }
+
+    /// The debug representation of the left hand value.
+    pub fn left(&self) -> &str {
+        self.left.as_str()
+    }
+
+    /// The debug representation of the right hand value.
+    pub fn right(&self) -> &str {
+        self.right.as_str()
+    }
+
+    /// The debug representation of the absolute difference between the values.
+    pub fn abs_diff(&self) -> &str {
+        self.abs_diff.as_str()
+    }
+
+    /// The debug representation of the ULPs difference between the values.
+    pub fn ulps_diff(&self) -> &str {
+        self.ulps_diff.as_str()
+    }
+
+    /// The tolerance used by each check, one per line, marking those that
+    /// failed within an `all(...)` group, such as `[ulps] t: `4` (failed)`.
+    pub fn tols(&self) -> &str {
+        self.tols.as_str()
+    }
+
+    /// The individual values that failed each check, which are listed in place
+    /// of the values when a collection is described concisely.
+    pub fn diffs(&self) -> Option<&str> {
+        self.diffs.as_ref().map(Text::as_str)
+    }
+
+    /// The custom message given to the macro, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_ref().map(Text::as_str)
+    }
+}
+
+impl fmt::Display for FloatEqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "check failed: `{}`", self.check)?;
+        match self.diffs() {
+            Some(diffs) => f.write_str(diffs)?,
+            None => write!(
+                f,
+                r#"        left: `{}`,
+       right: `{}`,
+    abs_diff: `{}`,
+   ulps_diff: `{}`,
+{}"#,
+                self.left(),
+                self.right(),
+                self.abs_diff(),
+                self.ulps_diff(),
+                self.tols()
+            )?,
+        }
+        if let Some(message) = self.message() {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FloatEqError {}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+struct Text(String);
+
+#[cfg(feature = "std")]
+impl Text {
+    fn new(args: fmt::Arguments<'_>) -> Self {
+        Text(args.to_string())
+    }
+
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// Without an allocator, text is written to a fixed size buffer and truncated.
+#[cfg(not(feature = "std"))]
+#[derive(Clone, PartialEq)]
+struct Text {
+    buf: [u8; TEXT_CAPACITY],
+    len: usize,
+    truncated: bool,
+}
+
+#[cfg(not(feature = "std"))]
+const TEXT_CAPACITY: usize = 128;
+
+#[cfg(not(feature = "std"))]
+impl Text {
+    fn new(args: fmt::Arguments<'_>) -> Self {
+        let mut text = Text {
+            buf: [0; TEXT_CAPACITY],
+            len: 0,
+            truncated: false,
+        };
+        let _ = fmt::Write::write_fmt(&mut text, args);
+        text
+    }
+
+    fn as_str(&self) -> &str {
+        // only ever holds whole chars copied from a str
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl fmt::Write for Text {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Ok(());
+        }
+        if s.len() <= TEXT_CAPACITY - self.len {
+            self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            return Ok(());
+        }
+
+        // keep as much as fits alongside the ellipsis, on a char boundary
+        let ellipsis = "...";
+        let mut end = TEXT_CAPACITY - ellipsis.len();
+        if end < self.len {
+            let text = self.as_str();
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+        } else {
+            let mut take = end - self.len;
+            while !s.is_char_boundary(take) {
+                take -= 1;
+            }
+            self.buf[self.len..self.len + take].copy_from_slice(&s.as_bytes()[..take]);
+            end = self.len + take;
+        }
+        self.buf[end..end + ellipsis.len()].copy_from_slice(ellipsis.as_bytes());
+        self.len = end + ellipsis.len();
+        self.truncated = true;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl fmt::Debug for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
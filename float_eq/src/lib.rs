@@ -32,6 +32,28 @@
 //! - `r2nd <= TOL` is a relative tolerance comparison with a tolerance of `TOL`,
 //!   scaled to the precision of the second operand.
 //!
+//! Where a panic is not acceptable, [`check_float_eq!`] and [`check_float_ne!`]
+//! take the same arguments as the asserts but return a [`FloatEqError`] holding
+//! the details of the failure:
+//!
+//! ```
+//! use float_eq::check_float_eq;
+//!
+//! # let y_pos = 0.000_1;
+//! if let Err(err) = check_float_eq!(y_pos, 0.0, abs <= 0.000_1) {
+//!     eprintln!("{}", err);
+//! }
+//! ```
+//!
+//! With the `std` feature enabled, a [`FloatEqChecker`] collects the failures of
+//! many such checks, then panics once listing all of them, so that a test of
+//! many values reports every failure rather than just the first.
+//!
+//! Each check also has an assert function, such as [`assert_eq_rmax`] or
+//! [`assert_ne_ulps_all`], which produces the same message as the macro and
+//! reports the location of its caller, for use in helpers marked
+//! `#[track_caller]`.
+//!
 //! # Comparison algorithms
 //!
 //! These are always of the form `CHECK <= tol`, where `CHECK` is one of:
@@ -42,6 +64,8 @@
 //! - `r1st`: a [relative tolerance comparison], scaled to the precision of the first operand/field.
 //! - `r2nd`: a [relative tolerance comparison], scaled to the precision of the second operand/field.
 //! - `ulps`: an [ULPs comparison].
+//! - `atol_rtol`: an [absolute and relative tolerance comparison], where `tol`
+//!   is an `(atol, rtol)` pair, with the same semantics as numpy's `isclose`.
 //!
 //! When comparing homogeneous composite types that implement [`FloatEqAll`],
 //! variants that use a uniform `tol` across all fields are also available:
@@ -52,14 +76,16 @@
 //! - `r1st_all`: a [relative tolerance comparison], scaled to the precision of the first field.
 //! - `r2nd_all`: a [relative tolerance comparison], scaled to the precision of the second field.
 //! - `ulps_all`: an [ULPs comparison].
+//! - `atol_rtol_all`: an [absolute and relative tolerance comparison].
 //!
 //! *Note: `rel` and `rel_all` are legacy aliases for `rmax` and `rmax_all`, but
 //! using the more precise algorithm names is recommended.*
 //!
 //! # Combining checks
 //!
-//! If multiple checks are specified in either a boolean comparison or an assert,
-//! they are applied left to right and will shortcut on success. For example:
+//! Any number of checks may be specified in either a boolean comparison or an
+//! assert, in which case they are applied left to right and will shortcut on
+//! success. For example:
 //!
 //! ```
 //! # use float_eq::float_eq;
@@ -77,14 +103,126 @@
 //! # ;
 //! ```
 //!
+//! To require that several checks all succeed instead, group them with `all`,
+//! which may be mixed with other checks in both comparisons and asserts:
+//!
+//! ```
+//! # use float_eq::float_eq;
+//! # let a = 0.1; let b = 0.1; let abs_tol = 0.0; let ulps_tol: u64 = 0;
+//! float_eq!(a, b, all(abs <= abs_tol, ulps <= ulps_tol))
+//! # ;
+//! ```
+//!
+//! Is equivalent to:
+//!
+//! ```
+//! # use float_eq::float_eq;
+//! # let a = 0.1; let b = 0.1; let abs_tol = 0.0; let ulps_tol: u64 = 0;
+//! float_eq!(a, b, abs <= abs_tol) && float_eq!(a, b, ulps <= ulps_tol)
+//! # ;
+//! ```
+//!
+//! If an assert of an `all` group fails, its message marks which of the checks
+//! in the group failed.
+//!
+//! # Comparing NaN values
+//!
+//! As with the standard comparison operators, NaN is never equal to anything,
+//! even another NaN. Values may be wrapped in [`NanEq`] to treat any two NaNs as
+//! equal, or [`NanEqBits`] to only match NaNs with the same sign and payload:
+//!
+//! ```
+//! # use float_eq::{assert_float_eq, NanEq};
+//! assert_float_eq!(NanEq(f64::NAN), NanEq(f64::NAN), abs <= NanEq(0.1));
+//! ```
+//!
+//! # Signed zeroes and infinities
+//!
+//! By default, `0.0` and `-0.0` are equal, and infinities are compared as the
+//! standard operators would. Values may be wrapped in [`Strict`] to distinguish
+//! the sign of zero and only match an infinity with itself, or in [`InfAsMax`]
+//! to compare infinities as the largest finite value of the same sign:
+//!
+//! ```
+//! # use float_eq::{assert_float_eq, assert_float_ne, InfAsMax, Strict};
+//! assert_float_ne!(Strict(0.0_f64), Strict(-0.0), abs <= Strict(0.1));
+//! assert_float_eq!(InfAsMax(f64::INFINITY), InfAsMax(f64::MAX), ulps <= 0);
+//! ```
+//!
+//! # Ordering with a tolerance
+//!
+//! [`float_le!`], [`float_lt!`], [`float_ge!`] and [`float_gt!`] compare the
+//! order of two values, using the same checks to move the boundary that the
+//! first value is compared against. `float_le!(a, b, abs <= t)` holds if `a` is
+//! no more than `t` above `b`, whereas `float_lt!(a, b, abs <= t)` requires that
+//! `a` is more than `t` below `b`:
+//!
+//! ```
+//! # use float_eq::{float_le, float_lt};
+//! assert!(float_le!(1.000_000_1_f32, 1.0, ulps <= 1));
+//! assert!(!float_lt!(0.999_999_94_f32, 1.0, ulps <= 1));
+//! ```
+//!
+//! Checks may be combined and grouped as above, and each has an assert form
+//! such as [`assert_float_le!`], whose failure message lists how far past its
+//! bound the first value was for each check. NaN values are never ordered.
+//!
+//! # Choosing checks at runtime
+//!
+//! The checks given to the macros are fixed at compile time. To load them from
+//! a test case or configuration instead, use a [`Check`], which holds one of
+//! the algorithms and its tolerance, or a [`CheckSet`] of them. These provide
+//! methods equivalent to each of the macros:
+//!
+//! ```
+//! # use float_eq::Check;
+//! let check = Check::Rmax(0.000_001);
+//! assert!(check.eq(&1.0_f64, &1.000_000_1_f64));
+//! ```
+//!
+//! A [`CheckSet`] requires the `std` feature:
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! # use float_eq::{Check, CheckSet};
+//! let checks: CheckSet<f32> = CheckSet::Any(vec![Check::Abs(0.000_1), Check::Ulps(4)]);
+//! checks.assert_eq_all(&[1.0_f32, 2.0], &[1.000_05_f32, 2.0]);
+//! # }
+//! ```
+//!
+//! With the `std` feature enabled, a [`CheckSet`] may also be parsed from the
+//! same text given to the macros, such as tolerances stored next to the
+//! expected values of a golden file:
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! # use float_eq::CheckSet;
+//! let checks: CheckSet<f64> = "rmax <= 1e-6, ulps <= 4".parse().unwrap();
+//! checks.assert_eq(&1.0, &1.000_000_1);
+//!
+//! let err = "rmx <= 1e-6".parse::<CheckSet<f64>>().unwrap_err();
+//! assert!(err.to_string().starts_with("unknown check `rmx`"));
+//! # }
+//! ```
+//!
+//! # Inspecting results
+//!
+//! With the `std` feature enabled, [`compare!`] accepts the same arguments as
+//! [`float_eq!`] but returns a [`Report`] detailing the result of each field or
+//! element compared, rather than a bool. See [How to inspect comparison reports].
+//!
 //! # Extending float_eq over custom types
 //!
 //! See [How to compare custom types].
 //!
 //! [How to compare custom types]: https://jtempest.github.io/float_eq-rs/book/how_to/compare_custom_types.html
+//! [How to inspect comparison reports]: https://jtempest.github.io/float_eq-rs/book/how_to/inspect_comparison_reports.html
 //! [the float_eq guide]: https://jtempest.github.io/float_eq-rs/book/index.html
 //! [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
 //! [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+//! [absolute and relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-and-relative-tolerance-comparison
 //! [ULPs comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
 
 #![warn(missing_docs)]
@@ -97,8 +235,61 @@ pub use crate::macros::*;
 mod traits;
 pub use crate::traits::*;
 
+mod ord;
+pub use crate::ord::*;
+
+mod wrappers;
+pub use crate::wrappers::*;
+
+mod check;
+pub use crate::check::*;
+
+mod error;
+pub use crate::error::*;
+
+mod asserts;
+pub use crate::asserts::*;
+
+#[cfg(feature = "std")]
+mod checker;
+#[cfg(feature = "std")]
+pub use crate::checker::*;
+
+#[cfg(feature = "std")]
+mod parse;
+#[cfg(feature = "std")]
+pub use crate::parse::*;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "std")]
+mod report;
+#[cfg(feature = "std")]
+pub use crate::report::*;
+
+#[cfg(feature = "std")]
+mod assert_diffs;
+#[cfg(feature = "std")]
+pub use crate::assert_diffs::*;
+
+#[cfg(feature = "audit")]
+pub mod audit;
+
 mod trait_impls;
+#[allow(unused_imports)] // only non-empty when optional features are enabled
 pub use crate::trait_impls::*;
 
 #[cfg(feature = "float_eq_derive")]
 pub use float_eq_derive::*;
+
+// Used by the serde derives on types generated by `#[float_eq(serde)]`, so that
+// crates deriving them need not depend on serde directly.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+
+// Used by prop_assert_float_eq! and prop_assert_float_ne! to return failures.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub use ::proptest as __proptest;
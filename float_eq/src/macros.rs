@@ -1,4 +1,7 @@
-use crate::{AssertFloatEq, AssertFloatEqAll, FloatEq, FloatEqAll, UlpsTol};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqError, FloatOrdOp,
+    UlpsTol,
+};
 
 /// Checks if two floating point expressions are equal to each other.
 ///
@@ -14,22 +17,62 @@ use crate::{AssertFloatEq, AssertFloatEqAll, FloatEq, FloatEqAll, UlpsTol};
 /// assert!(float_eq!(a, b, ulps <= 10));
 /// assert!(float_eq!(a, 3.999_999_6, rmax <= 2.0 * f32::EPSILON));
 /// assert!(float_eq!(a - b, 0.0, abs <= 0.000_01));
+/// assert!(float_eq!(a, b, all(abs <= 0.000_01, ulps <= 10)));
 /// ```
 ///
 /// [comparison algorithms]: index.html#comparison-algorithms
 /// [from left to right]: index.html#combining-checks
 #[macro_export]
 macro_rules! float_eq {
-    ($a:expr, $b:expr, $($eq:ident <= $tol:expr),+) => ({
+    (@any $a:ident, $b:ident,) => (false);
+    (@any $a:ident, $b:ident, all($($eq:ident <= $tol:expr),+ $(,)?) $(, $($rest:tt)*)?) => (
+        (true $(&& $crate::__float_eq_cmp!($eq, $a, $b, &$tol))+)
+            || $crate::float_eq!(@any $a, $b, $($($rest)*)?)
+    );
+    (@any $a:ident, $b:ident, $eq:ident <= $tol:expr $(, $($rest:tt)*)?) => (
+        $crate::__float_eq_cmp!($eq, $a, $b, &$tol) || $crate::float_eq!(@any $a, $b, $($($rest)*)?)
+    );
+    ($a:expr, $b:expr, $($checks:tt)+) => ({
         match (&$a, &$b) {
             (a_val, b_val) => {
-                false $(|| $crate::FloatEqCmp::$eq(a_val, b_val, &$tol))+
+                $crate::float_eq!(@any a_val, b_val, $($checks)+)
             }
         }
     });
-    ($a:expr, $b:expr, $($eq:ident <= $tol:expr),+,) => ({
-        $crate::float_eq!($a, $b $(, $eq <= $tol)+)
-    })
+}
+
+// Compares two values with a single check. With the audit feature, the check
+// is also recorded, and measured if the values support reports.
+#[cfg(not(feature = "audit"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __float_eq_cmp {
+    ($eq:ident, $a:expr, $b:expr, $tol:expr) => {
+        $crate::FloatEqCmp::$eq($a, $b, $tol)
+    };
+}
+
+#[cfg(feature = "audit")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __float_eq_cmp {
+    ($eq:ident, $a:expr, $b:expr, $tol:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::audit::{
+            AuditAllTol as _, AuditAtolRtol as _, AuditAtolRtolAll as _, AuditTol as _,
+            AuditUlpsAllTol as _, AuditUlpsTol as _, AuditUnsupported as _,
+        };
+        let (a, b, tol) = ($a, $b, $tol);
+        let eq = $crate::FloatEqCmp::$eq(a, b, tol);
+        if $crate::audit::is_auditing() {
+            $crate::audit::record(
+                stringify!($eq),
+                eq,
+                (&$crate::audit::AuditCheck::new(a, b, tol)).$eq(),
+            );
+        }
+        eq
+    }};
 }
 
 /// Checks if two floating point expressions are not equal to each other.
@@ -46,17 +89,15 @@ macro_rules! float_eq {
 /// assert!(float_ne!(a, b, ulps <= 10));
 /// assert!(float_ne!(a, b, rmax <= 2.0 * f32::EPSILON));
 /// assert!(float_ne!(a - b, 0.0, abs <= 0.000_01));
+/// assert!(float_ne!(a, b, all(abs <= 0.5, ulps <= 10)));
 /// ```
 ///
 /// [comparison algorithms]: index.html#comparison-algorithms
 /// [from left to right]: index.html#combining-checks
 #[macro_export]
 macro_rules! float_ne {
-    ($a:expr, $b:expr, $($eq:ident <= $tol:expr),+) => ({
-        !$crate::float_eq!($a, $b $(, $eq <= $tol)+)
-    });
-    ($a:expr, $b:expr, $($eq:ident <= $tol:expr),+,) => ({
-        !$crate::float_eq!($a, $b $(, $eq <= $tol)+)
+    ($a:expr, $b:expr, $($checks:tt)+) => ({
+        !$crate::float_eq!($a, $b, $($checks)+)
     });
 }
 
@@ -66,8 +107,9 @@ macro_rules! float_ne {
 ///
 /// On panic, this macro will print the values of the expressions with their debug
 /// representations, with [additional information] from the comparison operations.
-/// Like [`assert!`], this macro has a second form, where a custom panic message can
-/// be provided.
+/// When comparing collections, only the elements that failed are listed instead,
+/// see [`set_assert_message`]. Like [`assert!`], this macro has a second form,
+/// where a custom panic message can be provided.
 ///
 /// # Examples
 /// ```
@@ -79,199 +121,106 @@ macro_rules! float_ne {
 /// assert_float_eq!(a, b, ulps <= 10);
 /// assert_float_eq!(a, 3.999_999_6, rmax <= 2.0 * f32::EPSILON);
 /// assert_float_eq!(a - b, 0.0, abs <= 0.000_01, "Checking that {} == {}", a, b);
+/// assert_float_eq!(a, b, all(abs <= 0.000_01, ulps <= 10));
 /// ```
 ///
 /// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
 /// [additional information]: https://jtempest.github.io/float_eq-rs/book/how_to/interpret_assert_failure_messages.html
+/// [`set_assert_message`]: fn.set_assert_message.html
 /// [comparison algorithms]: index.html#comparison-algorithms
 /// [from left to right]: index.html#combining-checks
 #[macro_export]
 macro_rules! assert_float_eq {
-    // the order of these rules matters a *lot* for the format string functionality
-    // to work, otherwise we end up consuming the general case too early.
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
-        match (&$left, &$right, &$tol_1, &$tol_2, &$tol_3) {
-            (left_val, right_val, tol_1_val, tol_2_val, tol_3_val) => {
-                if !$crate::float_eq!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val,
-                    $eq2 <= *tol_2_val,
-                    $eq3 <= *tol_3_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        concat!("[", stringify!($eq3), "]"),
-                        $crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val)
-                    )
-                }
-            }
-        }
+    // each tolerance is bound in turn, building up the checks to evaluate, the
+    // header of the message and the checks to itemise on failure, and leaving
+    // any tokens after the last check as the format message
+    (@bind [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] all($($group:tt)+) $(, $($rest:tt)*)?) => ({
+        $crate::assert_float_eq!(@bind_all [$($vals)*] [$($header)*, ", all("] [$($checks)*] [$($items)*] [] "" [$($group)+] $($($rest)*)?)
     });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => {
-                if !$crate::float_eq!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val,
-                    $eq2 <= *tol_2_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                    )
-                }
-            }
+    (@bind [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] $eq:ident <= $tol:expr $(, $($rest:tt)*)?) => ({
+        match &$tol {
+            tol_val => $crate::assert_float_eq!(@bind [$($vals)*]
+                [$($header)*, ", ", stringify!($eq), " <= t"]
+                [$($checks)* $eq <= *tol_val,]
+                [$($items)* $eq tol_val (::core::option::Option::None)]
+                $($($rest)*)?
+            )
         }
     });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr) => ({
-        match (&$left, &$right, &$tol_1) {
-            (left_val, right_val, tol_1_val) => {
-                if !$crate::float_eq!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                    )
-                }
-            }
+    (@bind [$left_val:ident, $right_val:ident, $fail:ident] [$($header:tt)*] [$($checks:tt)+] [$($items:tt)+] $($arg:tt)*) => ({
+        if !$crate::float_eq!(*$left_val, *$right_val, $($checks)+) {
+            $crate::assert_float_eq!(@fail
+                [concat!("float_eq!(left, right" $($header)*, ")")]
+                [$left_val, $right_val, [$($arg)*], $fail]
+                [$($items)+]
+            )
+        } else {
+            $crate::assert_float_eq!(@pass $fail)
         }
     });
-    ($left:expr, $right:expr, $($eq:ident <= $tol:expr,)+) => ({
-        $crate::assert_float_eq!($left, $right $(, $eq <= $tol)+)
-    });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
-        match (&$left, &$right, &$tol_1, &$tol_2, &$tol_3) {
-            (left_val, right_val, tol_1_val, tol_2_val, tol_3_val) => {
-                if !$crate::float_eq!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val,
-                    $eq2 <= *tol_2_val,
-                    $eq3 <= *tol_3_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        concat!("[", stringify!($eq3), "]"),
-                        $crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val),
-                        format_args!($($arg)+)
-                    )
-                }
-            }
+    // the checks of an `all(...)` group are itemised with whether each failed
+    (@bind_all [$left_val:ident, $right_val:ident, $fail:ident] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] [$($group:tt)*] $sep:literal [$eq:ident <= $tol:expr $(, $($next:tt)*)?] $($rest:tt)*) => ({
+        match &$tol {
+            tol_val => $crate::assert_float_eq!(@bind_all [$left_val, $right_val, $fail]
+                [$($header)*, $sep, stringify!($eq), " <= t"]
+                [$($checks)*]
+                [$($items)* $eq tol_val (::core::option::Option::Some($crate::FloatEqCmp::$eq))]
+                [$($group)* $eq <= *tol_val,]
+                ", "
+                [$($($next)*)?]
+                $($rest)*
+            )
         }
     });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => {
-                if !$crate::float_eq!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val,
-                    $eq2 <= *tol_2_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        format_args!($($arg)+)
-                    )
-                }
-            }
-        }
+    (@bind_all [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] [$($group:tt)+] $sep:literal [] $($rest:tt)*) => ({
+        $crate::assert_float_eq!(@bind [$($vals)*] [$($header)*, ")"] [$($checks)* all($($group)+),] [$($items)*] $($rest)*)
     });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
-        match (&$left, &$right, &$tol_1) {
-            (left_val, right_val, tol_1_val) => {
-                if !$crate::float_eq!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_eq!(left, right, ", stringify!($eq1), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        format_args!($($arg)+)
-                    )
-                }
+    // the message is formatted out of line by assert_failed or check_failed,
+    // which only compute the debug tolerance, itemised failures and, within an
+    // `all(...)` group, the result of each check from the one lazy value
+    // expanded for it here. Its tolerance comes last, so that its type is
+    // inferred from the functions and a tolerance such as a `Vec` may deref to
+    // theirs. The reborrows below are intentional. See assert_eq! in the
+    // standard library.
+    (@fail [$header:expr] [$left_val:ident, $right_val:ident, [$($arg:tt)*], $fail:ident] [$($eq:ident $tol:ident $cmp:tt)+]) => ({
+        $crate::assert_float_eq!(@raise $fail,
+            $header,
+            &*$left_val,
+            &*$right_val,
+            &[$(&$crate::LazyEqCheck {
+                name: concat!("[", stringify!($eq), "]"),
+                left: &*$left_val,
+                right: &*$right_val,
+                debug_tol: $crate::FloatCmpOpTol::$eq,
+                diffs: {
+                    use $crate::__diffs_cmp::*;
+                    (&$crate::DiffsProbe::new(&*$left_val, &*$right_val)).$eq()
+                },
+                eq: $cmp,
+                tol: &*$tol,
+            },)+],
+            $crate::assert_float_eq!(@error_message $($arg)*)
+        )
+    });
+    (@error_message) => (::core::option::Option::None);
+    (@error_message $($arg:tt)+) => (::core::option::Option::Some(format_args!($($arg)+)));
+    (@pass check) => (::core::result::Result::Ok(()));
+    (@pass $fail:ident) => (());
+    // failures either panic, evaluate to an error from check_float_eq!, or
+    // return a proptest error from prop_assert_float_eq!
+    (@raise panic, $($args:tt)+) => ($crate::assert_failed($($args)+));
+    (@raise check, $($args:tt)+) => (
+        ::core::result::Result::Err($crate::check_failed($($args)+))
+    );
+    (@raise prop, $($args:tt)+) => (
+        return ::core::result::Result::Err($crate::__proptest::test_runner::TestCaseError::fail(
+            $crate::assert_failure_message($($args)+)
+        ))
+    );
+    ($left:expr, $right:expr, $($checks:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_float_eq!(@bind [left_val, right_val, panic] [] [] [] $($checks)+)
             }
         }
     });
@@ -296,6 +245,7 @@ macro_rules! assert_float_eq {
 /// assert_float_ne!(a, b, ulps <= 10);
 /// assert_float_ne!(a, b, rmax <= 2.0 * f32::EPSILON);
 /// assert_float_ne!(a - b, 0.0, abs <= 0.000_01, "Checking that {} != {}", a, b);
+/// assert_float_ne!(a, b, all(abs <= 0.5, ulps <= 10));
 /// ```
 ///
 /// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
@@ -304,191 +254,71 @@ macro_rules! assert_float_eq {
 /// [from left to right]: index.html#combining-checks
 #[macro_export]
 macro_rules! assert_float_ne {
-    // the order of these rules matters a *lot* for the format string functionality
-    // to work, otherwise we end up consuming the general case too early.
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr) => ({
-        match (&$left, &$right, &$tol_1, &$tol_2, &$tol_3) {
-            (left_val, right_val, tol_1_val, tol_2_val, tol_3_val) => {
-                if !$crate::float_ne!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val,
-                    $eq2 <= *tol_2_val,
-                    $eq3 <= *tol_3_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        concat!("[", stringify!($eq3), "]"),
-                        $crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val)
-                    )
-                }
-            }
-        }
+    // each tolerance is bound in turn, building up the checks to evaluate, the
+    // header of the message and the checks to list on failure, and leaving any
+    // tokens after the last check as the format message
+    (@bind [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] all($($group:tt)+) $(, $($rest:tt)*)?) => ({
+        $crate::assert_float_ne!(@bind_all [$($vals)*] [$($header)*, ", all("] [$($checks)*] [$($items)*] [] "" [$($group)+] $($($rest)*)?)
     });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr) => ({
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => {
-                if !$crate::float_ne!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val,
-                    $eq2 <= *tol_2_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                    )
-                }
-            }
+    (@bind [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] $eq:ident <= $tol:expr $(, $($rest:tt)*)?) => ({
+        match &$tol {
+            tol_val => $crate::assert_float_ne!(@bind [$($vals)*]
+                [$($header)*, ", ", stringify!($eq), " <= t"]
+                [$($checks)* $eq <= *tol_val,]
+                [$($items)* $eq tol_val]
+                $($($rest)*)?
+            )
         }
     });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr) => ({
-        match (&$left, &$right, &$tol_1) {
-            (left_val, right_val, tol_1_val) => {
-                if !$crate::float_ne!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                    )
-                }
-            }
+    (@bind [$left_val:ident, $right_val:ident, $fail:ident] [$($header:tt)*] [$($checks:tt)+] [$($items:tt)+] $($arg:tt)*) => ({
+        if !$crate::float_ne!(*$left_val, *$right_val, $($checks)+) {
+            $crate::assert_float_ne!(@fail
+                [concat!("float_ne!(left, right" $($header)*, ")")]
+                [$left_val, $right_val, [$($arg)*], $fail]
+                [$($items)+]
+            )
+        } else {
+            $crate::assert_float_eq!(@pass $fail)
         }
     });
-    ($left:expr, $right:expr, $($eq:ident <= $tol:expr,)+) => ({
-        $crate::assert_float_ne!($left, $right $(, $eq <= $tol)+)
-    });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $eq3:ident <= $tol_3:expr, $($arg:tt)+) => ({
-        match (&$left, &$right, &$tol_1, &$tol_2, &$tol_3) {
-            (left_val, right_val, tol_1_val, tol_2_val, tol_3_val) => {
-                if !$crate::float_ne!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val,
-                    $eq2 <= *tol_2_val,
-                    $eq3 <= *tol_3_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), " <= t, ", stringify!($eq3), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        concat!("[", stringify!($eq3), "]"),
-                        $crate::FloatCmpOpTol::$eq3(&*left_val, &*right_val, &*tol_3_val),
-                        format_args!($($arg)+)
-                    )
-                }
-            }
+    (@bind_all [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] [$($group:tt)*] $sep:literal [$eq:ident <= $tol:expr $(, $($next:tt)*)?] $($rest:tt)*) => ({
+        match &$tol {
+            tol_val => $crate::assert_float_ne!(@bind_all [$($vals)*]
+                [$($header)*, $sep, stringify!($eq), " <= t"]
+                [$($checks)*]
+                [$($items)* $eq tol_val]
+                [$($group)* $eq <= *tol_val,]
+                ", "
+                [$($($next)*)?]
+                $($rest)*
+            )
         }
     });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => ({
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => {
-                if !$crate::float_ne!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val,
-                    $eq2 <= *tol_2_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::FloatCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
-                        format_args!($($arg)+)
-                    )
-                }
-            }
-        }
+    (@bind_all [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] [$($group:tt)+] $sep:literal [] $($rest:tt)*) => ({
+        $crate::assert_float_ne!(@bind [$($vals)*] [$($header)*, ")"] [$($checks)* all($($group)+),] [$($items)*] $($rest)*)
     });
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $($arg:tt)+) => ({
-        match (&$left, &$right, &$tol_1) {
-            (left_val, right_val, tol_1_val) => {
-                if !$crate::float_ne!(
-                    *left_val,
-                    *right_val,
-                    $eq1 <= *tol_1_val) {
-                    // The reborrows below are intentional. See assert_eq! in the standard library.
-                    panic!(concat!(
-"assertion failed: `float_ne!(left, right, ", stringify!($eq1), r#" <= t)`
-        left: `{:?}`,
-       right: `{:?}`,
-    abs_diff: `{:?}`,
-   ulps_diff: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertFloatEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertFloatEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::FloatCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        format_args!($($arg)+)
-                    )
-                }
+    (@fail [$header:expr] [$left_val:ident, $right_val:ident, [$($arg:tt)*], $fail:ident] [$($eq:ident $tol:ident)+]) => ({
+        // The reborrows below are intentional. See assert_eq! in the standard library.
+        $crate::assert_float_eq!(@raise $fail,
+            $header,
+            &*$left_val,
+            &*$right_val,
+            &[$(&$crate::LazyEqCheck {
+                name: concat!("[", stringify!($eq), "]"),
+                left: &*$left_val,
+                right: &*$right_val,
+                debug_tol: $crate::FloatCmpOpTol::$eq,
+                diffs: $crate::no_assert_diffs,
+                eq: ::core::option::Option::None,
+                tol: &*$tol,
+            },)+],
+            $crate::assert_float_eq!(@error_message $($arg)*)
+        )
+    });
+    ($left:expr, $right:expr, $($checks:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_float_ne!(@bind [left_val, right_val, panic] [] [] [] $($checks)+)
             }
         }
     });
@@ -562,122 +392,684 @@ macro_rules! debug_assert_float_ne {
     ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_float_ne!($($arg)*); })
 }
 
+/// Checks that two floating point expressions are equal to each other, returning
+/// an error rather than panicking if they are not.
+///
+/// Accepts the same arguments as [`assert_float_eq!`], including an optional
+/// custom message, and evaluates to a `Result<(), FloatEqError>`. On failure,
+/// the [`FloatEqError`] holds the values of the expressions with their debug
+/// representations, along with the same [additional information] shown by the
+/// assert. This makes it suitable for validating data outside of tests, where
+/// a panic is not acceptable.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{check_float_eq, FloatEqError};
+/// fn validate(reading: f64, expected: f64) -> Result<(), FloatEqError> {
+///     check_float_eq!(reading, expected, rmax <= 0.001, "sensor {}", 3)?;
+///     Ok(())
+/// }
+///
+/// assert!(validate(20.001, 20.0).is_ok());
+///
+/// let err = validate(21.0, 20.0).unwrap_err();
+/// assert_eq!(err.message(), Some("sensor 3"));
+/// assert!(err.to_string().starts_with("check failed: `float_eq!(left, right, rmax <= t)`"));
+/// ```
+///
+/// [`FloatEqError`]: struct.FloatEqError.html
+/// [additional information]: https://jtempest.github.io/float_eq-rs/book/how_to/interpret_assert_failure_messages.html
+#[macro_export]
+macro_rules! check_float_eq {
+    ($left:expr, $right:expr, $($checks:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_float_eq!(@bind [left_val, right_val, check] [] [] [] $($checks)+)
+            }
+        }
+    });
+}
+
+/// Checks that two floating point expressions are not equal to each other,
+/// returning an error rather than panicking if they are.
+///
+/// Accepts the same arguments as [`assert_float_ne!`], including an optional
+/// custom message, and evaluates to a `Result<(), FloatEqError>`. On failure,
+/// the [`FloatEqError`] holds the values of the expressions with their debug
+/// representations, along with the same [additional information] shown by the
+/// assert.
+///
+/// # Examples
+/// ```
+/// # use float_eq::check_float_ne;
+/// assert!(check_float_ne!(1.0_f32, 1.1_f32, ulps <= 4).is_ok());
+///
+/// let err = check_float_ne!(1.0_f32, 1.000_000_1_f32, ulps <= 4).unwrap_err();
+/// assert_eq!(err.check(), "float_ne!(left, right, ulps <= t)");
+/// assert_eq!(err.ulps_diff(), "Some(1)");
+/// ```
+///
+/// [`FloatEqError`]: struct.FloatEqError.html
+/// [additional information]: https://jtempest.github.io/float_eq-rs/book/how_to/interpret_assert_failure_messages.html
+#[macro_export]
+macro_rules! check_float_ne {
+    ($left:expr, $right:expr, $($checks:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_float_ne!(@bind [left_val, right_val, check] [] [] [] $($checks)+)
+            }
+        }
+    });
+}
+
+/// Checks if two floating point expressions are ordered, within the tolerance of
+/// any of the checks. Used to implement [`float_le!`] and its siblings.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! float_ord {
+    (@any $op:ident, $a:ident, $b:ident,) => (false);
+    (@any $op:ident, $a:ident, $b:ident, all($($eq:ident <= $tol:expr),+ $(,)?) $(, $($rest:tt)*)?) => (
+        (true $(&& $crate::FloatOrdCmp::$eq($a, $b, $crate::FloatOrdOp::$op, &$tol))+)
+            || $crate::float_ord!(@any $op, $a, $b, $($($rest)*)?)
+    );
+    (@any $op:ident, $a:ident, $b:ident, $eq:ident <= $tol:expr $(, $($rest:tt)*)?) => (
+        $crate::FloatOrdCmp::$eq($a, $b, $crate::FloatOrdOp::$op, &$tol)
+            || $crate::float_ord!(@any $op, $a, $b, $($($rest)*)?)
+    );
+    ($op:ident, $a:expr, $b:expr, $($checks:tt)+) => ({
+        match (&$a, &$b) {
+            (a_val, b_val) => {
+                $crate::float_ord!(@any $op, a_val, b_val, $($checks)+)
+            }
+        }
+    });
+}
+
+/// Asserts that two floating point expressions are ordered, within the
+/// tolerance of any of the checks. Used to implement [`assert_float_le!`] and
+/// its siblings.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_float_ord {
+    // each tolerance is bound in turn, building up the checks to evaluate, the
+    // header of the message and the checks to itemise on failure, and leaving
+    // any tokens after the last check as the format message
+    (@bind [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] all($($group:tt)+) $(, $($rest:tt)*)?) => ({
+        $crate::assert_float_ord!(@bind_all [$($vals)*] [$($header)*, ", all("] [$($checks)*] [$($items)*] [] "" [$($group)+] $($($rest)*)?)
+    });
+    (@bind [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] $eq:ident <= $tol:expr $(, $($rest:tt)*)?) => ({
+        match &$tol {
+            tol_val => $crate::assert_float_ord!(@bind [$($vals)*]
+                [$($header)*, ", ", stringify!($eq), " <= t"]
+                [$($checks)* $eq <= *tol_val,]
+                [$($items)* $eq tol_val (::core::option::Option::None)]
+                $($($rest)*)?
+            )
+        }
+    });
+    (@bind [$name:literal $op:ident, $left_val:ident, $right_val:ident] [$($header:tt)*] [$($checks:tt)+] [$($items:tt)+] $($arg:tt)*) => ({
+        if !$crate::float_ord!($op, *$left_val, *$right_val, $($checks)+) {
+            $crate::assert_float_ord!(@fail
+                [concat!($name, "(left, right" $($header)*, ")")]
+                [$op, $left_val, $right_val, [$($arg)*]]
+                [$($items)+]
+            )
+        }
+    });
+    // failures are only marked in the tolerances of an `all(...)` group, since
+    // otherwise every check failed
+    (@bind_all [$name:literal $op:ident, $left_val:ident, $right_val:ident] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] [$($group:tt)*] $sep:literal [$eq:ident <= $tol:expr $(, $($next:tt)*)?] $($rest:tt)*) => ({
+        match &$tol {
+            tol_val => $crate::assert_float_ord!(@bind_all [$name $op, $left_val, $right_val]
+                [$($header)*, $sep, stringify!($eq), " <= t"]
+                [$($checks)*]
+                [$($items)* $eq tol_val (::core::option::Option::Some($crate::FloatOrdCmp::$eq))]
+                [$($group)* $eq <= *tol_val,]
+                ", "
+                [$($($next)*)?]
+                $($rest)*
+            )
+        }
+    });
+    (@bind_all [$($vals:tt)*] [$($header:tt)*] [$($checks:tt)*] [$($items:tt)*] [$($group:tt)+] $sep:literal [] $($rest:tt)*) => ({
+        $crate::assert_float_ord!(@bind [$($vals)*] [$($header)*, ")"] [$($checks)* all($($group)+),] [$($items)*] $($rest)*)
+    });
+    (@fail [$header:expr] [$op:ident, $left_val:ident, $right_val:ident, [$($arg:tt)*]] [$($eq:ident $tol:ident $cmp:tt)+]) => ({
+        // The reborrows below are intentional. See assert_eq! in the standard library.
+        $crate::assert_failed(
+            $header,
+            &*$left_val,
+            &*$right_val,
+            &[$(&$crate::LazyOrdCheck {
+                name: concat!("[", stringify!($eq), "]"),
+                left: &*$left_val,
+                right: &*$right_val,
+                op: $crate::FloatOrdOp::$op,
+                debug_tol: $crate::FloatCmpOpTol::$eq,
+                past_bound: $crate::FloatOrdPastBound::$eq,
+                ord: $cmp,
+                tol: &*$tol,
+            },)+],
+            $crate::assert_float_eq!(@error_message $($arg)*)
+        )
+    });
+    ($name:literal $op:ident, $left:expr, $right:expr, $($checks:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_float_ord!(@bind [$name $op, left_val, right_val] [] [] [] $($checks)+)
+            }
+        }
+    });
+}
+
+/// Checks if a floating point expression is less than or equal to another.
+///
+/// The left value is considered less than or equal to the right value unless it
+/// is greater by more than the tolerance. See [`FloatOrdOp`] for details and
+/// the top level documentation for a list of available [comparison algorithms].
+///
+/// # Examples
+/// ```
+/// # use float_eq::float_le;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// assert!(float_le!(a, b, ulps <= 0));
+/// assert!(float_le!(b, a, ulps <= 10));
+/// assert!(float_le!(b, a, abs <= 0.000_01));
+/// assert!(float_le!(b, a, all(abs <= 0.000_01, ulps <= 10)));
+/// ```
+///
+/// [`FloatOrdOp`]: enum.FloatOrdOp.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! float_le {
+    ($a:expr, $b:expr, $($checks:tt)+) => ($crate::float_ord!(Le, $a, $b, $($checks)+));
+}
+
+/// Checks if a floating point expression is less than another.
+///
+/// The left value is only considered less than the right value by more than the
+/// tolerance. See [`FloatOrdOp`] for details and the top level documentation
+/// for a list of available [comparison algorithms].
+///
+/// # Examples
+/// ```
+/// # use float_eq::float_lt;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// assert!(float_lt!(a, b, ulps <= 4));
+/// assert!(float_lt!(a, b, rmax <= 2.0 * f32::EPSILON));
+/// assert!(float_lt!(a, b, abs <= 0.000_001));
+/// assert!(float_lt!(a, b, all(abs <= 0.000_001, ulps <= 4)));
+/// ```
+///
+/// [`FloatOrdOp`]: enum.FloatOrdOp.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! float_lt {
+    ($a:expr, $b:expr, $($checks:tt)+) => ($crate::float_ord!(Lt, $a, $b, $($checks)+));
+}
+
+/// Checks if a floating point expression is greater than or equal to another.
+///
+/// The left value is considered greater than or equal to the right value unless
+/// it is less by more than the tolerance. See [`FloatOrdOp`] for details and
+/// the top level documentation for a list of available [comparison algorithms].
+///
+/// # Examples
+/// ```
+/// # use float_eq::float_ge;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// assert!(float_ge!(b, a, ulps <= 0));
+/// assert!(float_ge!(a, b, ulps <= 10));
+/// assert!(float_ge!(a, b, abs <= 0.000_01));
+/// assert!(float_ge!(a, b, all(abs <= 0.000_01, ulps <= 10)));
+/// ```
+///
+/// [`FloatOrdOp`]: enum.FloatOrdOp.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! float_ge {
+    ($a:expr, $b:expr, $($checks:tt)+) => ($crate::float_ord!(Ge, $a, $b, $($checks)+));
+}
+
+/// Checks if a floating point expression is greater than another.
+///
+/// The left value is only considered greater than the right value by more than
+/// the tolerance. See [`FloatOrdOp`] for details and the top level
+/// documentation for a list of available [comparison algorithms].
+///
+/// # Examples
+/// ```
+/// # use float_eq::float_gt;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// assert!(float_gt!(b, a, ulps <= 4));
+/// assert!(float_gt!(b, a, rmax <= 2.0 * f32::EPSILON));
+/// assert!(float_gt!(b, a, abs <= 0.000_001));
+/// assert!(float_gt!(b, a, all(abs <= 0.000_001, ulps <= 4)));
+/// ```
+///
+/// [`FloatOrdOp`]: enum.FloatOrdOp.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! float_gt {
+    ($a:expr, $b:expr, $($checks:tt)+) => ($crate::float_ord!(Gt, $a, $b, $($checks)+));
+}
+
+/// Asserts that a floating point expression is less than or equal to another.
+///
+/// See [`float_le!`] for how the tolerance is applied, and the top level
+/// documentation for a list of available [comparison algorithms].
+///
+/// On panic, this macro will print the values of the expressions with their debug
+/// representations, along with how far the left value was past the bound of each
+/// check, as calculated by [`AssertFloatOrd`]. Like [`assert!`], this macro has a
+/// second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_le;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// assert_float_le!(a, b, ulps <= 0);
+/// assert_float_le!(b, a, ulps <= 10);
+/// assert_float_le!(b, a, abs <= 0.000_01, "Checking that {} <= {}", b, a);
+/// assert_float_le!(b, a, all(abs <= 0.000_01, ulps <= 10));
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`AssertFloatOrd`]: trait.AssertFloatOrd.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! assert_float_le {
+    ($($arg:tt)+) => ($crate::assert_float_ord!("float_le!" Le, $($arg)+));
+}
+
+/// Asserts that a floating point expression is less than another.
+///
+/// See [`float_lt!`] for how the tolerance is applied, and the top level
+/// documentation for a list of available [comparison algorithms].
+///
+/// On panic, this macro will print the values of the expressions with their debug
+/// representations, along with how far the left value was past the bound of each
+/// check, as calculated by [`AssertFloatOrd`]. Like [`assert!`], this macro has a
+/// second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_lt;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// assert_float_lt!(a, b, ulps <= 4);
+/// assert_float_lt!(a, b, rmax <= 2.0 * f32::EPSILON);
+/// assert_float_lt!(a, b, abs <= 0.000_001, "Checking that {} < {}", a, b);
+/// assert_float_lt!(a, b, all(abs <= 0.000_001, ulps <= 4));
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`AssertFloatOrd`]: trait.AssertFloatOrd.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! assert_float_lt {
+    ($($arg:tt)+) => ($crate::assert_float_ord!("float_lt!" Lt, $($arg)+));
+}
+
+/// Asserts that a floating point expression is greater than or equal to another.
+///
+/// See [`float_ge!`] for how the tolerance is applied, and the top level
+/// documentation for a list of available [comparison algorithms].
+///
+/// On panic, this macro will print the values of the expressions with their debug
+/// representations, along with how far the left value was past the bound of each
+/// check, as calculated by [`AssertFloatOrd`]. Like [`assert!`], this macro has a
+/// second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_ge;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// assert_float_ge!(b, a, ulps <= 0);
+/// assert_float_ge!(a, b, ulps <= 10);
+/// assert_float_ge!(a, b, abs <= 0.000_01, "Checking that {} >= {}", a, b);
+/// assert_float_ge!(a, b, all(abs <= 0.000_01, ulps <= 10));
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`AssertFloatOrd`]: trait.AssertFloatOrd.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! assert_float_ge {
+    ($($arg:tt)+) => ($crate::assert_float_ord!("float_ge!" Ge, $($arg)+));
+}
+
+/// Asserts that a floating point expression is greater than another.
+///
+/// See [`float_gt!`] for how the tolerance is applied, and the top level
+/// documentation for a list of available [comparison algorithms].
+///
+/// On panic, this macro will print the values of the expressions with their debug
+/// representations, along with how far the left value was past the bound of each
+/// check, as calculated by [`AssertFloatOrd`]. Like [`assert!`], this macro has a
+/// second form, where a custom panic message can be provided.
+///
+/// # Examples
+/// ```
+/// # use float_eq::assert_float_gt;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// assert_float_gt!(b, a, ulps <= 4);
+/// assert_float_gt!(b, a, rmax <= 2.0 * f32::EPSILON);
+/// assert_float_gt!(b, a, abs <= 0.000_001, "Checking that {} > {}", b, a);
+/// assert_float_gt!(b, a, all(abs <= 0.000_001, ulps <= 4));
+/// ```
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`AssertFloatOrd`]: trait.AssertFloatOrd.html
+/// [comparison algorithms]: index.html#comparison-algorithms
+#[macro_export]
+macro_rules! assert_float_gt {
+    ($($arg:tt)+) => ($crate::assert_float_ord!("float_gt!" Gt, $($arg)+));
+}
+
+/// Asserts that a floating point expression is less than or equal to another.
+///
+/// Unlike [`assert_float_le!`], `debug_assert_float_le!` statements are only enabled
+/// in non optimized builds by default. See [`debug_assert!`] for more details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::debug_assert_float_le;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// debug_assert_float_le!(a, b, ulps <= 0);
+/// debug_assert_float_le!(b, a, ulps <= 10);
+/// debug_assert_float_le!(b, a, abs <= 0.000_01, "Checking that {} <= {}", b, a);
+/// ```
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+#[macro_export]
+macro_rules! debug_assert_float_le {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_float_le!($($arg)*); })
+}
+
+/// Asserts that a floating point expression is less than another.
+///
+/// Unlike [`assert_float_lt!`], `debug_assert_float_lt!` statements are only enabled
+/// in non optimized builds by default. See [`debug_assert!`] for more details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::debug_assert_float_lt;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// debug_assert_float_lt!(a, b, ulps <= 4);
+/// debug_assert_float_lt!(a, b, rmax <= 2.0 * f32::EPSILON);
+/// debug_assert_float_lt!(a, b, abs <= 0.000_001, "Checking that {} < {}", a, b);
+/// ```
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+#[macro_export]
+macro_rules! debug_assert_float_lt {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_float_lt!($($arg)*); })
+}
+
+/// Asserts that a floating point expression is greater than or equal to another.
+///
+/// Unlike [`assert_float_ge!`], `debug_assert_float_ge!` statements are only enabled
+/// in non optimized builds by default. See [`debug_assert!`] for more details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::debug_assert_float_ge;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// debug_assert_float_ge!(b, a, ulps <= 0);
+/// debug_assert_float_ge!(a, b, ulps <= 10);
+/// debug_assert_float_ge!(a, b, abs <= 0.000_01, "Checking that {} >= {}", a, b);
+/// ```
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+#[macro_export]
+macro_rules! debug_assert_float_ge {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_float_ge!($($arg)*); })
+}
+
+/// Asserts that a floating point expression is greater than another.
+///
+/// Unlike [`assert_float_gt!`], `debug_assert_float_gt!` statements are only enabled
+/// in non optimized builds by default. See [`debug_assert!`] for more details.
+///
+/// # Examples
+/// ```
+/// # use float_eq::debug_assert_float_gt;
+/// # use std::f32;
+/// let a: f32 = 4.0;
+/// let b: f32 = 4.000_002_5;
+///
+/// debug_assert_float_gt!(b, a, ulps <= 4);
+/// debug_assert_float_gt!(b, a, rmax <= 2.0 * f32::EPSILON);
+/// debug_assert_float_gt!(b, a, abs <= 0.000_001, "Checking that {} > {}", b, a);
+/// ```
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+#[macro_export]
+macro_rules! debug_assert_float_gt {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_float_gt!($($arg)*); })
+}
+
+/// Compares two floating point expressions, returning a structured [`Report`]
+/// of the result instead of a `bool`.
+///
+/// Takes a single check of the same form as [`float_eq!`]. The report
+/// describes the comparison of every field and element of the two values,
+/// including their absolute and ULPs differences and the effective tolerance
+/// each was compared to, so that failures may be logged or displayed without
+/// panicking. Requires the `std` feature.
+///
+/// # Examples
+/// ```
+/// # use float_eq::{compare, ReportKind};
+/// let a = [1.0_f32, 2.0, 3.0];
+/// let b = [1.0_f32, 2.000_001, 3.5];
+///
+/// let report = compare!(a, b, rmax <= [0.0, 0.000_001, 0.1]);
+/// assert!(!report.passed());
+///
+/// let failures: Vec<_> = report.failures().collect();
+/// assert_eq!(failures.len(), 1);
+/// assert_eq!(failures[0].path, "[2]");
+/// if let ReportKind::Float(float) = &failures[0].kind {
+///     assert_eq!(float.abs_diff, 0.5);
+/// }
+/// ```
+///
+/// [`Report`]: struct.Report.html
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! compare {
+    ($a:expr, $b:expr, $eq:ident <= $tol:expr) => {{
+        $crate::FloatEqReportCmp::$eq(&$a, &$b, &$tol)
+    }};
+    ($a:expr, $b:expr, $eq:ident <= $tol:expr,) => {{
+        $crate::compare!($a, $b, $eq <= $tol)
+    }};
+}
+
 #[doc(hidden)]
 pub struct FloatEqCmp;
 
 #[doc(hidden)]
 impl FloatEqCmp {
     #[inline]
-    pub fn abs<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    pub fn abs<A, B>(a: &A, b: &B, tol: &A::Tol) -> bool
     where
-        A: FloatEq<B>,
+        A: ?Sized + FloatEq<B>,
+        B: ?Sized,
     {
         a.eq_abs(b, tol)
     }
 
     #[inline]
-    pub fn abs_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    pub fn abs_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> bool
     where
-        A: FloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B>,
+        B: ?Sized,
     {
         a.eq_abs_all(b, tol)
     }
 
     #[inline]
-    pub fn rel<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    pub fn rel<A, B>(a: &A, b: &B, tol: &A::Tol) -> bool
     where
-        A: FloatEq<B>,
+        A: ?Sized + FloatEq<B>,
+        B: ?Sized,
     {
         a.eq_rel(b, tol)
     }
 
     #[inline]
-    pub fn rel_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    pub fn rel_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> bool
     where
-        A: FloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B>,
+        B: ?Sized,
     {
         a.eq_rel_all(b, tol)
     }
 
     #[inline]
-    pub fn rmax<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    pub fn rmax<A, B>(a: &A, b: &B, tol: &A::Tol) -> bool
     where
-        A: FloatEq<B>,
+        A: ?Sized + FloatEq<B>,
+        B: ?Sized,
     {
         a.eq_rmax(b, tol)
     }
 
     #[inline]
-    pub fn rmax_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    pub fn rmax_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> bool
     where
-        A: FloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B>,
+        B: ?Sized,
     {
         a.eq_rmax_all(b, tol)
     }
 
     #[inline]
-    pub fn rmin<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    pub fn rmin<A, B>(a: &A, b: &B, tol: &A::Tol) -> bool
     where
-        A: FloatEq<B>,
+        A: ?Sized + FloatEq<B>,
+        B: ?Sized,
     {
         a.eq_rmin(b, tol)
     }
 
     #[inline]
-    pub fn rmin_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    pub fn rmin_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> bool
     where
-        A: FloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B>,
+        B: ?Sized,
     {
         a.eq_rmin_all(b, tol)
     }
 
     #[inline]
-    pub fn r1st<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    pub fn r1st<A, B>(a: &A, b: &B, tol: &A::Tol) -> bool
     where
-        A: FloatEq<B>,
+        A: ?Sized + FloatEq<B>,
+        B: ?Sized,
     {
         a.eq_r1st(b, tol)
     }
 
     #[inline]
-    pub fn r1st_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    pub fn r1st_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> bool
     where
-        A: FloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B>,
+        B: ?Sized,
     {
         a.eq_r1st_all(b, tol)
     }
 
     #[inline]
-    pub fn r2nd<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> bool
+    pub fn r2nd<A, B>(a: &A, b: &B, tol: &A::Tol) -> bool
     where
-        A: FloatEq<B>,
+        A: ?Sized + FloatEq<B>,
+        B: ?Sized,
     {
         a.eq_r2nd(b, tol)
     }
 
     #[inline]
-    pub fn r2nd_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> bool
+    pub fn r2nd_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> bool
     where
-        A: FloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B>,
+        B: ?Sized,
     {
         a.eq_r2nd_all(b, tol)
     }
 
     #[inline]
-    pub fn ulps<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &UlpsTol<A::Tol>) -> bool
+    pub fn ulps<A, B>(a: &A, b: &B, tol: &UlpsTol<A::Tol>) -> bool
     where
-        A: FloatEq<B>,
+        A: ?Sized + FloatEq<B>,
+        B: ?Sized,
     {
         a.eq_ulps(b, tol)
     }
 
     #[inline]
-    pub fn ulps_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &UlpsTol<A::AllTol>) -> bool
+    pub fn ulps_all<A, B>(a: &A, b: &B, tol: &UlpsTol<A::AllTol>) -> bool
     where
-        A: FloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B>,
+        B: ?Sized,
     {
         a.eq_ulps_all(b, tol)
     }
+
+    #[inline]
+    pub fn atol_rtol<A, B>(a: &A, b: &B, tol: &(A::Tol, A::Tol)) -> bool
+    where
+        A: ?Sized + FloatEq<B>,
+        A::Tol: Sized,
+        B: ?Sized,
+    {
+        a.eq_atol_rtol(b, &tol.0, &tol.1)
+    }
+
+    #[inline]
+    pub fn atol_rtol_all<A, B>(a: &A, b: &B, tol: &(A::AllTol, A::AllTol)) -> bool
+    where
+        A: ?Sized + FloatEqAll<B>,
+        A::AllTol: Sized,
+        B: ?Sized,
+    {
+        a.eq_atol_rtol_all(b, &tol.0, &tol.1)
+    }
 }
 
 #[doc(hidden)]
@@ -686,120 +1078,652 @@ pub struct FloatCmpOpTol;
 #[doc(hidden)]
 impl FloatCmpOpTol {
     #[inline]
-    pub fn abs<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
+    pub fn abs<A, B>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
     where
-        A: FloatEq<B> + AssertFloatEq<B>,
+        A: ?Sized + FloatEq<B> + AssertFloatEq<B>,
+        B: ?Sized,
     {
         a.debug_abs_tol(b, tol)
     }
 
     #[inline]
-    pub fn abs_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
+    pub fn abs_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
     where
-        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B> + AssertFloatEqAll<B>,
+        B: ?Sized,
     {
         a.debug_abs_all_tol(b, tol)
     }
 
     #[inline]
-    pub fn rel<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
+    pub fn rel<A, B>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
     where
-        A: FloatEq<B> + AssertFloatEq<B>,
+        A: ?Sized + FloatEq<B> + AssertFloatEq<B>,
+        B: ?Sized,
     {
         a.debug_rel_tol(b, tol)
     }
 
     #[inline]
-    pub fn rel_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
+    pub fn rel_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
     where
-        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B> + AssertFloatEqAll<B>,
+        B: ?Sized,
     {
         a.debug_rel_all_tol(b, tol)
     }
 
     #[inline]
-    pub fn rmax<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
+    pub fn rmax<A, B>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
     where
-        A: FloatEq<B> + AssertFloatEq<B>,
+        A: ?Sized + FloatEq<B> + AssertFloatEq<B>,
+        B: ?Sized,
     {
         a.debug_rmax_tol(b, tol)
     }
 
     #[inline]
-    pub fn rmax_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
+    pub fn rmax_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
     where
-        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B> + AssertFloatEqAll<B>,
+        B: ?Sized,
     {
         a.debug_rmax_all_tol(b, tol)
     }
 
     #[inline]
-    pub fn rmin<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
+    pub fn rmin<A, B>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
     where
-        A: FloatEq<B> + AssertFloatEq<B>,
+        A: ?Sized + FloatEq<B> + AssertFloatEq<B>,
+        B: ?Sized,
     {
         a.debug_rmin_tol(b, tol)
     }
 
     #[inline]
-    pub fn rmin_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
+    pub fn rmin_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
     where
-        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B> + AssertFloatEqAll<B>,
+        B: ?Sized,
     {
         a.debug_rmin_all_tol(b, tol)
     }
 
     #[inline]
-    pub fn r1st<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
+    pub fn r1st<A, B>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
     where
-        A: FloatEq<B> + AssertFloatEq<B>,
+        A: ?Sized + FloatEq<B> + AssertFloatEq<B>,
+        B: ?Sized,
     {
         a.debug_r1st_tol(b, tol)
     }
 
     #[inline]
-    pub fn r1st_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
+    pub fn r1st_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
     where
-        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B> + AssertFloatEqAll<B>,
+        B: ?Sized,
     {
         a.debug_r1st_all_tol(b, tol)
     }
 
     #[inline]
-    pub fn r2nd<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
+    pub fn r2nd<A, B>(a: &A, b: &B, tol: &A::Tol) -> A::DebugTol
     where
-        A: FloatEq<B> + AssertFloatEq<B>,
+        A: ?Sized + FloatEq<B> + AssertFloatEq<B>,
+        B: ?Sized,
     {
         a.debug_r2nd_tol(b, tol)
     }
 
     #[inline]
-    pub fn r2nd_all<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
+    pub fn r2nd_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> A::AllDebugTol
     where
-        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B> + AssertFloatEqAll<B>,
+        B: ?Sized,
     {
         a.debug_r2nd_all_tol(b, tol)
     }
 
     #[inline]
-    pub fn ulps<A: ?Sized, B: ?Sized>(a: &A, b: &B, tol: &UlpsTol<A::Tol>) -> UlpsTol<A::DebugTol>
+    pub fn ulps<A, B>(a: &A, b: &B, tol: &UlpsTol<A::Tol>) -> UlpsTol<A::DebugTol>
     where
-        A: FloatEq<B> + AssertFloatEq<B>,
+        A: ?Sized + FloatEq<B> + AssertFloatEq<B>,
+        B: ?Sized,
         UlpsTol<A::DebugTol>: Sized,
     {
         a.debug_ulps_tol(b, tol)
     }
 
     #[inline]
-    pub fn ulps_all<A: ?Sized, B: ?Sized>(
-        a: &A,
-        b: &B,
-        tol: &UlpsTol<A::AllTol>,
-    ) -> UlpsTol<A::AllDebugTol>
+    pub fn ulps_all<A, B>(a: &A, b: &B, tol: &UlpsTol<A::AllTol>) -> UlpsTol<A::AllDebugTol>
     where
-        A: FloatEqAll<B> + AssertFloatEqAll<B>,
+        A: ?Sized + FloatEqAll<B> + AssertFloatEqAll<B>,
+        B: ?Sized,
         UlpsTol<A::AllDebugTol>: Sized,
     {
         a.debug_ulps_all_tol(b, tol)
     }
+
+    #[inline]
+    pub fn atol_rtol<A, B>(a: &A, b: &B, tol: &(A::Tol, A::Tol)) -> A::DebugTol
+    where
+        A: ?Sized + FloatEq<B> + AssertFloatEq<B>,
+        A::Tol: Sized,
+        B: ?Sized,
+    {
+        a.debug_atol_rtol_tol(b, &tol.0, &tol.1)
+    }
+
+    #[inline]
+    pub fn atol_rtol_all<A, B>(a: &A, b: &B, tol: &(A::AllTol, A::AllTol)) -> A::AllDebugTol
+    where
+        A: ?Sized + FloatEqAll<B> + AssertFloatEqAll<B>,
+        A::AllTol: Sized,
+        B: ?Sized,
+    {
+        a.debug_atol_rtol_all_tol(b, &tol.0, &tol.1)
+    }
+}
+
+// Passed to the methods of the diffs comparison traits to choose the function
+// that itemises the failures of a check, without evaluating anything.
+#[doc(hidden)]
+pub struct DiffsProbe<A: ?Sized, B: ?Sized>(core::marker::PhantomData<fn(&A, &B)>);
+
+impl<A: ?Sized, B: ?Sized> DiffsProbe<A, B> {
+    #[inline]
+    pub fn new(_left: &A, _right: &B) -> Self {
+        DiffsProbe(core::marker::PhantomData)
+    }
+}
+
+#[cfg(feature = "std")]
+type CheckDiffs = crate::AssertDiffs;
+#[cfg(not(feature = "std"))]
+type CheckDiffs = NoAssertDiffs;
+
+// Values whose types do not implement the diffs traits, or any values without
+// std, are never itemised and so are described by the full message.
+#[doc(hidden)]
+pub trait NoDiffsCmp<A: ?Sized, B: ?Sized> {
+    #[inline]
+    fn abs<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn abs_all<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn rel<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn rel_all<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn rmax<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn rmax_all<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn rmin<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn rmin_all<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn r1st<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn r1st_all<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn r2nd<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn r2nd_all<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn ulps<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn ulps_all<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn atol_rtol<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+
+    #[inline]
+    fn atol_rtol_all<T: ?Sized>(&self) -> fn(&A, &B, &T) -> Option<CheckDiffs> {
+        |_, _, _| None
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> NoDiffsCmp<A, B> for &DiffsProbe<A, B> {}
+
+// Imported by the macros to bring every diffs comparison trait into scope.
+#[doc(hidden)]
+pub mod __diffs_cmp {
+    #[cfg(feature = "std")]
+    pub use crate::assert_diffs::{FloatEqAllDiffsCmp, FloatEqDiffsCmp};
+    pub use crate::macros::NoDiffsCmp;
+}
+
+// Without std failures cannot be itemised, so asserts always use the full
+// message, as do asserts of inequality and ordering.
+#[doc(hidden)]
+pub struct NoAssertDiffs;
+
+#[doc(hidden)]
+#[inline]
+pub fn no_assert_diffs<A: ?Sized, B: ?Sized, T: ?Sized>(
+    _left: &A,
+    _right: &B,
+    _tol: &T,
+) -> Option<NoAssertDiffs> {
+    None
+}
+
+impl core::fmt::Display for NoAssertDiffs {
+    fn fmt(&self, _f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+// A check made by a failed assert, which computes what its message lists about
+// the check only once it is formatted.
+#[doc(hidden)]
+pub trait AssertCheck<D> {
+    // Writes the tolerance of the check, marked if it failed in an `all(...)`
+    // group.
+    fn fmt_tol(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+
+    // The name of the check, whether it failed and its itemised failures.
+    fn diffs(&self) -> (&'static str, bool, Option<D>);
+}
+
+// A check of an equality assert, expanded once per check. Its debug tolerance,
+// whether it failed and its itemised failures are all computed from the same
+// operands and tolerance, by functions shared between asserts of the same
+// types.
+#[doc(hidden)]
+pub struct LazyEqCheck<'a, A: ?Sized, B: ?Sized, T: ?Sized, R, D> {
+    pub name: &'static str,
+    pub left: &'a A,
+    pub right: &'a B,
+    pub debug_tol: fn(&A, &B, &T) -> R,
+    pub diffs: fn(&A, &B, &T) -> Option<D>,
+    // Only the checks of an `all(...)` group are compared again, since
+    // otherwise every check failed.
+    pub eq: Option<fn(&A, &B, &T) -> bool>,
+    pub tol: &'a T,
+}
+
+impl<A: ?Sized, B: ?Sized, T: ?Sized, R, D> LazyEqCheck<'_, A, B, T, R, D> {
+    fn failed(&self) -> bool {
+        self.eq
+            .map_or(true, |eq| !eq(self.left, self.right, self.tol))
+    }
+}
+
+impl<A: ?Sized, B: ?Sized, T: ?Sized, R: core::fmt::Debug, D> AssertCheck<D>
+    for LazyEqCheck<'_, A, B, T, R, D>
+{
+    fn fmt_tol(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let tol = (self.debug_tol)(self.left, self.right, self.tol);
+        let marked = self.eq.is_some() && self.failed();
+        AssertCheckTol::new(self.name, marked, &tol).fmt_tol(f)
+    }
+
+    fn diffs(&self) -> (&'static str, bool, Option<D>) {
+        let diffs = (self.diffs)(self.left, self.right, self.tol);
+        (self.name, self.failed(), diffs)
+    }
+}
+
+type OrdCmpFn<A, B, T> = fn(&A, &B, FloatOrdOp, &T) -> bool;
+
+// A check of an ordering assert, which also displays how far the left value
+// was past the bound.
+#[doc(hidden)]
+pub struct LazyOrdCheck<'a, A: ?Sized, B: ?Sized, T: ?Sized, R, P> {
+    pub name: &'static str,
+    pub left: &'a A,
+    pub right: &'a B,
+    pub op: FloatOrdOp,
+    pub debug_tol: fn(&A, &B, &T) -> R,
+    pub past_bound: fn(&A, &B, FloatOrdOp, &T) -> P,
+    // Only the checks of an `all(...)` group are compared again, since
+    // otherwise every check failed.
+    pub ord: Option<OrdCmpFn<A, B, T>>,
+    pub tol: &'a T,
+}
+
+impl<A, B, T, R, P> AssertCheck<NoAssertDiffs> for LazyOrdCheck<'_, A, B, T, R, P>
+where
+    A: ?Sized,
+    B: ?Sized,
+    T: ?Sized,
+    R: core::fmt::Debug,
+    P: core::fmt::Debug,
+{
+    fn fmt_tol(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let tol = (self.debug_tol)(self.left, self.right, self.tol);
+        let past_bound = (self.past_bound)(self.left, self.right, self.op, self.tol);
+        let marked = self
+            .ord
+            .map_or(false, |ord| !ord(self.left, self.right, self.op, self.tol));
+        AssertCheckTol::new(self.name, marked, &tol)
+            .with_past_bound(&past_bound)
+            .fmt_tol(f)
+    }
+
+    fn diffs(&self) -> (&'static str, bool, Option<NoAssertDiffs>) {
+        (self.name, true, None)
+    }
+}
+
+#[doc(hidden)]
+pub struct AssertCheckTol<'a> {
+    name: &'static str,
+    failed: bool,
+    tol: &'a dyn core::fmt::Debug,
+    past_bound: Option<&'a dyn core::fmt::Debug>,
+}
+
+#[doc(hidden)]
+impl<'a> AssertCheckTol<'a> {
+    #[inline]
+    pub fn new<T: core::fmt::Debug>(name: &'static str, failed: bool, tol: &'a T) -> Self {
+        AssertCheckTol {
+            name,
+            failed,
+            tol,
+            past_bound: None,
+        }
+    }
+
+    // Ordering checks also display how far the left value was past the bound.
+    #[inline]
+    pub fn with_past_bound<T: core::fmt::Debug>(mut self, past_bound: &'a T) -> Self {
+        self.past_bound = Some(past_bound);
+        self
+    }
+
+    pub(crate) fn fmt_tol(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:>10} t: `{:?}`", self.name, self.tol)?;
+        if let Some(past_bound) = self.past_bound {
+            write!(f, ", past_bound: `{:?}`", past_bound)?;
+        }
+        if self.failed {
+            f.write_str(" (failed)")?;
+        }
+        Ok(())
+    }
+}
+
+// The tolerances of each check made by an assert, one per line, marking those
+// that failed.
+struct AssertCheckTols<'a, D>(&'a [&'a dyn AssertCheck<D>]);
+
+impl<D> core::fmt::Display for AssertCheckTols<'_, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, check) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",\n")?;
+            }
+            check.fmt_tol(f)?;
+        }
+        Ok(())
+    }
+}
+
+// The itemised failures of each check that failed in an assert, if every check
+// is able to itemise them.
+#[doc(hidden)]
+pub struct AssertCheckDiffs<'a, D>(&'a [(&'static str, bool, Option<D>)]);
+
+#[doc(hidden)]
+impl<'a, D> AssertCheckDiffs<'a, D> {
+    #[inline]
+    pub fn new(checks: &'a [(&'static str, bool, Option<D>)]) -> Option<Self> {
+        if !checks.is_empty() && checks.iter().all(|(_, _, diffs)| diffs.is_some()) {
+            Some(AssertCheckDiffs(checks))
+        } else {
+            None
+        }
+    }
+}
+
+impl<D: core::fmt::Display> core::fmt::Display for AssertCheckDiffs<'_, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let failed = self.0.iter().filter(|(_, failed, _)| *failed);
+        for (i, (name, _, diffs)) in failed.enumerate() {
+            if i > 0 {
+                f.write_str(",\n")?;
+            }
+            if let Some(diffs) = diffs {
+                write!(f, "{:>10} {}", name, diffs)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Panics with the message of a failed assert.
+///
+/// Called by the assert macros once a comparison has failed, so that the
+/// message is formatted out of line rather than in each expansion. Each failed
+/// check of an `assert_float_eq!` may itemise its failures, in which case only
+/// those are listed.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn assert_failed<A, B, D>(
+    header: &'static str,
+    left: &A,
+    right: &B,
+    checks: &[&dyn AssertCheck<D>],
+    message: Option<core::fmt::Arguments<'_>>,
+) -> !
+where
+    A: ?Sized + AssertFloatEq<B> + core::fmt::Debug,
+    B: ?Sized + core::fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: core::fmt::Debug,
+    D: core::fmt::Display,
+{
+    let diffs = check_diffs(checks);
+    assert_failed_with(
+        &header,
+        left,
+        right,
+        &AssertCheckTols(checks),
+        AssertCheckDiffs::new(&diffs).as_ref().map(|d| d as _),
+        message,
+    )
+}
+
+// The out of line body of `assert_failed`, which also panics for asserts whose
+// header and tolerances are only known at runtime, such as those of a
+// `CheckSet`.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn assert_failed_with<A, B>(
+    header: &dyn core::fmt::Display,
+    left: &A,
+    right: &B,
+    tols: &dyn core::fmt::Display,
+    diffs: Option<&dyn core::fmt::Display>,
+    message: Option<core::fmt::Arguments<'_>>,
+) -> !
+where
+    A: ?Sized + AssertFloatEq<B> + core::fmt::Debug,
+    B: ?Sized + core::fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: core::fmt::Debug,
+{
+    let abs_diff = left.debug_abs_diff(right);
+    let ulps_diff = left.debug_ulps_diff(right);
+    AssertFailure {
+        header,
+        left: &left,
+        right: &right,
+        abs_diff: &abs_diff,
+        ulps_diff: &ulps_diff,
+        tols,
+        diffs,
+        message,
+    }
+    .panic()
+}
+
+/// The message of a failed assert, as it would be given to [`assert_failed`].
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+pub fn assert_failure_message<A, B, D>(
+    header: &'static str,
+    left: &A,
+    right: &B,
+    checks: &[&dyn AssertCheck<D>],
+    message: Option<core::fmt::Arguments<'_>>,
+) -> String
+where
+    A: ?Sized + AssertFloatEq<B> + core::fmt::Debug,
+    B: ?Sized + core::fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: core::fmt::Debug,
+    D: core::fmt::Display,
+{
+    let abs_diff = left.debug_abs_diff(right);
+    let ulps_diff = left.debug_ulps_diff(right);
+    let diffs = check_diffs(checks);
+    AssertFailure {
+        header: &header,
+        left: &left,
+        right: &right,
+        abs_diff: &abs_diff,
+        ulps_diff: &ulps_diff,
+        tols: &AssertCheckTols(checks),
+        diffs: AssertCheckDiffs::new(&diffs).as_ref().map(|d| d as _),
+        message,
+    }
+    .to_string()
+}
+
+/// The error of a failed check, holding the message that [`assert_failed`]
+/// would panic with.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+pub fn check_failed<A, B, D>(
+    header: &'static str,
+    left: &A,
+    right: &B,
+    checks: &[&dyn AssertCheck<D>],
+    message: Option<core::fmt::Arguments<'_>>,
+) -> FloatEqError
+where
+    A: ?Sized + AssertFloatEq<B> + core::fmt::Debug,
+    B: ?Sized + core::fmt::Debug,
+    DebugUlpsDiff<A::DebugAbsDiff>: core::fmt::Debug,
+    D: core::fmt::Display,
+{
+    let diffs = check_diffs(checks);
+    FloatEqError::new(
+        header,
+        left,
+        right,
+        &left.debug_abs_diff(right),
+        &left.debug_ulps_diff(right),
+        &AssertCheckTols(checks),
+        AssertCheckDiffs::new(&diffs).as_ref().map(|d| d as _),
+        message,
+    )
+}
+
+// Computes the itemised failures of each check once an assert has failed.
+#[cfg(feature = "std")]
+fn check_diffs<D>(checks: &[&dyn AssertCheck<D>]) -> Vec<(&'static str, bool, Option<D>)> {
+    checks.iter().map(|check| check.diffs()).collect()
+}
+
+// Only collections, which require std, itemise their failures.
+#[cfg(not(feature = "std"))]
+fn check_diffs<D>(_checks: &[&dyn AssertCheck<D>]) -> [(&'static str, bool, Option<D>); 0] {
+    []
+}
+
+// Type erased so that the message is only formatted by a single function,
+// whatever the types of the values compared.
+struct AssertFailure<'a> {
+    header: &'a dyn core::fmt::Display,
+    left: &'a dyn core::fmt::Debug,
+    right: &'a dyn core::fmt::Debug,
+    abs_diff: &'a dyn core::fmt::Debug,
+    ulps_diff: &'a dyn core::fmt::Debug,
+    tols: &'a dyn core::fmt::Display,
+    diffs: Option<&'a dyn core::fmt::Display>,
+    message: Option<core::fmt::Arguments<'a>>,
+}
+
+impl AssertFailure<'_> {
+    #[cold]
+    #[inline(never)]
+    #[track_caller]
+    fn panic(&self) -> ! {
+        panic!("{}", self)
+    }
+}
+
+impl core::fmt::Display for AssertFailure<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "assertion failed: `{}`", self.header)?;
+        match self.diffs {
+            Some(diffs) => write!(f, "{}", diffs)?,
+            None => write!(
+                f,
+                r#"        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+{}"#,
+                self.left, self.right, self.abs_diff, self.ulps_diff, self.tols
+            )?,
+        }
+        if let Some(message) = self.message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
 }
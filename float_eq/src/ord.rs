@@ -0,0 +1,378 @@
+use crate::{AssertFloatEq, FloatEq, UlpsTol};
+use core::ops::{Add, Sub};
+
+/// A tolerance-aware ordering of two values, as checked by [`FloatOrd`].
+///
+/// The tolerance widens `le` and `ge` and narrows `lt` and `gt`, so that `a`
+/// is only "less than" `b` if it is definitely less, by more than the
+/// tolerance, and is "less than or equal to" `b` unless it is definitely
+/// greater. With a tolerance of zero, these are the usual operators:
+///
+/// - `Lt`: `a < b - tol`
+/// - `Le`: `a <= b + tol`
+/// - `Gt`: `a > b + tol`
+/// - `Ge`: `a >= b - tol`
+///
+/// Where `tol` is the effective tolerance of the comparison algorithm, for
+/// example `max(|a|, |b|) * tol` for `rmax`. For ULPs checks, the bound is `b`
+/// moved by `tol` representable values instead. As with the usual operators, a
+/// NaN value is never ordered relative to anything.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FloatOrdOp {
+    /// Less than the other value by more than the tolerance.
+    Lt,
+    /// Less than the other value, or within the tolerance of it.
+    Le,
+    /// Greater than the other value by more than the tolerance.
+    Gt,
+    /// Greater than the other value, or within the tolerance of it.
+    Ge,
+}
+
+impl FloatOrdOp {
+    /// Whether the bound lies above the other value, rather than below it.
+    #[inline]
+    pub(crate) fn is_bound_above(self) -> bool {
+        matches!(self, FloatOrdOp::Le | FloatOrdOp::Gt)
+    }
+
+    /// The bound that a value is compared to, given the other value and the
+    /// effective tolerance.
+    #[inline]
+    pub(crate) fn bound<T: Add<Output = T> + Sub<Output = T>>(self, other: T, tol: T) -> T {
+        if self.is_bound_above() {
+            other + tol
+        } else {
+            other - tol
+        }
+    }
+
+    /// Whether `value` satisfies this ordering relative to `bound`.
+    #[inline]
+    pub(crate) fn holds<T: PartialOrd>(self, value: &T, bound: &T) -> bool {
+        match self {
+            FloatOrdOp::Lt => value < bound,
+            FloatOrdOp::Le => value <= bound,
+            FloatOrdOp::Gt => value > bound,
+            FloatOrdOp::Ge => value >= bound,
+        }
+    }
+
+    /// Whether `value` satisfies this ordering relative to `other`, within the
+    /// tolerance that moved it to `bound`. Since a tolerance only ever widens
+    /// `Le` and `Ge`, they also hold wherever the plain operator does, which
+    /// covers bounds that are not a number, such as infinity plus infinity.
+    #[inline]
+    pub(crate) fn holds_within<T: PartialOrd>(self, value: &T, other: &T, bound: &T) -> bool {
+        match self {
+            FloatOrdOp::Le | FloatOrdOp::Ge => self.holds(value, other) || self.holds(value, bound),
+            FloatOrdOp::Lt | FloatOrdOp::Gt => self.holds(value, other) && self.holds(value, bound),
+        }
+    }
+
+    /// The signed distance by which `value` is past `bound`, which is positive
+    /// if it is on the wrong side.
+    #[inline]
+    pub(crate) fn past_bound<T: Sub<Output = T>>(self, value: T, bound: T) -> T {
+        match self {
+            FloatOrdOp::Lt | FloatOrdOp::Le => value - bound,
+            FloatOrdOp::Gt | FloatOrdOp::Ge => bound - value,
+        }
+    }
+}
+
+/// Compare the ordering of IEEE floating point values using per-field
+/// tolerances.
+///
+/// This trait is used in the implementation of the [`float_le!`], [`float_lt!`],
+/// [`float_ge!`] and [`float_gt!`] families of macros. Each method checks the
+/// ordering given by [`FloatOrdOp`], using the same tolerances and algorithms
+/// as the matching methods of [`FloatEq`]. Composite types must satisfy the
+/// ordering in every field.
+///
+/// ## Examples
+///
+/// ```
+/// # use float_eq::{FloatOrd, FloatOrdOp};
+/// assert!(1.05_f32.ord_abs(&1.0, FloatOrdOp::Le, &0.1));
+/// assert!(!1.05_f32.ord_abs(&1.0, FloatOrdOp::Gt, &0.1));
+/// assert!(1.2_f32.ord_abs(&1.0, FloatOrdOp::Gt, &0.1));
+///
+/// assert!([1.0_f64, 2.0].ord_ulps(&[1.0, 2.0], FloatOrdOp::Ge, &[0, 0]));
+/// ```
+pub trait FloatOrd<Rhs: ?Sized = Self>: FloatEq<Rhs> {
+    /// Check whether `self` is ordered relative to `other` by `op`, using an
+    /// [absolute tolerance comparison].
+    ///
+    /// The implementation should be the equivalent of:
+    ///
+    /// ```
+    /// # use float_eq::FloatOrdOp;
+    /// # fn ord_abs(a: f32, b: f32, op: FloatOrdOp, tol: f32) -> bool {
+    /// match op {
+    ///     FloatOrdOp::Lt => a < b - tol,
+    ///     FloatOrdOp::Le => a <= b + tol,
+    ///     FloatOrdOp::Gt => a > b + tol,
+    ///     FloatOrdOp::Ge => a >= b - tol,
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    fn ord_abs(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> bool;
+
+    /// Check whether `self` is ordered relative to `other` by `op`, using a
+    /// [relative tolerance comparison].
+    ///
+    /// Equal to `self.ord_rmax(other, op, tol)`, there is no need to
+    /// reimplement this for your own types.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    #[inline]
+    fn ord_rel(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.ord_rmax(other, op, tol)
+    }
+
+    /// Check whether `self` is ordered relative to `other` by `op`, using a
+    /// [relative tolerance comparison] scaled to the larger magnitude.
+    ///
+    /// As [`ord_abs`](FloatOrd::ord_abs), with a tolerance of
+    /// `max(|self|, |other|) * tol`.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    fn ord_rmax(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> bool;
+
+    /// Check whether `self` is ordered relative to `other` by `op`, using a
+    /// [relative tolerance comparison] scaled to the smaller magnitude.
+    ///
+    /// As [`ord_abs`](FloatOrd::ord_abs), with a tolerance of
+    /// `min(|self|, |other|) * tol`.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    fn ord_rmin(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> bool;
+
+    /// Check whether `self` is ordered relative to `other` by `op`, using a
+    /// [relative tolerance comparison] scaled to the first input.
+    ///
+    /// As [`ord_abs`](FloatOrd::ord_abs), with a tolerance of `|self| * tol`.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    fn ord_r1st(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> bool;
+
+    /// Check whether `self` is ordered relative to `other` by `op`, using a
+    /// [relative tolerance comparison] scaled to the second input.
+    ///
+    /// As [`ord_abs`](FloatOrd::ord_abs), with a tolerance of `|other| * tol`.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    fn ord_r2nd(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> bool;
+
+    /// Check whether `self` is ordered relative to `other` by `op`, using an
+    /// [ULPs comparison].
+    ///
+    /// The bound is `other` moved `tol` representable values up for `Le` and
+    /// `Gt`, or down for `Lt` and `Ge`, stopping at infinity. Otherwise, this is
+    /// as [`ord_abs`](FloatOrd::ord_abs).
+    ///
+    /// [ULPs comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    fn ord_ulps(&self, other: &Rhs, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool;
+}
+
+/// Debug context for when an ordering assert using [`FloatOrd`] fails.
+///
+/// This trait is used by the [`assert_float_le!`], [`assert_float_lt!`],
+/// [`assert_float_ge!`] and [`assert_float_gt!`] families of macros to show how
+/// far each field was past the bound of the check.
+///
+/// ## Examples
+///
+/// ```
+/// # use float_eq::{AssertFloatOrd, FloatOrdOp};
+/// assert_eq!(1.25_f32.debug_ord_abs(&1.0, FloatOrdOp::Le, &0.125), 0.125);
+/// assert_eq!(1.0_f32.debug_ord_abs(&1.25, FloatOrdOp::Ge, &0.125), 0.125);
+/// assert_eq!(1.0_f32.debug_ord_abs(&1.25, FloatOrdOp::Le, &0.125), -0.375);
+/// ```
+pub trait AssertFloatOrd<Rhs: ?Sized = Self>: FloatOrd<Rhs> + AssertFloatEq<Rhs> {
+    /// The signed distance of each field of `self` past the bound of an
+    /// [`ord_abs`](FloatOrd::ord_abs) check, which is positive where it is on
+    /// the wrong side.
+    ///
+    /// For example, `self - (other + tol)` for [`FloatOrdOp::Le`] or
+    /// `(other - tol) - self` for [`FloatOrdOp::Ge`].
+    fn debug_ord_abs(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol;
+
+    /// The signed distance of each field of `self` past the bound of an
+    /// [`ord_rel`](FloatOrd::ord_rel) check.
+    ///
+    /// Equal to `self.debug_ord_rmax(other, op, tol)`, there is no need to
+    /// reimplement this for your own types.
+    #[inline]
+    fn debug_ord_rel(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        self.debug_ord_rmax(other, op, tol)
+    }
+
+    /// The signed distance of each field of `self` past the bound of an
+    /// [`ord_rmax`](FloatOrd::ord_rmax) check.
+    fn debug_ord_rmax(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol;
+
+    /// The signed distance of each field of `self` past the bound of an
+    /// [`ord_rmin`](FloatOrd::ord_rmin) check.
+    fn debug_ord_rmin(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol;
+
+    /// The signed distance of each field of `self` past the bound of an
+    /// [`ord_r1st`](FloatOrd::ord_r1st) check.
+    fn debug_ord_r1st(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol;
+
+    /// The signed distance of each field of `self` past the bound of an
+    /// [`ord_r2nd`](FloatOrd::ord_r2nd) check.
+    fn debug_ord_r2nd(&self, other: &Rhs, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol;
+
+    /// The signed distance of each field of `self` past the bound of an
+    /// [`ord_ulps`](FloatOrd::ord_ulps) check, measured in the same units as
+    /// the values rather than in ULPs.
+    fn debug_ord_ulps(
+        &self,
+        other: &Rhs,
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol;
+}
+
+#[doc(hidden)]
+pub struct FloatOrdCmp;
+
+#[doc(hidden)]
+impl FloatOrdCmp {
+    #[inline]
+    pub fn abs<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> bool
+    where
+        A: ?Sized + FloatOrd<B>,
+        B: ?Sized,
+    {
+        a.ord_abs(b, op, tol)
+    }
+
+    #[inline]
+    pub fn rel<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> bool
+    where
+        A: ?Sized + FloatOrd<B>,
+        B: ?Sized,
+    {
+        a.ord_rel(b, op, tol)
+    }
+
+    #[inline]
+    pub fn rmax<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> bool
+    where
+        A: ?Sized + FloatOrd<B>,
+        B: ?Sized,
+    {
+        a.ord_rmax(b, op, tol)
+    }
+
+    #[inline]
+    pub fn rmin<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> bool
+    where
+        A: ?Sized + FloatOrd<B>,
+        B: ?Sized,
+    {
+        a.ord_rmin(b, op, tol)
+    }
+
+    #[inline]
+    pub fn r1st<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> bool
+    where
+        A: ?Sized + FloatOrd<B>,
+        B: ?Sized,
+    {
+        a.ord_r1st(b, op, tol)
+    }
+
+    #[inline]
+    pub fn r2nd<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> bool
+    where
+        A: ?Sized + FloatOrd<B>,
+        B: ?Sized,
+    {
+        a.ord_r2nd(b, op, tol)
+    }
+
+    #[inline]
+    pub fn ulps<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &UlpsTol<A::Tol>) -> bool
+    where
+        A: ?Sized + FloatOrd<B>,
+        B: ?Sized,
+    {
+        a.ord_ulps(b, op, tol)
+    }
+}
+
+#[doc(hidden)]
+pub struct FloatOrdPastBound;
+
+#[doc(hidden)]
+impl FloatOrdPastBound {
+    #[inline]
+    pub fn abs<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> A::DebugTol
+    where
+        A: ?Sized + AssertFloatOrd<B>,
+        B: ?Sized,
+    {
+        a.debug_ord_abs(b, op, tol)
+    }
+
+    #[inline]
+    pub fn rel<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> A::DebugTol
+    where
+        A: ?Sized + AssertFloatOrd<B>,
+        B: ?Sized,
+    {
+        a.debug_ord_rel(b, op, tol)
+    }
+
+    #[inline]
+    pub fn rmax<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> A::DebugTol
+    where
+        A: ?Sized + AssertFloatOrd<B>,
+        B: ?Sized,
+    {
+        a.debug_ord_rmax(b, op, tol)
+    }
+
+    #[inline]
+    pub fn rmin<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> A::DebugTol
+    where
+        A: ?Sized + AssertFloatOrd<B>,
+        B: ?Sized,
+    {
+        a.debug_ord_rmin(b, op, tol)
+    }
+
+    #[inline]
+    pub fn r1st<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> A::DebugTol
+    where
+        A: ?Sized + AssertFloatOrd<B>,
+        B: ?Sized,
+    {
+        a.debug_ord_r1st(b, op, tol)
+    }
+
+    #[inline]
+    pub fn r2nd<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &A::Tol) -> A::DebugTol
+    where
+        A: ?Sized + AssertFloatOrd<B>,
+        B: ?Sized,
+    {
+        a.debug_ord_r2nd(b, op, tol)
+    }
+
+    #[inline]
+    pub fn ulps<A, B>(a: &A, b: &B, op: FloatOrdOp, tol: &UlpsTol<A::Tol>) -> A::DebugTol
+    where
+        A: ?Sized + AssertFloatOrd<B>,
+        B: ?Sized,
+    {
+        a.debug_ord_ulps(b, op, tol)
+    }
+}
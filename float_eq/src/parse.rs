@@ -0,0 +1,500 @@
+use crate::{Check, CheckSet, FloatEqUlpsTol, UlpsTol};
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// A tolerance that may be parsed from the text of a [`CheckSet`].
+///
+/// Implemented for each of the floating point types, which accept decimal
+/// literals such as `1e-6`, hexadecimal literals such as `0x1p-20`, and the
+/// `inf` and `NaN` accepted by [`str::parse`]. Digits may be separated by
+/// underscores, as in Rust source.
+pub trait ParseTol: FloatEqUlpsTol + Sized
+where
+    UlpsTol<Self>: Sized,
+{
+    /// Parse a tolerance, or return `None` if `s` is not a valid number.
+    fn parse_tol(s: &str) -> Option<Self>;
+}
+
+macro_rules! impl_parse_tol {
+    ($float:ident) => {
+        impl ParseTol for $float {
+            fn parse_tol(s: &str) -> Option<Self> {
+                let s = strip_underscores(s)?;
+                let (negative, digits) = split_sign(&s);
+                match strip_hex_prefix(digits) {
+                    Some(hex) => {
+                        let (mantissa, exp) = parse_hex_float(hex)?;
+                        let value =
+                            scale_by_pow2(mantissa as $float, exp, |n| (2.0 as $float).powi(n));
+                        Some(if negative { -value } else { value })
+                    }
+                    None => s.parse().ok(),
+                }
+            }
+        }
+    };
+}
+
+impl_parse_tol!(f32);
+impl_parse_tol!(f64);
+
+#[cfg(feature = "half")]
+impl ParseTol for half::f16 {
+    fn parse_tol(s: &str) -> Option<Self> {
+        f64::parse_tol(s).map(half::f16::from_f64)
+    }
+}
+
+#[cfg(feature = "half")]
+impl ParseTol for half::bf16 {
+    fn parse_tol(s: &str) -> Option<Self> {
+        f64::parse_tol(s).map(half::bf16::from_f64)
+    }
+}
+
+/// An error parsing a [`CheckSet`] from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseCheckError {
+    /// The text ended while expecting a part of a check.
+    UnexpectedEnd {
+        /// What was expected.
+        expected: &'static str,
+    },
+    /// Some text was not the part of a check that was expected.
+    Unexpected {
+        /// What was expected.
+        expected: &'static str,
+        /// The text that was found instead.
+        found: String,
+    },
+    /// A check named an algorithm that does not exist.
+    UnknownAlgorithm(String),
+    /// A tolerance was not a valid number for its check.
+    InvalidTol {
+        /// The name of the check, such as `"rmax_all"`.
+        check: &'static str,
+        /// The text of the tolerance.
+        tol: String,
+    },
+    /// An ULPs tolerance was too large for the [`UlpsTol`] type.
+    UlpsTolOutOfRange {
+        /// The name of the check, such as `"ulps_all"`.
+        check: &'static str,
+        /// The text of the tolerance.
+        tol: String,
+        /// The name of the ULPs tolerance type, such as `"u32"`.
+        ulps_tol: &'static str,
+    },
+    /// An `all(...)` group was combined with other checks.
+    MixedGroup,
+}
+
+impl fmt::Display for ParseCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCheckError::UnexpectedEnd { expected } => {
+                write!(f, "expected {}, found end of input", expected)
+            }
+            ParseCheckError::Unexpected { expected, found } => {
+                write!(f, "expected {}, found `{}`", expected, found)
+            }
+            ParseCheckError::UnknownAlgorithm(name) => write!(
+                f,
+                "unknown check `{}`, expected one of abs, rel, rmax, rmin, r1st, r2nd, \
+                 atol_rtol or ulps, or their _all variants",
+                name
+            ),
+            ParseCheckError::InvalidTol { check, tol } => {
+                let expected = if check.starts_with("ulps") {
+                    "an unsigned integer"
+                } else {
+                    "a floating point number"
+                };
+                write!(
+                    f,
+                    "invalid tolerance `{}` for `{}`, expected {}",
+                    tol, check, expected
+                )
+            }
+            ParseCheckError::UlpsTolOutOfRange {
+                check,
+                tol,
+                ulps_tol,
+            } => write!(
+                f,
+                "tolerance `{}` for `{}` is too large for `{}`",
+                tol, check, ulps_tol
+            ),
+            ParseCheckError::MixedGroup => {
+                write!(
+                    f,
+                    "an `all(...)` group cannot be combined with other checks"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseCheckError {}
+
+/// Parses the checks given to the macros, such as `"rmax <= 1e-6, ulps <= 4"`.
+///
+/// Checks separated by commas become an [`Any`](CheckSet::Any), and a single
+/// `all(...)` group becomes an [`All`](CheckSet::All). Tolerances are parsed
+/// by [`ParseTol`], and ULPs tolerances may be decimal or hexadecimal integers
+/// that fit in the [`UlpsTol`] type:
+///
+/// ```
+/// # use float_eq::{Check, CheckSet};
+/// let checks: CheckSet<f32> = "rmax <= 1e-6, ulps <= 4".parse().unwrap();
+/// assert_eq!(checks, CheckSet::Any(vec![Check::Rmax(1e-6), Check::Ulps(4)]));
+///
+/// let checks: CheckSet<f64> = "all(abs_all <= 0x1p-20, rmax_all <= 0.1)".parse().unwrap();
+/// assert_eq!(checks, CheckSet::All(vec![Check::Abs(1.0 / 1_048_576.0), Check::Rmax(0.1)]));
+///
+/// let err = "ulps <= 5000000000".parse::<CheckSet<f32>>().unwrap_err();
+/// assert_eq!(err.to_string(), "tolerance `5000000000` for `ulps` is too large for `u32`");
+/// ```
+///
+/// Since every tolerance is a single value, a check and its `_all` variant
+/// parse to the same [`Check`], and which of them is made is chosen by calling
+/// either [`eq`](CheckSet::eq) or [`eq_all`](CheckSet::eq_all) and so on.
+impl<T: ParseTol> FromStr for CheckSet<T>
+where
+    UlpsTol<T>: Sized + TryFrom<u64>,
+{
+    type Err = ParseCheckError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { rest: s };
+        let checks = if parser.eat_group() {
+            let checks = parser.checks(Some(')'))?;
+            parser.expect(")", "`)`")?;
+            if parser.eat(",") && !parser.at_end() {
+                return Err(ParseCheckError::MixedGroup);
+            }
+            CheckSet::All(checks)
+        } else {
+            CheckSet::Any(parser.checks(None)?)
+        };
+        parser.end()?;
+        Ok(checks)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Algorithm {
+    Abs,
+    Rmax,
+    Rmin,
+    R1st,
+    R2nd,
+    AtolRtol,
+    Ulps,
+}
+
+/// The name of every check accepted by the macros.
+pub(crate) const CHECK_NAMES: &[&str] = &[
+    "abs",
+    "abs_all",
+    "rel",
+    "rel_all",
+    "rmax",
+    "rmax_all",
+    "rmin",
+    "rmin_all",
+    "r1st",
+    "r1st_all",
+    "r2nd",
+    "r2nd_all",
+    "atol_rtol",
+    "atol_rtol_all",
+    "ulps",
+    "ulps_all",
+];
+
+/// The algorithm used by a check, which is the same for its `_all` variant.
+fn algorithm(name: &str) -> Algorithm {
+    match name.trim_end_matches("_all") {
+        "abs" => Algorithm::Abs,
+        "rel" | "rmax" => Algorithm::Rmax,
+        "rmin" => Algorithm::Rmin,
+        "r1st" => Algorithm::R1st,
+        "r2nd" => Algorithm::R2nd,
+        "atol_rtol" => Algorithm::AtolRtol,
+        _ => Algorithm::Ulps,
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest.starts_with(token) {
+            self.rest = &self.rest[token.len()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str, expected: &'static str) -> Result<(), ParseCheckError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ParseCheckError {
+        let found = self.rest.split_whitespace().next().unwrap_or("");
+        if found.is_empty() {
+            ParseCheckError::UnexpectedEnd { expected }
+        } else {
+            ParseCheckError::Unexpected {
+                expected,
+                found: found.to_owned(),
+            }
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest.is_empty()
+    }
+
+    fn end(&mut self) -> Result<(), ParseCheckError> {
+        if self.at_end() {
+            Ok(())
+        } else if self.peek_group() {
+            Err(ParseCheckError::MixedGroup)
+        } else {
+            Err(self.unexpected("`,` or end of input"))
+        }
+    }
+
+    /// Takes a run of characters that may form a name or number.
+    fn word(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let len = self
+            .rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || "_.+-".contains(c)))
+            .unwrap_or(self.rest.len());
+        let (word, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        word
+    }
+
+    fn peek_group(&self) -> bool {
+        let mut peek = Parser { rest: self.rest };
+        peek.word() == "all" && peek.eat("(")
+    }
+
+    fn eat_group(&mut self) -> bool {
+        if self.peek_group() {
+            self.word();
+            self.eat("(");
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Parses checks separated by commas, up to the end of the input or the
+    /// closing delimiter of a group.
+    fn checks<T: ParseTol>(&mut self, close: Option<char>) -> Result<Vec<Check<T>>, ParseCheckError>
+    where
+        UlpsTol<T>: Sized + TryFrom<u64>,
+    {
+        let mut checks = vec![self.check()?];
+        while self.eat(",") {
+            if self.at_end() || self.rest.chars().next() == close {
+                break;
+            }
+            checks.push(self.check()?);
+        }
+        Ok(checks)
+    }
+
+    fn check<T: ParseTol>(&mut self) -> Result<Check<T>, ParseCheckError>
+    where
+        UlpsTol<T>: Sized + TryFrom<u64>,
+    {
+        if self.peek_group() {
+            return Err(ParseCheckError::MixedGroup);
+        }
+        let name = self.word();
+        if name.is_empty() {
+            return Err(self.unexpected("a check"));
+        }
+        let name = CHECK_NAMES
+            .iter()
+            .find(|known| **known == name)
+            .copied()
+            .ok_or_else(|| ParseCheckError::UnknownAlgorithm(name.to_owned()))?;
+        self.expect("<=", "`<=`")?;
+
+        Ok(match algorithm(name) {
+            Algorithm::Abs => Check::Abs(self.tol(name)?),
+            Algorithm::Rmax => Check::Rmax(self.tol(name)?),
+            Algorithm::Rmin => Check::Rmin(self.tol(name)?),
+            Algorithm::R1st => Check::R1st(self.tol(name)?),
+            Algorithm::R2nd => Check::R2nd(self.tol(name)?),
+            Algorithm::AtolRtol => {
+                self.expect("(", "`(`")?;
+                let atol = self.tol(name)?;
+                self.expect(",", "`,`")?;
+                let rtol = self.tol(name)?;
+                self.eat(",");
+                self.expect(")", "`)`")?;
+                Check::AtolRtol(atol, rtol)
+            }
+            Algorithm::Ulps => Check::Ulps(self.ulps_tol::<T>(name)?),
+        })
+    }
+
+    fn tol<T: ParseTol>(&mut self, check: &'static str) -> Result<T, ParseCheckError>
+    where
+        UlpsTol<T>: Sized,
+    {
+        let tol = self.word();
+        if tol.is_empty() {
+            return Err(self.unexpected("a tolerance"));
+        }
+        T::parse_tol(tol).ok_or_else(|| ParseCheckError::InvalidTol {
+            check,
+            tol: tol.to_owned(),
+        })
+    }
+
+    fn ulps_tol<T: FloatEqUlpsTol>(
+        &mut self,
+        check: &'static str,
+    ) -> Result<UlpsTol<T>, ParseCheckError>
+    where
+        UlpsTol<T>: Sized + TryFrom<u64>,
+    {
+        let tol = self.word();
+        if tol.is_empty() {
+            return Err(self.unexpected("a tolerance"));
+        }
+        let invalid = || ParseCheckError::InvalidTol {
+            check,
+            tol: tol.to_owned(),
+        };
+        let digits = strip_underscores(tol).ok_or_else(invalid)?;
+        let (radix, digits) = match strip_hex_prefix(&digits) {
+            Some(hex) => (16, hex),
+            None => (10, digits.as_str()),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(invalid());
+        }
+        // the digits are valid, so the only way this can fail is by overflow
+        let value =
+            u64::from_str_radix(digits, radix).map_err(|_| out_of_range::<T>(check, tol))?;
+        UlpsTol::<T>::try_from(value).map_err(|_| out_of_range::<T>(check, tol))
+    }
+}
+
+fn out_of_range<T: FloatEqUlpsTol>(check: &'static str, tol: &str) -> ParseCheckError
+where
+    UlpsTol<T>: Sized,
+{
+    ParseCheckError::UlpsTolOutOfRange {
+        check,
+        tol: tol.to_owned(),
+        ulps_tol: core::any::type_name::<UlpsTol<T>>(),
+    }
+}
+
+/// Removes the underscores separating digits, rejecting any that lead or
+/// trail the number, or follow a sign.
+fn strip_underscores(s: &str) -> Option<String> {
+    let (_, digits) = split_sign(s);
+    if digits.starts_with('_') || s.ends_with('_') {
+        None
+    } else {
+        Some(s.replace('_', ""))
+    }
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(digits) = s.strip_prefix('-') {
+        (true, digits)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+fn strip_hex_prefix(s: &str) -> Option<&str> {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))
+}
+
+/// Parses the digits of a hexadecimal float following its `0x` prefix, such
+/// as `1.8p-3`, into an integer mantissa and a binary exponent.
+///
+/// Mantissa digits beyond the precision of a `u64` are folded into its lowest
+/// bit, so that converting it to a float rounds correctly.
+fn parse_hex_float(s: &str) -> Option<(u64, i32)> {
+    let (digits, mut exp) = match s.find(['p', 'P']) {
+        Some(p) => (&s[..p], s[p + 1..].parse::<i32>().ok()?),
+        None => (s, 0),
+    };
+    let (int, frac) = match digits.find('.') {
+        Some(dot) => (&digits[..dot], &digits[dot + 1..]),
+        None => (digits, ""),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+
+    let mut mantissa = 0_u64;
+    let mut sticky = false;
+    for (c, is_frac) in int
+        .chars()
+        .map(|c| (c, false))
+        .chain(frac.chars().map(|c| (c, true)))
+    {
+        let digit = u64::from(c.to_digit(16)?);
+        if mantissa >> 60 == 0 {
+            mantissa = mantissa * 16 + digit;
+            if is_frac {
+                exp = exp.checked_sub(4)?;
+            }
+        } else {
+            sticky |= digit != 0;
+            if !is_frac {
+                exp = exp.checked_add(4)?;
+            }
+        }
+    }
+    Some((mantissa | u64::from(sticky), exp))
+}
+
+/// Multiplies `value` by `2^exp` in steps that stay within the range of the
+/// float type, so that large exponents only overflow or underflow if the
+/// result does.
+fn scale_by_pow2<T>(mut value: T, mut exp: i32, pow2: impl Fn(i32) -> T) -> T
+where
+    T: Copy + core::ops::MulAssign,
+{
+    const STEP: i32 = 100;
+    while exp > STEP {
+        value *= pow2(STEP);
+        exp -= STEP;
+    }
+    while exp < -STEP {
+        value *= pow2(-STEP);
+        exp += STEP;
+    }
+    value *= pow2(exp);
+    value
+}
@@ -0,0 +1,270 @@
+//! Strategies for testing with [proptest], enabled by the `proptest` feature.
+//!
+//! Each floating point type has a module of strategies that generate pairs of
+//! values that are guaranteed to either satisfy or violate a [`Check`], or that
+//! straddle the boundary of its tolerance. The first value of each pair is
+//! drawn from [`values`](f64::values), which favours edge cases such as zeroes
+//! of both signs, subnormals and infinities.
+//!
+//! Failures within a property may be reported using [`prop_assert_float_eq!`]
+//! and [`prop_assert_float_ne!`], which return a [`TestCaseError`] with the same
+//! message as [`assert_float_eq!`] and [`assert_float_ne!`] rather than
+//! panicking:
+//!
+//! ```
+//! use float_eq::{proptest::f64, prop_assert_float_eq, Check};
+//! use proptest::proptest;
+//!
+//! proptest!(|((a, b) in f64::eq_pairs(Check::Rmax(0.000_001)))| {
+//!     prop_assert_float_eq!(b, a, rmax <= 0.000_001);
+//! });
+//!
+//! proptest!(|((a, inside, outside) in f64::boundaries(Check::Ulps(4)))| {
+//!     prop_assert_float_eq!(a, inside, ulps <= 4);
+//!     assert!(!float_eq::float_eq!(a, outside, ulps <= 4));
+//! });
+//! ```
+//!
+//! [proptest]: https://docs.rs/proptest
+//! [`TestCaseError`]: https://docs.rs/proptest/1/proptest/test_runner/enum.TestCaseError.html
+
+macro_rules! impl_strategies {
+    ($float:ident, $uint:ident, $key:ident) => {
+        /// Strategies generating values of this floating point type.
+        pub mod $float {
+            use crate::Check;
+            use ::proptest::num::$float as num;
+            use ::proptest::prelude::{any, BoxedStrategy, Strategy};
+            use ::proptest::{prop_oneof, sample::select};
+
+            /// Any value that is not NaN.
+            ///
+            /// Zeroes of both signs, the smallest subnormals, the smallest and
+            /// largest normal values and infinities are generated more often
+            /// than they would be by chance.
+            pub fn values() -> BoxedStrategy<$float> {
+                let edges = vec![
+                    0.0,
+                    -0.0,
+                    $float::from_bits(1),
+                    -$float::from_bits(1),
+                    $float::MIN_POSITIVE,
+                    -$float::MIN_POSITIVE,
+                    1.0,
+                    -1.0,
+                    $float::MAX,
+                    $float::MIN,
+                    $float::INFINITY,
+                    $float::NEG_INFINITY,
+                ];
+                let any = num::POSITIVE
+                    | num::NEGATIVE
+                    | num::NORMAL
+                    | num::SUBNORMAL
+                    | num::ZERO
+                    | num::INFINITE;
+                prop_oneof![1 => select(edges), 3 => any].boxed()
+            }
+
+            /// Pairs of values `(a, b)` where `check.eq(&a, &b)` is true.
+            ///
+            /// Values of `b` are spread between `a` and the boundary of the
+            /// tolerance, and shrink towards `a`.
+            pub fn eq_pairs(check: Check<$float>) -> BoxedStrategy<($float, $float)> {
+                (values(), any::<bool>(), any::<$uint>())
+                    .prop_map(move |(a, up, offset)| {
+                        let end = if up { infinity_key() } else { -infinity_key() };
+                        let boundary = boundary(&check, a, end);
+                        let limit = boundary.map_or(end, |(inside, _)| key(inside));
+                        let b = from_key(toward(key(a), limit, offset));
+                        if check.eq(&a, &b) {
+                            (a, b)
+                        } else {
+                            (a, boundary.map_or(a, |(inside, _)| inside))
+                        }
+                    })
+                    .boxed()
+            }
+
+            /// Pairs of values `(a, b)` where `check.ne(&a, &b)` is true.
+            ///
+            /// Values of `b` are spread beyond the boundary of the tolerance,
+            /// and shrink towards it. Pairs where either value is NaN are also
+            /// generated, as are pairs with a NaN `b` if nothing else fails the
+            /// check.
+            pub fn ne_pairs(check: Check<$float>) -> BoxedStrategy<($float, $float)> {
+                let beyond = (values(), any::<bool>(), any::<$uint>()).prop_map(
+                    move |(a, up, offset)| {
+                        for &end in &ends(up) {
+                            if let Some((_, outside)) = boundary(&check, a, end) {
+                                let b = from_key(toward(key(outside), end, offset));
+                                return (a, if check.ne(&a, &b) { b } else { outside });
+                            }
+                        }
+                        (a, $float::NAN)
+                    },
+                );
+                let nan = prop_oneof![
+                    values().prop_map(|a| (a, $float::NAN)),
+                    values().prop_map(|b| ($float::NAN, b)),
+                ];
+                prop_oneof![8 => beyond, 1 => nan].boxed()
+            }
+
+            /// Triples of values `(a, inside, outside)` that straddle the
+            /// boundary of the tolerance, where `inside` and `outside` are
+            /// adjacent, `check.eq(&a, &inside)` is true and
+            /// `check.ne(&a, &outside)` is true.
+            ///
+            /// Values of `a` that have no such boundary are rejected, so
+            /// checks that almost always succeed, such as `Check::Abs(INFINITY)`
+            /// or relative checks with a tolerance of 1 or more, will exhaust
+            /// proptest's limit on rejections.
+            pub fn boundaries(check: Check<$float>) -> BoxedStrategy<($float, $float, $float)> {
+                (values(), any::<bool>())
+                    .prop_filter_map("no tolerance boundary", move |(a, up)| {
+                        let [first, second] = ends(up);
+                        boundary(&check, a, first)
+                            .or_else(|| boundary(&check, a, second))
+                            .map(|(inside, outside)| (a, inside, outside))
+                    })
+                    .boxed()
+            }
+
+            /// The adjacent values either side of the boundary of the tolerance
+            /// of `check` around `a`, between `a` and the value whose key is
+            /// `end`, or `None` if `a` compares equal to `end` and the largest
+            /// finite value before it.
+            ///
+            /// The checks are not always monotonic, for example relative
+            /// tolerances consider infinity equal to every finite value, but
+            /// the search keeps a succeeding and a failing value at all times
+            /// so the pair found always straddles a boundary.
+            fn boundary(check: &Check<$float>, a: $float, end: $key) -> Option<($float, $float)> {
+                let (mut inside, mut outside) = (key(a), end);
+                if check.eq(&a, &from_key(outside)) {
+                    outside -= (end - inside).signum();
+                }
+                if inside == outside || check.eq(&a, &from_key(outside)) {
+                    return None;
+                }
+                while (outside - inside).abs() > 1 {
+                    let mid = inside + (outside - inside) / 2;
+                    if check.eq(&a, &from_key(mid)) {
+                        inside = mid;
+                    } else {
+                        outside = mid;
+                    }
+                }
+                Some((from_key(inside), from_key(outside)))
+            }
+
+            fn ends(up: bool) -> [$key; 2] {
+                if up {
+                    [infinity_key(), -infinity_key()]
+                } else {
+                    [-infinity_key(), infinity_key()]
+                }
+            }
+
+            /// Moves `offset` values from `from` towards `to`, wrapping within
+            /// the range so that any offset may be used.
+            fn toward(from: $key, to: $key, offset: $uint) -> $key {
+                let step = $key::from(offset) % ((to - from).abs() + 1);
+                if to >= from {
+                    from + step
+                } else {
+                    from - step
+                }
+            }
+
+            const SIGN: $uint = 1 << ($uint::BITS - 1);
+
+            /// Orders the values that are not NaN by their integer keys, such
+            /// that adjacent values have adjacent keys. Both zeroes are `0`.
+            fn key(value: $float) -> $key {
+                let bits = value.to_bits();
+                if bits & SIGN == 0 {
+                    $key::from(bits)
+                } else {
+                    -$key::from(bits & !SIGN)
+                }
+            }
+
+            fn from_key(key: $key) -> $float {
+                if key < 0 {
+                    $float::from_bits((-key) as $uint | SIGN)
+                } else {
+                    $float::from_bits(key as $uint)
+                }
+            }
+
+            fn infinity_key() -> $key {
+                key($float::INFINITY)
+            }
+        }
+    };
+}
+
+impl_strategies!(f32, u32, i64);
+impl_strategies!(f64, u64, i128);
+
+/// Asserts that two floating point expressions are equal to each other, within
+/// a [proptest] property.
+///
+/// Accepts the same arguments as [`assert_float_eq!`], but rather than
+/// panicking on failure, returns a [`TestCaseError`] with the same message
+/// from the enclosing function, as [`prop_assert_eq!`] does.
+///
+/// ```
+/// use float_eq::{proptest::f32, prop_assert_float_eq, Check};
+/// use proptest::proptest;
+///
+/// proptest!(|((a, b) in f32::eq_pairs(Check::Ulps(2)))| {
+///     prop_assert_float_eq!(a, b, ulps <= 2, "a = {}", a);
+/// });
+/// ```
+///
+/// [proptest]: https://docs.rs/proptest
+/// [`TestCaseError`]: https://docs.rs/proptest/1/proptest/test_runner/enum.TestCaseError.html
+/// [`prop_assert_eq!`]: https://docs.rs/proptest/1/proptest/macro.prop_assert_eq.html
+#[macro_export]
+macro_rules! prop_assert_float_eq {
+    ($left:expr, $right:expr, $($checks:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_float_eq!(@bind [left_val, right_val, prop] [] [] [] $($checks)+)
+            }
+        }
+    });
+}
+
+/// Asserts that two floating point expressions are not equal to each other,
+/// within a [proptest] property.
+///
+/// Accepts the same arguments as [`assert_float_ne!`], but rather than
+/// panicking on failure, returns a [`TestCaseError`] with the same message
+/// from the enclosing function, as [`prop_assert_ne!`] does.
+///
+/// ```
+/// use float_eq::{proptest::f32, prop_assert_float_ne, Check};
+/// use proptest::proptest;
+///
+/// proptest!(|((a, b) in f32::ne_pairs(Check::Abs(0.5)))| {
+///     prop_assert_float_ne!(a, b, abs <= 0.5);
+/// });
+/// ```
+///
+/// [proptest]: https://docs.rs/proptest
+/// [`TestCaseError`]: https://docs.rs/proptest/1/proptest/test_runner/enum.TestCaseError.html
+/// [`prop_assert_ne!`]: https://docs.rs/proptest/1/proptest/macro.prop_assert_ne.html
+#[macro_export]
+macro_rules! prop_assert_float_ne {
+    ($left:expr, $right:expr, $($checks:tt)+) => ({
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                $crate::assert_float_ne!(@bind [left_val, right_val, prop] [] [] [] $($checks)+)
+            }
+        }
+    });
+}
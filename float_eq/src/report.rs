@@ -0,0 +1,733 @@
+use crate::{FloatEq, FloatEqAll, FloatEqUlpsTol, UlpsTol};
+use core::fmt;
+
+/// A reference to the tolerance of a check, tagged with the algorithm it is to
+/// be used with.
+///
+/// This is the tolerance passed to [`FloatEqReport::report`] and
+/// [`FloatEqAllReport::report_all`]. Composite types split it into the
+/// tolerances of their fields or elements using [`map`](ReportTol::map),
+/// [`try_map`](ReportTol::try_map) or [`elements`](ReportTol::elements), which
+/// preserve the algorithm, and may inspect it using [`apply`](ReportTol::apply).
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(
+        rename_all = "snake_case",
+        bound(serialize = "T: serde::Serialize, UlpsTol<T>: serde::Serialize")
+    )
+)]
+pub enum ReportTol<'a, T: ?Sized + FloatEqUlpsTol> {
+    /// An [absolute tolerance comparison].
+    ///
+    /// [absolute tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-tolerance-comparison
+    Abs(&'a T),
+    /// A [relative tolerance comparison], scaled to the precision of the larger operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    Rmax(&'a T),
+    /// A [relative tolerance comparison], scaled to the precision of the smaller operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    Rmin(&'a T),
+    /// A [relative tolerance comparison], scaled to the precision of the first operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    R1st(&'a T),
+    /// A [relative tolerance comparison], scaled to the precision of the second operand.
+    ///
+    /// [relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#relative-tolerance-comparison
+    R2nd(&'a T),
+    /// An [absolute and relative tolerance comparison], with the absolute
+    /// tolerance followed by the relative tolerance.
+    ///
+    /// [absolute and relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-and-relative-tolerance-comparison
+    AtolRtol(&'a T, &'a T),
+    /// An [ULPs comparison].
+    ///
+    /// [ULPs comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison
+    Ulps(&'a UlpsTol<T>),
+}
+
+impl<'a, T: ?Sized + FloatEqUlpsTol> ReportTol<'a, T> {
+    /// The name of the check's algorithm, for example `"rmax"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReportTol::Abs(_) => "abs",
+            ReportTol::Rmax(_) => "rmax",
+            ReportTol::Rmin(_) => "rmin",
+            ReportTol::R1st(_) => "r1st",
+            ReportTol::R2nd(_) => "r2nd",
+            ReportTol::AtolRtol(_, _) => "atol_rtol",
+            ReportTol::Ulps(_) => "ulps",
+        }
+    }
+
+    /// Selects the tolerance of a field using `tol`, or `ulps_tol` if this is
+    /// an ULPs check.
+    pub fn map<U, F, G>(self, tol: F, ulps_tol: G) -> ReportTol<'a, U>
+    where
+        U: ?Sized + FloatEqUlpsTol,
+        F: Fn(&'a T) -> &'a U,
+        G: FnOnce(&'a UlpsTol<T>) -> &'a UlpsTol<U>,
+    {
+        match self {
+            ReportTol::Abs(t) => ReportTol::Abs(tol(t)),
+            ReportTol::Rmax(t) => ReportTol::Rmax(tol(t)),
+            ReportTol::Rmin(t) => ReportTol::Rmin(tol(t)),
+            ReportTol::R1st(t) => ReportTol::R1st(tol(t)),
+            ReportTol::R2nd(t) => ReportTol::R2nd(tol(t)),
+            ReportTol::AtolRtol(a, r) => ReportTol::AtolRtol(tol(a), tol(r)),
+            ReportTol::Ulps(t) => ReportTol::Ulps(ulps_tol(t)),
+        }
+    }
+
+    /// As [`map`](ReportTol::map), for tolerances that may not contain the
+    /// field, such as an `Option` or the value of a map.
+    pub fn try_map<U, F, G>(self, tol: F, ulps_tol: G) -> Option<ReportTol<'a, U>>
+    where
+        U: ?Sized + FloatEqUlpsTol,
+        F: Fn(&'a T) -> Option<&'a U>,
+        G: FnOnce(&'a UlpsTol<T>) -> Option<&'a UlpsTol<U>>,
+    {
+        Some(match self {
+            ReportTol::Abs(t) => ReportTol::Abs(tol(t)?),
+            ReportTol::Rmax(t) => ReportTol::Rmax(tol(t)?),
+            ReportTol::Rmin(t) => ReportTol::Rmin(tol(t)?),
+            ReportTol::R1st(t) => ReportTol::R1st(tol(t)?),
+            ReportTol::R2nd(t) => ReportTol::R2nd(tol(t)?),
+            ReportTol::AtolRtol(a, r) => ReportTol::AtolRtol(tol(a)?, tol(r)?),
+            ReportTol::Ulps(t) => ReportTol::Ulps(ulps_tol(t)?),
+        })
+    }
+
+    /// Applies `tol`, or `ulps_tol` if this is an ULPs check, to the tolerance.
+    ///
+    /// The tolerances of an `AtolRtol` check are expected to have the same
+    /// shape, so `tol` is only applied to the absolute tolerance.
+    pub fn apply<R, F, G>(self, tol: F, ulps_tol: G) -> R
+    where
+        F: FnOnce(&'a T) -> R,
+        G: FnOnce(&'a UlpsTol<T>) -> R,
+    {
+        match self {
+            ReportTol::Abs(t)
+            | ReportTol::Rmax(t)
+            | ReportTol::Rmin(t)
+            | ReportTol::R1st(t)
+            | ReportTol::R2nd(t)
+            | ReportTol::AtolRtol(t, _) => tol(t),
+            ReportTol::Ulps(t) => ulps_tol(t),
+        }
+    }
+
+    /// Splits the tolerance of a collection into the tolerances of its
+    /// elements, using `tol`, or `ulps_tol` if this is an ULPs check, to
+    /// iterate over them.
+    pub fn elements<U, F, G, I, J>(self, tol: F, ulps_tol: G) -> Vec<ReportTol<'a, U>>
+    where
+        U: 'a + FloatEqUlpsTol,
+        UlpsTol<U>: 'a + Sized,
+        F: Fn(&'a T) -> I,
+        G: FnOnce(&'a UlpsTol<T>) -> J,
+        I: Iterator<Item = &'a U>,
+        J: Iterator<Item = &'a UlpsTol<U>>,
+    {
+        match self {
+            ReportTol::Abs(t) => tol(t).map(ReportTol::Abs).collect(),
+            ReportTol::Rmax(t) => tol(t).map(ReportTol::Rmax).collect(),
+            ReportTol::Rmin(t) => tol(t).map(ReportTol::Rmin).collect(),
+            ReportTol::R1st(t) => tol(t).map(ReportTol::R1st).collect(),
+            ReportTol::R2nd(t) => tol(t).map(ReportTol::R2nd).collect(),
+            ReportTol::AtolRtol(a, r) => tol(a)
+                .zip(tol(r))
+                .map(|(a, r)| ReportTol::AtolRtol(a, r))
+                .collect(),
+            ReportTol::Ulps(t) => ulps_tol(t).map(ReportTol::Ulps).collect(),
+        }
+    }
+}
+
+impl<T: ?Sized + FloatEqUlpsTol> Clone for ReportTol<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized + FloatEqUlpsTol> Copy for ReportTol<'_, T> {}
+
+impl<T> fmt::Debug for ReportTol<'_, T>
+where
+    T: ?Sized + FloatEqUlpsTol + fmt::Debug,
+    UlpsTol<T>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportTol::Abs(t) => f.debug_tuple("Abs").field(t).finish(),
+            ReportTol::Rmax(t) => f.debug_tuple("Rmax").field(t).finish(),
+            ReportTol::Rmin(t) => f.debug_tuple("Rmin").field(t).finish(),
+            ReportTol::R1st(t) => f.debug_tuple("R1st").field(t).finish(),
+            ReportTol::R2nd(t) => f.debug_tuple("R2nd").field(t).finish(),
+            ReportTol::AtolRtol(a, r) => f.debug_tuple("AtolRtol").field(a).field(r).finish(),
+            ReportTol::Ulps(t) => f.debug_tuple("Ulps").field(t).finish(),
+        }
+    }
+}
+
+/// Build a structured [`Report`] of a comparison using per-field tolerances.
+///
+/// Where [`FloatEq`] only answers whether two values are equal, this describes
+/// the result of comparing each of their fields or elements, so that failures
+/// may be logged, aggregated or displayed by the caller. It is used in the
+/// implementation of the [`compare!`] macro.
+///
+/// Implementations should agree with the corresponding [`FloatEq`] method as to
+/// whether the comparison passed.
+///
+/// To implement this trait over a new type, see [How to inspect comparison reports].
+///
+/// [How to inspect comparison reports]: https://jtempest.github.io/float_eq-rs/book/how_to/inspect_comparison_reports.html#supporting-custom-types
+pub trait FloatEqReport<Rhs: ?Sized = Self>: FloatEq<Rhs> {
+    /// Compare `self` to `other` using the check and tolerance in `tol`,
+    /// returning a node whose path is `path`.
+    fn report(&self, other: &Rhs, tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode;
+}
+
+/// Build a structured [`Report`] of a comparison using a uniform tolerance.
+///
+/// This is the `_all` equivalent of [`FloatEqReport`], used in the
+/// implementation of the [`compare!`] macro.
+///
+/// To implement this trait over a new type, see [How to inspect comparison reports].
+///
+/// [How to inspect comparison reports]: https://jtempest.github.io/float_eq-rs/book/how_to/inspect_comparison_reports.html#supporting-custom-types
+pub trait FloatEqAllReport<Rhs: ?Sized = Self>: FloatEqAll<Rhs> {
+    /// Compare `self` to `other` using the check and tolerance in `tol` for
+    /// every field, returning a node whose path is `path`.
+    fn report_all(&self, other: &Rhs, tol: ReportTol<'_, Self::AllTol>, path: String)
+        -> ReportNode;
+}
+
+/// The result of a comparison made by [`compare!`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Report {
+    /// The name of the check that was made, for example `"rmax"` or `"abs_all"`.
+    pub check: &'static str,
+    /// The result of comparing the two values, whose path is empty.
+    pub root: ReportNode,
+}
+
+/// Deserializes the name of the check as the matching static name, since only
+/// the checks accepted by the macros may be made.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Report {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Report")]
+        struct OwnedReport {
+            check: String,
+            root: ReportNode,
+        }
+
+        let report = OwnedReport::deserialize(deserializer)?;
+        let check = crate::parse::CHECK_NAMES
+            .iter()
+            .find(|known| **known == report.check)
+            .copied()
+            .ok_or_else(|| {
+                serde::de::Error::unknown_variant(&report.check, crate::parse::CHECK_NAMES)
+            })?;
+        Ok(Report {
+            check,
+            root: report.root,
+        })
+    }
+}
+
+impl Report {
+    /// Whether the two values compared equal.
+    #[inline]
+    pub fn passed(&self) -> bool {
+        self.root.passed
+    }
+
+    /// Iterate over every node of the report, depth first.
+    pub fn nodes(&self) -> ReportNodes<'_> {
+        ReportNodes {
+            stack: vec![&self.root],
+        }
+    }
+
+    /// Iterate over the leaf nodes that did not pass, which are the individual
+    /// fields or elements that caused the comparison to fail.
+    pub fn failures(&self) -> impl Iterator<Item = &ReportNode> {
+        self.nodes()
+            .filter(|node| !node.passed && !matches!(node.kind, ReportKind::Composite(_)))
+    }
+}
+
+impl fmt::Display for Report {
+    /// Lists the failures of the report, one per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.check)?;
+        if self.passed() {
+            return write!(f, "passed");
+        }
+
+        let count = self.failures().count();
+        write!(f, "{} failure{}", count, if count == 1 { "" } else { "s" })?;
+        for node in self.failures() {
+            write!(f, "\n    {}", node)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of comparing a single value, field or element.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReportNode {
+    /// The location of the value within the compared values, for example
+    /// `.pos[2]` or `["key"].re`. The path of the values themselves is empty.
+    pub path: String,
+    /// Whether the comparison passed.
+    pub passed: bool,
+    /// What was compared.
+    pub kind: ReportKind,
+}
+
+impl ReportNode {
+    /// A comparison of two floating point values.
+    #[inline]
+    pub fn float(path: String, passed: bool, report: FloatReport) -> Self {
+        ReportNode {
+            path,
+            passed,
+            kind: ReportKind::Float(report),
+        }
+    }
+
+    /// A comparison of values that are not floating point, made with `==`.
+    #[inline]
+    pub fn exact(path: String, passed: bool) -> Self {
+        ReportNode {
+            path,
+            passed,
+            kind: ReportKind::Exact,
+        }
+    }
+
+    /// A comparison of composite values, which passes if all of its fields or
+    /// elements do.
+    #[inline]
+    pub fn composite(path: String, children: Vec<ReportNode>) -> Self {
+        ReportNode {
+            path,
+            passed: children.iter().all(|child| child.passed),
+            kind: ReportKind::Composite(children),
+        }
+    }
+
+    /// A comparison that failed because the values had a different structure.
+    #[inline]
+    pub fn mismatch(path: String, mismatch: Mismatch) -> Self {
+        ReportNode {
+            path,
+            passed: false,
+            kind: ReportKind::Mismatch(mismatch),
+        }
+    }
+}
+
+impl fmt::Display for ReportNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "<root>"
+        } else {
+            &self.path
+        };
+        match &self.kind {
+            ReportKind::Float(report) => write!(f, "{}: {}", path, report),
+            ReportKind::Exact => write!(f, "{}: values differ", path),
+            ReportKind::Composite(children) => {
+                let failed = children.iter().filter(|child| !child.passed).count();
+                write!(f, "{}: {} of {} failed", path, failed, children.len())
+            }
+            ReportKind::Mismatch(mismatch) => write!(f, "{}: {}", path, mismatch),
+        }
+    }
+}
+
+/// What was compared by a [`ReportNode`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReportKind {
+    /// Two floating point values.
+    Float(FloatReport),
+    /// Two values that are not floating point, compared with `==`.
+    Exact,
+    /// Two composite values, with the results of comparing each of their
+    /// fields or elements.
+    Composite(Vec<ReportNode>),
+    /// Two values that could not be compared since their structure differs.
+    Mismatch(Mismatch),
+}
+
+/// The comparison of two floating point values.
+///
+/// Values are widened to `f64` and ULPs to `u64`, which is lossless for all
+/// of the floating point types supported by float_eq.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FloatReport {
+    /// The first value.
+    pub left: f64,
+    /// The second value.
+    pub right: f64,
+    /// The absolute difference between the values.
+    pub abs_diff: f64,
+    /// The difference between the values in ULPs, or `None` if their signs
+    /// differ or either is NaN.
+    pub ulps_diff: Option<u64>,
+    /// The tolerance the absolute or ULPs difference was compared to.
+    pub tol: EffectiveTol,
+}
+
+impl fmt::Display for FloatReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "left = {:?}, right = {:?}, abs_diff = {:?}, ulps_diff = {:?}, tol = {}",
+            self.left, self.right, self.abs_diff, self.ulps_diff, self.tol
+        )
+    }
+}
+
+/// The tolerance used to compare two floating point values, after any scaling
+/// by a relative check has been applied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EffectiveTol {
+    /// An absolute tolerance, compared to the absolute difference.
+    Abs(f64),
+    /// A tolerance in ULPs, compared to the ULPs difference.
+    Ulps(u64),
+}
+
+impl fmt::Display for EffectiveTol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EffectiveTol::Abs(tol) => write!(f, "{:?}", tol),
+            EffectiveTol::Ulps(tol) => write!(f, "{} ulps", tol),
+        }
+    }
+}
+
+/// Why two values could not be compared.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Mismatch {
+    /// The collections have different lengths.
+    Len {
+        /// Length of the first collection.
+        left: usize,
+        /// Length of the second collection.
+        right: usize,
+    },
+    /// The tolerance has a different length to the collections.
+    TolLen {
+        /// Length of the collections.
+        len: usize,
+        /// Length of the tolerance.
+        tol: usize,
+    },
+    /// The multidimensional arrays have different shapes.
+    Shape {
+        /// Shape of the first array.
+        left: Vec<usize>,
+        /// Shape of the second array.
+        right: Vec<usize>,
+    },
+    /// The tolerance has a different shape to the multidimensional arrays.
+    TolShape {
+        /// Shape of the arrays.
+        shape: Vec<usize>,
+        /// Shape of the tolerance.
+        tol: Vec<usize>,
+    },
+    /// The key is only present in the first map.
+    MissingRight,
+    /// The key is only present in the second map.
+    MissingLeft,
+    /// The key is not present in the tolerance.
+    MissingTol,
+    /// The values or the tolerance are different variants of an enum, or are
+    /// `None`.
+    Variant,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::Len { left, right } => {
+                write!(f, "lengths differ (left: {}, right: {})", left, right)
+            }
+            Mismatch::TolLen { len, tol } => {
+                write!(
+                    f,
+                    "tolerance length differs (values: {}, tol: {})",
+                    len, tol
+                )
+            }
+            Mismatch::Shape { left, right } => {
+                write!(f, "shapes differ (left: {:?}, right: {:?})", left, right)
+            }
+            Mismatch::TolShape { shape, tol } => {
+                write!(
+                    f,
+                    "tolerance shape differs (values: {:?}, tol: {:?})",
+                    shape, tol
+                )
+            }
+            Mismatch::MissingRight => write!(f, "missing from right"),
+            Mismatch::MissingLeft => write!(f, "missing from left"),
+            Mismatch::MissingTol => write!(f, "missing from tolerance"),
+            Mismatch::Variant => write!(f, "variants differ"),
+        }
+    }
+}
+
+/// Iterator over the nodes of a [`Report`], returned by [`Report::nodes`].
+#[derive(Clone, Debug)]
+pub struct ReportNodes<'a> {
+    stack: Vec<&'a ReportNode>,
+}
+
+impl<'a> Iterator for ReportNodes<'a> {
+    type Item = &'a ReportNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        if let ReportKind::Composite(children) = &node.kind {
+            self.stack.extend(children.iter().rev());
+        }
+        Some(node)
+    }
+}
+
+/// Helper for comparing a pair of collections element by element, which reports
+/// a mismatch if their lengths differ. If `tol` is finite, as it is for
+/// per-element tolerances, then it must also have the same length.
+pub(crate) fn report_elements<A, B, T, F>(
+    path: String,
+    left: A,
+    right: B,
+    tol: T,
+    func: F,
+) -> ReportNode
+where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator,
+    T: Iterator,
+    F: Fn(A::Item, B::Item, T::Item, String) -> ReportNode,
+{
+    let (len, right_len) = (left.len(), right.len());
+    if len != right_len {
+        return ReportNode::mismatch(
+            path,
+            Mismatch::Len {
+                left: len,
+                right: right_len,
+            },
+        );
+    }
+    if let Some(tol_len) = tol.size_hint().1 {
+        if tol_len != len {
+            return ReportNode::mismatch(path, Mismatch::TolLen { len, tol: tol_len });
+        }
+    }
+
+    let children = left
+        .zip(right)
+        .zip(tol)
+        .enumerate()
+        .map(|(i, ((a, b), t))| func(a, b, t, format!("{}[{}]", path, i)))
+        .collect();
+    ReportNode::composite(path, children)
+}
+
+#[doc(hidden)]
+pub struct FloatEqReportCmp;
+
+#[doc(hidden)]
+impl FloatEqReportCmp {
+    #[inline]
+    pub fn abs<A, B>(a: &A, b: &B, tol: &A::Tol) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        B: ?Sized,
+    {
+        Self::report("abs", a, b, ReportTol::Abs(tol))
+    }
+
+    #[inline]
+    pub fn abs_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        B: ?Sized,
+    {
+        Self::report_all("abs_all", a, b, ReportTol::Abs(tol))
+    }
+
+    #[inline]
+    pub fn rel<A, B>(a: &A, b: &B, tol: &A::Tol) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        B: ?Sized,
+    {
+        Self::report("rmax", a, b, ReportTol::Rmax(tol))
+    }
+
+    #[inline]
+    pub fn rel_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        B: ?Sized,
+    {
+        Self::report_all("rmax_all", a, b, ReportTol::Rmax(tol))
+    }
+
+    #[inline]
+    pub fn rmax<A, B>(a: &A, b: &B, tol: &A::Tol) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        B: ?Sized,
+    {
+        Self::report("rmax", a, b, ReportTol::Rmax(tol))
+    }
+
+    #[inline]
+    pub fn rmax_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        B: ?Sized,
+    {
+        Self::report_all("rmax_all", a, b, ReportTol::Rmax(tol))
+    }
+
+    #[inline]
+    pub fn rmin<A, B>(a: &A, b: &B, tol: &A::Tol) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        B: ?Sized,
+    {
+        Self::report("rmin", a, b, ReportTol::Rmin(tol))
+    }
+
+    #[inline]
+    pub fn rmin_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        B: ?Sized,
+    {
+        Self::report_all("rmin_all", a, b, ReportTol::Rmin(tol))
+    }
+
+    #[inline]
+    pub fn r1st<A, B>(a: &A, b: &B, tol: &A::Tol) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        B: ?Sized,
+    {
+        Self::report("r1st", a, b, ReportTol::R1st(tol))
+    }
+
+    #[inline]
+    pub fn r1st_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        B: ?Sized,
+    {
+        Self::report_all("r1st_all", a, b, ReportTol::R1st(tol))
+    }
+
+    #[inline]
+    pub fn r2nd<A, B>(a: &A, b: &B, tol: &A::Tol) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        B: ?Sized,
+    {
+        Self::report("r2nd", a, b, ReportTol::R2nd(tol))
+    }
+
+    #[inline]
+    pub fn r2nd_all<A, B>(a: &A, b: &B, tol: &A::AllTol) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        B: ?Sized,
+    {
+        Self::report_all("r2nd_all", a, b, ReportTol::R2nd(tol))
+    }
+
+    #[inline]
+    pub fn ulps<A, B>(a: &A, b: &B, tol: &UlpsTol<A::Tol>) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        B: ?Sized,
+    {
+        Self::report("ulps", a, b, ReportTol::Ulps(tol))
+    }
+
+    #[inline]
+    pub fn ulps_all<A, B>(a: &A, b: &B, tol: &UlpsTol<A::AllTol>) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        B: ?Sized,
+    {
+        Self::report_all("ulps_all", a, b, ReportTol::Ulps(tol))
+    }
+
+    #[inline]
+    pub fn atol_rtol<A, B>(a: &A, b: &B, tol: &(A::Tol, A::Tol)) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        A::Tol: Sized,
+        B: ?Sized,
+    {
+        Self::report("atol_rtol", a, b, ReportTol::AtolRtol(&tol.0, &tol.1))
+    }
+
+    #[inline]
+    pub fn atol_rtol_all<A, B>(a: &A, b: &B, tol: &(A::AllTol, A::AllTol)) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        A::AllTol: Sized,
+        B: ?Sized,
+    {
+        Self::report_all("atol_rtol_all", a, b, ReportTol::AtolRtol(&tol.0, &tol.1))
+    }
+
+    fn report<A, B>(check: &'static str, a: &A, b: &B, tol: ReportTol<'_, A::Tol>) -> Report
+    where
+        A: ?Sized + FloatEqReport<B>,
+        B: ?Sized,
+    {
+        Report {
+            check,
+            root: a.report(b, tol, String::new()),
+        }
+    }
+
+    fn report_all<A, B>(check: &'static str, a: &A, b: &B, tol: ReportTol<'_, A::AllTol>) -> Report
+    where
+        A: ?Sized + FloatEqAllReport<B>,
+        B: ?Sized,
+    {
+        Report {
+            check,
+            root: a.report_all(b, tol, String::new()),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 mod arrays;
 mod core_types;
+#[macro_use]
 mod primitives;
+#[macro_use]
+mod wrappers;
 mod tuples;
 
 #[cfg(feature = "std")]
@@ -10,3 +13,20 @@ mod std_types;
 mod num_complex;
 #[cfg(feature = "num")]
 pub use self::num_complex::*;
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "glam")]
+pub use self::glam::*;
+
+#[cfg(feature = "half")]
+mod half;
+
+#[cfg(feature = "mint")]
+mod mint;
+
+#[cfg(feature = "ndarray")]
+mod ndarray;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
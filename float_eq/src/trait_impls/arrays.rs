@@ -1,6 +1,11 @@
+#[cfg(feature = "std")]
 use crate::{
-    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
-    FloatEqUlpsTol, UlpsTol,
+    assert_diffs::diff_elements, report::report_elements, AssertDiffs, AssertFloatEqAllDiffs,
+    AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport, ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, AssertFloatOrd, DebugUlpsDiff, FloatEq, FloatEqAll,
+    FloatEqDebugUlpsDiff, FloatEqUlpsTol, FloatOrd, FloatOrdOp, UlpsTol,
 };
 use core::mem::MaybeUninit;
 
@@ -85,6 +90,16 @@ where
         true
     }
 
+    #[inline]
+    fn eq_atol_rtol(&self, other: &[B; N], atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        for i in 0..N {
+            if !self[i].eq_atol_rtol(&other[i], &atol[i], &rtol[i]) {
+                return false;
+            }
+        }
+        true
+    }
+
     #[inline]
     fn eq_ulps(&self, other: &[B; N], tol: &UlpsTol<Self::Tol>) -> bool {
         for i in 0..N {
@@ -96,6 +111,73 @@ where
     }
 }
 
+impl<A, B, const N: usize> FloatOrd<[B; N]> for [A; N]
+where
+    A: FloatOrd<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+{
+    #[inline]
+    fn ord_abs(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        for i in 0..N {
+            if !self[i].ord_abs(&other[i], op, &tol[i]) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    fn ord_rmax(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        for i in 0..N {
+            if !self[i].ord_rmax(&other[i], op, &tol[i]) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    fn ord_rmin(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        for i in 0..N {
+            if !self[i].ord_rmin(&other[i], op, &tol[i]) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    fn ord_r1st(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        for i in 0..N {
+            if !self[i].ord_r1st(&other[i], op, &tol[i]) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    fn ord_r2nd(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        for i in 0..N {
+            if !self[i].ord_r2nd(&other[i], op, &tol[i]) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    fn ord_ulps(&self, other: &[B; N], op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+        for i in 0..N {
+            if !self[i].ord_ulps(&other[i], op, &tol[i]) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 impl<A, B, const N: usize> FloatEqAll<[B; N]> for [A; N]
 where
     A: FloatEqAll<B>,
@@ -137,6 +219,13 @@ where
             .all(|(a, b)| a.eq_r2nd_all(b, tol))
     }
 
+    #[inline]
+    fn eq_atol_rtol_all(&self, other: &[B; N], atol: &Self::AllTol, rtol: &Self::AllTol) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.eq_atol_rtol_all(b, atol, rtol))
+    }
+
     #[inline]
     fn eq_ulps_all(&self, other: &[B; N], tol: &UlpsTol<Self::AllTol>) -> bool {
         self.iter()
@@ -219,6 +308,21 @@ where
         unsafe { array_assume_init(result) }
     }
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &[B; N],
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
+        for i in 0..N {
+            result[i] =
+                MaybeUninit::new(self[i].debug_atol_rtol_tol(&other[i], &atol[i], &rtol[i]));
+        }
+        unsafe { array_assume_init(result) }
+    }
+
     #[inline]
     fn debug_ulps_tol(&self, other: &[B; N], tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol> {
         let mut result: [MaybeUninit<UlpsTol<A::DebugTol>>; N] = uninit_array();
@@ -227,6 +331,77 @@ where
         }
         unsafe { array_assume_init(result) }
     }
+
+    // Arrays are itemised when nested in a collection, but are usually small
+    // enough to display in full.
+}
+
+impl<A, B, const N: usize> AssertFloatOrd<[B; N]> for [A; N]
+where
+    A: AssertFloatOrd<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+{
+    #[inline]
+    fn debug_ord_abs(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
+        for i in 0..N {
+            result[i] = MaybeUninit::new(self[i].debug_ord_abs(&other[i], op, &tol[i]));
+        }
+        unsafe { array_assume_init(result) }
+    }
+
+    #[inline]
+    fn debug_ord_rmax(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
+        for i in 0..N {
+            result[i] = MaybeUninit::new(self[i].debug_ord_rmax(&other[i], op, &tol[i]));
+        }
+        unsafe { array_assume_init(result) }
+    }
+
+    #[inline]
+    fn debug_ord_rmin(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
+        for i in 0..N {
+            result[i] = MaybeUninit::new(self[i].debug_ord_rmin(&other[i], op, &tol[i]));
+        }
+        unsafe { array_assume_init(result) }
+    }
+
+    #[inline]
+    fn debug_ord_r1st(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
+        for i in 0..N {
+            result[i] = MaybeUninit::new(self[i].debug_ord_r1st(&other[i], op, &tol[i]));
+        }
+        unsafe { array_assume_init(result) }
+    }
+
+    #[inline]
+    fn debug_ord_r2nd(&self, other: &[B; N], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
+        for i in 0..N {
+            result[i] = MaybeUninit::new(self[i].debug_ord_r2nd(&other[i], op, &tol[i]));
+        }
+        unsafe { array_assume_init(result) }
+    }
+
+    #[inline]
+    fn debug_ord_ulps(
+        &self,
+        other: &[B; N],
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol {
+        let mut result: [MaybeUninit<A::DebugTol>; N] = uninit_array();
+        for i in 0..N {
+            result[i] = MaybeUninit::new(self[i].debug_ord_ulps(&other[i], op, &tol[i]));
+        }
+        unsafe { array_assume_init(result) }
+    }
 }
 
 impl<A, B, const N: usize> AssertFloatEqAll<[B; N]> for [A; N]
@@ -281,6 +456,20 @@ where
         unsafe { array_assume_init(result) }
     }
 
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &[B; N],
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        let mut result: [MaybeUninit<A::AllDebugTol>; N] = uninit_array();
+        for i in 0..N {
+            result[i] = MaybeUninit::new(self[i].debug_atol_rtol_all_tol(&other[i], atol, rtol));
+        }
+        unsafe { array_assume_init(result) }
+    }
+
     #[inline]
     fn debug_ulps_all_tol(
         &self,
@@ -294,3 +483,99 @@ where
         unsafe { array_assume_init(result) }
     }
 }
+
+#[cfg(feature = "std")]
+impl<A, B, const N: usize> FloatEqReport<[B; N]> for [A; N]
+where
+    A: FloatEqReport<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+{
+    fn report(&self, other: &[B; N], tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+        let tol = tol.elements(|t| t.iter(), |t| t.iter());
+        report_elements(
+            path,
+            self.iter(),
+            other.iter(),
+            tol.into_iter(),
+            |a, b, t, path| a.report(b, t, path),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, const N: usize> FloatEqAllReport<[B; N]> for [A; N]
+where
+    A: FloatEqAllReport<B>,
+{
+    fn report_all(
+        &self,
+        other: &[B; N],
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        let tol = core::iter::repeat(tol);
+        report_elements(path, self.iter(), other.iter(), tol, |a, b, t, path| {
+            a.report_all(b, t, path)
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, const N: usize> AssertFloatEqDiffs<[B; N]> for [A; N]
+where
+    A: AssertFloatEqDiffs<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+{
+    fn debug_diffs(
+        &self,
+        other: &[B; N],
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol = tol.elements(|t| t.iter(), |t| t.iter());
+        diff_elements(
+            path,
+            self.iter(),
+            other.iter(),
+            tol.into_iter(),
+            diffs,
+            |a, b, t, path, diffs| {
+                a.debug_diffs(b, t, path, diffs);
+            },
+        );
+        false
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, const N: usize> AssertFloatEqAllDiffs<[B; N]> for [A; N]
+where
+    A: AssertFloatEqAllDiffs<B>,
+    UlpsTol<A::AllDebugTol>: Sized,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &[B; N],
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol = core::iter::repeat(tol);
+        diff_elements(
+            path,
+            self.iter(),
+            other.iter(),
+            tol,
+            diffs,
+            |a, b, t, path, diffs| {
+                a.debug_all_diffs(b, t, path, diffs);
+            },
+        );
+        false
+    }
+}
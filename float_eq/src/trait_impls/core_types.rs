@@ -1,6 +1,11 @@
+#[cfg(feature = "std")]
 use crate::{
-    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
-    FloatEqUlpsTol, UlpsTol,
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport,
+    Mismatch, ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, AssertFloatOrd, DebugUlpsDiff, FloatEq, FloatEqAll,
+    FloatEqDebugUlpsDiff, FloatEqUlpsTol, FloatOrd, FloatOrdOp, UlpsTol,
 };
 use core::cell::{Cell, RefCell};
 
@@ -41,12 +46,52 @@ macro_rules! impl_for_refs {
                 FloatEq::eq_r2nd(*self, *other, tol)
             }
 
+            #[inline]
+            fn eq_atol_rtol(&self, other: &&$($b)? B, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                FloatEq::eq_atol_rtol(*self, *other, atol, rtol)
+            }
+
             #[inline]
             fn eq_ulps(&self, other: &&$($b)? B, tol: &UlpsTol<Self::Tol>) -> bool {
                 FloatEq::eq_ulps(*self, *other, tol)
             }
         }
 
+        impl<A: ?Sized, B: ?Sized> FloatOrd<&$($b)? B> for &$($a)? A
+        where
+            A: FloatOrd<B>,
+        {
+            #[inline]
+            fn ord_abs(&self, other: &&$($b)? B, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_abs(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn ord_rmax(&self, other: &&$($b)? B, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_rmax(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn ord_rmin(&self, other: &&$($b)? B, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_rmin(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn ord_r1st(&self, other: &&$($b)? B, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_r1st(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn ord_r2nd(&self, other: &&$($b)? B, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_r2nd(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn ord_ulps(&self, other: &&$($b)? B, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+                FloatOrd::ord_ulps(*self, *other, op, tol)
+            }
+        }
+
         impl<A: ?Sized, B: ?Sized> FloatEqAll<&$($b)? B> for &$($a)? A
         where
             A: FloatEqAll<B>,
@@ -78,6 +123,16 @@ macro_rules! impl_for_refs {
                 FloatEqAll::eq_r2nd_all(*self, *other, tol)
             }
 
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &&$($b)? B,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                FloatEqAll::eq_atol_rtol_all(*self, *other, atol, rtol)
+            }
+
             #[inline]
             fn eq_ulps_all(&self, other: &&$($b)? B, tol: &UlpsTol<Self::AllTol>) -> bool {
                 FloatEqAll::eq_ulps_all(*self, *other, tol)
@@ -146,6 +201,16 @@ macro_rules! impl_for_refs {
                 AssertFloatEq::debug_r2nd_tol(*self, *other, tol)
             }
 
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &&$($b)? B,
+                atol: &Self::Tol,
+                rtol: &Self::Tol
+            ) -> Self::DebugTol {
+                AssertFloatEq::debug_atol_rtol_tol(*self, *other, atol, rtol)
+            }
+
             #[inline]
             fn debug_ulps_tol(
                 &self,
@@ -159,6 +224,71 @@ macro_rules! impl_for_refs {
             }
         }
 
+        impl<A: ?Sized, B: ?Sized> AssertFloatOrd<&$($b)? B> for &$($a)? A
+        where
+            A: AssertFloatOrd<B>,
+        {
+            #[inline]
+            fn debug_ord_abs(
+                &self,
+                other: &&$($b)? B,
+                op: FloatOrdOp,
+                tol: &Self::Tol
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_abs(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_rmax(
+                &self,
+                other: &&$($b)? B,
+                op: FloatOrdOp,
+                tol: &Self::Tol
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_rmax(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_rmin(
+                &self,
+                other: &&$($b)? B,
+                op: FloatOrdOp,
+                tol: &Self::Tol
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_rmin(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_r1st(
+                &self,
+                other: &&$($b)? B,
+                op: FloatOrdOp,
+                tol: &Self::Tol
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_r1st(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_r2nd(
+                &self,
+                other: &&$($b)? B,
+                op: FloatOrdOp,
+                tol: &Self::Tol
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_r2nd(*self, *other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_ulps(
+                &self,
+                other: &&$($b)? B,
+                op: FloatOrdOp,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_ulps(*self, *other, op, tol)
+            }
+        }
+
         impl<A: ?Sized, B: ?Sized> AssertFloatEqAll<&$($b)? B> for &$($a)? A
         where
             A: AssertFloatEqAll<B>,
@@ -210,6 +340,16 @@ macro_rules! impl_for_refs {
                 AssertFloatEqAll::debug_r2nd_all_tol(*self, *other, tol)
             }
 
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &&$($b)? B,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol
+            ) -> Self::AllDebugTol {
+                AssertFloatEqAll::debug_atol_rtol_all_tol(*self, *other, atol, rtol)
+            }
+
             #[inline]
             fn debug_ulps_all_tol(
                 &self,
@@ -222,6 +362,72 @@ macro_rules! impl_for_refs {
                 AssertFloatEqAll::debug_ulps_all_tol(*self, *other, tol)
             }
         }
+
+        #[cfg(feature = "std")]
+        impl<A: ?Sized, B: ?Sized> FloatEqReport<&$($b)? B> for &$($a)? A
+        where
+            A: FloatEqReport<B>,
+        {
+            #[inline]
+            fn report(
+                &self,
+                other: &&$($b)? B,
+                tol: ReportTol<'_, Self::Tol>,
+                path: String
+            ) -> ReportNode {
+                FloatEqReport::report(*self, *other, tol, path)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<A: ?Sized, B: ?Sized> FloatEqAllReport<&$($b)? B> for &$($a)? A
+        where
+            A: FloatEqAllReport<B>,
+        {
+            #[inline]
+            fn report_all(
+                &self,
+                other: &&$($b)? B,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String
+            ) -> ReportNode {
+                FloatEqAllReport::report_all(*self, *other, tol, path)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<A: ?Sized, B: ?Sized> AssertFloatEqDiffs<&$($b)? B> for &$($a)? A
+        where
+            A: AssertFloatEqDiffs<B>,
+        {
+            #[inline]
+            fn debug_diffs(
+                &self,
+                other: &&$($b)? B,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                AssertFloatEqDiffs::debug_diffs(*self, *other, tol, path, diffs)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<A: ?Sized, B: ?Sized> AssertFloatEqAllDiffs<&$($b)? B> for &$($a)? A
+        where
+            A: AssertFloatEqAllDiffs<B>,
+        {
+            #[inline]
+            fn debug_all_diffs(
+                &self,
+                other: &&$($b)? B,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                AssertFloatEqAllDiffs::debug_all_diffs(*self, *other, tol, path, diffs)
+            }
+        }
     };
 }
 
@@ -301,6 +507,15 @@ where
         }
     }
 
+    #[inline]
+    fn eq_atol_rtol(&self, other: &Option<T>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        if let (Some(s), Some(o), Some(at), Some(rt)) = (self, other, atol, rtol) {
+            s.eq_atol_rtol(o, at, rt)
+        } else {
+            false
+        }
+    }
+
     #[inline]
     fn eq_ulps(&self, other: &Option<T>, tol: &UlpsTol<Self::Tol>) -> bool {
         if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
@@ -311,6 +526,66 @@ where
     }
 }
 
+impl<T: FloatOrd> FloatOrd for Option<T>
+where
+    T::Tol: Sized,
+    UlpsTol<T::Tol>: Sized,
+{
+    #[inline]
+    fn ord_abs(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.ord_abs(o, op, t)
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn ord_rmax(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.ord_rmax(o, op, t)
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn ord_rmin(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.ord_rmin(o, op, t)
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn ord_r1st(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.ord_r1st(o, op, t)
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn ord_r2nd(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.ord_r2nd(o, op, t)
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn ord_ulps(&self, other: &Option<T>, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.ord_ulps(o, op, t)
+        } else {
+            false
+        }
+    }
+}
+
 impl<T: FloatEqAll> FloatEqAll<Option<T>> for Option<T>
 where
     T::AllTol: Sized,
@@ -363,6 +638,20 @@ where
         }
     }
 
+    #[inline]
+    fn eq_atol_rtol_all(
+        &self,
+        other: &Option<T>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> bool {
+        if let (Some(s), Some(o), Some(at), Some(rt)) = (self, other, atol, rtol) {
+            s.eq_atol_rtol_all(o, at, rt)
+        } else {
+            false
+        }
+    }
+
     #[inline]
     fn eq_ulps_all(&self, other: &Option<T>, tol: &UlpsTol<Self::AllTol>) -> bool {
         if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
@@ -443,6 +732,21 @@ where
         ))
     }
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &Option<T>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        Some(AssertFloatEq::debug_atol_rtol_tol(
+            &self.as_ref()?,
+            &other.as_ref()?,
+            atol.as_ref()?,
+            rtol.as_ref()?,
+        ))
+    }
+
     #[inline]
     fn debug_ulps_tol(
         &self,
@@ -457,6 +761,78 @@ where
     }
 }
 
+impl<T: AssertFloatOrd> AssertFloatOrd for Option<T>
+where
+    T::Tol: Sized,
+    UlpsTol<T::Tol>: Sized,
+    UlpsTol<T::DebugTol>: Sized,
+{
+    #[inline]
+    fn debug_ord_abs(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatOrd::debug_ord_abs(
+            &self.as_ref()?,
+            &other.as_ref()?,
+            op,
+            tol.as_ref()?,
+        ))
+    }
+
+    #[inline]
+    fn debug_ord_rmax(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatOrd::debug_ord_rmax(
+            &self.as_ref()?,
+            &other.as_ref()?,
+            op,
+            tol.as_ref()?,
+        ))
+    }
+
+    #[inline]
+    fn debug_ord_rmin(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatOrd::debug_ord_rmin(
+            &self.as_ref()?,
+            &other.as_ref()?,
+            op,
+            tol.as_ref()?,
+        ))
+    }
+
+    #[inline]
+    fn debug_ord_r1st(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatOrd::debug_ord_r1st(
+            &self.as_ref()?,
+            &other.as_ref()?,
+            op,
+            tol.as_ref()?,
+        ))
+    }
+
+    #[inline]
+    fn debug_ord_r2nd(&self, other: &Option<T>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        Some(AssertFloatOrd::debug_ord_r2nd(
+            &self.as_ref()?,
+            &other.as_ref()?,
+            op,
+            tol.as_ref()?,
+        ))
+    }
+
+    #[inline]
+    fn debug_ord_ulps(
+        &self,
+        other: &Option<T>,
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol {
+        Some(AssertFloatOrd::debug_ord_ulps(
+            &self.as_ref()?,
+            &other.as_ref()?,
+            op,
+            tol.as_ref()?,
+        ))
+    }
+}
+
 impl<T: AssertFloatEqAll> AssertFloatEqAll for Option<T>
 where
     T::AllTol: Sized,
@@ -510,6 +886,21 @@ where
         ))
     }
 
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &Option<T>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Some(AssertFloatEqAll::debug_atol_rtol_all_tol(
+            &self.as_ref()?,
+            &other.as_ref()?,
+            atol.as_ref()?,
+            rtol.as_ref()?,
+        ))
+    }
+
     #[inline]
     fn debug_ulps_all_tol(
         &self,
@@ -527,13 +918,98 @@ where
     }
 }
 
-//------------------------------------------------------------------------------
-// Cell
-//------------------------------------------------------------------------------
-impl<A, B> FloatEq<Cell<B>> for Cell<A>
+#[cfg(feature = "std")]
+impl<T: FloatEqReport> FloatEqReport for Option<T>
 where
-    A: FloatEq<B> + Copy,
-    B: Copy,
+    T::Tol: Sized,
+    UlpsTol<T::Tol>: Sized,
+{
+    fn report(&self, other: &Option<T>, tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+        let tol = tol.try_map(Option::as_ref, Option::as_ref);
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.report(o, t, path)
+        } else {
+            ReportNode::mismatch(path, Mismatch::Variant)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FloatEqAllReport> FloatEqAllReport for Option<T>
+where
+    T::AllTol: Sized,
+    UlpsTol<T::AllTol>: Sized,
+{
+    fn report_all(
+        &self,
+        other: &Option<T>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        let tol = tol.try_map(Option::as_ref, Option::as_ref);
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.report_all(o, t, path)
+        } else {
+            ReportNode::mismatch(path, Mismatch::Variant)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AssertFloatEqDiffs> AssertFloatEqDiffs for Option<T>
+where
+    T::Tol: Sized,
+    UlpsTol<T::Tol>: Sized,
+    UlpsTol<T::DebugTol>: Sized,
+{
+    fn debug_diffs(
+        &self,
+        other: &Option<T>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol = tol.try_map(Option::as_ref, Option::as_ref);
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.debug_diffs(o, t, path, diffs)
+        } else {
+            diffs.push(path, format_args!("{}", Mismatch::Variant));
+            false
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AssertFloatEqAllDiffs> AssertFloatEqAllDiffs for Option<T>
+where
+    T::AllTol: Sized,
+    UlpsTol<T::AllTol>: Sized,
+    UlpsTol<T::AllDebugTol>: Sized,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &Option<T>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol = tol.try_map(Option::as_ref, Option::as_ref);
+        if let (Some(s), Some(o), Some(t)) = (self, other, tol) {
+            s.debug_all_diffs(o, t, path, diffs)
+        } else {
+            diffs.push(path, format_args!("{}", Mismatch::Variant));
+            false
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+// Cell
+//------------------------------------------------------------------------------
+impl<A, B> FloatEq<Cell<B>> for Cell<A>
+where
+    A: FloatEq<B> + Copy,
+    B: Copy,
 {
     type Tol = A::Tol;
 
@@ -562,12 +1038,53 @@ where
         FloatEq::eq_r2nd(&self.get(), &other.get(), tol)
     }
 
+    #[inline]
+    fn eq_atol_rtol(&self, other: &Cell<B>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        FloatEq::eq_atol_rtol(&self.get(), &other.get(), atol, rtol)
+    }
+
     #[inline]
     fn eq_ulps(&self, other: &Cell<B>, tol: &UlpsTol<Self::Tol>) -> bool {
         FloatEq::eq_ulps(&self.get(), &other.get(), tol)
     }
 }
 
+impl<A, B> FloatOrd<Cell<B>> for Cell<A>
+where
+    A: FloatOrd<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn ord_abs(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_abs(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn ord_rmax(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_rmax(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn ord_rmin(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_rmin(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn ord_r1st(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_r1st(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn ord_r2nd(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_r2nd(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn ord_ulps(&self, other: &Cell<B>, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+        FloatOrd::ord_ulps(&self.get(), &other.get(), op, tol)
+    }
+}
+
 impl<A, B> FloatEqAll<Cell<B>> for Cell<A>
 where
     A: FloatEqAll<B> + Copy,
@@ -600,6 +1117,11 @@ where
         FloatEqAll::eq_r2nd_all(&self.get(), &other.get(), tol)
     }
 
+    #[inline]
+    fn eq_atol_rtol_all(&self, other: &Cell<B>, atol: &Self::AllTol, rtol: &Self::AllTol) -> bool {
+        FloatEqAll::eq_atol_rtol_all(&self.get(), &other.get(), atol, rtol)
+    }
+
     #[inline]
     fn eq_ulps_all(&self, other: &Cell<B>, tol: &UlpsTol<Self::AllTol>) -> bool {
         FloatEqAll::eq_ulps_all(&self.get(), &other.get(), tol)
@@ -649,6 +1171,16 @@ where
         AssertFloatEq::debug_r2nd_tol(&self.get(), &other.get(), tol)
     }
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &Cell<B>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        AssertFloatEq::debug_atol_rtol_tol(&self.get(), &other.get(), atol, rtol)
+    }
+
     #[inline]
     fn debug_ulps_tol(&self, other: &Cell<B>, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol>
     where
@@ -658,6 +1190,47 @@ where
     }
 }
 
+impl<A, B> AssertFloatOrd<Cell<B>> for Cell<A>
+where
+    A: AssertFloatOrd<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn debug_ord_abs(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_abs(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_rmax(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_rmax(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_rmin(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_rmin(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_r1st(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_r1st(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_r2nd(&self, other: &Cell<B>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_r2nd(&self.get(), &other.get(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_ulps(
+        &self,
+        other: &Cell<B>,
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_ulps(&self.get(), &other.get(), op, tol)
+    }
+}
+
 impl<A, B> AssertFloatEqAll<Cell<B>> for Cell<A>
 where
     A: AssertFloatEqAll<B> + Copy,
@@ -690,6 +1263,16 @@ where
         AssertFloatEqAll::debug_r2nd_all_tol(&self.get(), &other.get(), tol)
     }
 
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &Cell<B>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        AssertFloatEqAll::debug_atol_rtol_all_tol(&self.get(), &other.get(), atol, rtol)
+    }
+
     #[inline]
     fn debug_ulps_all_tol(
         &self,
@@ -703,6 +1286,71 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<A, B> FloatEqReport<Cell<B>> for Cell<A>
+where
+    A: FloatEqReport<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn report(&self, other: &Cell<B>, tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+        FloatEqReport::report(&self.get(), &other.get(), tol, path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> FloatEqAllReport<Cell<B>> for Cell<A>
+where
+    A: FloatEqAllReport<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn report_all(
+        &self,
+        other: &Cell<B>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        FloatEqAllReport::report_all(&self.get(), &other.get(), tol, path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> AssertFloatEqDiffs<Cell<B>> for Cell<A>
+where
+    A: AssertFloatEqDiffs<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn debug_diffs(
+        &self,
+        other: &Cell<B>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        AssertFloatEqDiffs::debug_diffs(&self.get(), &other.get(), tol, path, diffs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> AssertFloatEqAllDiffs<Cell<B>> for Cell<A>
+where
+    A: AssertFloatEqAllDiffs<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn debug_all_diffs(
+        &self,
+        other: &Cell<B>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        AssertFloatEqAllDiffs::debug_all_diffs(&self.get(), &other.get(), tol, path, diffs)
+    }
+}
+
 //------------------------------------------------------------------------------
 // RefCell
 //------------------------------------------------------------------------------
@@ -737,12 +1385,52 @@ where
         FloatEq::eq_r2nd(&*self.borrow(), &*other.borrow(), tol)
     }
 
+    #[inline]
+    fn eq_atol_rtol(&self, other: &RefCell<B>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        FloatEq::eq_atol_rtol(&*self.borrow(), &*other.borrow(), atol, rtol)
+    }
+
     #[inline]
     fn eq_ulps(&self, other: &RefCell<B>, tol: &UlpsTol<Self::Tol>) -> bool {
         FloatEq::eq_ulps(&*self.borrow(), &*other.borrow(), tol)
     }
 }
 
+impl<A: ?Sized, B: ?Sized> FloatOrd<RefCell<B>> for RefCell<A>
+where
+    A: FloatOrd<B>,
+{
+    #[inline]
+    fn ord_abs(&self, other: &RefCell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_abs(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn ord_rmax(&self, other: &RefCell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_rmax(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn ord_rmin(&self, other: &RefCell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_rmin(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn ord_r1st(&self, other: &RefCell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_r1st(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn ord_r2nd(&self, other: &RefCell<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        FloatOrd::ord_r2nd(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn ord_ulps(&self, other: &RefCell<B>, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+        FloatOrd::ord_ulps(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+}
+
 impl<A: ?Sized, B: ?Sized> FloatEqAll<RefCell<B>> for RefCell<A>
 where
     A: FloatEqAll<B>,
@@ -774,12 +1462,24 @@ where
         FloatEqAll::eq_r2nd_all(&*self.borrow(), &*other.borrow(), tol)
     }
 
+    #[inline]
+    fn eq_atol_rtol_all(
+        &self,
+        other: &RefCell<B>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> bool {
+        FloatEqAll::eq_atol_rtol_all(&*self.borrow(), &*other.borrow(), atol, rtol)
+    }
+
     #[inline]
     fn eq_ulps_all(&self, other: &RefCell<B>, tol: &UlpsTol<Self::AllTol>) -> bool {
         FloatEqAll::eq_ulps_all(&*self.borrow(), &*other.borrow(), tol)
     }
 }
 
+// `Copy` already requires `Sized`, but the bounds are kept as published
+#[allow(unknown_lints, clippy::needless_maybe_sized)]
 impl<A: ?Sized, B: ?Sized> AssertFloatEq<RefCell<B>> for RefCell<A>
 where
     A: AssertFloatEq<B> + Copy,
@@ -823,6 +1523,16 @@ where
         AssertFloatEq::debug_r2nd_tol(&*self.borrow(), &*other.borrow(), tol)
     }
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &RefCell<B>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        AssertFloatEq::debug_atol_rtol_tol(&*self.borrow(), &*other.borrow(), atol, rtol)
+    }
+
     #[inline]
     fn debug_ulps_tol(
         &self,
@@ -836,6 +1546,69 @@ where
     }
 }
 
+impl<A, B> AssertFloatOrd<RefCell<B>> for RefCell<A>
+where
+    A: AssertFloatOrd<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn debug_ord_abs(&self, other: &RefCell<B>, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_abs(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_rmax(
+        &self,
+        other: &RefCell<B>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_rmax(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_rmin(
+        &self,
+        other: &RefCell<B>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_rmin(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_r1st(
+        &self,
+        other: &RefCell<B>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_r1st(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_r2nd(
+        &self,
+        other: &RefCell<B>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_r2nd(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+
+    #[inline]
+    fn debug_ord_ulps(
+        &self,
+        other: &RefCell<B>,
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol {
+        AssertFloatOrd::debug_ord_ulps(&*self.borrow(), &*other.borrow(), op, tol)
+    }
+}
+
+// `Copy` already requires `Sized`, but the bounds are kept as published
+#[allow(unknown_lints, clippy::needless_maybe_sized)]
 impl<A: ?Sized, B: ?Sized> AssertFloatEqAll<RefCell<B>> for RefCell<A>
 where
     A: AssertFloatEqAll<B> + Copy,
@@ -868,6 +1641,16 @@ where
         AssertFloatEqAll::debug_r2nd_all_tol(&*self.borrow(), &*other.borrow(), tol)
     }
 
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &RefCell<B>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        AssertFloatEqAll::debug_atol_rtol_all_tol(&*self.borrow(), &*other.borrow(), atol, rtol)
+    }
+
     #[inline]
     fn debug_ulps_all_tol(
         &self,
@@ -881,6 +1664,74 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<A: ?Sized, B: ?Sized> FloatEqReport<RefCell<B>> for RefCell<A>
+where
+    A: FloatEqReport<B>,
+{
+    #[inline]
+    fn report(
+        &self,
+        other: &RefCell<B>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: String,
+    ) -> ReportNode {
+        FloatEqReport::report(&*self.borrow(), &*other.borrow(), tol, path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: ?Sized, B: ?Sized> FloatEqAllReport<RefCell<B>> for RefCell<A>
+where
+    A: FloatEqAllReport<B>,
+{
+    #[inline]
+    fn report_all(
+        &self,
+        other: &RefCell<B>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        FloatEqAllReport::report_all(&*self.borrow(), &*other.borrow(), tol, path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> AssertFloatEqDiffs<RefCell<B>> for RefCell<A>
+where
+    A: AssertFloatEqDiffs<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn debug_diffs(
+        &self,
+        other: &RefCell<B>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        AssertFloatEqDiffs::debug_diffs(&*self.borrow(), &*other.borrow(), tol, path, diffs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> AssertFloatEqAllDiffs<RefCell<B>> for RefCell<A>
+where
+    A: AssertFloatEqAllDiffs<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn debug_all_diffs(
+        &self,
+        other: &RefCell<B>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        AssertFloatEqAllDiffs::debug_all_diffs(&*self.borrow(), &*other.borrow(), tol, path, diffs)
+    }
+}
+
 //------------------------------------------------------------------------------
 // Slices
 //------------------------------------------------------------------------------
@@ -954,6 +1805,18 @@ where
                 .all(|((a, b), eps)| a.eq_r2nd(b, eps))
     }
 
+    #[inline]
+    fn eq_atol_rtol(&self, other: &[B], atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == atol.len()
+            && self.len() == rtol.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(atol.iter().zip(rtol.iter()))
+                .all(|((a, b), (at, rt))| a.eq_atol_rtol(b, at, rt))
+    }
+
     #[inline]
     fn eq_ulps(&self, other: &[B], tol: &UlpsTol<Self::Tol>) -> bool {
         self.len() == other.len()
@@ -966,6 +1829,79 @@ where
     }
 }
 
+impl<A, B> FloatOrd<[B]> for [A]
+where
+    A: FloatOrd<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+{
+    #[inline]
+    fn ord_abs(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| a.ord_abs(b, op, eps))
+    }
+
+    #[inline]
+    fn ord_rmax(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| a.ord_rmax(b, op, eps))
+    }
+
+    #[inline]
+    fn ord_rmin(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| a.ord_rmin(b, op, eps))
+    }
+
+    #[inline]
+    fn ord_r1st(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| a.ord_r1st(b, op, eps))
+    }
+
+    #[inline]
+    fn ord_r2nd(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| a.ord_r2nd(b, op, eps))
+    }
+
+    #[inline]
+    fn ord_ulps(&self, other: &[B], op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| a.ord_ulps(b, op, eps))
+    }
+}
+
 impl<A, B> FloatEqAll<[B]> for [A]
 where
     A: FloatEqAll<B>,
@@ -1017,6 +1953,15 @@ where
                 .all(|(a, b)| a.eq_r2nd_all(b, tol))
     }
 
+    #[inline]
+    fn eq_atol_rtol_all(&self, other: &[B], atol: &Self::AllTol, rtol: &Self::AllTol) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.eq_atol_rtol_all(b, atol, rtol))
+    }
+
     #[inline]
     fn eq_ulps_all(&self, other: &[B], tol: &UlpsTol<Self::AllTol>) -> bool {
         self.len() == other.len()
@@ -0,0 +1,594 @@
+#[cfg(feature = "std")]
+use crate::{
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport,
+    ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, UlpsTol,
+};
+use glam::{
+    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2,
+    Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4,
+};
+
+/// The absolute difference between two floating point [`glam::Vec2`] or
+/// [`glam::DVec2`] instances in ULPs.
+///
+/// [`glam::Vec2`]: https://docs.rs/glam/0.29/glam/f32/struct.Vec2.html
+/// [`glam::DVec2`]: https://docs.rs/glam/0.29/glam/f64/struct.DVec2.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec2Ulps<T> {
+    /// `x` component in ULPs.
+    pub x: T,
+    /// `y` component in ULPs.
+    pub y: T,
+}
+
+impl<T> Vec2Ulps<T> {
+    /// Create a new Vec2Ulps
+    pub fn new(x: T, y: T) -> Self {
+        Vec2Ulps { x, y }
+    }
+}
+
+/// The absolute difference between two floating point [`glam::Vec3`],
+/// [`glam::Vec3A`] or [`glam::DVec3`] instances in ULPs.
+///
+/// [`glam::Vec3`]: https://docs.rs/glam/0.29/glam/f32/struct.Vec3.html
+/// [`glam::Vec3A`]: https://docs.rs/glam/0.29/glam/f32/struct.Vec3A.html
+/// [`glam::DVec3`]: https://docs.rs/glam/0.29/glam/f64/struct.DVec3.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3Ulps<T> {
+    /// `x` component in ULPs.
+    pub x: T,
+    /// `y` component in ULPs.
+    pub y: T,
+    /// `z` component in ULPs.
+    pub z: T,
+}
+
+impl<T> Vec3Ulps<T> {
+    /// Create a new Vec3Ulps
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vec3Ulps { x, y, z }
+    }
+}
+
+/// The absolute difference between two floating point [`glam::Vec4`] or
+/// [`glam::DVec4`] instances in ULPs.
+///
+/// [`glam::Vec4`]: https://docs.rs/glam/0.29/glam/f32/struct.Vec4.html
+/// [`glam::DVec4`]: https://docs.rs/glam/0.29/glam/f64/struct.DVec4.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec4Ulps<T> {
+    /// `x` component in ULPs.
+    pub x: T,
+    /// `y` component in ULPs.
+    pub y: T,
+    /// `z` component in ULPs.
+    pub z: T,
+    /// `w` component in ULPs.
+    pub w: T,
+}
+
+impl<T> Vec4Ulps<T> {
+    /// Create a new Vec4Ulps
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Vec4Ulps { x, y, z, w }
+    }
+}
+
+/// The absolute difference between two floating point [`glam::Quat`] or
+/// [`glam::DQuat`] instances in ULPs.
+///
+/// [`glam::Quat`]: https://docs.rs/glam/0.29/glam/f32/struct.Quat.html
+/// [`glam::DQuat`]: https://docs.rs/glam/0.29/glam/f64/struct.DQuat.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuatUlps<T> {
+    /// `x` component in ULPs.
+    pub x: T,
+    /// `y` component in ULPs.
+    pub y: T,
+    /// `z` component in ULPs.
+    pub z: T,
+    /// `w` component in ULPs.
+    pub w: T,
+}
+
+impl<T> QuatUlps<T> {
+    /// Create a new QuatUlps
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        QuatUlps { x, y, z, w }
+    }
+}
+
+/// The absolute difference between two floating point [`glam::Mat2`] or
+/// [`glam::DMat2`] instances in ULPs, by column.
+///
+/// [`glam::Mat2`]: https://docs.rs/glam/0.29/glam/f32/struct.Mat2.html
+/// [`glam::DMat2`]: https://docs.rs/glam/0.29/glam/f64/struct.DMat2.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mat2Ulps<T> {
+    /// First column in ULPs.
+    pub x_axis: Vec2Ulps<T>,
+    /// Second column in ULPs.
+    pub y_axis: Vec2Ulps<T>,
+}
+
+impl<T> Mat2Ulps<T> {
+    /// Create a new Mat2Ulps from its columns
+    pub fn from_cols(x_axis: Vec2Ulps<T>, y_axis: Vec2Ulps<T>) -> Self {
+        Mat2Ulps { x_axis, y_axis }
+    }
+}
+
+/// The absolute difference between two floating point [`glam::Mat3`],
+/// [`glam::Mat3A`] or [`glam::DMat3`] instances in ULPs, by column.
+///
+/// [`glam::Mat3`]: https://docs.rs/glam/0.29/glam/f32/struct.Mat3.html
+/// [`glam::Mat3A`]: https://docs.rs/glam/0.29/glam/f32/struct.Mat3A.html
+/// [`glam::DMat3`]: https://docs.rs/glam/0.29/glam/f64/struct.DMat3.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mat3Ulps<T> {
+    /// First column in ULPs.
+    pub x_axis: Vec3Ulps<T>,
+    /// Second column in ULPs.
+    pub y_axis: Vec3Ulps<T>,
+    /// Third column in ULPs.
+    pub z_axis: Vec3Ulps<T>,
+}
+
+impl<T> Mat3Ulps<T> {
+    /// Create a new Mat3Ulps from its columns
+    pub fn from_cols(x_axis: Vec3Ulps<T>, y_axis: Vec3Ulps<T>, z_axis: Vec3Ulps<T>) -> Self {
+        Mat3Ulps {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+}
+
+/// The absolute difference between two floating point [`glam::Mat4`] or
+/// [`glam::DMat4`] instances in ULPs, by column.
+///
+/// [`glam::Mat4`]: https://docs.rs/glam/0.29/glam/f32/struct.Mat4.html
+/// [`glam::DMat4`]: https://docs.rs/glam/0.29/glam/f64/struct.DMat4.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mat4Ulps<T> {
+    /// First column in ULPs.
+    pub x_axis: Vec4Ulps<T>,
+    /// Second column in ULPs.
+    pub y_axis: Vec4Ulps<T>,
+    /// Third column in ULPs.
+    pub z_axis: Vec4Ulps<T>,
+    /// Fourth column in ULPs.
+    pub w_axis: Vec4Ulps<T>,
+}
+
+impl<T> Mat4Ulps<T> {
+    /// Create a new Mat4Ulps from its columns
+    pub fn from_cols(
+        x_axis: Vec4Ulps<T>,
+        y_axis: Vec4Ulps<T>,
+        z_axis: Vec4Ulps<T>,
+        w_axis: Vec4Ulps<T>,
+    ) -> Self {
+        Mat4Ulps {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+}
+
+/// The absolute difference between two floating point [`glam::Affine2`] or
+/// [`glam::DAffine2`] instances in ULPs.
+///
+/// [`glam::Affine2`]: https://docs.rs/glam/0.29/glam/f32/struct.Affine2.html
+/// [`glam::DAffine2`]: https://docs.rs/glam/0.29/glam/f64/struct.DAffine2.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affine2Ulps<T> {
+    /// Linear transformation in ULPs.
+    pub matrix2: Mat2Ulps<T>,
+    /// Translation in ULPs.
+    pub translation: Vec2Ulps<T>,
+}
+
+/// The absolute difference between two floating point [`glam::Affine3A`] or
+/// [`glam::DAffine3`] instances in ULPs.
+///
+/// [`glam::Affine3A`]: https://docs.rs/glam/0.29/glam/f32/struct.Affine3A.html
+/// [`glam::DAffine3`]: https://docs.rs/glam/0.29/glam/f64/struct.DAffine3.html
+#[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affine3Ulps<T> {
+    /// Linear transformation in ULPs.
+    pub matrix3: Mat3Ulps<T>,
+    /// Translation in ULPs.
+    pub translation: Vec3Ulps<T>,
+}
+
+// glam types are compared component by component, and are their own tolerance
+// and debug types. `$new` builds one from its components, in field order.
+macro_rules! impl_traits_for_glam_type {
+    ($t:ident, $float:ident, $ulps:ident, $new:path, [$($field:ident),+]) => {
+        impl FloatEqUlpsTol for $t {
+            type UlpsTol = $ulps<UlpsTol<$float>>;
+        }
+
+        impl FloatEqDebugUlpsDiff for $t {
+            type DebugUlpsDiff = $ulps<DebugUlpsDiff<$float>>;
+        }
+
+        impl FloatEq for $t {
+            type Tol = $t;
+
+            #[inline]
+            fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_abs(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_rmax(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_rmin(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_r1st(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_r2nd(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_atol_rtol(&self, other: &Self, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                $(self.$field.eq_atol_rtol(&other.$field, &atol.$field, &rtol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+                $(self.$field.eq_ulps(&other.$field, &tol.$field))&&+
+            }
+        }
+
+        impl FloatEqAll for $t {
+            type AllTol = $float;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_abs_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_rmax_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_rmin_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_r1st_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_r2nd_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &Self,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                $(self.$field.eq_atol_rtol_all(&other.$field, atol, rtol))&&+
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+                $(self.$field.eq_ulps_all(&other.$field, tol))&&+
+            }
+        }
+
+        impl AssertFloatEq for $t {
+            type DebugAbsDiff = $t;
+            type DebugTol = $t;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                $new($(self.$field.debug_abs_diff(&other.$field)),+)
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                $ulps {
+                    $($field: self.$field.debug_ulps_diff(&other.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $new($(self.$field.debug_abs_tol(&other.$field, &tol.$field)),+)
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $new($(self.$field.debug_rmax_tol(&other.$field, &tol.$field)),+)
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $new($(self.$field.debug_rmin_tol(&other.$field, &tol.$field)),+)
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $new($(self.$field.debug_r1st_tol(&other.$field, &tol.$field)),+)
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $new($(self.$field.debug_r2nd_tol(&other.$field, &tol.$field)),+)
+            }
+
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &Self,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $new($(self
+                    .$field
+                    .debug_atol_rtol_tol(&other.$field, &atol.$field, &rtol.$field)),+)
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                other: &Self,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol> {
+                $ulps {
+                    $($field: self.$field.debug_ulps_tol(&other.$field, &tol.$field)),+
+                }
+            }
+        }
+
+        impl AssertFloatEqAll for $t {
+            type AllDebugTol = $t;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $new($(self.$field.debug_abs_all_tol(&other.$field, tol)),+)
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $new($(self.$field.debug_rmax_all_tol(&other.$field, tol)),+)
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $new($(self.$field.debug_rmin_all_tol(&other.$field, tol)),+)
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $new($(self.$field.debug_r1st_all_tol(&other.$field, tol)),+)
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $new($(self.$field.debug_r2nd_all_tol(&other.$field, tol)),+)
+            }
+
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &Self,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $new($(self.$field.debug_atol_rtol_all_tol(&other.$field, atol, rtol)),+)
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &Self,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol> {
+                $ulps {
+                    $($field: self.$field.debug_ulps_all_tol(&other.$field, tol)),+
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqReport for $t {
+            fn report(&self, other: &Self, tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+                let children = vec![$(
+                    self.$field.report(
+                        &other.$field,
+                        tol.map(|t| &t.$field, |t| &t.$field),
+                        format!("{}.{}", path, stringify!($field)),
+                    )
+                ),+];
+                ReportNode::composite(path, children)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqAllReport for $t {
+            fn report_all(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                let children = vec![$(
+                    self.$field.report_all(
+                        &other.$field,
+                        tol,
+                        format!("{}.{}", path, stringify!($field)),
+                    )
+                ),+];
+                ReportNode::composite(path, children)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqDiffs for $t {
+            fn debug_diffs(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                $(
+                    self.$field.debug_diffs(
+                        &other.$field,
+                        tol.map(|t| &t.$field, |t| &t.$field),
+                        &format!("{}.{}", path, stringify!($field)),
+                        diffs,
+                    );
+                )+
+                false
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqAllDiffs for $t {
+            fn debug_all_diffs(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                $(
+                    self.$field.debug_all_diffs(
+                        &other.$field,
+                        tol,
+                        &format!("{}.{}", path, stringify!($field)),
+                        diffs,
+                    );
+                )+
+                false
+            }
+        }
+    };
+}
+
+// Affine3A::from_mat3_translation takes the unaligned types.
+fn affine3a(matrix3: Mat3A, translation: Vec3A) -> Affine3A {
+    Affine3A {
+        matrix3,
+        translation,
+    }
+}
+
+impl_traits_for_glam_type!(Vec2, f32, Vec2Ulps, Vec2::new, [x, y]);
+impl_traits_for_glam_type!(Vec3, f32, Vec3Ulps, Vec3::new, [x, y, z]);
+impl_traits_for_glam_type!(Vec3A, f32, Vec3Ulps, Vec3A::new, [x, y, z]);
+impl_traits_for_glam_type!(Vec4, f32, Vec4Ulps, Vec4::new, [x, y, z, w]);
+impl_traits_for_glam_type!(Quat, f32, QuatUlps, Quat::from_xyzw, [x, y, z, w]);
+impl_traits_for_glam_type!(Mat2, f32, Mat2Ulps, Mat2::from_cols, [x_axis, y_axis]);
+impl_traits_for_glam_type!(
+    Mat3,
+    f32,
+    Mat3Ulps,
+    Mat3::from_cols,
+    [x_axis, y_axis, z_axis]
+);
+impl_traits_for_glam_type!(
+    Mat3A,
+    f32,
+    Mat3Ulps,
+    Mat3A::from_cols,
+    [x_axis, y_axis, z_axis]
+);
+impl_traits_for_glam_type!(
+    Mat4,
+    f32,
+    Mat4Ulps,
+    Mat4::from_cols,
+    [x_axis, y_axis, z_axis, w_axis]
+);
+impl_traits_for_glam_type!(
+    Affine2,
+    f32,
+    Affine2Ulps,
+    Affine2::from_mat2_translation,
+    [matrix2, translation]
+);
+impl_traits_for_glam_type!(Affine3A, f32, Affine3Ulps, affine3a, [matrix3, translation]);
+
+impl_traits_for_glam_type!(DVec2, f64, Vec2Ulps, DVec2::new, [x, y]);
+impl_traits_for_glam_type!(DVec3, f64, Vec3Ulps, DVec3::new, [x, y, z]);
+impl_traits_for_glam_type!(DVec4, f64, Vec4Ulps, DVec4::new, [x, y, z, w]);
+impl_traits_for_glam_type!(DQuat, f64, QuatUlps, DQuat::from_xyzw, [x, y, z, w]);
+impl_traits_for_glam_type!(DMat2, f64, Mat2Ulps, DMat2::from_cols, [x_axis, y_axis]);
+impl_traits_for_glam_type!(
+    DMat3,
+    f64,
+    Mat3Ulps,
+    DMat3::from_cols,
+    [x_axis, y_axis, z_axis]
+);
+impl_traits_for_glam_type!(
+    DMat4,
+    f64,
+    Mat4Ulps,
+    DMat4::from_cols,
+    [x_axis, y_axis, z_axis, w_axis]
+);
+impl_traits_for_glam_type!(
+    DAffine2,
+    f64,
+    Affine2Ulps,
+    DAffine2::from_mat2_translation,
+    [matrix2, translation]
+);
+impl_traits_for_glam_type!(
+    DAffine3,
+    f64,
+    Affine3Ulps,
+    DAffine3::from_mat3_translation,
+    [matrix3, translation]
+);
@@ -0,0 +1,334 @@
+#![allow(clippy::float_cmp)]
+
+#[cfg(feature = "std")]
+use crate::{
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, EffectiveTol, FloatEqAllReport,
+    FloatEqReport, FloatReport, ReportKind, ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, AssertFloatOrd, DebugUlpsDiff, FloatEq, FloatEqAll,
+    FloatEqDebugUlpsDiff, FloatEqUlpsTol, FloatOrd, FloatOrdOp, InfAsMax, NanEq, NanEqBits, Strict,
+    UlpsTol,
+};
+use half::{bf16, f16};
+
+// Both formats store their sign in the highest of their 16 bits.
+const ABS_MASK: u16 = 0x7fff;
+
+#[inline]
+fn abs_f16(value: f16) -> f16 {
+    f16::from_bits(value.to_bits() & ABS_MASK)
+}
+
+#[inline]
+fn abs_bf16(value: bf16) -> bf16 {
+    bf16::from_bits(value.to_bits() & ABS_MASK)
+}
+
+impl_float_traits!(f16, u16, abs_f16);
+impl_float_traits!(bf16, u16, abs_bf16);
+impl_wrappers!(f16);
+impl_wrappers!(bf16);
+
+/// Implements the float_eq traits between `$lhs` and `$rhs`, which are `$narrow`
+/// and `$wide` in either order. Absolute and relative checks are made by
+/// converting both to `$wide`, so that the tolerances are also of type `$wide`,
+/// whilst ULPs are measured in `$narrow` by rounding the wider value to it with
+/// `$from_wide`, since that is the precision the narrower value was produced in.
+macro_rules! impl_widened_traits {
+    ($lhs:ty, $rhs:ty, $narrow:ty, $wide:ty, $from_wide:ident) => {
+        impl FloatEq<$rhs> for $lhs {
+            type Tol = $wide;
+
+            #[inline]
+            fn eq_abs(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_abs(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_rmax(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_rmin(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_r1st(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_r2nd(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_atol_rtol(&self, other: &$rhs, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_atol_rtol(&<$wide>::from(*other), atol, rtol)
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &$rhs, tol: &UlpsTol<Self::Tol>) -> bool {
+                // NaNs and values of different signs have no ULPs difference,
+                // except for zero and negative zero which are a difference of 0
+                match self.debug_ulps_diff(other) {
+                    Some(diff) => diff <= *tol,
+                    None => false,
+                }
+            }
+        }
+
+        impl FloatEqAll<$rhs> for $lhs {
+            type AllTol = $wide;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_abs(other, tol)
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_rmax(other, tol)
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_rmin(other, tol)
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_r1st(other, tol)
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_r2nd(other, tol)
+            }
+
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &$rhs,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                self.eq_atol_rtol(other, atol, rtol)
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &$rhs, tol: &UlpsTol<Self::AllTol>) -> bool {
+                self.eq_ulps(other, tol)
+            }
+        }
+
+        impl AssertFloatEq<$rhs> for $lhs {
+            type DebugAbsDiff = $wide;
+            type DebugTol = $wide;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &$rhs) -> Self::DebugAbsDiff {
+                <$wide>::from(*self).debug_abs_diff(&<$wide>::from(*other))
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &$rhs) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                // the narrower value is unchanged, the wider is rounded to nearest
+                let a = <$narrow>::$from_wide(<$wide>::from(*self));
+                let b = <$narrow>::$from_wide(<$wide>::from(*other));
+                a.debug_ulps_diff(&b).map(Into::into)
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_abs_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_rmax_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_rmin_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_r1st_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_r2nd_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &$rhs,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                <$wide>::from(*self).debug_atol_rtol_tol(&<$wide>::from(*other), atol, rtol)
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                _other: &$rhs,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol> {
+                *tol
+            }
+        }
+
+        impl AssertFloatEqAll<$rhs> for $lhs {
+            type AllDebugTol = Self::AllTol;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_abs_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_rmax_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_rmin_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_r1st_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_r2nd_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &$rhs,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                self.debug_atol_rtol_tol(other, atol, rtol)
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &$rhs,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol> {
+                self.debug_ulps_tol(other, tol)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqReport<$rhs> for $lhs {
+            fn report(
+                &self,
+                other: &$rhs,
+                tol: ReportTol<'_, Self::Tol>,
+                path: String,
+            ) -> ReportNode {
+                let (passed, tol) = match tol {
+                    ReportTol::Abs(t) => (
+                        self.eq_abs(other, t),
+                        EffectiveTol::Abs(self.debug_abs_tol(other, t).into()),
+                    ),
+                    ReportTol::Rmax(t) => (
+                        self.eq_rmax(other, t),
+                        EffectiveTol::Abs(self.debug_rmax_tol(other, t).into()),
+                    ),
+                    ReportTol::Rmin(t) => (
+                        self.eq_rmin(other, t),
+                        EffectiveTol::Abs(self.debug_rmin_tol(other, t).into()),
+                    ),
+                    ReportTol::R1st(t) => (
+                        self.eq_r1st(other, t),
+                        EffectiveTol::Abs(self.debug_r1st_tol(other, t).into()),
+                    ),
+                    ReportTol::R2nd(t) => (
+                        self.eq_r2nd(other, t),
+                        EffectiveTol::Abs(self.debug_r2nd_tol(other, t).into()),
+                    ),
+                    ReportTol::AtolRtol(a, r) => (
+                        self.eq_atol_rtol(other, a, r),
+                        EffectiveTol::Abs(self.debug_atol_rtol_tol(other, a, r).into()),
+                    ),
+                    ReportTol::Ulps(t) => (self.eq_ulps(other, t), EffectiveTol::Ulps((*t).into())),
+                };
+                let report = FloatReport {
+                    left: f64::from(*self),
+                    right: f64::from(*other),
+                    abs_diff: self.debug_abs_diff(other).into(),
+                    ulps_diff: self.debug_ulps_diff(other).map(Into::into),
+                    tol,
+                };
+                ReportNode::float(path, passed, report)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqAllReport<$rhs> for $lhs {
+            #[inline]
+            fn report_all(
+                &self,
+                other: &$rhs,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                self.report(other, tol, path)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqDiffs<$rhs> for $lhs {
+            fn debug_diffs(
+                &self,
+                other: &$rhs,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                diffs.push_failure(path, self, other, tol);
+                false
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqAllDiffs<$rhs> for $lhs {
+            #[inline]
+            fn debug_all_diffs(
+                &self,
+                other: &$rhs,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                self.debug_diffs(other, tol, path, diffs)
+            }
+        }
+    };
+}
+
+impl_widened_traits!(f16, f32, f16, f32, from_f32);
+impl_widened_traits!(f32, f16, f16, f32, from_f32);
+impl_widened_traits!(f16, f64, f16, f64, from_f64);
+impl_widened_traits!(f64, f16, f16, f64, from_f64);
+impl_widened_traits!(bf16, f32, bf16, f32, from_f32);
+impl_widened_traits!(f32, bf16, bf16, f32, from_f32);
+impl_widened_traits!(bf16, f64, bf16, f64, from_f64);
+impl_widened_traits!(f64, bf16, bf16, f64, from_f64);
@@ -0,0 +1,395 @@
+#[cfg(feature = "std")]
+use crate::{
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport,
+    ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, UlpsTol,
+};
+use mint::{
+    ColumnMatrix2, ColumnMatrix2x3, ColumnMatrix2x4, ColumnMatrix3, ColumnMatrix3x2,
+    ColumnMatrix3x4, ColumnMatrix4, ColumnMatrix4x2, ColumnMatrix4x3, Point2, Point3, Quaternion,
+    RowMatrix2, RowMatrix2x3, RowMatrix2x4, RowMatrix3, RowMatrix3x2, RowMatrix3x4, RowMatrix4,
+    RowMatrix4x2, RowMatrix4x3, Vector2, Vector3, Vector4,
+};
+
+// mint types are compared field by field, like a derived struct. Since they are
+// generic, tolerances and debug values are the same type over the matching
+// element types, so that they mirror the component layout.
+macro_rules! impl_traits_for_mint_type {
+    ($t:ident, [$($field:ident),+]) => {
+        impl<T: FloatEqUlpsTol> FloatEqUlpsTol for $t<T>
+        where
+            UlpsTol<T>: Sized,
+        {
+            type UlpsTol = $t<UlpsTol<T>>;
+        }
+
+        impl<T: FloatEqDebugUlpsDiff> FloatEqDebugUlpsDiff for $t<T> {
+            type DebugUlpsDiff = $t<DebugUlpsDiff<T>>;
+        }
+
+        impl<T: FloatEq> FloatEq for $t<T>
+        where
+            T::Tol: Sized,
+            UlpsTol<T::Tol>: Sized,
+        {
+            type Tol = $t<T::Tol>;
+
+            #[inline]
+            fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_abs(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_rmax(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_rmin(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_r1st(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+                $(self.$field.eq_r2nd(&other.$field, &tol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_atol_rtol(&self, other: &Self, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                $(self.$field.eq_atol_rtol(&other.$field, &atol.$field, &rtol.$field))&&+
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+                $(self.$field.eq_ulps(&other.$field, &tol.$field))&&+
+            }
+        }
+
+        impl<T: FloatEqAll> FloatEqAll for $t<T> {
+            type AllTol = T::AllTol;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_abs_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_rmax_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_rmin_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_r1st_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                $(self.$field.eq_r2nd_all(&other.$field, tol))&&+
+            }
+
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &Self,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                $(self.$field.eq_atol_rtol_all(&other.$field, atol, rtol))&&+
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+                $(self.$field.eq_ulps_all(&other.$field, tol))&&+
+            }
+        }
+
+        impl<T: AssertFloatEq> AssertFloatEq for $t<T>
+        where
+            T::Tol: Sized,
+            T::DebugTol: Sized,
+            UlpsTol<T::Tol>: Sized,
+            UlpsTol<T::DebugTol>: Sized,
+        {
+            type DebugAbsDiff = $t<T::DebugAbsDiff>;
+            type DebugTol = $t<T::DebugTol>;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                $t {
+                    $($field: self.$field.debug_abs_diff(&other.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                $t {
+                    $($field: self.$field.debug_ulps_diff(&other.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $($field: self.$field.debug_abs_tol(&other.$field, &tol.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $($field: self.$field.debug_rmax_tol(&other.$field, &tol.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $($field: self.$field.debug_rmin_tol(&other.$field, &tol.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $($field: self.$field.debug_r1st_tol(&other.$field, &tol.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $($field: self.$field.debug_r2nd_tol(&other.$field, &tol.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &Self,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $t {
+                    $($field: self
+                        .$field
+                        .debug_atol_rtol_tol(&other.$field, &atol.$field, &rtol.$field)),+
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                other: &Self,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol>
+            where
+                UlpsTol<Self::DebugTol>: Sized,
+            {
+                $t {
+                    $($field: self.$field.debug_ulps_tol(&other.$field, &tol.$field)),+
+                }
+            }
+        }
+
+        impl<T> AssertFloatEqAll for $t<T>
+        where
+            T: AssertFloatEqAll,
+            T::AllDebugTol: Sized,
+            UlpsTol<T::AllDebugTol>: Sized,
+        {
+            type AllDebugTol = $t<T::AllDebugTol>;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $t {
+                    $($field: self.$field.debug_abs_all_tol(&other.$field, tol)),+
+                }
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $t {
+                    $($field: self.$field.debug_rmax_all_tol(&other.$field, tol)),+
+                }
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $t {
+                    $($field: self.$field.debug_rmin_all_tol(&other.$field, tol)),+
+                }
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $t {
+                    $($field: self.$field.debug_r1st_all_tol(&other.$field, tol)),+
+                }
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $t {
+                    $($field: self.$field.debug_r2nd_all_tol(&other.$field, tol)),+
+                }
+            }
+
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &Self,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $t {
+                    $($field: self.$field.debug_atol_rtol_all_tol(&other.$field, atol, rtol)),+
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &Self,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol>
+            where
+                UlpsTol<Self::AllDebugTol>: Sized,
+            {
+                $t {
+                    $($field: self.$field.debug_ulps_all_tol(&other.$field, tol)),+
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<T: FloatEqReport> FloatEqReport for $t<T>
+        where
+            T::Tol: Sized,
+            UlpsTol<T::Tol>: Sized,
+        {
+            fn report(&self, other: &Self, tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+                let children = vec![$(
+                    self.$field.report(
+                        &other.$field,
+                        tol.map(|t| &t.$field, |t| &t.$field),
+                        format!("{}.{}", path, stringify!($field)),
+                    )
+                ),+];
+                ReportNode::composite(path, children)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<T: FloatEqAllReport> FloatEqAllReport for $t<T> {
+            fn report_all(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                let children = vec![$(
+                    self.$field.report_all(
+                        &other.$field,
+                        tol,
+                        format!("{}.{}", path, stringify!($field)),
+                    )
+                ),+];
+                ReportNode::composite(path, children)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<T: AssertFloatEqDiffs> AssertFloatEqDiffs for $t<T>
+        where
+            T::Tol: Sized,
+            T::DebugTol: Sized,
+            UlpsTol<T::Tol>: Sized,
+            UlpsTol<T::DebugTol>: Sized,
+        {
+            fn debug_diffs(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                $(
+                    self.$field.debug_diffs(
+                        &other.$field,
+                        tol.map(|t| &t.$field, |t| &t.$field),
+                        &format!("{}.{}", path, stringify!($field)),
+                        diffs,
+                    );
+                )+
+                false
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<T> AssertFloatEqAllDiffs for $t<T>
+        where
+            T: AssertFloatEqAllDiffs,
+            T::AllDebugTol: Sized,
+            UlpsTol<T::AllDebugTol>: Sized,
+        {
+            fn debug_all_diffs(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                $(
+                    self.$field.debug_all_diffs(
+                        &other.$field,
+                        tol,
+                        &format!("{}.{}", path, stringify!($field)),
+                        diffs,
+                    );
+                )+
+                false
+            }
+        }
+    };
+}
+
+impl_traits_for_mint_type!(Vector2, [x, y]);
+impl_traits_for_mint_type!(Vector3, [x, y, z]);
+impl_traits_for_mint_type!(Vector4, [x, y, z, w]);
+impl_traits_for_mint_type!(Point2, [x, y]);
+impl_traits_for_mint_type!(Point3, [x, y, z]);
+impl_traits_for_mint_type!(Quaternion, [v, s]);
+
+impl_traits_for_mint_type!(ColumnMatrix2, [x, y]);
+impl_traits_for_mint_type!(ColumnMatrix2x3, [x, y, z]);
+impl_traits_for_mint_type!(ColumnMatrix2x4, [x, y, z, w]);
+impl_traits_for_mint_type!(ColumnMatrix3x2, [x, y]);
+impl_traits_for_mint_type!(ColumnMatrix3, [x, y, z]);
+impl_traits_for_mint_type!(ColumnMatrix3x4, [x, y, z, w]);
+impl_traits_for_mint_type!(ColumnMatrix4x2, [x, y]);
+impl_traits_for_mint_type!(ColumnMatrix4x3, [x, y, z]);
+impl_traits_for_mint_type!(ColumnMatrix4, [x, y, z, w]);
+
+impl_traits_for_mint_type!(RowMatrix2, [x, y]);
+impl_traits_for_mint_type!(RowMatrix2x3, [x, y]);
+impl_traits_for_mint_type!(RowMatrix2x4, [x, y]);
+impl_traits_for_mint_type!(RowMatrix3x2, [x, y, z]);
+impl_traits_for_mint_type!(RowMatrix3, [x, y, z]);
+impl_traits_for_mint_type!(RowMatrix3x4, [x, y, z]);
+impl_traits_for_mint_type!(RowMatrix4x2, [x, y, z, w]);
+impl_traits_for_mint_type!(RowMatrix4x3, [x, y, z, w]);
+impl_traits_for_mint_type!(RowMatrix4, [x, y, z, w]);
@@ -0,0 +1,1969 @@
+#[cfg(feature = "std")]
+use crate::{
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport,
+    Mismatch, ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, UlpsTol,
+};
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, Dim, Isometry, Matrix, OMatrix, Point, Quaternion,
+    RawStorage, Scalar, Translation, Unit,
+};
+
+//------------------------------------------------------------------------------
+// Matrix
+//------------------------------------------------------------------------------
+
+// Matrices of any storage are compared element by element. Tolerances and debug
+// values are always owned matrices of the same dimensions, and matrices of
+// different shapes are never equal.
+
+impl<T, R, C, S> FloatEqUlpsTol for Matrix<T, R, C, S>
+where
+    T: FloatEqUlpsTol,
+    UlpsTol<T>: Sized,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<UlpsTol<T>, R, C>,
+{
+    type UlpsTol = OMatrix<UlpsTol<T>, R, C>;
+}
+
+impl<T, R, C, S> FloatEqDebugUlpsDiff for Matrix<T, R, C, S>
+where
+    T: FloatEqDebugUlpsDiff,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<DebugUlpsDiff<T>, R, C>,
+{
+    type DebugUlpsDiff = OMatrix<DebugUlpsDiff<T>, R, C>;
+}
+
+impl<A, B, R, C, SA, SB> FloatEq<Matrix<B, R, C, SB>> for Matrix<A, R, C, SA>
+where
+    A: FloatEq<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    R: Dim,
+    C: Dim,
+    SA: RawStorage<A, R, C>,
+    SB: RawStorage<B, R, C>,
+    DefaultAllocator: Allocator<A::Tol, R, C> + Allocator<UlpsTol<A::Tol>, R, C>,
+{
+    type Tol = OMatrix<A::Tol, R, C>;
+
+    #[inline]
+    fn eq_abs(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| FloatEq::eq_abs(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| FloatEq::eq_rmax(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| FloatEq::eq_rmin(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| FloatEq::eq_r1st(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| FloatEq::eq_r2nd(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_atol_rtol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == atol.shape()
+            && self.shape() == rtol.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(atol.iter().zip(rtol.iter()))
+                .all(|((a, b), (at, rt))| FloatEq::eq_atol_rtol(a, b, at, rt))
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &Matrix<B, R, C, SB>, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(tol.iter())
+                .all(|((a, b), eps)| FloatEq::eq_ulps(a, b, eps))
+    }
+}
+
+impl<A, B, R, C, SA, SB> FloatEqAll<Matrix<B, R, C, SB>> for Matrix<A, R, C, SA>
+where
+    A: FloatEqAll<B>,
+    R: Dim,
+    C: Dim,
+    SA: RawStorage<A, R, C>,
+    SB: RawStorage<B, R, C>,
+{
+    type AllTol = A::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &Matrix<B, R, C, SB>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| FloatEqAll::eq_abs_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &Matrix<B, R, C, SB>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| FloatEqAll::eq_rmax_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &Matrix<B, R, C, SB>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| FloatEqAll::eq_rmin_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &Matrix<B, R, C, SB>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| FloatEqAll::eq_r1st_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &Matrix<B, R, C, SB>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| FloatEqAll::eq_r2nd_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_atol_rtol_all(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| FloatEqAll::eq_atol_rtol_all(a, b, atol, rtol))
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &Matrix<B, R, C, SB>, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| FloatEqAll::eq_ulps_all(a, b, tol))
+    }
+}
+
+impl<A, B, R, C, SA, SB> AssertFloatEq<Matrix<B, R, C, SB>> for Matrix<A, R, C, SA>
+where
+    A: AssertFloatEq<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+    R: Dim,
+    C: Dim,
+    SA: RawStorage<A, R, C>,
+    SB: RawStorage<B, R, C>,
+    DefaultAllocator: Allocator<A::Tol, R, C>
+        + Allocator<UlpsTol<A::Tol>, R, C>
+        + Allocator<A::DebugAbsDiff, R, C>
+        + Allocator<DebugUlpsDiff<A::DebugAbsDiff>, R, C>
+        + Allocator<A::DebugTol, R, C>
+        + Allocator<UlpsTol<A::DebugTol>, R, C>,
+{
+    type DebugAbsDiff = Option<OMatrix<A::DebugAbsDiff, R, C>>;
+    type DebugTol = Option<OMatrix<A::DebugTol, R, C>>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Matrix<B, R, C, SB>) -> Self::DebugAbsDiff {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_abs_diff(b)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Matrix<B, R, C, SB>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_ulps_diff(b)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol.iter())
+                    .map(|((a, b), eps)| a.debug_abs_tol(b, eps)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol.iter())
+                    .map(|((a, b), eps)| a.debug_rmax_tol(b, eps)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol.iter())
+                    .map(|((a, b), eps)| a.debug_rmin_tol(b, eps)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol.iter())
+                    .map(|((a, b), eps)| a.debug_r1st_tol(b, eps)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &Matrix<B, R, C, SB>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol.iter())
+                    .map(|((a, b), eps)| a.debug_r2nd_tol(b, eps)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.shape() == other.shape()
+            && self.shape() == atol.shape()
+            && self.shape() == rtol.shape()
+        {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .zip(atol.iter().zip(rtol.iter()))
+                    .map(|((a, b), (at, rt))| a.debug_atol_rtol_tol(b, at, rt)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> UlpsTol<Self::DebugTol> {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol.iter())
+                    .map(|((a, b), eps)| a.debug_ulps_tol(b, eps)),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<A, B, R, C, SA, SB> AssertFloatEqAll<Matrix<B, R, C, SB>> for Matrix<A, R, C, SA>
+where
+    A: AssertFloatEqAll<B>,
+    A::AllDebugTol: Sized,
+    UlpsTol<A::AllDebugTol>: Sized,
+    R: Dim,
+    C: Dim,
+    SA: RawStorage<A, R, C>,
+    SB: RawStorage<B, R, C>,
+    DefaultAllocator: Allocator<A::AllDebugTol, R, C> + Allocator<UlpsTol<A::AllDebugTol>, R, C>,
+{
+    type AllDebugTol = Option<OMatrix<A::AllDebugTol, R, C>>;
+
+    #[inline]
+    fn debug_abs_all_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_abs_all_tol(b, tol)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_rmax_all_tol(b, tol)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_rmin_all_tol(b, tol)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_r1st_all_tol(b, tol)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_r2nd_all_tol(b, tol)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_atol_rtol_all_tol(b, atol, rtol)),
+            ))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        if self.shape() == other.shape() {
+            Some(collect_matrix(
+                self.shape_generic(),
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_ulps_all_tol(b, tol)),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, R, C, SA, SB> FloatEqReport<Matrix<B, R, C, SB>> for Matrix<A, R, C, SA>
+where
+    A: FloatEqReport<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    R: Dim,
+    C: Dim,
+    SA: RawStorage<A, R, C>,
+    SB: RawStorage<B, R, C>,
+    DefaultAllocator: Allocator<A::Tol, R, C> + Allocator<UlpsTol<A::Tol>, R, C>,
+{
+    fn report(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: String,
+    ) -> ReportNode {
+        let tol_shape = tol.apply(|t| t.shape(), |t| t.shape());
+        if let Some(mismatch) = shape_mismatch(self.shape(), other.shape(), Some(tol_shape)) {
+            return ReportNode::mismatch(path, mismatch);
+        }
+
+        let shape = self.shape();
+        let tol = tol.elements(|t| t.iter(), |t| t.iter());
+        let children = self
+            .iter()
+            .zip(other.iter())
+            .zip(tol)
+            .enumerate()
+            .map(|(i, ((a, b), t))| a.report(b, t, index_path(&path, shape, i)))
+            .collect();
+        ReportNode::composite(path, children)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, R, C, SA, SB> FloatEqAllReport<Matrix<B, R, C, SB>> for Matrix<A, R, C, SA>
+where
+    A: FloatEqAllReport<B>,
+    R: Dim,
+    C: Dim,
+    SA: RawStorage<A, R, C>,
+    SB: RawStorage<B, R, C>,
+{
+    fn report_all(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        if let Some(mismatch) = shape_mismatch(self.shape(), other.shape(), None) {
+            return ReportNode::mismatch(path, mismatch);
+        }
+
+        let shape = self.shape();
+        let children = self
+            .iter()
+            .zip(other.iter())
+            .enumerate()
+            .map(|(i, (a, b))| a.report_all(b, tol, index_path(&path, shape, i)))
+            .collect();
+        ReportNode::composite(path, children)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, R, C, SA, SB> AssertFloatEqDiffs<Matrix<B, R, C, SB>> for Matrix<A, R, C, SA>
+where
+    A: AssertFloatEqDiffs<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+    R: Dim,
+    C: Dim,
+    SA: RawStorage<A, R, C>,
+    SB: RawStorage<B, R, C>,
+    DefaultAllocator: Allocator<A::Tol, R, C>
+        + Allocator<UlpsTol<A::Tol>, R, C>
+        + Allocator<A::DebugAbsDiff, R, C>
+        + Allocator<DebugUlpsDiff<A::DebugAbsDiff>, R, C>
+        + Allocator<A::DebugTol, R, C>
+        + Allocator<UlpsTol<A::DebugTol>, R, C>,
+{
+    fn debug_diffs(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol_shape = tol.apply(|t| t.shape(), |t| t.shape());
+        if let Some(mismatch) = shape_mismatch(self.shape(), other.shape(), Some(tol_shape)) {
+            diffs.push(path, format_args!("{}", mismatch));
+            return true;
+        }
+
+        let shape = self.shape();
+        let tol = tol.elements(|t| t.iter(), |t| t.iter());
+        for (i, ((a, b), t)) in self.iter().zip(other.iter()).zip(tol).enumerate() {
+            a.debug_diffs(b, t, &index_path(path, shape, i), diffs);
+        }
+        true
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, R, C, SA, SB> AssertFloatEqAllDiffs<Matrix<B, R, C, SB>> for Matrix<A, R, C, SA>
+where
+    A: AssertFloatEqAllDiffs<B>,
+    A::AllDebugTol: Sized,
+    UlpsTol<A::AllDebugTol>: Sized,
+    R: Dim,
+    C: Dim,
+    SA: RawStorage<A, R, C>,
+    SB: RawStorage<B, R, C>,
+    DefaultAllocator: Allocator<A::AllDebugTol, R, C> + Allocator<UlpsTol<A::AllDebugTol>, R, C>,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &Matrix<B, R, C, SB>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        if let Some(mismatch) = shape_mismatch(self.shape(), other.shape(), None) {
+            diffs.push(path, format_args!("{}", mismatch));
+            return true;
+        }
+
+        let shape = self.shape();
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            a.debug_all_diffs(b, tol, &index_path(path, shape, i), diffs);
+        }
+        true
+    }
+}
+
+/// Builds an owned matrix from elements in column-major order.
+fn collect_matrix<T, R, C, I>((nrows, ncols): (R, C), iter: I) -> OMatrix<T, R, C>
+where
+    R: Dim,
+    C: Dim,
+    I: IntoIterator<Item = T>,
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    Matrix::from_data(DefaultAllocator::allocate_from_iterator(nrows, ncols, iter))
+}
+
+/// Why matrices of these shapes, and optionally a tolerance of `tol` shape,
+/// cannot be compared, if they cannot.
+#[cfg(feature = "std")]
+fn shape_mismatch(
+    left: (usize, usize),
+    right: (usize, usize),
+    tol: Option<(usize, usize)>,
+) -> Option<Mismatch> {
+    if left != right {
+        Some(Mismatch::Shape {
+            left: vec![left.0, left.1],
+            right: vec![right.0, right.1],
+        })
+    } else {
+        match tol {
+            Some(tol) if tol != left => Some(Mismatch::TolShape {
+                shape: vec![left.0, left.1],
+                tol: vec![tol.0, tol.1],
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// The path of the `i`th element in column-major order, for example `[1]` for
+/// a column vector or `(1, 2)` otherwise, matching how they are indexed.
+#[cfg(feature = "std")]
+fn index_path(path: &str, (nrows, ncols): (usize, usize), i: usize) -> String {
+    if ncols == 1 {
+        format!("{}[{}]", path, i)
+    } else {
+        format!("{}[({}, {})]", path, i % nrows, i / nrows)
+    }
+}
+
+//------------------------------------------------------------------------------
+// Quaternion, Translation, Point
+//------------------------------------------------------------------------------
+
+// Types wrapping a single statically sized vector are compared as that vector,
+// with tolerances and debug values of the same wrapper type.
+
+macro_rules! impl_traits_for_vector_wrapper {
+    ($t:ident, $field:ident $(, $d:ident)?) => {
+        impl<T $(, const $d: usize)?> FloatEqUlpsTol for $t<T $(, $d)?>
+        where
+            T: Scalar + FloatEqUlpsTol,
+            UlpsTol<T>: Scalar,
+        {
+            type UlpsTol = $t<UlpsTol<T> $(, $d)?>;
+        }
+
+        impl<T $(, const $d: usize)?> FloatEqDebugUlpsDiff for $t<T $(, $d)?>
+        where
+            T: Scalar + FloatEqDebugUlpsDiff,
+            DebugUlpsDiff<T>: Scalar,
+        {
+            type DebugUlpsDiff = $t<DebugUlpsDiff<T> $(, $d)?>;
+        }
+
+        impl<A, B $(, const $d: usize)?> FloatEq<$t<B $(, $d)?>> for $t<A $(, $d)?>
+        where
+            A: Scalar + FloatEq<B>,
+            B: Scalar,
+            A::Tol: Scalar,
+            UlpsTol<A::Tol>: Scalar,
+        {
+            type Tol = $t<A::Tol $(, $d)?>;
+
+            #[inline]
+            fn eq_abs(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> bool {
+                FloatEq::eq_abs(&self.$field, &other.$field, &tol.$field)
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> bool {
+                FloatEq::eq_rmax(&self.$field, &other.$field, &tol.$field)
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> bool {
+                FloatEq::eq_rmin(&self.$field, &other.$field, &tol.$field)
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> bool {
+                FloatEq::eq_r1st(&self.$field, &other.$field, &tol.$field)
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> bool {
+                FloatEq::eq_r2nd(&self.$field, &other.$field, &tol.$field)
+            }
+
+            #[inline]
+            fn eq_atol_rtol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> bool {
+                FloatEq::eq_atol_rtol(&self.$field, &other.$field, &atol.$field, &rtol.$field)
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &$t<B $(, $d)?>, tol: &UlpsTol<Self::Tol>) -> bool {
+                FloatEq::eq_ulps(&self.$field, &other.$field, &tol.$field)
+            }
+        }
+
+        impl<A, B $(, const $d: usize)?> FloatEqAll<$t<B $(, $d)?>> for $t<A $(, $d)?>
+        where
+            A: Scalar + FloatEqAll<B>,
+            B: Scalar,
+        {
+            type AllTol = A::AllTol;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &$t<B $(, $d)?>, tol: &Self::AllTol) -> bool {
+                FloatEqAll::eq_abs_all(&self.$field, &other.$field, tol)
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &$t<B $(, $d)?>, tol: &Self::AllTol) -> bool {
+                FloatEqAll::eq_rmax_all(&self.$field, &other.$field, tol)
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &$t<B $(, $d)?>, tol: &Self::AllTol) -> bool {
+                FloatEqAll::eq_rmin_all(&self.$field, &other.$field, tol)
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &$t<B $(, $d)?>, tol: &Self::AllTol) -> bool {
+                FloatEqAll::eq_r1st_all(&self.$field, &other.$field, tol)
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &$t<B $(, $d)?>, tol: &Self::AllTol) -> bool {
+                FloatEqAll::eq_r2nd_all(&self.$field, &other.$field, tol)
+            }
+
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &$t<B $(, $d)?>,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                FloatEqAll::eq_atol_rtol_all(&self.$field, &other.$field, atol, rtol)
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &$t<B $(, $d)?>, tol: &UlpsTol<Self::AllTol>) -> bool {
+                FloatEqAll::eq_ulps_all(&self.$field, &other.$field, tol)
+            }
+        }
+
+        impl<A, B $(, const $d: usize)?> AssertFloatEq<$t<B $(, $d)?>> for $t<A $(, $d)?>
+        where
+            A: Scalar + AssertFloatEq<B>,
+            B: Scalar,
+            A::Tol: Scalar,
+            A::DebugAbsDiff: Scalar,
+            A::DebugTol: Scalar,
+            UlpsTol<A::Tol>: Scalar,
+            UlpsTol<A::DebugTol>: Scalar,
+            DebugUlpsDiff<A::DebugAbsDiff>: Scalar,
+        {
+            type DebugAbsDiff = $t<A::DebugAbsDiff $(, $d)?>;
+            type DebugTol = $t<A::DebugTol $(, $d)?>;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &$t<B $(, $d)?>) -> Self::DebugAbsDiff {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_abs_diff(b)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &$t<B $(, $d)?>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_ulps_diff(b)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .zip(tol.$field.iter())
+                            .map(|((a, b), eps)| a.debug_abs_tol(b, eps)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .zip(tol.$field.iter())
+                            .map(|((a, b), eps)| a.debug_rmax_tol(b, eps)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .zip(tol.$field.iter())
+                            .map(|((a, b), eps)| a.debug_rmin_tol(b, eps)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .zip(tol.$field.iter())
+                            .map(|((a, b), eps)| a.debug_r1st_tol(b, eps)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &$t<B $(, $d)?>, tol: &Self::Tol) -> Self::DebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .zip(tol.$field.iter())
+                            .map(|((a, b), eps)| a.debug_r2nd_tol(b, eps)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .zip(atol.$field.iter().zip(rtol.$field.iter()))
+                            .map(|((a, b), (at, rt))| a.debug_atol_rtol_tol(b, at, rt)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol> {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .zip(tol.$field.iter())
+                            .map(|((a, b), eps)| a.debug_ulps_tol(b, eps)),
+                    ),
+                }
+            }
+        }
+
+        impl<A, B $(, const $d: usize)?> AssertFloatEqAll<$t<B $(, $d)?>> for $t<A $(, $d)?>
+        where
+            A: Scalar + AssertFloatEqAll<B>,
+            B: Scalar,
+            A::AllDebugTol: Scalar,
+            UlpsTol<A::AllDebugTol>: Scalar,
+        {
+            type AllDebugTol = $t<A::AllDebugTol $(, $d)?>;
+
+            #[inline]
+            fn debug_abs_all_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_abs_all_tol(b, tol)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_rmax_all_tol(b, tol)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_rmin_all_tol(b, tol)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_r1st_all_tol(b, tol)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_r2nd_all_tol(b, tol)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_atol_rtol_all_tol(b, atol, rtol)),
+                    ),
+                }
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol>
+            where
+                UlpsTol<Self::AllDebugTol>: Sized,
+            {
+                $t {
+                    $field: collect_matrix(
+                        self.$field.shape_generic(),
+                        self.$field
+                            .iter()
+                            .zip(other.$field.iter())
+                            .map(|(a, b)| a.debug_ulps_all_tol(b, tol)),
+                    ),
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<A, B $(, const $d: usize)?> FloatEqReport<$t<B $(, $d)?>> for $t<A $(, $d)?>
+        where
+            A: Scalar + FloatEqReport<B>,
+            B: Scalar,
+            A::Tol: Scalar,
+            UlpsTol<A::Tol>: Scalar,
+        {
+            fn report(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: ReportTol<'_, Self::Tol>,
+                path: String,
+            ) -> ReportNode {
+                let field = self.$field.report(
+                    &other.$field,
+                    tol.map(|t| &t.$field, |t| &t.$field),
+                    format!("{}.{}", path, stringify!($field)),
+                );
+                ReportNode::composite(path, vec![field])
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<A, B $(, const $d: usize)?> FloatEqAllReport<$t<B $(, $d)?>> for $t<A $(, $d)?>
+        where
+            A: Scalar + FloatEqAllReport<B>,
+            B: Scalar,
+        {
+            fn report_all(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                let field = self.$field.report_all(
+                    &other.$field,
+                    tol,
+                    format!("{}.{}", path, stringify!($field)),
+                );
+                ReportNode::composite(path, vec![field])
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<A, B $(, const $d: usize)?> AssertFloatEqDiffs<$t<B $(, $d)?>> for $t<A $(, $d)?>
+        where
+            A: Scalar + AssertFloatEqDiffs<B>,
+            B: Scalar,
+            A::Tol: Scalar,
+            A::DebugAbsDiff: Scalar,
+            A::DebugTol: Scalar,
+            UlpsTol<A::Tol>: Scalar,
+            UlpsTol<A::DebugTol>: Scalar,
+            DebugUlpsDiff<A::DebugAbsDiff>: Scalar,
+        {
+            fn debug_diffs(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                let path = format!("{}.{}", path, stringify!($field));
+                let tol = tol.map(|t| &t.$field, |t| &t.$field);
+                self.$field.debug_diffs(&other.$field, tol, &path, diffs);
+                false
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<A, B $(, const $d: usize)?> AssertFloatEqAllDiffs<$t<B $(, $d)?>> for $t<A $(, $d)?>
+        where
+            A: Scalar + AssertFloatEqAllDiffs<B>,
+            B: Scalar,
+            A::AllDebugTol: Scalar,
+            UlpsTol<A::AllDebugTol>: Scalar,
+        {
+            fn debug_all_diffs(
+                &self,
+                other: &$t<B $(, $d)?>,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                let path = format!("{}.{}", path, stringify!($field));
+                self.$field.debug_all_diffs(&other.$field, tol, &path, diffs);
+                false
+            }
+        }
+    };
+}
+
+impl_traits_for_vector_wrapper!(Quaternion, coords);
+impl_traits_for_vector_wrapper!(Translation, vector, D);
+impl_traits_for_vector_wrapper!(Point, coords, D);
+
+//------------------------------------------------------------------------------
+// Unit
+//------------------------------------------------------------------------------
+
+// Unit values are compared as the value they wrap, which covers UnitQuaternion,
+// UnitComplex and unit vectors.
+
+impl<T: FloatEqUlpsTol> FloatEqUlpsTol for Unit<T> {
+    type UlpsTol = UlpsTol<T>;
+}
+
+impl<T: FloatEqDebugUlpsDiff> FloatEqDebugUlpsDiff for Unit<T> {
+    type DebugUlpsDiff = DebugUlpsDiff<T>;
+}
+
+impl<A, B> FloatEq<Unit<B>> for Unit<A>
+where
+    A: FloatEq<B>,
+{
+    type Tol = A::Tol;
+
+    #[inline]
+    fn eq_abs(&self, other: &Unit<B>, tol: &Self::Tol) -> bool {
+        FloatEq::eq_abs(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &Unit<B>, tol: &Self::Tol) -> bool {
+        FloatEq::eq_rmax(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &Unit<B>, tol: &Self::Tol) -> bool {
+        FloatEq::eq_rmin(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &Unit<B>, tol: &Self::Tol) -> bool {
+        FloatEq::eq_r1st(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &Unit<B>, tol: &Self::Tol) -> bool {
+        FloatEq::eq_r2nd(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_atol_rtol(&self, other: &Unit<B>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        FloatEq::eq_atol_rtol(&**self, &**other, atol, rtol)
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &Unit<B>, tol: &UlpsTol<Self::Tol>) -> bool {
+        FloatEq::eq_ulps(&**self, &**other, tol)
+    }
+}
+
+impl<A, B> FloatEqAll<Unit<B>> for Unit<A>
+where
+    A: FloatEqAll<B>,
+{
+    type AllTol = A::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &Unit<B>, tol: &Self::AllTol) -> bool {
+        FloatEqAll::eq_abs_all(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &Unit<B>, tol: &Self::AllTol) -> bool {
+        FloatEqAll::eq_rmax_all(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &Unit<B>, tol: &Self::AllTol) -> bool {
+        FloatEqAll::eq_rmin_all(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &Unit<B>, tol: &Self::AllTol) -> bool {
+        FloatEqAll::eq_r1st_all(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &Unit<B>, tol: &Self::AllTol) -> bool {
+        FloatEqAll::eq_r2nd_all(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn eq_atol_rtol_all(&self, other: &Unit<B>, atol: &Self::AllTol, rtol: &Self::AllTol) -> bool {
+        FloatEqAll::eq_atol_rtol_all(&**self, &**other, atol, rtol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &Unit<B>, tol: &UlpsTol<Self::AllTol>) -> bool {
+        FloatEqAll::eq_ulps_all(&**self, &**other, tol)
+    }
+}
+
+impl<A, B> AssertFloatEq<Unit<B>> for Unit<A>
+where
+    A: AssertFloatEq<B>,
+{
+    type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugTol = A::DebugTol;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Unit<B>) -> Self::DebugAbsDiff {
+        AssertFloatEq::debug_abs_diff(&**self, &**other)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Unit<B>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        AssertFloatEq::debug_ulps_diff(&**self, &**other)
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &Unit<B>, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatEq::debug_abs_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &Unit<B>, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatEq::debug_rmax_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &Unit<B>, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatEq::debug_rmin_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &Unit<B>, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatEq::debug_r1st_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &Unit<B>, tol: &Self::Tol) -> Self::DebugTol {
+        AssertFloatEq::debug_r2nd_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &Unit<B>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        AssertFloatEq::debug_atol_rtol_tol(&**self, &**other, atol, rtol)
+    }
+
+    #[inline]
+    fn debug_ulps_tol(&self, other: &Unit<B>, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol>
+    where
+        UlpsTol<Self::DebugTol>: Sized,
+    {
+        AssertFloatEq::debug_ulps_tol(&**self, &**other, tol)
+    }
+}
+
+impl<A, B> AssertFloatEqAll<Unit<B>> for Unit<A>
+where
+    A: AssertFloatEqAll<B>,
+{
+    type AllDebugTol = A::AllDebugTol;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &Unit<B>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        AssertFloatEqAll::debug_abs_all_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(&self, other: &Unit<B>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        AssertFloatEqAll::debug_rmax_all_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(&self, other: &Unit<B>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        AssertFloatEqAll::debug_rmin_all_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(&self, other: &Unit<B>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        AssertFloatEqAll::debug_r1st_all_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(&self, other: &Unit<B>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        AssertFloatEqAll::debug_r2nd_all_tol(&**self, &**other, tol)
+    }
+
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &Unit<B>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        AssertFloatEqAll::debug_atol_rtol_all_tol(&**self, &**other, atol, rtol)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &Unit<B>,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        AssertFloatEqAll::debug_ulps_all_tol(&**self, &**other, tol)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> FloatEqReport<Unit<B>> for Unit<A>
+where
+    A: FloatEqReport<B>,
+{
+    fn report(&self, other: &Unit<B>, tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+        FloatEqReport::report(&**self, &**other, tol, path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> FloatEqAllReport<Unit<B>> for Unit<A>
+where
+    A: FloatEqAllReport<B>,
+{
+    fn report_all(
+        &self,
+        other: &Unit<B>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        FloatEqAllReport::report_all(&**self, &**other, tol, path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> AssertFloatEqDiffs<Unit<B>> for Unit<A>
+where
+    A: AssertFloatEqDiffs<B>,
+{
+    fn debug_diffs(
+        &self,
+        other: &Unit<B>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        AssertFloatEqDiffs::debug_diffs(&**self, &**other, tol, path, diffs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> AssertFloatEqAllDiffs<Unit<B>> for Unit<A>
+where
+    A: AssertFloatEqAllDiffs<B>,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &Unit<B>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        AssertFloatEqAllDiffs::debug_all_diffs(&**self, &**other, tol, path, diffs)
+    }
+}
+
+//------------------------------------------------------------------------------
+// Isometry
+//------------------------------------------------------------------------------
+
+// Isometries compare their rotation and translation separately, so that any
+// rotation type may be used. For the `_all` checks, both must share the same
+// scalar tolerance.
+
+impl<T, R, const D: usize> FloatEqUlpsTol for Isometry<T, R, D>
+where
+    T: Scalar + FloatEqUlpsTol,
+    UlpsTol<T>: Scalar,
+    R: FloatEqUlpsTol,
+    UlpsTol<R>: Sized,
+{
+    type UlpsTol = Isometry<UlpsTol<T>, UlpsTol<R>, D>;
+}
+
+impl<T, R, const D: usize> FloatEqDebugUlpsDiff for Isometry<T, R, D>
+where
+    T: Scalar + FloatEqDebugUlpsDiff,
+    DebugUlpsDiff<T>: Scalar,
+    R: FloatEqDebugUlpsDiff,
+{
+    type DebugUlpsDiff = Isometry<DebugUlpsDiff<T>, DebugUlpsDiff<R>, D>;
+}
+
+impl<A, B, RA, RB, const D: usize> FloatEq<Isometry<B, RB, D>> for Isometry<A, RA, D>
+where
+    A: Scalar + FloatEq<B>,
+    B: Scalar,
+    A::Tol: Scalar,
+    UlpsTol<A::Tol>: Scalar,
+    RA: FloatEq<RB>,
+    RA::Tol: Sized,
+    UlpsTol<RA::Tol>: Sized,
+{
+    type Tol = Isometry<A::Tol, RA::Tol, D>;
+
+    #[inline]
+    fn eq_abs(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> bool {
+        self.rotation.eq_abs(&other.rotation, &tol.rotation)
+            && self
+                .translation
+                .eq_abs(&other.translation, &tol.translation)
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> bool {
+        self.rotation.eq_rmax(&other.rotation, &tol.rotation)
+            && self
+                .translation
+                .eq_rmax(&other.translation, &tol.translation)
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> bool {
+        self.rotation.eq_rmin(&other.rotation, &tol.rotation)
+            && self
+                .translation
+                .eq_rmin(&other.translation, &tol.translation)
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> bool {
+        self.rotation.eq_r1st(&other.rotation, &tol.rotation)
+            && self
+                .translation
+                .eq_r1st(&other.translation, &tol.translation)
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> bool {
+        self.rotation.eq_r2nd(&other.rotation, &tol.rotation)
+            && self
+                .translation
+                .eq_r2nd(&other.translation, &tol.translation)
+    }
+
+    #[inline]
+    fn eq_atol_rtol(&self, other: &Isometry<B, RB, D>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.rotation
+            .eq_atol_rtol(&other.rotation, &atol.rotation, &rtol.rotation)
+            && self.translation.eq_atol_rtol(
+                &other.translation,
+                &atol.translation,
+                &rtol.translation,
+            )
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &Isometry<B, RB, D>, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.rotation.eq_ulps(&other.rotation, &tol.rotation)
+            && self
+                .translation
+                .eq_ulps(&other.translation, &tol.translation)
+    }
+}
+
+impl<A, B, RA, RB, const D: usize> FloatEqAll<Isometry<B, RB, D>> for Isometry<A, RA, D>
+where
+    A: Scalar + FloatEqAll<B>,
+    B: Scalar,
+    RA: FloatEqAll<RB, AllTol = A::AllTol>,
+{
+    type AllTol = A::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &Isometry<B, RB, D>, tol: &Self::AllTol) -> bool {
+        self.rotation.eq_abs_all(&other.rotation, tol)
+            && self.translation.eq_abs_all(&other.translation, tol)
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &Isometry<B, RB, D>, tol: &Self::AllTol) -> bool {
+        self.rotation.eq_rmax_all(&other.rotation, tol)
+            && self.translation.eq_rmax_all(&other.translation, tol)
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &Isometry<B, RB, D>, tol: &Self::AllTol) -> bool {
+        self.rotation.eq_rmin_all(&other.rotation, tol)
+            && self.translation.eq_rmin_all(&other.translation, tol)
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &Isometry<B, RB, D>, tol: &Self::AllTol) -> bool {
+        self.rotation.eq_r1st_all(&other.rotation, tol)
+            && self.translation.eq_r1st_all(&other.translation, tol)
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &Isometry<B, RB, D>, tol: &Self::AllTol) -> bool {
+        self.rotation.eq_r2nd_all(&other.rotation, tol)
+            && self.translation.eq_r2nd_all(&other.translation, tol)
+    }
+
+    #[inline]
+    fn eq_atol_rtol_all(
+        &self,
+        other: &Isometry<B, RB, D>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> bool {
+        self.rotation.eq_atol_rtol_all(&other.rotation, atol, rtol)
+            && self
+                .translation
+                .eq_atol_rtol_all(&other.translation, atol, rtol)
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &Isometry<B, RB, D>, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.rotation.eq_ulps_all(&other.rotation, tol)
+            && self.translation.eq_ulps_all(&other.translation, tol)
+    }
+}
+
+impl<A, B, RA, RB, const D: usize> AssertFloatEq<Isometry<B, RB, D>> for Isometry<A, RA, D>
+where
+    A: Scalar + AssertFloatEq<B>,
+    B: Scalar,
+    A::Tol: Scalar,
+    A::DebugAbsDiff: Scalar,
+    A::DebugTol: Scalar,
+    UlpsTol<A::Tol>: Scalar,
+    UlpsTol<A::DebugTol>: Scalar,
+    DebugUlpsDiff<A::DebugAbsDiff>: Scalar,
+    RA: AssertFloatEq<RB>,
+    RA::Tol: Sized,
+    RA::DebugTol: Sized,
+    UlpsTol<RA::Tol>: Sized,
+    UlpsTol<RA::DebugTol>: Sized,
+{
+    type DebugAbsDiff = Isometry<A::DebugAbsDiff, RA::DebugAbsDiff, D>;
+    type DebugTol = Isometry<A::DebugTol, RA::DebugTol, D>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Isometry<B, RB, D>) -> Self::DebugAbsDiff {
+        Isometry {
+            rotation: self.rotation.debug_abs_diff(&other.rotation),
+            translation: self.translation.debug_abs_diff(&other.translation),
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Isometry<B, RB, D>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        Isometry {
+            rotation: self.rotation.debug_ulps_diff(&other.rotation),
+            translation: self.translation.debug_ulps_diff(&other.translation),
+        }
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        Isometry {
+            rotation: self.rotation.debug_abs_tol(&other.rotation, &tol.rotation),
+            translation: (self.translation).debug_abs_tol(&other.translation, &tol.translation),
+        }
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        Isometry {
+            rotation: self.rotation.debug_rmax_tol(&other.rotation, &tol.rotation),
+            translation: (self.translation).debug_rmax_tol(&other.translation, &tol.translation),
+        }
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        Isometry {
+            rotation: self.rotation.debug_rmin_tol(&other.rotation, &tol.rotation),
+            translation: (self.translation).debug_rmin_tol(&other.translation, &tol.translation),
+        }
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        Isometry {
+            rotation: self.rotation.debug_r1st_tol(&other.rotation, &tol.rotation),
+            translation: (self.translation).debug_r1st_tol(&other.translation, &tol.translation),
+        }
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &Isometry<B, RB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        Isometry {
+            rotation: self.rotation.debug_r2nd_tol(&other.rotation, &tol.rotation),
+            translation: (self.translation).debug_r2nd_tol(&other.translation, &tol.translation),
+        }
+    }
+
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        Isometry {
+            rotation: self.rotation.debug_atol_rtol_tol(
+                &other.rotation,
+                &atol.rotation,
+                &rtol.rotation,
+            ),
+            translation: (self.translation).debug_atol_rtol_tol(
+                &other.translation,
+                &atol.translation,
+                &rtol.translation,
+            ),
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> UlpsTol<Self::DebugTol> {
+        Isometry {
+            rotation: self.rotation.debug_ulps_tol(&other.rotation, &tol.rotation),
+            translation: (self.translation).debug_ulps_tol(&other.translation, &tol.translation),
+        }
+    }
+}
+
+impl<A, B, RA, RB, const D: usize> AssertFloatEqAll<Isometry<B, RB, D>> for Isometry<A, RA, D>
+where
+    A: Scalar + AssertFloatEqAll<B>,
+    B: Scalar,
+    A::AllDebugTol: Scalar,
+    UlpsTol<A::AllDebugTol>: Scalar,
+    RA: AssertFloatEqAll<RB, AllTol = A::AllTol>,
+    RA::AllDebugTol: Sized,
+    UlpsTol<RA::AllDebugTol>: Sized,
+{
+    type AllDebugTol = Isometry<A::AllDebugTol, RA::AllDebugTol, D>;
+
+    #[inline]
+    fn debug_abs_all_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Isometry {
+            rotation: self.rotation.debug_abs_all_tol(&other.rotation, tol),
+            translation: self.translation.debug_abs_all_tol(&other.translation, tol),
+        }
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Isometry {
+            rotation: self.rotation.debug_rmax_all_tol(&other.rotation, tol),
+            translation: self.translation.debug_rmax_all_tol(&other.translation, tol),
+        }
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Isometry {
+            rotation: self.rotation.debug_rmin_all_tol(&other.rotation, tol),
+            translation: self.translation.debug_rmin_all_tol(&other.translation, tol),
+        }
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Isometry {
+            rotation: self.rotation.debug_r1st_all_tol(&other.rotation, tol),
+            translation: self.translation.debug_r1st_all_tol(&other.translation, tol),
+        }
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Isometry {
+            rotation: self.rotation.debug_r2nd_all_tol(&other.rotation, tol),
+            translation: self.translation.debug_r2nd_all_tol(&other.translation, tol),
+        }
+    }
+
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Isometry {
+            rotation: self
+                .rotation
+                .debug_atol_rtol_all_tol(&other.rotation, atol, rtol),
+            translation: self
+                .translation
+                .debug_atol_rtol_all_tol(&other.translation, atol, rtol),
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        Isometry {
+            rotation: self.rotation.debug_ulps_all_tol(&other.rotation, tol),
+            translation: self.translation.debug_ulps_all_tol(&other.translation, tol),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, RA, RB, const D: usize> FloatEqReport<Isometry<B, RB, D>> for Isometry<A, RA, D>
+where
+    A: Scalar + FloatEqReport<B>,
+    B: Scalar,
+    A::Tol: Scalar,
+    UlpsTol<A::Tol>: Scalar,
+    RA: FloatEqReport<RB>,
+    RA::Tol: Sized,
+    UlpsTol<RA::Tol>: Sized,
+{
+    fn report(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: String,
+    ) -> ReportNode {
+        let rotation = self.rotation.report(
+            &other.rotation,
+            tol.map(|t| &t.rotation, |t| &t.rotation),
+            format!("{}.rotation", path),
+        );
+        let translation = self.translation.report(
+            &other.translation,
+            tol.map(|t| &t.translation, |t| &t.translation),
+            format!("{}.translation", path),
+        );
+        ReportNode::composite(path, vec![rotation, translation])
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, RA, RB, const D: usize> FloatEqAllReport<Isometry<B, RB, D>> for Isometry<A, RA, D>
+where
+    A: Scalar + FloatEqAllReport<B>,
+    B: Scalar,
+    RA: FloatEqAllReport<RB, AllTol = A::AllTol>,
+{
+    fn report_all(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        let rotation =
+            (self.rotation).report_all(&other.rotation, tol, format!("{}.rotation", path));
+        let translation =
+            (self.translation).report_all(&other.translation, tol, format!("{}.translation", path));
+        ReportNode::composite(path, vec![rotation, translation])
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, RA, RB, const D: usize> AssertFloatEqDiffs<Isometry<B, RB, D>> for Isometry<A, RA, D>
+where
+    A: Scalar + AssertFloatEqDiffs<B>,
+    B: Scalar,
+    A::Tol: Scalar,
+    A::DebugAbsDiff: Scalar,
+    A::DebugTol: Scalar,
+    UlpsTol<A::Tol>: Scalar,
+    UlpsTol<A::DebugTol>: Scalar,
+    DebugUlpsDiff<A::DebugAbsDiff>: Scalar,
+    RA: AssertFloatEqDiffs<RB>,
+    RA::Tol: Sized,
+    RA::DebugTol: Sized,
+    UlpsTol<RA::Tol>: Sized,
+    UlpsTol<RA::DebugTol>: Sized,
+{
+    fn debug_diffs(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let rotation = format!("{}.rotation", path);
+        let translation = format!("{}.translation", path);
+        let rotation_tol = tol.map(|t| &t.rotation, |t| &t.rotation);
+        let translation_tol = tol.map(|t| &t.translation, |t| &t.translation);
+        (self.rotation).debug_diffs(&other.rotation, rotation_tol, &rotation, diffs);
+        (self.translation).debug_diffs(&other.translation, translation_tol, &translation, diffs);
+        false
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, RA, RB, const D: usize> AssertFloatEqAllDiffs<Isometry<B, RB, D>> for Isometry<A, RA, D>
+where
+    A: Scalar + AssertFloatEqAllDiffs<B>,
+    B: Scalar,
+    A::AllDebugTol: Scalar,
+    UlpsTol<A::AllDebugTol>: Scalar,
+    RA: AssertFloatEqAllDiffs<RB, AllTol = A::AllTol>,
+    RA::AllDebugTol: Sized,
+    UlpsTol<RA::AllDebugTol>: Sized,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &Isometry<B, RB, D>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let rotation = format!("{}.rotation", path);
+        let translation = format!("{}.translation", path);
+        (self.rotation).debug_all_diffs(&other.rotation, tol, &rotation, diffs);
+        (self.translation).debug_all_diffs(&other.translation, tol, &translation, diffs);
+        false
+    }
+}
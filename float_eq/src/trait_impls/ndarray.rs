@@ -0,0 +1,620 @@
+#[cfg(feature = "std")]
+use crate::{
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport,
+    Mismatch, ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
+    FloatEqUlpsTol, UlpsTol,
+};
+#[cfg(feature = "std")]
+use ndarray::IntoDimension;
+use ndarray::{Array, ArrayBase, Data, Dimension, Zip};
+
+// Arrays of any storage are compared element by element. Tolerances and debug
+// values are always owned arrays of the same dimension, and arrays of different
+// shapes are never equal.
+
+impl<S, D> FloatEqUlpsTol for ArrayBase<S, D>
+where
+    S: Data,
+    S::Elem: FloatEqUlpsTol,
+    UlpsTol<S::Elem>: Sized,
+    D: Dimension,
+{
+    type UlpsTol = Array<UlpsTol<S::Elem>, D>;
+}
+
+impl<S, D> FloatEqDebugUlpsDiff for ArrayBase<S, D>
+where
+    S: Data,
+    S::Elem: FloatEqDebugUlpsDiff,
+    D: Dimension,
+{
+    type DebugUlpsDiff = Array<DebugUlpsDiff<S::Elem>, D>;
+}
+
+impl<A, B, SA, SB, D> FloatEq<ArrayBase<SB, D>> for ArrayBase<SA, D>
+where
+    A: FloatEq<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    SA: Data<Elem = A>,
+    SB: Data<Elem = B>,
+    D: Dimension,
+{
+    type Tol = Array<A::Tol, D>;
+
+    #[inline]
+    fn eq_abs(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && Zip::from(self)
+                .and(other)
+                .and(tol)
+                .all(|a, b, eps| FloatEq::eq_abs(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && Zip::from(self)
+                .and(other)
+                .and(tol)
+                .all(|a, b, eps| FloatEq::eq_rmax(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && Zip::from(self)
+                .and(other)
+                .and(tol)
+                .all(|a, b, eps| FloatEq::eq_rmin(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && Zip::from(self)
+                .and(other)
+                .and(tol)
+                .all(|a, b, eps| FloatEq::eq_r1st(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && Zip::from(self)
+                .and(other)
+                .and(tol)
+                .all(|a, b, eps| FloatEq::eq_r2nd(a, b, eps))
+    }
+
+    #[inline]
+    fn eq_atol_rtol(&self, other: &ArrayBase<SB, D>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == atol.shape()
+            && self.shape() == rtol.shape()
+            && Zip::from(self)
+                .and(other)
+                .and(atol)
+                .and(rtol)
+                .all(|a, b, at, rt| FloatEq::eq_atol_rtol(a, b, at, rt))
+    }
+
+    #[inline]
+    fn eq_ulps(&self, other: &ArrayBase<SB, D>, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.shape() == other.shape()
+            && self.shape() == tol.shape()
+            && Zip::from(self)
+                .and(other)
+                .and(tol)
+                .all(|a, b, eps| FloatEq::eq_ulps(a, b, eps))
+    }
+}
+
+impl<A, B, SA, SB, D> FloatEqAll<ArrayBase<SB, D>> for ArrayBase<SA, D>
+where
+    A: FloatEqAll<B>,
+    SA: Data<Elem = A>,
+    SB: Data<Elem = B>,
+    D: Dimension,
+{
+    type AllTol = A::AllTol;
+
+    #[inline]
+    fn eq_abs_all(&self, other: &ArrayBase<SB, D>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && Zip::from(self)
+                .and(other)
+                .all(|a, b| FloatEqAll::eq_abs_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_rmax_all(&self, other: &ArrayBase<SB, D>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && Zip::from(self)
+                .and(other)
+                .all(|a, b| FloatEqAll::eq_rmax_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_rmin_all(&self, other: &ArrayBase<SB, D>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && Zip::from(self)
+                .and(other)
+                .all(|a, b| FloatEqAll::eq_rmin_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_r1st_all(&self, other: &ArrayBase<SB, D>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && Zip::from(self)
+                .and(other)
+                .all(|a, b| FloatEqAll::eq_r1st_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_r2nd_all(&self, other: &ArrayBase<SB, D>, tol: &Self::AllTol) -> bool {
+        self.shape() == other.shape()
+            && Zip::from(self)
+                .and(other)
+                .all(|a, b| FloatEqAll::eq_r2nd_all(a, b, tol))
+    }
+
+    #[inline]
+    fn eq_atol_rtol_all(
+        &self,
+        other: &ArrayBase<SB, D>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> bool {
+        self.shape() == other.shape()
+            && Zip::from(self)
+                .and(other)
+                .all(|a, b| FloatEqAll::eq_atol_rtol_all(a, b, atol, rtol))
+    }
+
+    #[inline]
+    fn eq_ulps_all(&self, other: &ArrayBase<SB, D>, tol: &UlpsTol<Self::AllTol>) -> bool {
+        self.shape() == other.shape()
+            && Zip::from(self)
+                .and(other)
+                .all(|a, b| FloatEqAll::eq_ulps_all(a, b, tol))
+    }
+}
+
+impl<A, B, SA, SB, D> AssertFloatEq<ArrayBase<SB, D>> for ArrayBase<SA, D>
+where
+    A: AssertFloatEq<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+    SA: Data<Elem = A>,
+    SB: Data<Elem = B>,
+    D: Dimension,
+{
+    type DebugAbsDiff = Option<Array<A::DebugAbsDiff, D>>;
+    type DebugTol = Option<Array<A::DebugTol, D>>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &ArrayBase<SB, D>) -> Self::DebugAbsDiff {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_abs_diff(b)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &ArrayBase<SB, D>) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_ulps_diff(b)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_abs_tol(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .and(tol)
+                    .map_collect(|a, b, eps| a.debug_abs_tol(b, eps)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_rmax_tol(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .and(tol)
+                    .map_collect(|a, b, eps| a.debug_rmax_tol(b, eps)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_rmin_tol(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .and(tol)
+                    .map_collect(|a, b, eps| a.debug_rmin_tol(b, eps)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_r1st_tol(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .and(tol)
+                    .map_collect(|a, b, eps| a.debug_r1st_tol(b, eps)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_r2nd_tol(&self, other: &ArrayBase<SB, D>, tol: &Self::Tol) -> Self::DebugTol {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .and(tol)
+                    .map_collect(|a, b, eps| a.debug_r2nd_tol(b, eps)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &ArrayBase<SB, D>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.shape() == other.shape()
+            && self.shape() == atol.shape()
+            && self.shape() == rtol.shape()
+        {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .and(atol)
+                    .and(rtol)
+                    .map_collect(|a, b, at, rt| a.debug_atol_rtol_tol(b, at, rt)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_tol(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> UlpsTol<Self::DebugTol> {
+        if self.shape() == other.shape() && self.shape() == tol.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .and(tol)
+                    .map_collect(|a, b, eps| a.debug_ulps_tol(b, eps)),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<A, B, SA, SB, D> AssertFloatEqAll<ArrayBase<SB, D>> for ArrayBase<SA, D>
+where
+    A: AssertFloatEqAll<B>,
+    A::AllDebugTol: Sized,
+    UlpsTol<A::AllDebugTol>: Sized,
+    SA: Data<Elem = A>,
+    SB: Data<Elem = B>,
+    D: Dimension,
+{
+    type AllDebugTol = Option<Array<A::AllDebugTol, D>>;
+
+    #[inline]
+    fn debug_abs_all_tol(&self, other: &ArrayBase<SB, D>, tol: &Self::AllTol) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_abs_all_tol(b, tol)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_rmax_all_tol(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_rmax_all_tol(b, tol)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_rmin_all_tol(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_rmin_all_tol(b, tol)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_r1st_all_tol(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_r1st_all_tol(b, tol)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_r2nd_all_tol(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_r2nd_all_tol(b, tol)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &ArrayBase<SB, D>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_atol_rtol_all_tol(b, atol, rtol)),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        if self.shape() == other.shape() {
+            Some(
+                Zip::from(self)
+                    .and(other)
+                    .map_collect(|a, b| a.debug_ulps_all_tol(b, tol)),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, SA, SB, D> FloatEqReport<ArrayBase<SB, D>> for ArrayBase<SA, D>
+where
+    A: FloatEqReport<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    SA: Data<Elem = A>,
+    SB: Data<Elem = B>,
+    D: Dimension,
+{
+    fn report(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: String,
+    ) -> ReportNode {
+        let tol_shape = tol.apply(|t| t.shape(), |t| t.shape());
+        if let Some(mismatch) = shape_mismatch(self.shape(), other.shape(), Some(tol_shape)) {
+            return ReportNode::mismatch(path, mismatch);
+        }
+
+        let tol = tol.elements(|t| t.iter(), |t| t.iter());
+        let children = self
+            .indexed_iter()
+            .zip(other.iter())
+            .zip(tol)
+            .map(|(((index, a), b), t)| a.report(b, t, index_path(&path, index)))
+            .collect();
+        ReportNode::composite(path, children)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, SA, SB, D> FloatEqAllReport<ArrayBase<SB, D>> for ArrayBase<SA, D>
+where
+    A: FloatEqAllReport<B>,
+    SA: Data<Elem = A>,
+    SB: Data<Elem = B>,
+    D: Dimension,
+{
+    fn report_all(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        if let Some(mismatch) = shape_mismatch(self.shape(), other.shape(), None) {
+            return ReportNode::mismatch(path, mismatch);
+        }
+
+        let children = self
+            .indexed_iter()
+            .zip(other.iter())
+            .map(|((index, a), b)| a.report_all(b, tol, index_path(&path, index)))
+            .collect();
+        ReportNode::composite(path, children)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, SA, SB, D> AssertFloatEqDiffs<ArrayBase<SB, D>> for ArrayBase<SA, D>
+where
+    A: AssertFloatEqDiffs<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+    SA: Data<Elem = A>,
+    SB: Data<Elem = B>,
+    D: Dimension,
+{
+    fn debug_diffs(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol_shape = tol.apply(|t| t.shape(), |t| t.shape());
+        if let Some(mismatch) = shape_mismatch(self.shape(), other.shape(), Some(tol_shape)) {
+            diffs.push(path, format_args!("{}", mismatch));
+            return true;
+        }
+
+        let tol = tol.elements(|t| t.iter(), |t| t.iter());
+        for (((index, a), b), t) in self.indexed_iter().zip(other.iter()).zip(tol) {
+            a.debug_diffs(b, t, &index_path(path, index), diffs);
+        }
+        true
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B, SA, SB, D> AssertFloatEqAllDiffs<ArrayBase<SB, D>> for ArrayBase<SA, D>
+where
+    A: AssertFloatEqAllDiffs<B>,
+    A::AllDebugTol: Sized,
+    UlpsTol<A::AllDebugTol>: Sized,
+    SA: Data<Elem = A>,
+    SB: Data<Elem = B>,
+    D: Dimension,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &ArrayBase<SB, D>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        if let Some(mismatch) = shape_mismatch(self.shape(), other.shape(), None) {
+            diffs.push(path, format_args!("{}", mismatch));
+            return true;
+        }
+
+        for ((index, a), b) in self.indexed_iter().zip(other.iter()) {
+            a.debug_all_diffs(b, tol, &index_path(path, index), diffs);
+        }
+        true
+    }
+}
+
+/// Why arrays of these shapes, and optionally a tolerance of `tol` shape,
+/// cannot be compared, if they cannot.
+#[cfg(feature = "std")]
+fn shape_mismatch(left: &[usize], right: &[usize], tol: Option<&[usize]>) -> Option<Mismatch> {
+    if left != right {
+        Some(Mismatch::Shape {
+            left: left.to_vec(),
+            right: right.to_vec(),
+        })
+    } else {
+        match tol {
+            Some(tol) if tol != left => Some(Mismatch::TolShape {
+                shape: left.to_vec(),
+                tol: tol.to_vec(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// The path of an element, for example `[1, 2]`.
+#[cfg(feature = "std")]
+fn index_path<I: IntoDimension>(path: &str, index: I) -> String {
+    format!("{}{:?}", path, index.into_dimension().slice())
+}
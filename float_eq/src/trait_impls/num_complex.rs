@@ -1,3 +1,8 @@
+#[cfg(feature = "std")]
+use crate::{
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport,
+    ReportNode, ReportTol,
+};
 use crate::{
     AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
     FloatEqUlpsTol, UlpsTol,
@@ -13,6 +18,7 @@ use num_complex::Complex;
 /// [`num::Complex<T>`]: https://docs.rs/num/0.3.0/num/struct.Complex.html
 #[allow(clippy::derive_partial_eq_without_eq)] // Most likely this is going to use floats, and we don't want to derive Eq for those
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComplexUlps<T> {
     /// Real portion of the complex number in ULPs.
     pub re: T,
@@ -80,6 +86,12 @@ where
         self.re.eq_r2nd(&other.re, &tol.re) && self.im.eq_r2nd(&other.im, &tol.im)
     }
 
+    #[inline]
+    fn eq_atol_rtol(&self, other: &Self, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.re.eq_atol_rtol(&other.re, &atol.re, &rtol.re)
+            && self.im.eq_atol_rtol(&other.im, &atol.im, &rtol.im)
+    }
+
     #[inline]
     fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
         self.re.eq_ulps(&other.re, &tol.re) && self.im.eq_ulps(&other.im, &tol.im)
@@ -114,6 +126,12 @@ impl<T: FloatEqAll> FloatEqAll for Complex<T> {
         self.re.eq_r2nd_all(&other.re, tol) && self.im.eq_r2nd_all(&other.im, tol)
     }
 
+    #[inline]
+    fn eq_atol_rtol_all(&self, other: &Self, atol: &Self::AllTol, rtol: &Self::AllTol) -> bool {
+        self.re.eq_atol_rtol_all(&other.re, atol, rtol)
+            && self.im.eq_atol_rtol_all(&other.im, atol, rtol)
+    }
+
     #[inline]
     fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
         self.re.eq_ulps_all(&other.re, tol) && self.im.eq_ulps_all(&other.im, tol)
@@ -187,6 +205,19 @@ where
         }
     }
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &Self,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        Self::DebugTol {
+            re: self.re.debug_atol_rtol_tol(&other.re, &atol.re, &rtol.re),
+            im: self.im.debug_atol_rtol_tol(&other.im, &atol.im, &rtol.im),
+        }
+    }
+
     #[inline]
     fn debug_ulps_tol(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol>
     where
@@ -247,6 +278,19 @@ where
         }
     }
 
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &Self,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Self::AllDebugTol {
+            re: self.re.debug_atol_rtol_all_tol(&other.re, atol, rtol),
+            im: self.im.debug_atol_rtol_all_tol(&other.im, atol, rtol),
+        }
+    }
+
     #[inline]
     fn debug_ulps_all_tol(
         &self,
@@ -262,3 +306,84 @@ where
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl<T: FloatEqReport> FloatEqReport for Complex<T>
+where
+    T::Tol: Sized,
+    UlpsTol<T::Tol>: Sized,
+{
+    fn report(&self, other: &Self, tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+        let re = self.re.report(
+            &other.re,
+            tol.map(|t| &t.re, |t| &t.re),
+            format!("{}.re", path),
+        );
+        let im = self.im.report(
+            &other.im,
+            tol.map(|t| &t.im, |t| &t.im),
+            format!("{}.im", path),
+        );
+        ReportNode::composite(path, vec![re, im])
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: FloatEqAllReport> FloatEqAllReport for Complex<T> {
+    fn report_all(
+        &self,
+        other: &Self,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        let re = self.re.report_all(&other.re, tol, format!("{}.re", path));
+        let im = self.im.report_all(&other.im, tol, format!("{}.im", path));
+        ReportNode::composite(path, vec![re, im])
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> AssertFloatEqDiffs for Complex<T>
+where
+    T: AssertFloatEqDiffs,
+    T::Tol: Sized,
+    T::DebugTol: Sized,
+    UlpsTol<T::Tol>: Sized,
+    UlpsTol<T::DebugTol>: Sized,
+{
+    fn debug_diffs(
+        &self,
+        other: &Self,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let (re, im) = (format!("{}.re", path), format!("{}.im", path));
+        let re_tol = tol.map(|t| &t.re, |t| &t.re);
+        let im_tol = tol.map(|t| &t.im, |t| &t.im);
+        self.re.debug_diffs(&other.re, re_tol, &re, diffs);
+        self.im.debug_diffs(&other.im, im_tol, &im, diffs);
+        false
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> AssertFloatEqAllDiffs for Complex<T>
+where
+    T: AssertFloatEqAllDiffs,
+    T::AllDebugTol: Sized,
+    UlpsTol<T::AllDebugTol>: Sized,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &Self,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let (re, im) = (format!("{}.re", path), format!("{}.im", path));
+        self.re.debug_all_diffs(&other.re, tol, &re, diffs);
+        self.im.debug_all_diffs(&other.im, tol, &im, diffs);
+        false
+    }
+}
@@ -1,8 +1,13 @@
 #![allow(clippy::float_cmp)]
 
+#[cfg(feature = "std")]
 use crate::{
-    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
-    FloatEqUlpsTol, UlpsTol,
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, EffectiveTol, FloatEqAllReport,
+    FloatEqReport, FloatReport, ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, AssertFloatOrd, DebugUlpsDiff, FloatEq, FloatEqAll,
+    FloatEqDebugUlpsDiff, FloatEqUlpsTol, FloatOrd, FloatOrdOp, UlpsTol,
 };
 
 macro_rules! impl_traits {
@@ -25,6 +30,61 @@ macro_rules! impl_traits {
             }
         }
 
+        impl_float_traits!($float, $uint, $float::abs);
+    };
+}
+
+/// The bound of an ordering check, found by moving `$b` by the effective
+/// tolerance given by the `$debug_tol` method of `$a`. A zero tolerance leaves
+/// `$b` as it is, even where scaling it by an infinite value is not a number.
+macro_rules! ord_bound {
+    ($float:ty, $a:expr, $b:expr, $op:expr, $tol:expr, $debug_tol:ident) => {
+        if *$tol == <$float>::from_bits(0) {
+            *$b
+        } else {
+            $op.bound(*$b, $a.$debug_tol($b, $tol))
+        }
+    };
+}
+
+/// The bound of an ULPs ordering check, found by moving `$value` by `$tol`
+/// representable values of `$float` in the direction given by `$op`. Steps are
+/// made through a mapping of the bits to `$uint` keys that sort in the same
+/// order as the values, where both zeroes share a key, and stop at infinity.
+macro_rules! ulps_bound {
+    ($float:ty, $uint:ty, $value:expr, $op:expr, $tol:expr) => {{
+        const SIGN: $uint = 1 << ((::core::mem::size_of::<$uint>() * 8) - 1);
+        let to_key = |value: $float| {
+            let bits = value.to_bits();
+            if bits & SIGN != 0 {
+                SIGN - (bits & !SIGN)
+            } else {
+                SIGN + bits
+            }
+        };
+        let value: $float = $value;
+        if value.is_nan() {
+            value
+        } else {
+            let key = to_key(value);
+            let key = if $op.is_bound_above() {
+                key.saturating_add($tol).min(to_key(<$float>::INFINITY))
+            } else {
+                key.saturating_sub($tol).max(to_key(<$float>::NEG_INFINITY))
+            };
+            <$float>::from_bits(if key >= SIGN {
+                key - SIGN
+            } else {
+                SIGN | (SIGN - key)
+            })
+        }
+    }};
+}
+
+/// Implements the float_eq traits for a floating point type `$float` whose bits
+/// are represented by `$uint`, using `$abs` to take absolute values.
+macro_rules! impl_float_traits {
+    ($float:ty, $uint:ty, $abs:path) => {
         impl FloatEqUlpsTol for $float {
             type UlpsTol = $uint;
         }
@@ -39,16 +99,16 @@ macro_rules! impl_traits {
             #[inline]
             fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
                 // the PartialEq check covers equality of infinities
-                self == other || $float::abs(self - other).le(tol)
+                self == other || $abs(self - other).le(tol)
             }
 
             #[inline]
             fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
                 // the PartialEq check covers equality of infinities
                 self == other || {
-                    let largest = $float::abs(*self).max($float::abs(*other));
+                    let largest = $abs(*self).max($abs(*other));
                     let tol = largest * tol;
-                    $float::abs(self - other) <= tol
+                    $abs(self - other) <= tol
                 }
             }
 
@@ -56,9 +116,9 @@ macro_rules! impl_traits {
             fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
                 // the PartialEq check covers equality of infinities
                 self == other || {
-                    let largest = $float::abs(*self).min($float::abs(*other));
+                    let largest = $abs(*self).min($abs(*other));
                     let tol = largest * tol;
-                    $float::abs(self - other) <= tol
+                    $abs(self - other) <= tol
                 }
             }
 
@@ -66,8 +126,8 @@ macro_rules! impl_traits {
             fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
                 // the PartialEq check covers equality of infinities
                 self == other || {
-                    let tol = $float::abs(*self) * tol;
-                    $float::abs(self - other) <= tol
+                    let tol = $abs(*self) * tol;
+                    $abs(self - other) <= tol
                 }
             }
 
@@ -75,11 +135,21 @@ macro_rules! impl_traits {
             fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
                 // the PartialEq check covers equality of infinities
                 self == other || {
-                    let tol = $float::abs(*other) * tol;
-                    $float::abs(self - other) <= tol
+                    let tol = $abs(*other) * tol;
+                    $abs(self - other) <= tol
                 }
             }
 
+            #[inline]
+            fn eq_atol_rtol(&self, other: &Self, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                // as in NumPy, infinities are only equal to themselves
+                self == other
+                    || (self.is_finite() && other.is_finite() && {
+                        let tol = *atol + $abs(*other) * rtol;
+                        $abs(self - other) <= tol
+                    })
+            }
+
             #[inline]
             fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
                 if self.is_nan() || other.is_nan() {
@@ -124,6 +194,16 @@ macro_rules! impl_traits {
                 self.eq_r2nd(other, tol)
             }
 
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &Self,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                self.eq_atol_rtol(other, atol, rtol)
+            }
+
             #[inline]
             fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
                 self.eq_ulps(other, tol)
@@ -136,7 +216,7 @@ macro_rules! impl_traits {
 
             #[inline]
             fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
-                $float::abs(self - other)
+                $abs(self - other)
             }
 
             #[inline]
@@ -163,22 +243,32 @@ macro_rules! impl_traits {
 
             #[inline]
             fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
-                $float::abs(*self).max($float::abs(*other)) * tol
+                $abs(*self).max($abs(*other)) * tol
             }
 
             #[inline]
             fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
-                $float::abs(*self).min($float::abs(*other)) * tol
+                $abs(*self).min($abs(*other)) * tol
             }
 
             #[inline]
             fn debug_r1st_tol(&self, _other: &Self, tol: &Self::Tol) -> Self::DebugTol {
-                $float::abs(*self) * tol
+                $abs(*self) * tol
             }
 
             #[inline]
             fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
-                $float::abs(*other) * tol
+                $abs(*other) * tol
+            }
+
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &Self,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                *atol + $abs(*other) * rtol
             }
 
             #[inline]
@@ -219,6 +309,16 @@ macro_rules! impl_traits {
                 self.debug_r2nd_tol(other, tol)
             }
 
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &Self,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                self.debug_atol_rtol_tol(other, atol, rtol)
+            }
+
             #[inline]
             fn debug_ulps_all_tol(
                 &self,
@@ -228,8 +328,538 @@ macro_rules! impl_traits {
                 self.debug_ulps_tol(other, tol)
             }
         }
+
+        impl FloatOrd for $float {
+            #[inline]
+            fn ord_abs(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                op.holds_within(
+                    self,
+                    other,
+                    &ord_bound!($float, self, other, op, tol, debug_abs_tol),
+                )
+            }
+
+            #[inline]
+            fn ord_rmax(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                op.holds_within(
+                    self,
+                    other,
+                    &ord_bound!($float, self, other, op, tol, debug_rmax_tol),
+                )
+            }
+
+            #[inline]
+            fn ord_rmin(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                op.holds_within(
+                    self,
+                    other,
+                    &ord_bound!($float, self, other, op, tol, debug_rmin_tol),
+                )
+            }
+
+            #[inline]
+            fn ord_r1st(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                op.holds_within(
+                    self,
+                    other,
+                    &ord_bound!($float, self, other, op, tol, debug_r1st_tol),
+                )
+            }
+
+            #[inline]
+            fn ord_r2nd(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                op.holds_within(
+                    self,
+                    other,
+                    &ord_bound!($float, self, other, op, tol, debug_r2nd_tol),
+                )
+            }
+
+            #[inline]
+            fn ord_ulps(&self, other: &Self, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+                op.holds_within(self, other, &ulps_bound!($float, $uint, *other, op, *tol))
+            }
+        }
+
+        impl AssertFloatOrd for $float {
+            #[inline]
+            fn debug_ord_abs(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                op.past_bound(
+                    *self,
+                    ord_bound!($float, self, other, op, tol, debug_abs_tol),
+                )
+            }
+
+            #[inline]
+            fn debug_ord_rmax(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                op.past_bound(
+                    *self,
+                    ord_bound!($float, self, other, op, tol, debug_rmax_tol),
+                )
+            }
+
+            #[inline]
+            fn debug_ord_rmin(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                op.past_bound(
+                    *self,
+                    ord_bound!($float, self, other, op, tol, debug_rmin_tol),
+                )
+            }
+
+            #[inline]
+            fn debug_ord_r1st(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                op.past_bound(
+                    *self,
+                    ord_bound!($float, self, other, op, tol, debug_r1st_tol),
+                )
+            }
+
+            #[inline]
+            fn debug_ord_r2nd(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                op.past_bound(
+                    *self,
+                    ord_bound!($float, self, other, op, tol, debug_r2nd_tol),
+                )
+            }
+
+            #[inline]
+            fn debug_ord_ulps(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> Self::DebugTol {
+                op.past_bound(*self, ulps_bound!($float, $uint, *other, op, *tol))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqReport for $float {
+            fn report(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::Tol>,
+                path: String,
+            ) -> ReportNode {
+                let (passed, tol) = match tol {
+                    ReportTol::Abs(t) => (
+                        self.eq_abs(other, t),
+                        EffectiveTol::Abs(self.debug_abs_tol(other, t).into()),
+                    ),
+                    ReportTol::Rmax(t) => (
+                        self.eq_rmax(other, t),
+                        EffectiveTol::Abs(self.debug_rmax_tol(other, t).into()),
+                    ),
+                    ReportTol::Rmin(t) => (
+                        self.eq_rmin(other, t),
+                        EffectiveTol::Abs(self.debug_rmin_tol(other, t).into()),
+                    ),
+                    ReportTol::R1st(t) => (
+                        self.eq_r1st(other, t),
+                        EffectiveTol::Abs(self.debug_r1st_tol(other, t).into()),
+                    ),
+                    ReportTol::R2nd(t) => (
+                        self.eq_r2nd(other, t),
+                        EffectiveTol::Abs(self.debug_r2nd_tol(other, t).into()),
+                    ),
+                    ReportTol::AtolRtol(a, r) => (
+                        self.eq_atol_rtol(other, a, r),
+                        EffectiveTol::Abs(self.debug_atol_rtol_tol(other, a, r).into()),
+                    ),
+                    ReportTol::Ulps(t) => (self.eq_ulps(other, t), EffectiveTol::Ulps((*t).into())),
+                };
+                let report = FloatReport {
+                    left: (*self).into(),
+                    right: (*other).into(),
+                    abs_diff: self.debug_abs_diff(other).into(),
+                    ulps_diff: self.debug_ulps_diff(other).map(Into::into),
+                    tol,
+                };
+                ReportNode::float(path, passed, report)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqAllReport for $float {
+            #[inline]
+            fn report_all(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                self.report(other, tol, path)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqDiffs for $float {
+            #[inline]
+            fn debug_diffs(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                diffs.push_failure(path, self, other, tol);
+                false
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqAllDiffs for $float {
+            #[inline]
+            fn debug_all_diffs(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                self.debug_diffs(other, tol, path, diffs)
+            }
+        }
     };
 }
 
 impl_traits!(f32, u32);
 impl_traits!(f64, u64);
+
+#[cfg(feature = "cross_precision")]
+/// Implements the float_eq traits between `$lhs` and `$rhs`, which are `$narrow`
+/// and `$wide` in either order. Absolute and relative checks are made in the
+/// precision of `$wide`, whilst ULPs are measured in `$narrow` by rounding the
+/// wider value to it, since that is the precision the result was produced in.
+/// Values outside of the range of `$narrow` have no ULPs difference.
+macro_rules! impl_cross_traits {
+    ($lhs:ty, $rhs:ty, $narrow:ty, $wide:ty) => {
+        impl FloatEq<$rhs> for $lhs {
+            type Tol = $wide;
+
+            #[inline]
+            fn eq_abs(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_abs(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_rmax(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_rmin(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_r1st(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &$rhs, tol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_r2nd(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn eq_atol_rtol(&self, other: &$rhs, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                <$wide>::from(*self).eq_atol_rtol(&<$wide>::from(*other), atol, rtol)
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &$rhs, tol: &UlpsTol<Self::Tol>) -> bool {
+                // NaNs and values of different signs have no ULPs difference,
+                // except for zero and negative zero which are a difference of 0
+                match self.debug_ulps_diff(other) {
+                    Some(diff) => diff <= *tol,
+                    None => false,
+                }
+            }
+        }
+
+        impl FloatEqAll<$rhs> for $lhs {
+            type AllTol = $wide;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_abs(other, tol)
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_rmax(other, tol)
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_rmin(other, tol)
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_r1st(other, tol)
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &$rhs, tol: &Self::AllTol) -> bool {
+                self.eq_r2nd(other, tol)
+            }
+
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &$rhs,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                self.eq_atol_rtol(other, atol, rtol)
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &$rhs, tol: &UlpsTol<Self::AllTol>) -> bool {
+                self.eq_ulps(other, tol)
+            }
+        }
+
+        impl AssertFloatEq<$rhs> for $lhs {
+            type DebugAbsDiff = $wide;
+            type DebugTol = $wide;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &$rhs) -> Self::DebugAbsDiff {
+                <$wide>::from(*self).debug_abs_diff(&<$wide>::from(*other))
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &$rhs) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                // `as` rounds to the nearest representable value, so a finite
+                // value that overflows to infinity or a non-zero value that
+                // underflows to zero is outside of the range of `$narrow`
+                let narrow = |value: $wide| {
+                    let rounded = value as $narrow;
+                    let overflow = rounded.is_infinite() && value.is_finite();
+                    let underflow = rounded == 0.0 && value != 0.0;
+                    if overflow || underflow {
+                        None
+                    } else {
+                        Some(rounded)
+                    }
+                };
+                let a = narrow(<$wide>::from(*self))?;
+                let b = narrow(<$wide>::from(*other))?;
+                a.debug_ulps_diff(&b).map(Into::into)
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_abs_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_rmax_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_rmin_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_r1st_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &$rhs, tol: &Self::Tol) -> Self::DebugTol {
+                <$wide>::from(*self).debug_r2nd_tol(&<$wide>::from(*other), tol)
+            }
+
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &$rhs,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                <$wide>::from(*self).debug_atol_rtol_tol(&<$wide>::from(*other), atol, rtol)
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                _other: &$rhs,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol> {
+                *tol
+            }
+        }
+
+        impl AssertFloatEqAll<$rhs> for $lhs {
+            type AllDebugTol = Self::AllTol;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_abs_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_rmax_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_rmin_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_r1st_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &$rhs, tol: &Self::AllTol) -> Self::AllDebugTol {
+                self.debug_r2nd_tol(other, tol)
+            }
+
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &$rhs,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                self.debug_atol_rtol_tol(other, atol, rtol)
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                other: &$rhs,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol> {
+                self.debug_ulps_tol(other, tol)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqReport<$rhs> for $lhs {
+            fn report(
+                &self,
+                other: &$rhs,
+                tol: ReportTol<'_, Self::Tol>,
+                path: String,
+            ) -> ReportNode {
+                let (passed, tol) = match tol {
+                    ReportTol::Abs(t) => (
+                        self.eq_abs(other, t),
+                        EffectiveTol::Abs(self.debug_abs_tol(other, t).into()),
+                    ),
+                    ReportTol::Rmax(t) => (
+                        self.eq_rmax(other, t),
+                        EffectiveTol::Abs(self.debug_rmax_tol(other, t).into()),
+                    ),
+                    ReportTol::Rmin(t) => (
+                        self.eq_rmin(other, t),
+                        EffectiveTol::Abs(self.debug_rmin_tol(other, t).into()),
+                    ),
+                    ReportTol::R1st(t) => (
+                        self.eq_r1st(other, t),
+                        EffectiveTol::Abs(self.debug_r1st_tol(other, t).into()),
+                    ),
+                    ReportTol::R2nd(t) => (
+                        self.eq_r2nd(other, t),
+                        EffectiveTol::Abs(self.debug_r2nd_tol(other, t).into()),
+                    ),
+                    ReportTol::AtolRtol(a, r) => (
+                        self.eq_atol_rtol(other, a, r),
+                        EffectiveTol::Abs(self.debug_atol_rtol_tol(other, a, r).into()),
+                    ),
+                    ReportTol::Ulps(t) => (self.eq_ulps(other, t), EffectiveTol::Ulps((*t).into())),
+                };
+                let report = FloatReport {
+                    left: (*self).into(),
+                    right: (*other).into(),
+                    abs_diff: self.debug_abs_diff(other).into(),
+                    ulps_diff: self.debug_ulps_diff(other).map(Into::into),
+                    tol,
+                };
+                ReportNode::float(path, passed, report)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqAllReport<$rhs> for $lhs {
+            #[inline]
+            fn report_all(
+                &self,
+                other: &$rhs,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                self.report(other, tol, path)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqDiffs<$rhs> for $lhs {
+            #[inline]
+            fn debug_diffs(
+                &self,
+                other: &$rhs,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                diffs.push_failure(path, self, other, tol);
+                false
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqAllDiffs<$rhs> for $lhs {
+            #[inline]
+            fn debug_all_diffs(
+                &self,
+                other: &$rhs,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                self.debug_diffs(other, tol, path, diffs)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "cross_precision")]
+impl_cross_traits!(f32, f64, f32, f64);
+#[cfg(feature = "cross_precision")]
+impl_cross_traits!(f64, f32, f32, f64);
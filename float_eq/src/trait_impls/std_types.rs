@@ -1,6 +1,10 @@
 use crate::{
-    AssertFloatEq, AssertFloatEqAll, DebugUlpsDiff, FloatEq, FloatEqAll, FloatEqDebugUlpsDiff,
-    FloatEqUlpsTol, UlpsTol,
+    assert_diffs::diff_elements, report::report_elements, AssertDiffs, AssertFloatEqAllDiffs,
+    AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport, Mismatch, ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, AssertFloatOrd, DebugUlpsDiff, FloatEq, FloatEqAll,
+    FloatEqDebugUlpsDiff, FloatEqUlpsTol, FloatOrd, FloatOrdOp, UlpsTol,
 };
 use std::boxed::Box;
 use std::collections::{BTreeMap, HashMap, LinkedList, VecDeque};
@@ -49,12 +53,52 @@ macro_rules! impl_traits_for_wrapper {
                 FloatEq::eq_r2nd(&**self, &**other, tol)
             }
 
+            #[inline]
+            fn eq_atol_rtol(&self, other: &$t<B>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                FloatEq::eq_atol_rtol(&**self, &**other, atol, rtol)
+            }
+
             #[inline]
             fn eq_ulps(&self, other: &$t<B>, tol: &UlpsTol<Self::Tol>) -> bool {
                 FloatEq::eq_ulps(&**self, &**other, tol)
             }
         }
 
+        impl<A: ?Sized, B: ?Sized> FloatOrd<$t<B>> for $t<A>
+        where
+            A: FloatOrd<B>,
+        {
+            #[inline]
+            fn ord_abs(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_abs(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn ord_rmax(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_rmax(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn ord_rmin(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_rmin(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn ord_r1st(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_r1st(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn ord_r2nd(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                FloatOrd::ord_r2nd(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn ord_ulps(&self, other: &$t<B>, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+                FloatOrd::ord_ulps(&**self, &**other, op, tol)
+            }
+        }
+
         impl<A: ?Sized, B: ?Sized> FloatEqAll<$t<B>> for $t<A>
         where
             A: FloatEqAll<B>,
@@ -86,6 +130,16 @@ macro_rules! impl_traits_for_wrapper {
                 FloatEqAll::eq_r2nd_all(&**self, &**other, tol)
             }
 
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &$t<B>,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                FloatEqAll::eq_atol_rtol_all(&**self, &**other, atol, rtol)
+            }
+
             #[inline]
             fn eq_ulps_all(&self, other: &$t<B>, tol: &UlpsTol<Self::AllTol>) -> bool {
                 FloatEqAll::eq_ulps_all(&**self, &**other, tol)
@@ -135,6 +189,16 @@ macro_rules! impl_traits_for_wrapper {
                 AssertFloatEq::debug_r2nd_tol(&**self, &**other, tol)
             }
 
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &$t<B>,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                AssertFloatEq::debug_atol_rtol_tol(&**self, &**other, atol, rtol)
+            }
+
             #[inline]
             fn debug_ulps_tol(
                 &self,
@@ -148,6 +212,72 @@ macro_rules! impl_traits_for_wrapper {
             }
         }
 
+        impl<A: ?Sized, B: ?Sized> AssertFloatOrd<$t<B>> for $t<A>
+        where
+            A: AssertFloatOrd<B> + Copy,
+            B: Copy,
+        {
+            #[inline]
+            fn debug_ord_abs(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_abs(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_rmax(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_rmax(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_rmin(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_rmin(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_r1st(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_r1st(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_r2nd(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_r2nd(&**self, &**other, op, tol)
+            }
+
+            #[inline]
+            fn debug_ord_ulps(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> Self::DebugTol {
+                AssertFloatOrd::debug_ord_ulps(&**self, &**other, op, tol)
+            }
+        }
+
         impl<A: ?Sized, B: ?Sized> AssertFloatEqAll<$t<B>> for $t<A>
         where
             A: AssertFloatEqAll<B> + Copy,
@@ -180,6 +310,16 @@ macro_rules! impl_traits_for_wrapper {
                 AssertFloatEqAll::debug_r2nd_all_tol(&**self, &**other, tol)
             }
 
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &$t<B>,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                AssertFloatEqAll::debug_atol_rtol_all_tol(&**self, &**other, atol, rtol)
+            }
+
             #[inline]
             fn debug_ulps_all_tol(
                 &self,
@@ -192,6 +332,70 @@ macro_rules! impl_traits_for_wrapper {
                 AssertFloatEqAll::debug_ulps_all_tol(&**self, &**other, tol)
             }
         }
+
+        impl<A: ?Sized, B: ?Sized> FloatEqReport<$t<B>> for $t<A>
+        where
+            A: FloatEqReport<B>,
+        {
+            #[inline]
+            fn report(
+                &self,
+                other: &$t<B>,
+                tol: ReportTol<'_, Self::Tol>,
+                path: String,
+            ) -> ReportNode {
+                FloatEqReport::report(&**self, &**other, tol, path)
+            }
+        }
+
+        impl<A: ?Sized, B: ?Sized> FloatEqAllReport<$t<B>> for $t<A>
+        where
+            A: FloatEqAllReport<B>,
+        {
+            #[inline]
+            fn report_all(
+                &self,
+                other: &$t<B>,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                FloatEqAllReport::report_all(&**self, &**other, tol, path)
+            }
+        }
+
+        impl<A, B> AssertFloatEqDiffs<$t<B>> for $t<A>
+        where
+            A: AssertFloatEqDiffs<B> + Copy,
+            B: Copy,
+        {
+            #[inline]
+            fn debug_diffs(
+                &self,
+                other: &$t<B>,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                AssertFloatEqDiffs::debug_diffs(&**self, &**other, tol, path, diffs)
+            }
+        }
+
+        impl<A, B> AssertFloatEqAllDiffs<$t<B>> for $t<A>
+        where
+            A: AssertFloatEqAllDiffs<B> + Copy,
+            B: Copy,
+        {
+            #[inline]
+            fn debug_all_diffs(
+                &self,
+                other: &$t<B>,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                AssertFloatEqAllDiffs::debug_all_diffs(&**self, &**other, tol, path, diffs)
+            }
+        }
     };
 }
 
@@ -316,6 +520,26 @@ where
         }
     }
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &[B],
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == atol.len() && self.len() == rtol.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .zip(atol.iter().zip(rtol))
+                    .map(|((a, b), (at, rt))| AssertFloatEq::debug_atol_rtol_tol(a, b, at, rt))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_ulps_tol(&self, other: &[B], tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol> {
         if self.len() == other.len() && self.len() == tol.len() {
@@ -332,6 +556,110 @@ where
     }
 }
 
+impl<A, B> AssertFloatOrd<[B]> for [A]
+where
+    A: AssertFloatOrd<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+{
+    #[inline]
+    fn debug_ord_abs(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol)
+                    .map(|((a, b), eps)| AssertFloatOrd::debug_ord_abs(a, b, op, eps))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_rmax(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol)
+                    .map(|((a, b), eps)| AssertFloatOrd::debug_ord_rmax(a, b, op, eps))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_rmin(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol)
+                    .map(|((a, b), eps)| AssertFloatOrd::debug_ord_rmin(a, b, op, eps))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_r1st(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol)
+                    .map(|((a, b), eps)| AssertFloatOrd::debug_ord_r1st(a, b, op, eps))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_r2nd(&self, other: &[B], op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol)
+                    .map(|((a, b), eps)| AssertFloatOrd::debug_ord_r2nd(a, b, op, eps))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_ulps(
+        &self,
+        other: &[B],
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .zip(tol)
+                    .map(|((a, b), eps)| AssertFloatOrd::debug_ord_ulps(a, b, op, eps))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
 impl<A, B> AssertFloatEqAll<[B]> for [A]
 where
     A: AssertFloatEqAll<B>,
@@ -410,6 +738,25 @@ where
         }
     }
 
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &[B],
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.len() == other.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.debug_atol_rtol_all_tol(b, atol, rtol))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_ulps_all_tol(
         &self,
@@ -432,26 +779,119 @@ where
     }
 }
 
-//------------------------------------------------------------------------------
-// Linear collections
-//------------------------------------------------------------------------------
-macro_rules! impl_traits_for_linear_collection {
-    ($t:ident) => {
-        impl<T: FloatEqUlpsTol> FloatEqUlpsTol for $t<T>
-        where
-            UlpsTol<T>: Sized,
-        {
-            type UlpsTol = $t<UlpsTol<T>>;
-        }
+impl<A, B> FloatEqReport<[B]> for [A]
+where
+    A: FloatEqReport<B>,
+    A::Tol: Sized,
+    UlpsTol<A::Tol>: Sized,
+{
+    fn report(&self, other: &[B], tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+        let tol = tol.elements(|t| t.iter(), |t| t.iter());
+        report_elements(
+            path,
+            self.iter(),
+            other.iter(),
+            tol.into_iter(),
+            |a, b, t, path| a.report(b, t, path),
+        )
+    }
+}
 
-        impl<T: FloatEqDebugUlpsDiff> FloatEqDebugUlpsDiff for $t<T> {
-            type DebugUlpsDiff = $t<DebugUlpsDiff<T>>;
-        }
+impl<A, B> FloatEqAllReport<[B]> for [A]
+where
+    A: FloatEqAllReport<B>,
+{
+    fn report_all(
+        &self,
+        other: &[B],
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        let tol = core::iter::repeat(tol);
+        report_elements(path, self.iter(), other.iter(), tol, |a, b, t, path| {
+            a.report_all(b, t, path)
+        })
+    }
+}
 
-        impl<A, B> FloatEq<$t<B>> for $t<A>
-        where
-            A: FloatEq<B>,
-            A::Tol: Sized,
+impl<A, B> AssertFloatEqDiffs<[B]> for [A]
+where
+    A: AssertFloatEqDiffs<B>,
+    A::Tol: Sized,
+    A::DebugTol: Sized,
+    UlpsTol<A::Tol>: Sized,
+    UlpsTol<A::DebugTol>: Sized,
+{
+    fn debug_diffs(
+        &self,
+        other: &[B],
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol = tol.elements(|t| t.iter(), |t| t.iter());
+        diff_elements(
+            path,
+            self.iter(),
+            other.iter(),
+            tol.into_iter(),
+            diffs,
+            |a, b, t, path, diffs| {
+                a.debug_diffs(b, t, path, diffs);
+            },
+        );
+        true
+    }
+}
+
+impl<A, B> AssertFloatEqAllDiffs<[B]> for [A]
+where
+    A: AssertFloatEqAllDiffs<B>,
+    A::AllDebugTol: Sized,
+    UlpsTol<A::AllDebugTol>: Sized,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &[B],
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol = core::iter::repeat(tol);
+        diff_elements(
+            path,
+            self.iter(),
+            other.iter(),
+            tol,
+            diffs,
+            |a, b, t, path, diffs| {
+                a.debug_all_diffs(b, t, path, diffs);
+            },
+        );
+        true
+    }
+}
+
+//------------------------------------------------------------------------------
+// Linear collections
+//------------------------------------------------------------------------------
+macro_rules! impl_traits_for_linear_collection {
+    ($t:ident) => {
+        impl<T: FloatEqUlpsTol> FloatEqUlpsTol for $t<T>
+        where
+            UlpsTol<T>: Sized,
+        {
+            type UlpsTol = $t<UlpsTol<T>>;
+        }
+
+        impl<T: FloatEqDebugUlpsDiff> FloatEqDebugUlpsDiff for $t<T> {
+            type DebugUlpsDiff = $t<DebugUlpsDiff<T>>;
+        }
+
+        impl<A, B> FloatEq<$t<B>> for $t<A>
+        where
+            A: FloatEq<B>,
+            A::Tol: Sized,
             UlpsTol<A::Tol>: Sized,
         {
             type Tol = $t<A::Tol>;
@@ -511,6 +951,18 @@ macro_rules! impl_traits_for_linear_collection {
                         .all(|((a, b), eps)| FloatEq::eq_r2nd(a, b, eps))
             }
 
+            #[inline]
+            fn eq_atol_rtol(&self, other: &$t<B>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self.len() == atol.len()
+                    && self.len() == rtol.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .zip(atol.iter().zip(rtol))
+                        .all(|((a, b), (at, rt))| FloatEq::eq_atol_rtol(a, b, at, rt))
+            }
+
             #[inline]
             fn eq_ulps(&self, other: &$t<B>, tol: &UlpsTol<Self::Tol>) -> bool {
                 self.len() == other.len()
@@ -523,6 +975,79 @@ macro_rules! impl_traits_for_linear_collection {
             }
         }
 
+        impl<A, B> FloatOrd<$t<B>> for $t<A>
+        where
+            A: FloatOrd<B>,
+            A::Tol: Sized,
+            UlpsTol<A::Tol>: Sized,
+        {
+            #[inline]
+            fn ord_abs(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self.len() == tol.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .zip(tol)
+                        .all(|((a, b), eps)| FloatOrd::ord_abs(a, b, op, eps))
+            }
+
+            #[inline]
+            fn ord_rmax(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self.len() == tol.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .zip(tol)
+                        .all(|((a, b), eps)| FloatOrd::ord_rmax(a, b, op, eps))
+            }
+
+            #[inline]
+            fn ord_rmin(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self.len() == tol.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .zip(tol)
+                        .all(|((a, b), eps)| FloatOrd::ord_rmin(a, b, op, eps))
+            }
+
+            #[inline]
+            fn ord_r1st(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self.len() == tol.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .zip(tol)
+                        .all(|((a, b), eps)| FloatOrd::ord_r1st(a, b, op, eps))
+            }
+
+            #[inline]
+            fn ord_r2nd(&self, other: &$t<B>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.len() == other.len()
+                    && self.len() == tol.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .zip(tol)
+                        .all(|((a, b), eps)| FloatOrd::ord_r2nd(a, b, op, eps))
+            }
+
+            #[inline]
+            fn ord_ulps(&self, other: &$t<B>, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+                self.len() == other.len()
+                    && self.len() == tol.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .zip(tol)
+                        .all(|((a, b), eps)| FloatOrd::ord_ulps(a, b, op, eps))
+            }
+        }
+
         impl<A, B> FloatEqAll<$t<B>> for $t<A>
         where
             A: FloatEqAll<B>,
@@ -574,6 +1099,20 @@ macro_rules! impl_traits_for_linear_collection {
                         .all(|(a, b)| FloatEqAll::eq_r2nd_all(a, b, tol))
             }
 
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &$t<B>,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| FloatEqAll::eq_atol_rtol_all(a, b, atol, rtol))
+            }
+
             #[inline]
             fn eq_ulps_all(&self, other: &$t<B>, tol: &UlpsTol<Self::AllTol>) -> bool {
                 self.len() == other.len()
@@ -699,6 +1238,29 @@ macro_rules! impl_traits_for_linear_collection {
                 }
             }
 
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &$t<B>,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                if self.len() == other.len() && self.len() == atol.len() && self.len() == rtol.len()
+                {
+                    Some(
+                        self.iter()
+                            .zip(other.iter())
+                            .zip(atol.iter().zip(rtol))
+                            .map(|((a, b), (at, rt))| {
+                                AssertFloatEq::debug_atol_rtol_tol(a, b, at, rt)
+                            })
+                            .collect(),
+                    )
+                } else {
+                    None
+                }
+            }
+
             #[inline]
             fn debug_ulps_tol(
                 &self,
@@ -719,6 +1281,136 @@ macro_rules! impl_traits_for_linear_collection {
             }
         }
 
+        impl<A: ?Sized, B: ?Sized> AssertFloatOrd<$t<B>> for $t<A>
+        where
+            A: AssertFloatOrd<B> + Copy,
+            B: Copy,
+            A::Tol: Sized,
+            A::DebugTol: Sized,
+            UlpsTol<A::Tol>: Sized,
+            UlpsTol<A::DebugTol>: Sized,
+        {
+            #[inline]
+            fn debug_ord_abs(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                if self.len() == other.len() && self.len() == tol.len() {
+                    Some(
+                        self.iter()
+                            .zip(other.iter())
+                            .zip(tol)
+                            .map(|((a, b), eps)| AssertFloatOrd::debug_ord_abs(a, b, op, eps))
+                            .collect(),
+                    )
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn debug_ord_rmax(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                if self.len() == other.len() && self.len() == tol.len() {
+                    Some(
+                        self.iter()
+                            .zip(other.iter())
+                            .zip(tol)
+                            .map(|((a, b), eps)| AssertFloatOrd::debug_ord_rmax(a, b, op, eps))
+                            .collect(),
+                    )
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn debug_ord_rmin(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                if self.len() == other.len() && self.len() == tol.len() {
+                    Some(
+                        self.iter()
+                            .zip(other.iter())
+                            .zip(tol)
+                            .map(|((a, b), eps)| AssertFloatOrd::debug_ord_rmin(a, b, op, eps))
+                            .collect(),
+                    )
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn debug_ord_r1st(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                if self.len() == other.len() && self.len() == tol.len() {
+                    Some(
+                        self.iter()
+                            .zip(other.iter())
+                            .zip(tol)
+                            .map(|((a, b), eps)| AssertFloatOrd::debug_ord_r1st(a, b, op, eps))
+                            .collect(),
+                    )
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn debug_ord_r2nd(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                if self.len() == other.len() && self.len() == tol.len() {
+                    Some(
+                        self.iter()
+                            .zip(other.iter())
+                            .zip(tol)
+                            .map(|((a, b), eps)| AssertFloatOrd::debug_ord_r2nd(a, b, op, eps))
+                            .collect(),
+                    )
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn debug_ord_ulps(
+                &self,
+                other: &$t<B>,
+                op: FloatOrdOp,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> Self::DebugTol {
+                if self.len() == other.len() && self.len() == tol.len() {
+                    Some(
+                        self.iter()
+                            .zip(other.iter())
+                            .zip(tol)
+                            .map(|((a, b), eps)| AssertFloatOrd::debug_ord_ulps(a, b, op, eps))
+                            .collect(),
+                    )
+                } else {
+                    None
+                }
+            }
+        }
+
         impl<A: ?Sized, B: ?Sized> AssertFloatEqAll<$t<B>> for $t<A>
         where
             A: AssertFloatEqAll<B> + Copy,
@@ -798,6 +1490,27 @@ macro_rules! impl_traits_for_linear_collection {
                 }
             }
 
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &$t<B>,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                if self.len() == other.len() {
+                    Some(
+                        self.iter()
+                            .zip(other.iter())
+                            .map(|(a, b)| {
+                                AssertFloatEqAll::debug_atol_rtol_all_tol(a, b, atol, rtol)
+                            })
+                            .collect(),
+                    )
+                } else {
+                    None
+                }
+            }
+
             #[inline]
             fn debug_ulps_all_tol(
                 &self,
@@ -819,15 +1532,115 @@ macro_rules! impl_traits_for_linear_collection {
                 }
             }
         }
-    };
-}
 
-impl_traits_for_linear_collection!(Vec);
-impl_traits_for_linear_collection!(VecDeque);
-impl_traits_for_linear_collection!(LinkedList);
+        impl<A, B> FloatEqReport<$t<B>> for $t<A>
+        where
+            A: FloatEqReport<B>,
+            A::Tol: Sized,
+            UlpsTol<A::Tol>: Sized,
+        {
+            fn report(
+                &self,
+                other: &$t<B>,
+                tol: ReportTol<'_, Self::Tol>,
+                path: String,
+            ) -> ReportNode {
+                let tol = tol.elements(|t| t.iter(), |t| t.iter());
+                report_elements(
+                    path,
+                    self.iter(),
+                    other.iter(),
+                    tol.into_iter(),
+                    |a, b, t, path| a.report(b, t, path),
+                )
+            }
+        }
 
-//------------------------------------------------------------------------------
-// HashMap
+        impl<A, B> FloatEqAllReport<$t<B>> for $t<A>
+        where
+            A: FloatEqAllReport<B>,
+        {
+            fn report_all(
+                &self,
+                other: &$t<B>,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                let tol = core::iter::repeat(tol);
+                report_elements(path, self.iter(), other.iter(), tol, |a, b, t, path| {
+                    a.report_all(b, t, path)
+                })
+            }
+        }
+
+        impl<A, B> AssertFloatEqDiffs<$t<B>> for $t<A>
+        where
+            A: AssertFloatEqDiffs<B> + Copy,
+            B: Copy,
+            A::Tol: Sized,
+            A::DebugTol: Sized,
+            UlpsTol<A::Tol>: Sized,
+            UlpsTol<A::DebugTol>: Sized,
+        {
+            fn debug_diffs(
+                &self,
+                other: &$t<B>,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                let tol = tol.elements(|t| t.iter(), |t| t.iter());
+                diff_elements(
+                    path,
+                    self.iter(),
+                    other.iter(),
+                    tol.into_iter(),
+                    diffs,
+                    |a, b, t, path, diffs| {
+                        a.debug_diffs(b, t, path, diffs);
+                    },
+                );
+                true
+            }
+        }
+
+        impl<A, B> AssertFloatEqAllDiffs<$t<B>> for $t<A>
+        where
+            A: AssertFloatEqAllDiffs<B> + Copy,
+            B: Copy,
+            A::AllDebugTol: Sized,
+            UlpsTol<A::AllDebugTol>: Sized,
+        {
+            fn debug_all_diffs(
+                &self,
+                other: &$t<B>,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                let tol = core::iter::repeat(tol);
+                diff_elements(
+                    path,
+                    self.iter(),
+                    other.iter(),
+                    tol,
+                    diffs,
+                    |a, b, t, path, diffs| {
+                        a.debug_all_diffs(b, t, path, diffs);
+                    },
+                );
+                true
+            }
+        }
+    };
+}
+
+impl_traits_for_linear_collection!(Vec);
+impl_traits_for_linear_collection!(VecDeque);
+impl_traits_for_linear_collection!(LinkedList);
+
+//------------------------------------------------------------------------------
+// HashMap
 //------------------------------------------------------------------------------
 impl<K, V, S> FloatEqUlpsTol for HashMap<K, V, S>
 where
@@ -939,6 +1752,24 @@ where
             })
     }
 
+    #[inline]
+    fn eq_atol_rtol(&self, other: &HashMap<K, VB, S>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == atol.len()
+            && self.len() == rtol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let (Some(at), Some(rt)) = (atol.get(k), rtol.get(k)) {
+                        FloatEq::eq_atol_rtol(a, b, at, rt)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
     #[inline]
     fn eq_ulps(&self, other: &HashMap<K, VB, S>, tol: &UlpsTol<Self::Tol>) -> bool {
         self.len() == other.len()
@@ -957,6 +1788,122 @@ where
     }
 }
 
+impl<K, VA, VB, S> FloatOrd<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+    VA: FloatOrd<VB>,
+    VA::Tol: Sized,
+    UlpsTol<VA::Tol>: Sized,
+{
+    #[inline]
+    fn ord_abs(&self, other: &HashMap<K, VB, S>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatOrd::ord_abs(a, b, op, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn ord_rmax(&self, other: &HashMap<K, VB, S>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatOrd::ord_rmax(a, b, op, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn ord_rmin(&self, other: &HashMap<K, VB, S>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatOrd::ord_rmin(a, b, op, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn ord_r1st(&self, other: &HashMap<K, VB, S>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatOrd::ord_r1st(a, b, op, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn ord_r2nd(&self, other: &HashMap<K, VB, S>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatOrd::ord_r2nd(a, b, op, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn ord_ulps(
+        &self,
+        other: &HashMap<K, VB, S>,
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatOrd::ord_ulps(a, b, op, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+}
+
 impl<K, VA, VB, S> FloatEqAll<HashMap<K, VB, S>> for HashMap<K, VA, S>
 where
     K: Eq + Hash,
@@ -1025,6 +1972,23 @@ where
             })
     }
 
+    #[inline]
+    fn eq_atol_rtol_all(
+        &self,
+        other: &HashMap<K, VB, S>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    FloatEqAll::eq_atol_rtol_all(a, b, atol, rtol)
+                } else {
+                    false
+                }
+            })
+    }
+
     #[inline]
     fn eq_ulps_all(&self, other: &HashMap<K, VB, S>, tol: &UlpsTol<Self::AllTol>) -> bool {
         self.len() == other.len()
@@ -1142,6 +2106,27 @@ where
         }
     }
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &HashMap<K, VB, S>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == atol.len() && self.len() == rtol.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(
+                    k.clone(),
+                    v.debug_atol_rtol_tol(other.get(k)?, atol.get(k)?, rtol.get(k)?),
+                );
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_ulps_tol(
         &self,
@@ -1160,6 +2145,125 @@ where
     }
 }
 
+impl<K, VA, VB, S> AssertFloatOrd<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + Hash + Clone + fmt::Debug,
+    S: BuildHasher + Clone,
+    VA: AssertFloatOrd<VB>,
+    VA::Tol: Sized,
+    UlpsTol<VA::Tol>: Sized,
+    VA::DebugTol: Sized,
+    UlpsTol<VA::DebugTol>: Sized,
+{
+    #[inline]
+    fn debug_ord_abs(
+        &self,
+        other: &HashMap<K, VB, S>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_abs(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_rmax(
+        &self,
+        other: &HashMap<K, VB, S>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_rmax(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_rmin(
+        &self,
+        other: &HashMap<K, VB, S>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_rmin(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_r1st(
+        &self,
+        other: &HashMap<K, VB, S>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_r1st(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_r2nd(
+        &self,
+        other: &HashMap<K, VB, S>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_r2nd(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_ulps(
+        &self,
+        other: &HashMap<K, VB, S>,
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_ulps(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
 impl<K, VA, VB, S> AssertFloatEqAll<HashMap<K, VB, S>> for HashMap<K, VA, S>
 where
     K: Eq + Hash + Clone + fmt::Debug,
@@ -1256,61 +2360,360 @@ where
     }
 
     #[inline]
-    fn debug_ulps_all_tol(
-        &self,
-        other: &HashMap<K, VB, S>,
-        tol: &UlpsTol<Self::AllTol>,
-    ) -> UlpsTol<Self::AllDebugTol>
-    where
-        UlpsTol<Self::AllDebugTol>: Sized,
-    {
-        if self.len() == other.len() {
-            let mut result = HashMap::with_hasher(self.hasher().clone());
-            for (k, v) in self {
-                result.insert(k.clone(), v.debug_ulps_all_tol(other.get(k)?, tol));
-            }
-            Some(result)
-        } else {
-            None
-        }
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &HashMap<K, VB, S>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.len() == other.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(
+                    k.clone(),
+                    v.debug_atol_rtol_all_tol(other.get(k)?, atol, rtol),
+                );
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_all_tol(
+        &self,
+        other: &HashMap<K, VB, S>,
+        tol: &UlpsTol<Self::AllTol>,
+    ) -> UlpsTol<Self::AllDebugTol>
+    where
+        UlpsTol<Self::AllDebugTol>: Sized,
+    {
+        if self.len() == other.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ulps_all_tol(other.get(k)?, tol));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K, VA, VB, S> FloatEqReport<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + Hash + fmt::Debug,
+    S: BuildHasher,
+    VA: FloatEqReport<VB>,
+    VA::Tol: Sized,
+    UlpsTol<VA::Tol>: Sized,
+{
+    fn report(
+        &self,
+        other: &HashMap<K, VB, S>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: String,
+    ) -> ReportNode {
+        let tol_len = tol.apply(|t| t.len(), |t| t.len());
+        if self.len() != tol_len {
+            let len = self.len();
+            return ReportNode::mismatch(path, Mismatch::TolLen { len, tol: tol_len });
+        }
+
+        let mut children: Vec<ReportNode> = self
+            .iter()
+            .map(|(k, a)| {
+                let path = format!("{}[{:?}]", path, k);
+                match (other.get(k), tol.try_map(|t| t.get(k), |t| t.get(k))) {
+                    (Some(b), Some(t)) => a.report(b, t, path),
+                    (None, _) => ReportNode::mismatch(path, Mismatch::MissingRight),
+                    (_, None) => ReportNode::mismatch(path, Mismatch::MissingTol),
+                }
+            })
+            .collect();
+        children.extend(
+            other
+                .keys()
+                .filter(|k| !self.contains_key(k))
+                .map(|k| ReportNode::mismatch(format!("{}[{:?}]", path, k), Mismatch::MissingLeft)),
+        );
+        ReportNode::composite(path, children)
+    }
+}
+
+impl<K, VA, VB, S> FloatEqAllReport<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + Hash + fmt::Debug,
+    S: BuildHasher,
+    VA: FloatEqAllReport<VB>,
+{
+    fn report_all(
+        &self,
+        other: &HashMap<K, VB, S>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        let mut children: Vec<ReportNode> = self
+            .iter()
+            .map(|(k, a)| {
+                let path = format!("{}[{:?}]", path, k);
+                match other.get(k) {
+                    Some(b) => a.report_all(b, tol, path),
+                    None => ReportNode::mismatch(path, Mismatch::MissingRight),
+                }
+            })
+            .collect();
+        children.extend(
+            other
+                .keys()
+                .filter(|k| !self.contains_key(k))
+                .map(|k| ReportNode::mismatch(format!("{}[{:?}]", path, k), Mismatch::MissingLeft)),
+        );
+        ReportNode::composite(path, children)
+    }
+}
+
+impl<K, VA, VB, S> AssertFloatEqDiffs<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + Hash + Clone + fmt::Debug,
+    S: BuildHasher + Clone,
+    VA: AssertFloatEqDiffs<VB>,
+    VA::Tol: Sized,
+    UlpsTol<VA::Tol>: Sized,
+    VA::DebugTol: Sized,
+    UlpsTol<VA::DebugTol>: Sized,
+{
+    fn debug_diffs(
+        &self,
+        other: &HashMap<K, VB, S>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol_len = tol.apply(|t| t.len(), |t| t.len());
+        if self.len() != tol_len {
+            let mismatch = Mismatch::TolLen {
+                len: self.len(),
+                tol: tol_len,
+            };
+            diffs.push(path, format_args!("{}", mismatch));
+            return true;
+        }
+
+        for (k, a) in self {
+            let path = format!("{}[{:?}]", path, k);
+            match (other.get(k), tol.try_map(|t| t.get(k), |t| t.get(k))) {
+                (Some(b), Some(t)) => {
+                    a.debug_diffs(b, t, &path, diffs);
+                }
+                (None, _) => diffs.push(&path, format_args!("{}", Mismatch::MissingRight)),
+                (_, None) => diffs.push(&path, format_args!("{}", Mismatch::MissingTol)),
+            }
+        }
+        for k in other.keys().filter(|k| !self.contains_key(k)) {
+            let path = format!("{}[{:?}]", path, k);
+            diffs.push(&path, format_args!("{}", Mismatch::MissingLeft));
+        }
+        true
+    }
+}
+
+impl<K, VA, VB, S> AssertFloatEqAllDiffs<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + Hash + Clone + fmt::Debug,
+    S: BuildHasher + Clone,
+    VA: AssertFloatEqAllDiffs<VB>,
+    VA::AllDebugTol: Sized,
+    UlpsTol<VA::AllDebugTol>: Sized,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &HashMap<K, VB, S>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        for (k, a) in self {
+            let path = format!("{}[{:?}]", path, k);
+            match other.get(k) {
+                Some(b) => {
+                    a.debug_all_diffs(b, tol, &path, diffs);
+                }
+                None => diffs.push(&path, format_args!("{}", Mismatch::MissingRight)),
+            }
+        }
+        for k in other.keys().filter(|k| !self.contains_key(k)) {
+            let path = format!("{}[{:?}]", path, k);
+            diffs.push(&path, format_args!("{}", Mismatch::MissingLeft));
+        }
+        true
+    }
+}
+
+//------------------------------------------------------------------------------
+// BTreeMap
+//------------------------------------------------------------------------------
+impl<K, V> FloatEqUlpsTol for BTreeMap<K, V>
+where
+    V: FloatEqUlpsTol,
+    UlpsTol<V>: Sized,
+{
+    type UlpsTol = BTreeMap<K, UlpsTol<V>>;
+}
+
+impl<K, V> FloatEqDebugUlpsDiff for BTreeMap<K, V>
+where
+    V: FloatEqDebugUlpsDiff,
+{
+    type DebugUlpsDiff = BTreeMap<K, DebugUlpsDiff<V>>;
+}
+
+impl<K, VA, VB> FloatEq<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord,
+    VA: FloatEq<VB>,
+    VA::Tol: Sized,
+    UlpsTol<VA::Tol>: Sized,
+{
+    type Tol = BTreeMap<K, VA::Tol>;
+
+    #[inline]
+    fn eq_abs(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatEq::eq_abs(a, b, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn eq_rmax(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatEq::eq_rmax(a, b, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn eq_rmin(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatEq::eq_rmin(a, b, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn eq_r1st(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatEq::eq_r1st(a, b, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn eq_r2nd(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatEq::eq_r2nd(a, b, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn eq_atol_rtol(&self, other: &BTreeMap<K, VB>, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.len() == other.len()
+            && self.len() == atol.len()
+            && self.len() == rtol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let (Some(at), Some(rt)) = (atol.get(k), rtol.get(k)) {
+                        FloatEq::eq_atol_rtol(a, b, at, rt)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
     }
-}
-
-//------------------------------------------------------------------------------
-// BTreeMap
-//------------------------------------------------------------------------------
-impl<K, V> FloatEqUlpsTol for BTreeMap<K, V>
-where
-    V: FloatEqUlpsTol,
-    UlpsTol<V>: Sized,
-{
-    type UlpsTol = BTreeMap<K, UlpsTol<V>>;
-}
 
-impl<K, V> FloatEqDebugUlpsDiff for BTreeMap<K, V>
-where
-    V: FloatEqDebugUlpsDiff,
-{
-    type DebugUlpsDiff = BTreeMap<K, DebugUlpsDiff<V>>;
+    #[inline]
+    fn eq_ulps(&self, other: &BTreeMap<K, VB>, tol: &UlpsTol<Self::Tol>) -> bool {
+        self.len() == other.len()
+            && self.len() == tol.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    if let Some(eps) = tol.get(k) {
+                        FloatEq::eq_ulps(a, b, eps)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
 }
 
-impl<K, VA, VB> FloatEq<BTreeMap<K, VB>> for BTreeMap<K, VA>
+impl<K, VA, VB> FloatOrd<BTreeMap<K, VB>> for BTreeMap<K, VA>
 where
     K: Eq + Ord,
-    VA: FloatEq<VB>,
+    VA: FloatOrd<VB>,
     VA::Tol: Sized,
     UlpsTol<VA::Tol>: Sized,
 {
-    type Tol = BTreeMap<K, VA::Tol>;
-
     #[inline]
-    fn eq_abs(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+    fn ord_abs(&self, other: &BTreeMap<K, VB>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
         self.len() == other.len()
             && self.len() == tol.len()
             && self.iter().all(|(k, a)| {
                 if let Some(b) = other.get(k) {
                     if let Some(eps) = tol.get(k) {
-                        FloatEq::eq_abs(a, b, eps)
+                        FloatOrd::ord_abs(a, b, op, eps)
                     } else {
                         false
                     }
@@ -1321,13 +2724,13 @@ where
     }
 
     #[inline]
-    fn eq_rmax(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+    fn ord_rmax(&self, other: &BTreeMap<K, VB>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
         self.len() == other.len()
             && self.len() == tol.len()
             && self.iter().all(|(k, a)| {
                 if let Some(b) = other.get(k) {
                     if let Some(eps) = tol.get(k) {
-                        FloatEq::eq_rmax(a, b, eps)
+                        FloatOrd::ord_rmax(a, b, op, eps)
                     } else {
                         false
                     }
@@ -1338,13 +2741,13 @@ where
     }
 
     #[inline]
-    fn eq_rmin(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+    fn ord_rmin(&self, other: &BTreeMap<K, VB>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
         self.len() == other.len()
             && self.len() == tol.len()
             && self.iter().all(|(k, a)| {
                 if let Some(b) = other.get(k) {
                     if let Some(eps) = tol.get(k) {
-                        FloatEq::eq_rmin(a, b, eps)
+                        FloatOrd::ord_rmin(a, b, op, eps)
                     } else {
                         false
                     }
@@ -1355,13 +2758,13 @@ where
     }
 
     #[inline]
-    fn eq_r1st(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+    fn ord_r1st(&self, other: &BTreeMap<K, VB>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
         self.len() == other.len()
             && self.len() == tol.len()
             && self.iter().all(|(k, a)| {
                 if let Some(b) = other.get(k) {
                     if let Some(eps) = tol.get(k) {
-                        FloatEq::eq_r1st(a, b, eps)
+                        FloatOrd::ord_r1st(a, b, op, eps)
                     } else {
                         false
                     }
@@ -1372,13 +2775,13 @@ where
     }
 
     #[inline]
-    fn eq_r2nd(&self, other: &BTreeMap<K, VB>, tol: &Self::Tol) -> bool {
+    fn ord_r2nd(&self, other: &BTreeMap<K, VB>, op: FloatOrdOp, tol: &Self::Tol) -> bool {
         self.len() == other.len()
             && self.len() == tol.len()
             && self.iter().all(|(k, a)| {
                 if let Some(b) = other.get(k) {
                     if let Some(eps) = tol.get(k) {
-                        FloatEq::eq_r2nd(a, b, eps)
+                        FloatOrd::ord_r2nd(a, b, op, eps)
                     } else {
                         false
                     }
@@ -1389,13 +2792,13 @@ where
     }
 
     #[inline]
-    fn eq_ulps(&self, other: &BTreeMap<K, VB>, tol: &UlpsTol<Self::Tol>) -> bool {
+    fn ord_ulps(&self, other: &BTreeMap<K, VB>, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
         self.len() == other.len()
             && self.len() == tol.len()
             && self.iter().all(|(k, a)| {
                 if let Some(b) = other.get(k) {
                     if let Some(eps) = tol.get(k) {
-                        FloatEq::eq_ulps(a, b, eps)
+                        FloatOrd::ord_ulps(a, b, op, eps)
                     } else {
                         false
                     }
@@ -1473,6 +2876,23 @@ where
             })
     }
 
+    #[inline]
+    fn eq_atol_rtol_all(
+        &self,
+        other: &BTreeMap<K, VB>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(k, a)| {
+                if let Some(b) = other.get(k) {
+                    FloatEqAll::eq_atol_rtol_all(a, b, atol, rtol)
+                } else {
+                    false
+                }
+            })
+    }
+
     #[inline]
     fn eq_ulps_all(&self, other: &BTreeMap<K, VB>, tol: &UlpsTol<Self::AllTol>) -> bool {
         self.len() == other.len()
@@ -1589,6 +3009,27 @@ where
         }
     }
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &BTreeMap<K, VB>,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == atol.len() && self.len() == rtol.len() {
+            let mut result = BTreeMap::new();
+            for (k, v) in self {
+                result.insert(
+                    k.clone(),
+                    v.debug_atol_rtol_tol(other.get(k)?, atol.get(k)?, rtol.get(k)?),
+                );
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_ulps_tol(
         &self,
@@ -1610,6 +3051,124 @@ where
     }
 }
 
+impl<K, VA, VB> AssertFloatOrd<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord + Clone + fmt::Debug,
+    VA: AssertFloatOrd<VB>,
+    VA::Tol: Sized,
+    VA::DebugTol: Sized,
+    UlpsTol<VA::Tol>: Sized,
+    UlpsTol<VA::DebugTol>: Sized,
+{
+    #[inline]
+    fn debug_ord_abs(
+        &self,
+        other: &BTreeMap<K, VB>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = BTreeMap::new();
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_abs(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_rmax(
+        &self,
+        other: &BTreeMap<K, VB>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = BTreeMap::new();
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_rmax(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_rmin(
+        &self,
+        other: &BTreeMap<K, VB>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = BTreeMap::new();
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_rmin(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_r1st(
+        &self,
+        other: &BTreeMap<K, VB>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = BTreeMap::new();
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_r1st(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_r2nd(
+        &self,
+        other: &BTreeMap<K, VB>,
+        op: FloatOrdOp,
+        tol: &Self::Tol,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = BTreeMap::new();
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_r2nd(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn debug_ord_ulps(
+        &self,
+        other: &BTreeMap<K, VB>,
+        op: FloatOrdOp,
+        tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol {
+        if self.len() == other.len() && self.len() == tol.len() {
+            let mut result = BTreeMap::new();
+            for (k, v) in self {
+                result.insert(k.clone(), v.debug_ord_ulps(other.get(k)?, op, tol.get(k)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
 impl<K, VA, VB> AssertFloatEqAll<BTreeMap<K, VB>> for BTreeMap<K, VA>
 where
     K: Eq + Ord + Clone + fmt::Debug,
@@ -1684,6 +3243,27 @@ where
         }
     }
 
+    #[inline]
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &BTreeMap<K, VB>,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        if self.len() == other.len() {
+            let mut result = BTreeMap::new();
+            for (k, v) in self {
+                result.insert(
+                    k.clone(),
+                    v.debug_atol_rtol_all_tol(other.get(k)?, atol, rtol),
+                );
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_ulps_all_tol(
         &self,
@@ -1704,3 +3284,149 @@ where
         }
     }
 }
+
+impl<K, VA, VB> FloatEqReport<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Ord + fmt::Debug,
+    VA: FloatEqReport<VB>,
+    VA::Tol: Sized,
+    UlpsTol<VA::Tol>: Sized,
+{
+    fn report(
+        &self,
+        other: &BTreeMap<K, VB>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: String,
+    ) -> ReportNode {
+        let tol_len = tol.apply(|t| t.len(), |t| t.len());
+        if self.len() != tol_len {
+            let len = self.len();
+            return ReportNode::mismatch(path, Mismatch::TolLen { len, tol: tol_len });
+        }
+
+        let mut children: Vec<ReportNode> = self
+            .iter()
+            .map(|(k, a)| {
+                let path = format!("{}[{:?}]", path, k);
+                match (other.get(k), tol.try_map(|t| t.get(k), |t| t.get(k))) {
+                    (Some(b), Some(t)) => a.report(b, t, path),
+                    (None, _) => ReportNode::mismatch(path, Mismatch::MissingRight),
+                    (_, None) => ReportNode::mismatch(path, Mismatch::MissingTol),
+                }
+            })
+            .collect();
+        children.extend(
+            other
+                .keys()
+                .filter(|k| !self.contains_key(k))
+                .map(|k| ReportNode::mismatch(format!("{}[{:?}]", path, k), Mismatch::MissingLeft)),
+        );
+        ReportNode::composite(path, children)
+    }
+}
+
+impl<K, VA, VB> FloatEqAllReport<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Ord + fmt::Debug,
+    VA: FloatEqAllReport<VB>,
+{
+    fn report_all(
+        &self,
+        other: &BTreeMap<K, VB>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: String,
+    ) -> ReportNode {
+        let mut children: Vec<ReportNode> = self
+            .iter()
+            .map(|(k, a)| {
+                let path = format!("{}[{:?}]", path, k);
+                match other.get(k) {
+                    Some(b) => a.report_all(b, tol, path),
+                    None => ReportNode::mismatch(path, Mismatch::MissingRight),
+                }
+            })
+            .collect();
+        children.extend(
+            other
+                .keys()
+                .filter(|k| !self.contains_key(k))
+                .map(|k| ReportNode::mismatch(format!("{}[{:?}]", path, k), Mismatch::MissingLeft)),
+        );
+        ReportNode::composite(path, children)
+    }
+}
+
+impl<K, VA, VB> AssertFloatEqDiffs<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord + Clone + fmt::Debug,
+    VA: AssertFloatEqDiffs<VB>,
+    VA::Tol: Sized,
+    VA::DebugTol: Sized,
+    UlpsTol<VA::Tol>: Sized,
+    UlpsTol<VA::DebugTol>: Sized,
+{
+    fn debug_diffs(
+        &self,
+        other: &BTreeMap<K, VB>,
+        tol: ReportTol<'_, Self::Tol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        let tol_len = tol.apply(|t| t.len(), |t| t.len());
+        if self.len() != tol_len {
+            let mismatch = Mismatch::TolLen {
+                len: self.len(),
+                tol: tol_len,
+            };
+            diffs.push(path, format_args!("{}", mismatch));
+            return true;
+        }
+
+        for (k, a) in self {
+            let path = format!("{}[{:?}]", path, k);
+            match (other.get(k), tol.try_map(|t| t.get(k), |t| t.get(k))) {
+                (Some(b), Some(t)) => {
+                    a.debug_diffs(b, t, &path, diffs);
+                }
+                (None, _) => diffs.push(&path, format_args!("{}", Mismatch::MissingRight)),
+                (_, None) => diffs.push(&path, format_args!("{}", Mismatch::MissingTol)),
+            }
+        }
+        for k in other.keys().filter(|k| !self.contains_key(k)) {
+            let path = format!("{}[{:?}]", path, k);
+            diffs.push(&path, format_args!("{}", Mismatch::MissingLeft));
+        }
+        true
+    }
+}
+
+impl<K, VA, VB> AssertFloatEqAllDiffs<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord + Clone + fmt::Debug,
+    VA: AssertFloatEqAllDiffs<VB>,
+    VA::AllDebugTol: Sized,
+    UlpsTol<VA::AllDebugTol>: Sized,
+{
+    fn debug_all_diffs(
+        &self,
+        other: &BTreeMap<K, VB>,
+        tol: ReportTol<'_, Self::AllTol>,
+        path: &str,
+        diffs: &mut AssertDiffs,
+    ) -> bool {
+        for (k, a) in self {
+            let path = format!("{}[{:?}]", path, k);
+            match other.get(k) {
+                Some(b) => {
+                    a.debug_all_diffs(b, tol, &path, diffs);
+                }
+                None => diffs.push(&path, format_args!("{}", Mismatch::MissingRight)),
+            }
+        }
+        for k in other.keys().filter(|k| !self.contains_key(k)) {
+            let path = format!("{}[{:?}]", path, k);
+            diffs.push(&path, format_args!("{}", Mismatch::MissingLeft));
+        }
+        true
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{AssertFloatEq, DebugUlpsDiff, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol, UlpsTol};
+#[cfg(feature = "std")]
+use crate::{AssertDiffs, AssertFloatEqDiffs, FloatEqReport, ReportNode, ReportTol};
+use crate::{
+    AssertFloatEq, AssertFloatOrd, DebugUlpsDiff, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol,
+    FloatOrd, FloatOrdOp, UlpsTol,
+};
 use core::fmt;
 
 impl FloatEqUlpsTol for () {
@@ -37,12 +42,49 @@ impl FloatEq for () {
         true
     }
 
+    #[inline]
+    fn eq_atol_rtol(&self, _other: &(), _atol: &Self::Tol, _rtol: &Self::Tol) -> bool {
+        true
+    }
+
     #[inline]
     fn eq_ulps(&self, _other: &(), _tol: &UlpsTol<Self::Tol>) -> bool {
         true
     }
 }
 
+impl FloatOrd for () {
+    #[inline]
+    fn ord_abs(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> bool {
+        true
+    }
+
+    #[inline]
+    fn ord_rmax(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> bool {
+        true
+    }
+
+    #[inline]
+    fn ord_rmin(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> bool {
+        true
+    }
+
+    #[inline]
+    fn ord_r1st(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> bool {
+        true
+    }
+
+    #[inline]
+    fn ord_r2nd(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> bool {
+        true
+    }
+
+    #[inline]
+    fn ord_ulps(&self, _other: &(), _op: FloatOrdOp, _tol: &UlpsTol<Self::Tol>) -> bool {
+        true
+    }
+}
+
 impl AssertFloatEq for () {
     type DebugAbsDiff = ();
     type DebugTol = ();
@@ -68,10 +110,67 @@ impl AssertFloatEq for () {
     #[inline]
     fn debug_r2nd_tol(&self, _other: &(), _tol: &Self::Tol) -> Self::DebugTol {}
 
+    #[inline]
+    fn debug_atol_rtol_tol(
+        &self,
+        _other: &(),
+        _atol: &Self::Tol,
+        _rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+    }
+
     #[inline]
     fn debug_ulps_tol(&self, _other: &(), _tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol> {}
 }
 
+impl AssertFloatOrd for () {
+    #[inline]
+    fn debug_ord_abs(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> Self::DebugTol {}
+
+    #[inline]
+    fn debug_ord_rmax(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> Self::DebugTol {}
+
+    #[inline]
+    fn debug_ord_rmin(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> Self::DebugTol {}
+
+    #[inline]
+    fn debug_ord_r1st(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> Self::DebugTol {}
+
+    #[inline]
+    fn debug_ord_r2nd(&self, _other: &(), _op: FloatOrdOp, _tol: &Self::Tol) -> Self::DebugTol {}
+
+    #[inline]
+    fn debug_ord_ulps(
+        &self,
+        _other: &(),
+        _op: FloatOrdOp,
+        _tol: &UlpsTol<Self::Tol>,
+    ) -> Self::DebugTol {
+    }
+}
+
+#[cfg(feature = "std")]
+impl FloatEqReport for () {
+    #[inline]
+    fn report(&self, _other: &(), _tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+        ReportNode::composite(path, Vec::new())
+    }
+}
+
+#[cfg(feature = "std")]
+impl AssertFloatEqDiffs for () {
+    #[inline]
+    fn debug_diffs(
+        &self,
+        _other: &(),
+        _tol: ReportTol<'_, Self::Tol>,
+        _path: &str,
+        _diffs: &mut AssertDiffs,
+    ) -> bool {
+        false
+    }
+}
+
 // Non-unit type tuple impls, as for std PartialEq implementation
 macro_rules! tuple_impls {
     ($(
@@ -126,12 +225,54 @@ macro_rules! tuple_impls {
                     $(self.$idx.eq_r2nd(&other.$idx, &tol.$idx))&&+
                 }
 
+                #[inline]
+                fn eq_atol_rtol(&self, other: &Self, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                    $(self.$idx.eq_atol_rtol(&other.$idx, &atol.$idx, &rtol.$idx))&&+
+                }
+
                 #[inline]
                 fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
                     $(self.$idx.eq_ulps(&other.$idx, &tol.$idx))&&+
                 }
             }
 
+            impl<$($T:FloatOrd),+> FloatOrd for ($($T,)+)
+            where
+                last_type!($($T,)+): ?Sized,
+                $($T::Tol: Sized,)+
+                $(UlpsTol<$T::Tol>: Sized,)+
+            {
+                #[inline]
+                fn ord_abs(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                    $(self.$idx.ord_abs(&other.$idx, op, &tol.$idx))&&+
+                }
+
+                #[inline]
+                fn ord_rmax(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                    $(self.$idx.ord_rmax(&other.$idx, op, &tol.$idx))&&+
+                }
+
+                #[inline]
+                fn ord_rmin(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                    $(self.$idx.ord_rmin(&other.$idx, op, &tol.$idx))&&+
+                }
+
+                #[inline]
+                fn ord_r1st(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                    $(self.$idx.ord_r1st(&other.$idx, op, &tol.$idx))&&+
+                }
+
+                #[inline]
+                fn ord_r2nd(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                    $(self.$idx.ord_r2nd(&other.$idx, op, &tol.$idx))&&+
+                }
+
+                #[inline]
+                fn ord_ulps(&self, other: &Self, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+                    $(self.$idx.ord_ulps(&other.$idx, op, &tol.$idx))&&+
+                }
+            }
+
             impl<$($T:AssertFloatEq + fmt::Debug),+> AssertFloatEq for ($($T,)+)
             where
                 last_type!($($T,)+): ?Sized,
@@ -178,11 +319,107 @@ macro_rules! tuple_impls {
                     ($(self.$idx.debug_r2nd_tol(&other.$idx, &tol.$idx),)+)
                 }
 
+                #[inline]
+                fn debug_atol_rtol_tol(
+                    &self,
+                    other: &Self,
+                    atol: &Self::Tol,
+                    rtol: &Self::Tol,
+                ) -> Self::DebugTol {
+                    ($(self.$idx.debug_atol_rtol_tol(&other.$idx, &atol.$idx, &rtol.$idx),)+)
+                }
+
                 #[inline]
                 fn debug_ulps_tol(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol> {
                     ($(self.$idx.debug_ulps_tol(&other.$idx, &tol.$idx),)+)
                 }
             }
+
+            impl<$($T:AssertFloatOrd + fmt::Debug),+> AssertFloatOrd for ($($T,)+)
+            where
+                last_type!($($T,)+): ?Sized,
+                $($T::Tol: Sized,)+
+                $($T::DebugTol: Sized,)+
+                $(UlpsTol<$T::Tol>: Sized,)+
+                $(UlpsTol<$T::DebugTol>: Sized,)+
+            {
+                #[inline]
+                fn debug_ord_abs(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+                    ($(self.$idx.debug_ord_abs(&other.$idx, op, &tol.$idx),)+)
+                }
+
+                #[inline]
+                fn debug_ord_rmax(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+                    ($(self.$idx.debug_ord_rmax(&other.$idx, op, &tol.$idx),)+)
+                }
+
+                #[inline]
+                fn debug_ord_rmin(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+                    ($(self.$idx.debug_ord_rmin(&other.$idx, op, &tol.$idx),)+)
+                }
+
+                #[inline]
+                fn debug_ord_r1st(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+                    ($(self.$idx.debug_ord_r1st(&other.$idx, op, &tol.$idx),)+)
+                }
+
+                #[inline]
+                fn debug_ord_r2nd(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> Self::DebugTol {
+                    ($(self.$idx.debug_ord_r2nd(&other.$idx, op, &tol.$idx),)+)
+                }
+
+                #[inline]
+                fn debug_ord_ulps(&self, other: &Self, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> Self::DebugTol {
+                    ($(self.$idx.debug_ord_ulps(&other.$idx, op, &tol.$idx),)+)
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl<$($T:FloatEqReport),+> FloatEqReport for ($($T,)+)
+            where
+                last_type!($($T,)+): ?Sized,
+                $($T::Tol: Sized,)+
+                $(UlpsTol<$T::Tol>: Sized,)+
+            {
+                fn report(&self, other: &Self, tol: ReportTol<'_, Self::Tol>, path: String) -> ReportNode {
+                    let children = vec![$(
+                        self.$idx.report(
+                            &other.$idx,
+                            tol.map(|t| &t.$idx, |t| &t.$idx),
+                            format!("{}.{}", path, $idx),
+                        ),
+                    )+];
+                    ReportNode::composite(path, children)
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl<$($T:AssertFloatEqDiffs + fmt::Debug),+> AssertFloatEqDiffs for ($($T,)+)
+            where
+                last_type!($($T,)+): ?Sized,
+                $($T::Tol: Sized,)+
+                $($T::DebugTol: Sized,)+
+                $(UlpsTol<$T::Tol>: Sized,)+
+                $(UlpsTol<$T::DebugTol>: Sized,)+
+            {
+                fn debug_diffs(
+                    &self,
+                    other: &Self,
+                    tol: ReportTol<'_, Self::Tol>,
+                    path: &str,
+                    diffs: &mut AssertDiffs,
+                ) -> bool {
+                    $(
+                        self.$idx.debug_diffs(
+                            &other.$idx,
+                            tol.map(|t| &t.$idx, |t| &t.$idx),
+                            &format!("{}.{}", path, $idx),
+                            diffs,
+                        );
+                    )+
+                    false
+                }
+            }
         )+
     };
 }
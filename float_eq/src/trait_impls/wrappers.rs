@@ -0,0 +1,544 @@
+#![allow(clippy::float_cmp)]
+
+#[cfg(feature = "std")]
+use crate::{
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, FloatEqAllReport, FloatEqReport,
+    ReportKind, ReportNode, ReportTol,
+};
+use crate::{
+    AssertFloatEq, AssertFloatEqAll, AssertFloatOrd, DebugUlpsDiff, FloatEq, FloatEqAll,
+    FloatEqDebugUlpsDiff, FloatEqUlpsTol, FloatOrd, FloatOrdOp, InfAsMax, NanEq, NanEqBits, Strict,
+    UlpsTol,
+};
+
+/// Implements `PartialEq` and the float_eq traits for `$wrapper<$float>`, which
+/// compares the wrapped values as `$float` would, with two adjustments:
+///
+/// - `value` maps a wrapped value `$v` to the `$float` that is compared.
+/// - `eq` decides the equality of two wrapped values `$a` and `$b` outright by
+///   returning `Some`, regardless of the check and tolerance, or `None` to
+///   compare their mapped values. `ord` does the same for ordering checks, given
+///   their `$op` as well.
+///
+/// Tolerances are wrapped values, so that the wrappers may be the fields of a
+/// derived type, except for ULPs and `_all` tolerances, which are unwrapped.
+macro_rules! impl_wrapper_traits {
+    (
+        $wrapper:ident,
+        $float:ty,
+        value: |$v:ident| $value:expr,
+        eq: |$a:ident, $b:ident| $eq:expr,
+        ord: |$oa:ident, $ob:ident, $op:ident| $ord:expr $(,)?
+    ) => {
+        impl $wrapper<$float> {
+            #[inline]
+            fn value(&self) -> $float {
+                let $v = self.0;
+                $value
+            }
+
+            #[inline]
+            fn decide_eq(&self, other: &Self) -> Option<bool> {
+                let ($a, $b) = (self.0, other.0);
+                $eq
+            }
+
+            #[inline]
+            fn decide_ord(&self, other: &Self, op: FloatOrdOp) -> Option<bool> {
+                let ($oa, $ob, $op) = (self.0, other.0, op);
+                $ord
+            }
+
+            /// Reports the wrapped values, and this type's view of them, rather
+            /// than the mapped values that were compared.
+            #[cfg(feature = "std")]
+            fn wrapper_report(&self, other: &Self, mut node: ReportNode) -> ReportNode {
+                if let Some(passed) = self.decide_eq(other) {
+                    node.passed = passed;
+                }
+                if let ReportKind::Float(report) = &mut node.kind {
+                    report.left = self.0.into();
+                    report.right = other.0.into();
+                    report.abs_diff = self.debug_abs_diff(other).0.into();
+                    report.ulps_diff = self.debug_ulps_diff(other).map(Into::into);
+                }
+                node
+            }
+        }
+
+        impl PartialEq for $wrapper<$float> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value() == other.value())
+            }
+        }
+
+        impl FloatEqUlpsTol for $wrapper<$float> {
+            type UlpsTol = UlpsTol<$float>;
+        }
+
+        impl FloatEqDebugUlpsDiff for $wrapper<$float> {
+            type DebugUlpsDiff = DebugUlpsDiff<$float>;
+        }
+
+        impl FloatEq for $wrapper<$float> {
+            type Tol = Self;
+
+            #[inline]
+            fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_abs(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_rmax(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_rmin(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_r1st(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_r2nd(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn eq_atol_rtol(&self, other: &Self, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_atol_rtol(&other.value(), &atol.0, &rtol.0))
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_ulps(&other.value(), tol))
+            }
+        }
+
+        impl FloatEqAll for $wrapper<$float> {
+            type AllTol = $float;
+
+            #[inline]
+            fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_abs_all(&other.value(), tol))
+            }
+
+            #[inline]
+            fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_rmax_all(&other.value(), tol))
+            }
+
+            #[inline]
+            fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_rmin_all(&other.value(), tol))
+            }
+
+            #[inline]
+            fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_r1st_all(&other.value(), tol))
+            }
+
+            #[inline]
+            fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_r2nd_all(&other.value(), tol))
+            }
+
+            #[inline]
+            fn eq_atol_rtol_all(
+                &self,
+                other: &Self,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_atol_rtol_all(&other.value(), atol, rtol))
+            }
+
+            #[inline]
+            fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
+                self.decide_eq(other)
+                    .unwrap_or_else(|| self.value().eq_ulps_all(&other.value(), tol))
+            }
+        }
+
+        impl AssertFloatEq for $wrapper<$float> {
+            type DebugAbsDiff = Self;
+            type DebugTol = Self;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                $wrapper(match self.decide_eq(other) {
+                    Some(true) => <$float>::from_bits(0),
+                    _ => self.value().debug_abs_diff(&other.value()),
+                })
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &Self) -> DebugUlpsDiff<Self::DebugAbsDiff> {
+                match self.decide_eq(other) {
+                    Some(true) => Some(0),
+                    Some(false) => None,
+                    None => self.value().debug_ulps_diff(&other.value()),
+                }
+            }
+
+            #[inline]
+            fn debug_abs_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $wrapper(self.value().debug_abs_tol(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $wrapper(self.value().debug_rmax_tol(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $wrapper(self.value().debug_rmin_tol(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn debug_r1st_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $wrapper(self.value().debug_r1st_tol(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> Self::DebugTol {
+                $wrapper(self.value().debug_r2nd_tol(&other.value(), &tol.0))
+            }
+
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &Self,
+                atol: &Self::Tol,
+                rtol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $wrapper(
+                    self.value()
+                        .debug_atol_rtol_tol(&other.value(), &atol.0, &rtol.0),
+                )
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                _other: &Self,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> UlpsTol<Self::DebugTol> {
+                *tol
+            }
+        }
+
+        impl AssertFloatEqAll for $wrapper<$float> {
+            type AllDebugTol = Self;
+
+            #[inline]
+            fn debug_abs_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $wrapper(self.value().debug_abs_all_tol(&other.value(), tol))
+            }
+
+            #[inline]
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $wrapper(self.value().debug_rmax_all_tol(&other.value(), tol))
+            }
+
+            #[inline]
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $wrapper(self.value().debug_rmin_all_tol(&other.value(), tol))
+            }
+
+            #[inline]
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $wrapper(self.value().debug_r1st_all_tol(&other.value(), tol))
+            }
+
+            #[inline]
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &Self::AllTol) -> Self::AllDebugTol {
+                $wrapper(self.value().debug_r2nd_all_tol(&other.value(), tol))
+            }
+
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &Self,
+                atol: &Self::AllTol,
+                rtol: &Self::AllTol,
+            ) -> Self::AllDebugTol {
+                $wrapper(
+                    self.value()
+                        .debug_atol_rtol_all_tol(&other.value(), atol, rtol),
+                )
+            }
+
+            #[inline]
+            fn debug_ulps_all_tol(
+                &self,
+                _other: &Self,
+                tol: &UlpsTol<Self::AllTol>,
+            ) -> UlpsTol<Self::AllDebugTol> {
+                *tol
+            }
+        }
+
+        impl FloatOrd for $wrapper<$float> {
+            #[inline]
+            fn ord_abs(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.decide_ord(other, op)
+                    .unwrap_or_else(|| self.value().ord_abs(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn ord_rmax(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.decide_ord(other, op)
+                    .unwrap_or_else(|| self.value().ord_rmax(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn ord_rmin(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.decide_ord(other, op)
+                    .unwrap_or_else(|| self.value().ord_rmin(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn ord_r1st(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.decide_ord(other, op)
+                    .unwrap_or_else(|| self.value().ord_r1st(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn ord_r2nd(&self, other: &Self, op: FloatOrdOp, tol: &Self::Tol) -> bool {
+                self.decide_ord(other, op)
+                    .unwrap_or_else(|| self.value().ord_r2nd(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn ord_ulps(&self, other: &Self, op: FloatOrdOp, tol: &UlpsTol<Self::Tol>) -> bool {
+                self.decide_ord(other, op)
+                    .unwrap_or_else(|| self.value().ord_ulps(&other.value(), op, tol))
+            }
+        }
+
+        impl AssertFloatOrd for $wrapper<$float> {
+            #[inline]
+            fn debug_ord_abs(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $wrapper(self.value().debug_ord_abs(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn debug_ord_rmax(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $wrapper(self.value().debug_ord_rmax(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn debug_ord_rmin(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $wrapper(self.value().debug_ord_rmin(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn debug_ord_r1st(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $wrapper(self.value().debug_ord_r1st(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn debug_ord_r2nd(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &Self::Tol,
+            ) -> Self::DebugTol {
+                $wrapper(self.value().debug_ord_r2nd(&other.value(), op, &tol.0))
+            }
+
+            #[inline]
+            fn debug_ord_ulps(
+                &self,
+                other: &Self,
+                op: FloatOrdOp,
+                tol: &UlpsTol<Self::Tol>,
+            ) -> Self::DebugTol {
+                $wrapper(self.value().debug_ord_ulps(&other.value(), op, tol))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqReport for $wrapper<$float> {
+            fn report(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::Tol>,
+                path: String,
+            ) -> ReportNode {
+                let tol = tol.map(|t| &t.0, |t| t);
+                self.wrapper_report(other, self.value().report(&other.value(), tol, path))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl FloatEqAllReport for $wrapper<$float> {
+            #[inline]
+            fn report_all(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: String,
+            ) -> ReportNode {
+                self.wrapper_report(other, self.value().report_all(&other.value(), tol, path))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqDiffs for $wrapper<$float> {
+            #[inline]
+            fn debug_diffs(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                diffs.push_failure(path, self, other, tol);
+                false
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl AssertFloatEqAllDiffs for $wrapper<$float> {
+            #[inline]
+            fn debug_all_diffs(
+                &self,
+                other: &Self,
+                tol: ReportTol<'_, Self::AllTol>,
+                path: &str,
+                diffs: &mut AssertDiffs,
+            ) -> bool {
+                diffs.push_all_failure(path, self, other, tol);
+                false
+            }
+        }
+    };
+}
+
+/// Implements the traits for each of the wrappers of `$float`.
+macro_rules! impl_wrappers {
+    ($float:ty) => {
+        // any two NaNs are equal, and so are ordered by `le` and `ge`
+        impl_wrapper_traits!(
+            NanEq,
+            $float,
+            value: |v| v,
+            eq: |a, b| if a.is_nan() && b.is_nan() { Some(true) } else { None },
+            ord: |a, b, op| {
+                let le_or_ge = matches!(op, FloatOrdOp::Le | FloatOrdOp::Ge);
+                if le_or_ge && a.is_nan() && b.is_nan() {
+                    Some(true)
+                } else {
+                    None
+                }
+            },
+        );
+
+        // as NanEq, but only for NaNs with the same bits
+        impl_wrapper_traits!(
+            NanEqBits,
+            $float,
+            value: |v| v,
+            eq: |a, b| if a.is_nan() && a.to_bits() == b.to_bits() {
+                Some(true)
+            } else {
+                None
+            },
+            ord: |a, b, op| {
+                let le_or_ge = matches!(op, FloatOrdOp::Le | FloatOrdOp::Ge);
+                if le_or_ge && a.is_nan() && a.to_bits() == b.to_bits() {
+                    Some(true)
+                } else {
+                    None
+                }
+            },
+        );
+
+        // infinities are only equal to themselves, and two zeroes are only equal
+        // or ordered as equal if they have the same sign
+        impl_wrapper_traits!(
+            Strict,
+            $float,
+            value: |v| v,
+            eq: |a, b| {
+                let zero = <$float>::from_bits(0);
+                if a.is_infinite() || b.is_infinite() {
+                    Some(a == b)
+                } else if a == zero && b == zero && a.is_sign_negative() != b.is_sign_negative() {
+                    Some(false)
+                } else {
+                    None
+                }
+            },
+            ord: |a, b, op| {
+                let zero = <$float>::from_bits(0);
+                if a == zero && b == zero && a.is_sign_negative() != b.is_sign_negative() {
+                    let below = matches!(op, FloatOrdOp::Lt | FloatOrdOp::Le);
+                    Some(below == a.is_sign_negative())
+                } else {
+                    None
+                }
+            },
+        );
+
+        // infinities are compared as the largest finite value of the same sign
+        impl_wrapper_traits!(
+            InfAsMax,
+            $float,
+            value: |v| if v == <$float>::INFINITY {
+                <$float>::MAX
+            } else if v == <$float>::NEG_INFINITY {
+                <$float>::MIN
+            } else {
+                v
+            },
+            eq: |_a, _b| None,
+            ord: |_a, _b, _op| None,
+        );
+    };
+}
+
+impl_wrappers!(f32);
+impl_wrappers!(f64);
@@ -270,6 +270,41 @@ pub trait FloatEq<Rhs: ?Sized = Self> {
         !self.eq_r2nd(other, tol)
     }
 
+    /// Check whether `self` is equal to `other`, using an [absolute and relative
+    /// tolerance comparison], where the relative tolerance is scaled to the
+    /// granularity of the second input.
+    ///
+    /// This matches `isclose` in NumPy and PyTorch, and the implementation
+    /// should be the equivalent of:
+    ///
+    /// ```
+    /// # trait TestFloatEq { fn eq_atol_rtol(&self, other: &Self, atol: &Self, rtol: &Self) -> bool; }
+    /// # impl TestFloatEq for f32 {
+    /// # fn eq_atol_rtol(&self, other: &Self, atol: &Self, rtol: &Self) -> bool {
+    /// // as in NumPy, infinities are only equal to themselves
+    /// self == other
+    ///     || (self.is_finite() && other.is_finite() && {
+    ///         let tolerance = atol + rtol * other.abs();
+    ///         (self - other).abs() <= tolerance
+    ///     })
+    /// # }}
+    /// ```
+    ///
+    /// [absolute and relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-and-relative-tolerance-comparison
+    fn eq_atol_rtol(&self, other: &Rhs, atol: &Self::Tol, rtol: &Self::Tol) -> bool;
+
+    /// Check whether `self` is not equal to `other`, using an [absolute and
+    /// relative tolerance comparison].
+    ///
+    /// Equal to `!self.eq_atol_rtol(other, atol, rtol)`, there is no need to
+    /// reimplement this for your own types.
+    ///
+    /// [absolute and relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-and-relative-tolerance-comparison
+    #[inline]
+    fn ne_atol_rtol(&self, other: &Rhs, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        !self.eq_atol_rtol(other, atol, rtol)
+    }
+
     /// Check whether `self` is equal to `other`, using an [ULPs comparison](https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#units-in-the-last-place-ulps-comparison).
     ///
     /// The implementation should be the equivalent of:
@@ -465,6 +500,26 @@ pub trait FloatEqAll<Rhs: ?Sized = Self> {
         !self.eq_r2nd_all(other, tol)
     }
 
+    /// Check whether `self` is equal to `other`, using an [absolute and relative
+    /// tolerance comparison].
+    ///
+    /// This must use the same algorithm as [`FloatEq::eq_atol_rtol`].
+    ///
+    /// [absolute and relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-and-relative-tolerance-comparison
+    fn eq_atol_rtol_all(&self, other: &Rhs, atol: &Self::AllTol, rtol: &Self::AllTol) -> bool;
+
+    /// Check whether `self` is not equal to `other`, using an [absolute and
+    /// relative tolerance comparison].
+    ///
+    /// Equal to `!self.eq_atol_rtol_all(other, atol, rtol)`, there is no need
+    /// to reimplement this for your own types.
+    ///
+    /// [absolute and relative tolerance comparison]: https://jtempest.github.io/float_eq-rs/book/background/float_comparison_algorithms.html#absolute-and-relative-tolerance-comparison
+    #[inline]
+    fn ne_atol_rtol_all(&self, other: &Rhs, atol: &Self::AllTol, rtol: &Self::AllTol) -> bool {
+        !self.eq_atol_rtol_all(other, atol, rtol)
+    }
+
     /// Check whether `self` is equal to `other`, using an [ULPs comparison].
     ///
     /// This must use the same algorithm as [`FloatEq::eq_ulps`].
@@ -601,6 +656,19 @@ pub trait AssertFloatEq<Rhs: ?Sized = Self>: FloatEq<Rhs> {
     /// [comparison]: index.html#comparison-algorithms
     fn debug_r2nd_tol(&self, other: &Rhs, tol: &Self::Tol) -> Self::DebugTol;
 
+    /// The tolerance used by an `atol_rtol` [comparison], displayed when an
+    /// assert fails.
+    ///
+    /// Returns `atol` plus `rtol` scaled by the magnitude of the second operand.
+    ///
+    /// [comparison]: index.html#comparison-algorithms
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &Rhs,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol;
+
     /// The tolerance used by an `ulps` [comparison], displayed when an assert fails.
     ///
     /// [comparison]: index.html#comparison-algorithms
@@ -668,6 +736,19 @@ pub trait AssertFloatEqAll<Rhs: ?Sized = Self>: FloatEqAll<Rhs> {
     /// [comparison]: index.html#comparison-algorithms
     fn debug_r2nd_all_tol(&self, other: &Rhs, tol: &Self::AllTol) -> Self::AllDebugTol;
 
+    /// The tolerance used by an `atol_rtol_all` [comparison], displayed when an
+    /// assert fails.
+    ///
+    /// Returns `atol` plus `rtol` scaled by the magnitude of the second operand.
+    ///
+    /// [comparison]: index.html#comparison-algorithms
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &Rhs,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol;
+
     /// The tolerance used by an `ulps_all` [comparison], displayed when an assert fails.
     ///
     /// [comparison]: index.html#comparison-algorithms
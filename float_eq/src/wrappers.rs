@@ -0,0 +1,145 @@
+use core::fmt;
+
+/// A floating point value that is equal to another if both are NaN.
+///
+/// Usually, NaN is never equal to anything, including another NaN. Wrapping a
+/// value in `NanEq` makes every check treat a pair of NaNs as a match, whatever
+/// their sign or payload, whilst comparing other values exactly as the wrapped
+/// type would. It is intended for data that uses NaN to mark a missing value:
+///
+/// ```
+/// # use float_eq::{assert_float_eq, NanEq};
+/// let a = [NanEq(1.0_f32), NanEq(f32::NAN)];
+/// let b = [NanEq(1.000_000_1_f32), NanEq(-f32::NAN)];
+/// assert_float_eq!(a, b, ulps <= [1, 0]);
+/// ```
+///
+/// Since the checks are made per value, wrappers may be used inside any of the
+/// composite types that implement the float_eq traits, or as the fields of a
+/// type that derives them. For that reason, a tolerance is wrapped in the same
+/// way as the values, such as `abs <= NanEq(0.1)`, whereas the tolerances of
+/// ULPs and `_all` checks are those of the wrapped type. See [`NanEqBits`] to
+/// only match NaNs with the same sign and payload.
+///
+/// `PartialEq` is implemented in the same way, so that derived `PartialEq` and
+/// the float_eq traits agree.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct NanEq<T>(pub T);
+
+/// A floating point value that is equal to another if both are NaN with the
+/// same bits.
+///
+/// This is a stricter form of [`NanEq`], under which two NaNs only match if
+/// their sign and payload are the same, for data in which different NaNs carry
+/// different meanings:
+///
+/// ```
+/// # use float_eq::{assert_float_eq, assert_float_ne, NanEqBits};
+/// let nan = NanEqBits(f32::NAN);
+/// assert_float_eq!(nan, nan, ulps <= 0);
+/// assert_float_ne!(nan, NanEqBits(-f32::NAN), abs <= NanEqBits(f32::INFINITY));
+/// ```
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct NanEqBits<T>(pub T);
+
+/// A floating point value that distinguishes the sign of zero and only equals
+/// an infinity if it is the same infinity.
+///
+/// Usually, `0.0` is equal to `-0.0` under every check, and infinities may be
+/// within the tolerance of a finite value, such as an `rmax` tolerance of `1.0`.
+/// Wrapping a value in `Strict` makes every check treat zeroes of different
+/// signs as unequal, and an infinity as unequal to anything other than itself,
+/// for code such as branch cuts and `atan2` that depends on the sign of zero.
+/// Other values are compared exactly as the wrapped type would:
+///
+/// ```
+/// # use float_eq::{assert_float_eq, assert_float_ne, float_lt, Strict};
+/// assert_float_ne!(Strict(0.0_f64), Strict(-0.0), abs <= Strict(1.0));
+/// assert_float_ne!(Strict(f64::INFINITY), Strict(f64::MAX), rmax <= Strict(1.0));
+/// assert_float_eq!(Strict(1.0_f64), Strict(1.000_000_1), abs <= Strict(0.001));
+///
+/// // When ordered, -0.0 is less than 0.0
+/// assert!(float_lt!(Strict(-0.0_f64), Strict(0.0), abs <= Strict(0.0)));
+/// ```
+///
+/// As with [`NanEq`], tolerances are wrapped in the same way as the values,
+/// and the wrapper is transparent when printed, so that assert messages show
+/// the sign of each zero.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct Strict<T>(pub T);
+
+/// A floating point value that is compared as if an infinity were the largest
+/// finite value of the same sign.
+///
+/// Usually, an infinity is only equal to itself, and is infinitely far from any
+/// finite value. Wrapping a value in `InfAsMax` compares positive infinity as
+/// `MAX` and negative infinity as `MIN`, so that a value that has overflowed is
+/// within a tolerance of the largest values of the same sign, whilst remaining
+/// unequal to those of the other sign:
+///
+/// ```
+/// # use float_eq::{assert_float_eq, assert_float_ne, InfAsMax};
+/// let inf = InfAsMax(f32::INFINITY);
+/// assert_float_eq!(inf, InfAsMax(f32::MAX), ulps <= 0);
+/// assert_float_eq!(inf, InfAsMax(f32::MAX / 2.0), rmax <= InfAsMax(0.5));
+/// assert_float_ne!(inf, InfAsMax(f32::NEG_INFINITY), ulps <= u32::MAX);
+/// ```
+///
+/// Assert messages and reports show the original infinite values, alongside
+/// the differences between the finite values they were compared as.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct InfAsMax<T>(pub T);
+
+macro_rules! impl_wrapper {
+    ($wrapper:ident) => {
+        impl<T> From<T> for $wrapper<T> {
+            #[inline]
+            fn from(value: T) -> Self {
+                $wrapper(value)
+            }
+        }
+
+        // The wrapper is transparent when printed, so that assert messages read
+        // the same as they would for the wrapped values.
+        impl<T: fmt::Debug> fmt::Debug for $wrapper<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl<T: fmt::Display> fmt::Display for $wrapper<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+impl_wrapper!(NanEq);
+impl_wrapper!(NanEqBits);
+impl_wrapper!(Strict);
+impl_wrapper!(InfAsMax);
@@ -0,0 +1,99 @@
+#![cfg(feature = "std")]
+
+// Kept apart from the other tests, since the assert message is set globally.
+
+use float_eq::{
+    assert_eq_abs_all, assert_float_eq, assert_message, check_float_eq, set_assert_message,
+    AssertDiffs, AssertFloatEqAllDiffs, AssertFloatEqDiffs, AssertMessage, ReportTol,
+};
+use std::cell::{Cell, RefCell};
+use std::panic::{catch_unwind, set_hook, take_hook};
+
+fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    let err = catch_unwind(f).unwrap_err();
+    err.downcast_ref::<String>().unwrap().clone()
+}
+
+#[test]
+fn set_assert_message_changes_format() {
+    let hook = take_hook();
+    set_hook(Box::new(|_| {}));
+
+    let fail = || assert_float_eq!(vec![1_f32, 2., 3.], vec![1_f32, 2.5, 3.5], abs_all <= 0.25);
+    let check = || {
+        check_float_eq!(vec![1_f32, 2., 3.], vec![1_f32, 2.5, 3.5], abs_all <= 0.25).unwrap_err()
+    };
+
+    set_assert_message(AssertMessage::Concise { limit: 1 });
+    assert_eq!(assert_message(), AssertMessage::Concise { limit: 1 });
+    assert_eq!(
+        panic_message(fail),
+        "assertion failed: `float_eq!(left, right, abs_all <= t)`
+ [abs_all] 2 failures, showing the first 1:
+           [1]: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `0.25`"
+    );
+    let err = check();
+    assert_eq!(
+        err.diffs(),
+        Some(
+            " [abs_all] 2 failures, showing the first 1:
+           [1]: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `0.25`"
+        )
+    );
+    assert_eq!(
+        err.to_string(),
+        panic_message(fail).replacen("assertion failed", "check failed", 1)
+    );
+
+    let ulps = || assert_float_eq!(vec![1_f32, 2.], vec![1_f32, 2.5], ulps <= vec![0, 4]);
+    assert_eq!(
+        panic_message(ulps),
+        "assertion failed: `float_eq!(left, right, ulps <= t)`
+    [ulps] 1 failure:
+           [1]: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `4`"
+    );
+
+    // Asserts made in a generic context cannot tell whether the values may be
+    // itemised, and so print them in full.
+    let generic = || assert_eq_abs_all(&vec![1_f32, 2.], &vec![1_f32, 2.5], &0.25);
+    assert!(panic_message(generic).contains("left: `[1.0, 2.0]`"));
+
+    set_assert_message(AssertMessage::Full);
+    assert_eq!(assert_message(), AssertMessage::Full);
+    assert_eq!(
+        panic_message(fail),
+        "assertion failed: `float_eq!(left, right, abs_all <= t)`
+        left: `[1.0, 2.0, 3.0]`,
+       right: `[1.0, 2.5, 3.5]`,
+    abs_diff: `Some([0.0, 0.5, 0.5])`,
+   ulps_diff: `Some([Some(0), Some(2097152), Some(2097152)])`,
+ [abs_all] t: `Some([0.25, 0.25, 0.25])`"
+    );
+    let err = check();
+    assert_eq!(err.diffs(), None);
+    assert_eq!(
+        err.to_string(),
+        panic_message(fail).replacen("assertion failed", "check failed", 1)
+    );
+
+    for limit in [usize::MAX, usize::MAX - 1, 0] {
+        set_assert_message(AssertMessage::Concise { limit });
+        assert_eq!(assert_message(), AssertMessage::Concise { limit });
+    }
+
+    set_hook(hook);
+}
+
+#[test]
+fn cells_list_their_values() {
+    let mut diffs = AssertDiffs::new(10);
+    Cell::new(2_f32).debug_diffs(&Cell::new(2.5), ReportTol::Ulps(&4), "[1]", &mut diffs);
+    RefCell::new(1_f32).debug_all_diffs(&RefCell::new(1.5), ReportTol::Rmax(&0.25), "", &mut diffs);
+    assert_eq!(
+        diffs.iter().collect::<Vec<_>>(),
+        vec![
+            "[1]: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `4`",
+            "<root>: left: `1.0`, right: `1.5`, abs_diff: `0.5`, ulps_diff: `Some(4194304)`, t: `0.375`",
+        ]
+    );
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "audit")]
+
+// Kept apart from the other tests, since the audit file is chosen once per
+// process from the environment.
+
+use float_eq::audit::{summarize, AuditRecord, AUDIT_PATH_VAR};
+use float_eq::{assert_float_eq, compare, float_eq, float_ne, EffectiveTol, FloatEq};
+use std::panic::Location;
+
+#[derive(Debug, PartialEq)]
+struct NoReport(f64);
+
+impl FloatEq for NoReport {
+    type Tol = f64;
+
+    fn eq_abs(&self, other: &Self, tol: &f64) -> bool {
+        self.0.eq_abs(&other.0, tol)
+    }
+
+    fn eq_rmax(&self, other: &Self, tol: &f64) -> bool {
+        self.0.eq_rmax(&other.0, tol)
+    }
+
+    fn eq_rmin(&self, other: &Self, tol: &f64) -> bool {
+        self.0.eq_rmin(&other.0, tol)
+    }
+
+    fn eq_r1st(&self, other: &Self, tol: &f64) -> bool {
+        self.0.eq_r1st(&other.0, tol)
+    }
+
+    fn eq_r2nd(&self, other: &Self, tol: &f64) -> bool {
+        self.0.eq_r2nd(&other.0, tol)
+    }
+
+    fn eq_ulps(&self, other: &Self, tol: &u64) -> bool {
+        self.0.eq_ulps(&other.0, tol)
+    }
+
+    fn eq_atol_rtol(&self, other: &Self, atol: &f64, rtol: &f64) -> bool {
+        self.0.eq_atol_rtol(&other.0, atol, rtol)
+    }
+}
+
+// Generic code cannot tell whether its values support reports, so its checks
+// are recorded as unsupported.
+fn generic_eq<T: FloatEq<Tol = f64>>(a: &T, b: &T) -> bool {
+    float_eq!(a, b, abs <= 0.5)
+}
+
+#[test]
+fn records_checks() {
+    let path = std::env::temp_dir().join(format!("float_eq_audit_{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    std::env::set_var(AUDIT_PATH_VAR, &path);
+
+    let line = line!() + 1;
+    assert!(float_eq!(1.0_f64, 1.000_000_1, rmax <= 1e-3, ulps <= 4_u64));
+    assert!(float_ne!([1.0_f32, 2.0], [1.0_f32, 3.0], abs_all <= 0.5));
+    assert_float_eq!(2.0_f64, 2.5, atol_rtol <= (1.0, 0.0));
+    assert!(float_eq!(NoReport(1.0), NoReport(1.0), abs <= 0.1));
+    assert!(generic_eq(&1.0_f64, &1.25));
+
+    let records = AuditRecord::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(records.len(), 5);
+
+    let rmax = &records[0];
+    assert_eq!(rmax.file, file!());
+    assert_eq!(rmax.line, line);
+    assert_eq!(rmax.column, 13);
+    assert_eq!(rmax.check, "rmax");
+    assert!(rmax.passed);
+    assert_eq!(rmax.ulps_diff, Some(450_359_963));
+    assert_eq!(rmax.tol, Some(EffectiveTol::Abs(1.000_000_1e-3)));
+    assert!(rmax.margin.unwrap() < 1e-3);
+
+    let abs_all = &records[1];
+    assert_eq!(abs_all.line, line + 1);
+    assert_eq!(abs_all.check, "abs_all");
+    assert!(!abs_all.passed);
+    assert_eq!(abs_all.abs_diff, Some(1.0));
+    assert_eq!(abs_all.rel_diff, Some(1.0 / 3.0));
+    assert_eq!(abs_all.tol, Some(EffectiveTol::Abs(0.5)));
+    assert_eq!(abs_all.margin, Some(2.0));
+
+    let atol_rtol = &records[2];
+    assert_eq!(atol_rtol.line, line + 2);
+    assert_eq!(atol_rtol.column, 5);
+    assert_eq!(atol_rtol.check, "atol_rtol");
+    assert_eq!(atol_rtol.margin, Some(0.5));
+    assert!(!atol_rtol.unsupported);
+
+    let no_report = &records[3];
+    assert_eq!(no_report.line, line + 3);
+    assert_eq!(no_report.check, "abs");
+    assert!(no_report.passed);
+    assert!(no_report.unsupported);
+    assert_eq!(no_report.abs_diff, None);
+    assert_eq!(no_report.margin, None);
+
+    let generic = &records[4];
+    assert_eq!(generic.file, file!());
+    assert!(generic.passed);
+    assert!(generic.unsupported);
+}
+
+fn record(line: u32, report: float_eq::Report) -> AuditRecord {
+    let mut record = AuditRecord::new(Location::caller(), &report);
+    record.line = line;
+    record
+}
+
+#[test]
+fn record_worst_field() {
+    let report = compare!(
+        [1.0_f64, 2.0, 4.0],
+        [1.0_f64, 2.5, 4.25],
+        abs <= [0.0, 1.0, 0.5]
+    );
+    let record = AuditRecord::new(Location::caller(), &report);
+    assert!(record.passed);
+    assert_eq!(record.abs_diff, Some(0.5));
+    assert_eq!(record.rel_diff, Some(0.2));
+    assert_eq!(record.tol, Some(EffectiveTol::Abs(1.0)));
+    assert_eq!(record.margin, Some(0.5));
+
+    let report = compare!(f64::NAN, 1.0_f64, ulps <= 4_u64);
+    let record = AuditRecord::new(Location::caller(), &report);
+    assert!(!record.passed);
+    assert_eq!(record.abs_diff, None);
+    assert_eq!(record.ulps_diff, None);
+    assert_eq!(record.margin, None);
+}
+
+#[test]
+fn summaries() {
+    let records = vec![
+        record(1, compare!(1.0_f64, 1.01, abs <= 1.0)),
+        record(1, compare!(1.0_f64, 1.05, abs <= 1.0)),
+        record(2, compare!(1.0_f64, 1.5, abs <= 1.0)),
+        record(3, compare!(1.0_f64, 1.0, abs <= 0.0)),
+        record(4, compare!(1.0_f64, 1.0, abs <= 0.0)),
+        record(4, compare!(1.0_f64, 1.5, abs <= 0.0)),
+        AuditRecord::unsupported(Location::caller(), "abs", true),
+    ];
+    let summaries = summarize(records);
+    assert_eq!(summaries.len(), 5);
+
+    assert_eq!(summaries[0].line, 1);
+    assert_eq!(summaries[0].count, 2);
+    assert_eq!(summaries[0].failures, 0);
+    assert!((summaries[0].margin.unwrap() - 0.05).abs() < 1e-9);
+    assert!(summaries[0].can_tighten(10.0));
+    assert!(!summaries[0].can_tighten(100.0));
+
+    assert_eq!(summaries[1].margin, Some(0.5));
+    assert!(!summaries[1].can_tighten(10.0));
+
+    assert_eq!(summaries[2].margin, Some(0.0));
+    assert!(summaries[2].can_tighten(10.0));
+
+    assert_eq!(summaries[3].count, 2);
+    assert_eq!(summaries[3].failures, 1);
+    assert_eq!(summaries[3].margin, None);
+    assert!(!summaries[3].can_tighten(10.0));
+
+    assert_eq!(summaries[0].unsupported, 0);
+    assert_eq!(summaries[4].count, 1);
+    assert_eq!(summaries[4].unsupported, 1);
+    assert_eq!(summaries[4].margin, None);
+    assert!(!summaries[4].can_tighten(10.0));
+}
+
+#[test]
+fn unsupported_serialization() {
+    let record = AuditRecord::unsupported(Location::caller(), "ulps", false);
+    let json = serde_json::to_string(&record).unwrap();
+    assert!(
+        json.ends_with(r#""margin":null,"unsupported":true}"#),
+        "{}",
+        json
+    );
+    assert_eq!(serde_json::from_str::<AuditRecord>(&json).unwrap(), record);
+
+    let report = compare!(1.0_f64, 1.0, abs <= 0.0);
+    let json = serde_json::to_string(&AuditRecord::new(Location::caller(), &report)).unwrap();
+    assert!(!json.contains("unsupported"), "{}", json);
+    assert!(
+        !serde_json::from_str::<AuditRecord>(&json)
+            .unwrap()
+            .unsupported
+    );
+}
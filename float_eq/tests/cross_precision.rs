@@ -0,0 +1,136 @@
+#![cfg(feature = "cross_precision")]
+
+use float_eq::{assert_float_eq, assert_float_ne, float_eq, float_ne, AssertFloatEq};
+
+#[test]
+fn abs_and_relative_use_the_wider_type() {
+    // 0.1_f32 is 0.100000001490116..., so the difference is only visible in f64
+    let diff = f64::from(0.1_f32) - 0.1;
+    assert_eq!(0.1_f32.debug_abs_diff(&0.1_f64), diff);
+    assert_eq!(0.1_f64.debug_abs_diff(&0.1_f32), diff);
+
+    assert_float_eq!(0.1_f32, 0.1_f64, abs <= diff);
+    assert_float_ne!(0.1_f32, 0.1_f64, abs <= diff / 2.0);
+    assert_float_eq!(0.1_f64, 0.1_f32, abs_all <= diff);
+    assert_float_ne!(0.1_f64, 0.1_f32, abs_all <= diff / 2.0);
+
+    assert_float_eq!(0.1_f32, 0.1_f64, rmax <= 1.5e-8);
+    assert_float_ne!(0.1_f32, 0.1_f64, rmax <= 1.4e-8);
+    assert_float_eq!(0.1_f32, 0.1_f64, rmin <= 1.5e-8);
+    assert_float_eq!(0.1_f32, 0.1_f64, r1st <= 1.5e-8);
+    assert_float_eq!(0.1_f32, 0.1_f64, r2nd_all <= 1.5e-8);
+    assert_float_ne!(0.1_f32, 0.1_f64, r2nd_all <= 1.4e-8);
+
+    assert_eq!(
+        2.0_f32.debug_rmax_tol(&4.0_f64, &0.5),
+        2.0_f64.debug_rmax_tol(&4.0, &0.5)
+    );
+    assert_eq!(
+        4.0_f64.debug_r1st_tol(&2.0_f32, &0.5),
+        4.0_f64.debug_r1st_tol(&2.0, &0.5)
+    );
+}
+
+#[test]
+fn ulps_use_the_narrower_type() {
+    // the f64 reference rounds to the same f32
+    assert_eq!(0.1_f32.debug_ulps_diff(&0.1_f64), Some(0));
+    assert_float_eq!(0.1_f32, 0.1_f64, ulps <= 0);
+    assert_float_eq!(0.1_f64, 0.1_f32, ulps_all <= 0);
+
+    let two_ulps = 1.0 + 2.0 * f64::from(f32::EPSILON);
+    assert_eq!(1.0_f32.debug_ulps_diff(&two_ulps), Some(2));
+    assert_eq!(two_ulps.debug_ulps_diff(&1.0_f32), Some(2));
+    assert_float_eq!(1.0_f32, two_ulps, ulps <= 2);
+    assert_float_ne!(1.0_f32, two_ulps, ulps <= 1);
+    assert_float_eq!(two_ulps, 1.0_f32, ulps_all <= 2);
+    assert_float_ne!(two_ulps, 1.0_f32, ulps_all <= 1);
+    assert_eq!(1.0_f32.debug_ulps_tol(&two_ulps, &3), 3);
+}
+
+#[test]
+fn special_values() {
+    assert!(float_eq!(0.0_f32, -0.0_f64, ulps <= 0));
+    assert!(float_eq!(-0.0_f64, 0.0_f32, abs <= 0.0));
+    assert_eq!(1.0_f32.debug_ulps_diff(&-1.0_f64), None);
+    assert!(float_ne!(1.0_f32, -1.0_f64, ulps <= u64::MAX));
+
+    assert!(float_eq!(f32::INFINITY, f64::INFINITY, abs <= 0.0));
+    assert!(float_eq!(f64::INFINITY, f32::INFINITY, ulps <= 0));
+    assert!(float_ne!(
+        f32::INFINITY,
+        f64::NEG_INFINITY,
+        ulps <= u64::MAX
+    ));
+
+    assert!(float_ne!(f32::NAN, f64::NAN, abs <= f64::INFINITY));
+    assert!(float_ne!(f64::NAN, 1.0_f32, ulps <= u64::MAX));
+    assert_eq!(f32::NAN.debug_ulps_diff(&1.0_f64), None);
+}
+
+#[test]
+fn ulps_outside_of_the_narrower_range() {
+    // rounds to f32::MAX, whereas 1e39 overflows to infinity
+    assert_eq!(f32::MAX.debug_ulps_diff(&f64::from(f32::MAX)), Some(0));
+    assert_eq!(f32::MAX.debug_ulps_diff(&1e39_f64), None);
+    assert_eq!(1e39_f64.debug_ulps_diff(&f32::MAX), None);
+    assert!(float_ne!(f32::MAX, 1e39_f64, ulps <= 1));
+    assert!(float_ne!(f32::INFINITY, 1e39_f64, ulps <= u64::MAX));
+    assert!(float_ne!(-1e39_f64, f32::MIN, ulps_all <= 1));
+
+    // rounds to the smallest f32 subnormal, whereas 1e-50 underflows to zero
+    let min_subnormal = f32::from_bits(1);
+    assert_eq!(
+        min_subnormal.debug_ulps_diff(&f64::from(min_subnormal)),
+        Some(0)
+    );
+    assert_eq!(0.0_f32.debug_ulps_diff(&1e-50_f64), None);
+    assert_eq!((-1e-50_f64).debug_ulps_diff(&-0.0_f32), None);
+    assert!(float_ne!(0.0_f32, 1e-50_f64, ulps <= u64::MAX));
+    assert!(float_ne!(min_subnormal, 1e-50_f64, ulps <= 1));
+
+    // other checks are made in f64 and so are unaffected
+    assert!(float_eq!(f32::MAX, 1e39_f64, rmax <= 1.0));
+    assert!(float_eq!(0.0_f32, 1e-50_f64, abs <= 1e-49));
+}
+
+#[test]
+fn unsuffixed_literals_are_inferred_as_f64() {
+    // without the feature, `0.1` would be inferred as an f32 equal to 0.1_f32
+    assert_eq!(0.1_f32.debug_abs_diff(&0.1), f64::from(0.1_f32) - 0.1);
+    assert!(float_ne!(0.1_f32, 0.1, abs <= 0.0));
+    assert!(float_eq!(0.1_f32, 0.1_f32, abs <= 0.0));
+}
+
+#[test]
+fn unsuffixed_literals_in_ulps_checks_do_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/cross_precision/ulps_unsuffixed_literal.rs");
+}
+
+#[test]
+fn collections() {
+    let a = [1.0_f32, 0.1];
+    let b = [1.0_f64, 0.1];
+    assert_float_eq!(a, b, ulps_all <= 0);
+    assert_float_eq!(a, b, abs <= [0.0, 1.5e-9]);
+    assert_float_ne!(a, b, abs_all <= 1.0e-9);
+
+    #[cfg(feature = "std")]
+    {
+        assert_float_eq!(a.to_vec(), b.to_vec(), rmax_all <= 1.5e-8);
+        assert_float_eq!(&a[..], &b[..], ulps <= vec![0, 0]);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+        left: `0.1`,
+       right: `0.1`,
+    abs_diff: `1.4901161138336505e-9`,
+   ulps_diff: `Some(0)`,
+     [abs] t: `1e-9`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(0.1_f32, 0.1_f64, abs <= 1e-9);
+}
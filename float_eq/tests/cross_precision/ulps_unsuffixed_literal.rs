@@ -0,0 +1,7 @@
+use float_eq::float_eq;
+
+fn main() {
+    // the literal may now be either an f32 or an f64, so the type of the ULPs
+    // tolerance cannot be inferred
+    let _ = float_eq!(1.0_f32, 1.0, ulps <= 1);
+}
@@ -31,3 +31,88 @@ fn failed_assert() {
         ulps <= MyComplex32Ulps { re: 5, im: 2 },
     );
 }
+
+#[derive_float_eq(
+    ulps_tol = "SomeEnumUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "SomeEnumUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SomeEnum {
+    Complex { re: f32, im: f32 },
+    Float(f32),
+}
+
+#[test]
+#[should_panic(expected = "`float_eq!(left, right, ulps <= t)`
+        left: `Complex { re: 1.0, im: 2.0 }`,
+       right: `Complex { re: 1.0000005, im: -5.0 }`,
+    abs_diff: `Some(Complex { re: 4.7683716e-7, im: 7.0 })`,
+   ulps_diff: `Some(Complex { re: Some(4), im: None })`,
+    [ulps] t: `Some(Complex { re: 5, im: 2 })`")]
+fn failed_assert_enum() {
+    assert_float_eq!(
+        SomeEnum::Complex { re: 1.0, im: 2.0 },
+        SomeEnum::Complex {
+            re: 1.000_000_5,
+            im: -5.0
+        },
+        ulps <= SomeEnumUlps::Complex { re: 5, im: 2 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "`float_eq!(left, right, ulps <= t)`
+        left: `Complex { re: 1.0, im: 2.0 }`,
+       right: `Float(1.0)`,
+    abs_diff: `None`,
+   ulps_diff: `None`,
+    [ulps] t: `None`")]
+fn failed_assert_enum_different_variants() {
+    assert_float_eq!(
+        SomeEnum::Complex { re: 1.0, im: 2.0 },
+        SomeEnum::Float(1.0),
+        ulps <= SomeEnumUlps::Float(5),
+    );
+}
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ReadingUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "ReadingTol",
+    debug_tol = "ReadingDebugTol"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Reading {
+    value: f32,
+    #[float_eq(abs <= 0.5)]
+    temperature: f32,
+    #[float_eq(skip)]
+    id: u32,
+}
+
+#[test]
+#[should_panic(expected = "`float_eq!(left, right, abs <= t)`
+        left: `Reading { value: 1.0, temperature: 20.0, id: 1 }`,
+       right: `Reading { value: 2.0, temperature: 20.25, id: 2 }`,
+    abs_diff: `ReadingDebugTol { value: 1.0, temperature: 0.25 }`,
+   ulps_diff: `ReadingUlpsDiff { value: Some(8388608), temperature: Some(131072) }`,
+     [abs] t: `ReadingDebugTol { value: 0.5, temperature: 0.5 }`")]
+fn failed_assert_field_attributes() {
+    assert_float_eq!(
+        Reading {
+            value: 1.0,
+            temperature: 20.0,
+            id: 1
+        },
+        Reading {
+            value: 2.0,
+            temperature: 20.25,
+            id: 2
+        },
+        abs <= ReadingTol { value: 0.5 },
+    );
+}
@@ -10,8 +10,8 @@ fn tests() {
     t.pass("tests/derive_tests/ulps_tol/ulps_tol_struct_no_fields.rs");
     t.pass("tests/derive_tests/ulps_tol/ulps_tol_tuple_struct.rs");
     t.pass("tests/derive_tests/ulps_tol/ulps_tol_unit.rs");
-    t.compile_fail("tests/derive_tests/ulps_tol/ulps_tol_enum.rs");
-    t.compile_fail("tests/derive_tests/ulps_tol/ulps_tol_generic.rs");
+    t.pass("tests/derive_tests/ulps_tol/ulps_tol_enum.rs");
+    t.pass("tests/derive_tests/ulps_tol/ulps_tol_generic.rs");
     t.compile_fail("tests/derive_tests/ulps_tol/ulps_tol_missing_type_name.rs");
     t.compile_fail("tests/derive_tests/ulps_tol/ulps_tol_duplicate_type_name.rs");
 
@@ -21,8 +21,8 @@ fn tests() {
     t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_struct_no_fields.rs");
     t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_tuple_struct.rs");
     t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_unit.rs");
-    t.compile_fail("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_enum.rs");
-    t.compile_fail("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_generic.rs");
+    t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_enum.rs");
+    t.pass("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_generic.rs");
     t.compile_fail("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_missing_type_name.rs");
     t.compile_fail("tests/derive_tests/debug_ulps_diff/debug_ulps_diff_duplicate_type_name.rs");
 
@@ -31,16 +31,17 @@ fn tests() {
     t.pass("tests/derive_tests/float_eq/float_eq_struct_no_fields.rs");
     t.pass("tests/derive_tests/float_eq/float_eq_tuple_struct.rs");
     t.pass("tests/derive_tests/float_eq/float_eq_unit.rs");
-    t.compile_fail("tests/derive_tests/float_eq/float_eq_enum.rs");
-    t.compile_fail("tests/derive_tests/float_eq/float_eq_generic.rs");
+    t.pass("tests/derive_tests/float_eq/float_eq_enum.rs");
+    t.pass("tests/derive_tests/float_eq/float_eq_generic.rs");
+    t.compile_fail("tests/derive_tests/float_eq/float_eq_union.rs");
 
     // FloatEqAll
     t.pass("tests/derive_tests/float_eq_all/float_eq_all_struct.rs");
     t.pass("tests/derive_tests/float_eq_all/float_eq_all_struct_no_fields.rs");
     t.pass("tests/derive_tests/float_eq_all/float_eq_all_tuple_struct.rs");
     t.pass("tests/derive_tests/float_eq_all/float_eq_all_unit.rs");
-    t.compile_fail("tests/derive_tests/float_eq_all/float_eq_all_enum.rs");
-    t.compile_fail("tests/derive_tests/float_eq_all/float_eq_all_generic.rs");
+    t.pass("tests/derive_tests/float_eq_all/float_eq_all_enum.rs");
+    t.pass("tests/derive_tests/float_eq_all/float_eq_all_generic.rs");
     t.compile_fail("tests/derive_tests/float_eq_all/float_eq_all_missing_tol.rs");
     t.compile_fail("tests/derive_tests/float_eq_all/float_eq_all_duplicate_tol.rs");
 
@@ -49,16 +50,35 @@ fn tests() {
     t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_struct_no_fields.rs");
     t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_tuple_struct.rs");
     t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_unit.rs");
-    t.compile_fail("tests/derive_tests/assert_float_eq/assert_float_eq_enum.rs");
-    t.compile_fail("tests/derive_tests/assert_float_eq/assert_float_eq_generic.rs");
+    t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_enum.rs");
+    t.pass("tests/derive_tests/assert_float_eq/assert_float_eq_generic.rs");
 
     // AssertFloatEqAll
     t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_struct.rs");
     t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_struct_no_fields.rs");
     t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_tuple_struct.rs");
     t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_unit.rs");
-    t.compile_fail("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_enum.rs");
-    t.compile_fail("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_generic.rs");
+    t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_enum.rs");
+    t.pass("tests/derive_tests/assert_float_eq_all/assert_float_eq_all_generic.rs");
+
+    // FloatOrd and AssertFloatOrd
+    t.pass("tests/derive_tests/float_ord/float_ord_struct.rs");
+    t.pass("tests/derive_tests/float_ord/float_ord_enum.rs");
+    t.pass("tests/derive_tests/float_ord/float_ord_generic.rs");
+    t.pass("tests/derive_tests/float_ord/float_ord_field_attribute.rs");
+    t.compile_fail("tests/derive_tests/float_ord/float_ord_fixed_atol_rtol.rs");
+
+    // FloatEqReport
+    if cfg!(feature = "std") {
+        t.pass("tests/derive_tests/report/report_struct.rs");
+        t.pass("tests/derive_tests/report/report_derive_float_eq.rs");
+    }
+
+    // AssertFloatEqDiffs and AssertFloatEqAllDiffs
+    if cfg!(feature = "std") {
+        t.pass("tests/derive_tests/diffs/diffs_struct.rs");
+        t.pass("tests/derive_tests/diffs/diffs_derive_float_eq.rs");
+    }
 
     // #[float_eq(...)]
     t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_no_params_list.rs");
@@ -66,11 +86,29 @@ fn tests() {
     t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_malformed_value.rs");
     t.compile_fail("tests/derive_tests/float_eq_attribute/float_eq_unknown_param.rs");
 
+    // #[float_eq(...)] on fields
+    t.pass("tests/derive_tests/field_attribute/field_attribute_skip.rs");
+    t.pass("tests/derive_tests/field_attribute/field_attribute_exact.rs");
+    t.pass("tests/derive_tests/field_attribute/field_attribute_fixed.rs");
+    t.pass("tests/derive_tests/field_attribute/field_attribute_enum.rs");
+    t.compile_fail("tests/derive_tests/field_attribute/field_attribute_unknown.rs");
+    t.compile_fail("tests/derive_tests/field_attribute/field_attribute_duplicate.rs");
+    t.compile_fail("tests/derive_tests/field_attribute/field_attribute_missing_tol.rs");
+    t.compile_fail("tests/derive_tests/field_attribute/field_attribute_trailing_tokens.rs");
+
     // #[derive_float_eq(...)]
     t.pass("tests/derive_tests/derive_float_eq/derive_float_eq.rs");
     t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_tuple_struct.rs");
     t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_all.rs");
     t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_all_custom_debug.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_enum.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_generic.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_nan_eq.rs");
+    t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_strict.rs");
+    if cfg!(feature = "serde") {
+        t.pass("tests/derive_tests/derive_float_eq/derive_float_eq_serde.rs");
+    }
     t.compile_fail("tests/derive_tests/derive_float_eq/derive_float_eq_missing_ulps_tol.rs");
     t.compile_fail("tests/derive_tests/derive_float_eq/derive_float_eq_missing_debug_ulps_diff.rs");
+    t.compile_fail("tests/derive_tests/derive_float_eq/derive_float_eq_missing_tol.rs");
 }
@@ -1,19 +1,97 @@
-use float_eq::{AssertFloatEq, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol};
+use float_eq::{
+    AssertFloatEq, DebugUlpsDiff, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol, UlpsTol,
+};
 
 #[derive(
-    Clone,
-    Copy,
-    Debug,
-    PartialEq,
-    PartialOrd,
-    FloatEqUlpsTol,
-    FloatEq,
-    FloatEqDebugUlpsDiff,
-    AssertFloatEq,
+    Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqDebugUlpsDiff, AssertFloatEq,
+)]
+#[float_eq(
+    ulps_tol = "SomeEnumUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "SomeEnumDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
 )]
 enum SomeEnum {
+    Complex { re: f32, im: f32 },
     Float(f32),
-    Double(f64),
+    Empty,
+}
+
+fn complex(re: f32, im: f32) -> SomeEnum {
+    SomeEnum::Complex { re, im }
+}
+
+fn debug_diff() {
+    let a = complex(1.0, 2.000_003_6);
+    assert_eq!(a.debug_abs_diff(&a), Some(complex(0.0, 0.0)));
+    assert_eq!(
+        a.debug_ulps_diff(&a),
+        Some(DebugUlpsDiff::<SomeEnum>::Complex {
+            re: Some(0),
+            im: Some(0)
+        })
+    );
+
+    let b = complex(1.000_000_1, 2.0);
+    assert_eq!(
+        a.debug_abs_diff(&b),
+        Some(complex(0.000_000_119_209_29, 0.000_003_576_278_7))
+    );
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        Some(DebugUlpsDiff::<SomeEnum>::Complex {
+            re: Some(1),
+            im: Some(15)
+        })
+    );
+
+    let c = complex(1.000_000_2, -2.0);
+    assert_eq!(
+        a.debug_ulps_diff(&c),
+        Some(SomeEnumDebugUlpsDiff::Complex {
+            re: Some(2),
+            im: None
+        })
+    );
+
+    assert_eq!(
+        SomeEnum::Float(1.0).debug_ulps_diff(&SomeEnum::Float(1.0)),
+        Some(SomeEnumDebugUlpsDiff::Float(Some(0)))
+    );
+    assert_eq!(
+        SomeEnum::Empty.debug_abs_diff(&SomeEnum::Empty),
+        Some(SomeEnum::Empty)
+    );
+
+    // different variants cannot be compared
+    assert_eq!(a.debug_abs_diff(&SomeEnum::Float(1.0)), None);
+    assert_eq!(a.debug_ulps_diff(&SomeEnum::Empty), None);
 }
 
-fn main() {}
+fn debug_tol() {
+    let a = complex(1.0, 200.0);
+    let b = complex(50.0, 1.0);
+
+    assert_eq!(
+        a.debug_abs_tol(&b, &complex(0.1, 0.2)),
+        Some(complex(0.1, 0.2))
+    );
+    assert_eq!(
+        a.debug_rel_tol(&b, &complex(0.1, 0.2)),
+        Some(complex(5.0, 40.0))
+    );
+    assert_eq!(
+        a.debug_ulps_tol(&b, &UlpsTol::<SomeEnum>::Complex { re: 1, im: 2 }),
+        Some(UlpsTol::<SomeEnum>::Complex { re: 1, im: 2 })
+    );
+
+    // different variants cannot be compared
+    assert_eq!(a.debug_abs_tol(&b, &SomeEnum::Float(0.1)), None);
+    assert_eq!(a.debug_rel_tol(&SomeEnum::Empty, &complex(0.1, 0.2)), None);
+    assert_eq!(a.debug_ulps_tol(&b, &SomeEnumUlps::Empty), None);
+}
+
+fn main() {
+    debug_diff();
+    debug_tol();
+}
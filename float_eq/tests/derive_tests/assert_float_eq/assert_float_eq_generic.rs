@@ -1,11 +1,80 @@
-use float_eq::{AssertFloatEq, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol};
+use float_eq::{
+    AssertFloatEq, DebugUlpsDiff, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol, UlpsTol,
+};
 
 #[derive(
     Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqDebugUlpsDiff, AssertFloatEq,
 )]
-struct MyComplexUlps<T> {
+#[float_eq(
+    ulps_tol = "MyComplexUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "MyComplexDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+impl<T> MyComplex<T> {
+    fn new(re: T, im: T) -> MyComplex<T> {
+        MyComplex { re, im }
+    }
+}
+
+fn debug_diff() {
+    let a = MyComplex::<f32>::new(1.0, 2.000_003_6);
+    assert_eq!(a.debug_abs_diff(&a), MyComplex::new(0.0, 0.0));
+    assert_eq!(
+        a.debug_ulps_diff(&a),
+        DebugUlpsDiff::<MyComplex<f32>> {
+            re: Some(0),
+            im: Some(0)
+        }
+    );
+
+    let b = MyComplex::new(1.000_000_1, 2.0);
+    assert_eq!(
+        a.debug_abs_diff(&b),
+        MyComplex::new(0.000_000_119_209_29, 0.000_003_576_278_7)
+    );
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        MyComplexDebugUlpsDiff {
+            re: Some(1),
+            im: Some(15)
+        }
+    );
+
+    let c = MyComplex::new(1.000_000_2, -2.0);
+    assert_eq!(
+        a.debug_ulps_diff(&c),
+        MyComplexDebugUlpsDiff {
+            re: Some(2),
+            im: None
+        }
+    );
+}
+
+fn debug_tol() {
+    let a = MyComplex::<f64>::new(1.0, 200.0);
+    let b = MyComplex::<f64>::new(50.0, 1.0);
+
+    assert_eq!(
+        a.debug_abs_tol(&b, &MyComplex::new(0.1, 0.2)),
+        MyComplex::new(0.1, 0.2)
+    );
+    assert_eq!(
+        a.debug_rel_tol(&b, &MyComplex::new(0.1, 0.2)),
+        MyComplex::new(5.0, 40.0)
+    );
+    assert_eq!(
+        a.debug_ulps_tol(&b, &UlpsTol::<MyComplex<f64>> { re: 1, im: 2 }),
+        MyComplexUlps { re: 1, im: 2 }
+    );
+}
+
+fn main() {
+    debug_diff();
+    debug_tol();
+}
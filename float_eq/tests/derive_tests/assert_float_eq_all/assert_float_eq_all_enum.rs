@@ -3,11 +3,10 @@ use float_eq::{
 };
 
 #[derive(
+    Debug,
     Clone,
     Copy,
-    Debug,
     PartialEq,
-    PartialOrd,
     FloatEqUlpsTol,
     FloatEq,
     FloatEqDebugUlpsDiff,
@@ -15,9 +14,41 @@ use float_eq::{
     FloatEqAll,
     AssertFloatEqAll,
 )]
+#[float_eq(
+    ulps_tol = "SomeEnumUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "SomeEnumDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32"
+)]
 enum SomeEnum {
+    Complex { re: f32, im: f32 },
     Float(f32),
-    Double(f64),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let a = SomeEnum::Complex { re: 1.0, im: 200.0 };
+    let b = SomeEnum::Complex { re: 50.0, im: 1.0 };
+
+    assert_eq!(
+        a.debug_abs_all_tol(&b, &0.2),
+        Some(SomeEnum::Complex { re: 0.2, im: 0.2 })
+    );
+    assert_eq!(
+        a.debug_rel_all_tol(&b, &0.2),
+        Some(SomeEnum::Complex { re: 10.0, im: 40.0 })
+    );
+    assert_eq!(
+        a.debug_ulps_all_tol(&b, &2),
+        Some(SomeEnumUlps::Complex { re: 2, im: 2 })
+    );
+    assert_eq!(
+        SomeEnum::Float(1.0).debug_ulps_all_tol(&SomeEnum::Float(2.0), &2),
+        Some(SomeEnumUlps::Float(2))
+    );
+
+    // different variants cannot be compared
+    assert_eq!(a.debug_abs_all_tol(&SomeEnum::Float(1.0), &0.2), None);
+    assert_eq!(a.debug_ulps_all_tol(&SomeEnum::Empty, &2), None);
+}
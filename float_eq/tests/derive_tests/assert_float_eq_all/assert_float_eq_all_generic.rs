@@ -14,9 +14,29 @@ use float_eq::{
     FloatEqAll,
     AssertFloatEqAll,
 )]
-struct MyComplexUlps<T> {
+#[float_eq(
+    ulps_tol = "MyComplexUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "MyComplexDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "T"
+)]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+impl<T> MyComplex<T> {
+    fn new(re: T, im: T) -> MyComplex<T> {
+        MyComplex { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex::<f32>::new(1.0, 200.0);
+    let b = MyComplex::<f32>::new(50.0, 1.0);
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), MyComplex::new(0.2, 0.2));
+    assert_eq!(a.debug_rel_all_tol(&b, &0.2), MyComplex::new(10.0, 40.0));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), MyComplexUlps { re: 2, im: 2 });
+}
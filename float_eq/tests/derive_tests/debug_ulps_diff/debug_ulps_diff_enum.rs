@@ -1,9 +1,32 @@
-use float_eq::FloatEqDebugUlpsDiff;
+use float_eq::{DebugUlpsDiff, FloatEqDebugUlpsDiff};
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, FloatEqDebugUlpsDiff)]
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqDebugUlpsDiff)]
+#[float_eq(
+    debug_ulps_diff = "SomeEnumDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
 enum SomeEnum {
-    Float(f32),
+    Complex { re: f32, im: f32 },
     Double(f64),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let a = DebugUlpsDiff::<SomeEnum>::Complex {
+        re: Some(1),
+        im: Some(2),
+    };
+    let b = a; // Clone, Copy
+
+    // Debug, PartialEq
+    assert_eq!(a, b);
+    assert_ne!(
+        a,
+        SomeEnumDebugUlpsDiff::Complex {
+            re: None,
+            im: Some(2)
+        }
+    );
+    assert_ne!(a, SomeEnumDebugUlpsDiff::Double(Some(1)));
+    assert_ne!(a, SomeEnumDebugUlpsDiff::Empty);
+}
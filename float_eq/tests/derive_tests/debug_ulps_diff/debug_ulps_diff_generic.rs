@@ -1,9 +1,36 @@
-use float_eq::FloatEqDebugUlpsDiff;
+use float_eq::{DebugUlpsDiff, FloatEqDebugUlpsDiff};
 
 #[derive(Debug, Clone, Copy, PartialEq, FloatEqDebugUlpsDiff)]
-struct MyComplexUlps<T> {
+#[float_eq(
+    debug_ulps_diff = "MyComplexDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+fn main() {
+    let a = DebugUlpsDiff::<MyComplex<f32>> {
+        re: Some(1),
+        im: Some(2),
+    };
+    let b = a; // Clone, Copy
+
+    // Debug, PartialEq
+    assert_eq!(a, b);
+    assert_ne!(
+        a,
+        MyComplexDebugUlpsDiff::<f32> {
+            re: None,
+            im: Some(2)
+        }
+    );
+    assert_ne!(
+        a,
+        MyComplexDebugUlpsDiff::<f32> {
+            re: Some(1),
+            im: Some(3)
+        }
+    );
+}
@@ -0,0 +1,40 @@
+mod my_module {
+    use float_eq::derive_float_eq;
+
+    #[derive_float_eq(
+        ulps_tol = "SomeEnumUlps",
+        ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+        debug_ulps_diff = "SomeEnumUlpsDiff",
+        debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+        all_tol = "f32"
+    )]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum SomeEnum {
+        Complex { re: f32, im: f32 },
+        Float(f32),
+        Empty,
+    }
+}
+
+fn main() {
+    use float_eq::{assert_float_eq, assert_float_ne};
+    use my_module::{SomeEnum, SomeEnumUlps};
+
+    let a = SomeEnum::Complex { re: 1.0, im: -2.0 };
+    let b = SomeEnum::Complex {
+        re: 1.000_000_1,
+        im: -2.000_000_5,
+    };
+
+    assert_float_eq!(a, a, abs <= SomeEnum::Complex { re: 0.0, im: 0.0 });
+    assert_float_eq!(a, b, ulps <= SomeEnumUlps::Complex { re: 1, im: 2 });
+    assert_float_ne!(a, b, ulps <= SomeEnumUlps::Complex { re: 0, im: 2 });
+    assert_float_eq!(a, b, rmax_all <= 0.000_000_25);
+
+    let c = SomeEnum::Float(1.0);
+    assert_float_eq!(c, SomeEnum::Float(1.000_000_1), ulps <= SomeEnumUlps::Float(1));
+    assert_float_eq!(SomeEnum::Empty, SomeEnum::Empty, abs_all <= 0.0);
+
+    assert_float_ne!(a, c, abs_all <= f32::INFINITY);
+    assert_float_ne!(c, SomeEnum::Empty, ulps <= SomeEnumUlps::Float(u32::MAX));
+}
@@ -0,0 +1,110 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq};
+
+trait Float: Copy {}
+impl Float for f32 {}
+impl Float for f64 {}
+
+#[derive_float_eq(
+    ulps_tol = "Vec3Ulps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "Vec3DebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "T"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Vec3<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+#[derive_float_eq(
+    ulps_tol = "PoseUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "PoseDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "T"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pose<T: Float> {
+    pos: Vec3<T>,
+    dir: Vec3<T>,
+}
+
+#[derive_float_eq(
+    ulps_tol = "SamplesUlps",
+    ulps_tol_derive = "Debug, PartialEq",
+    debug_ulps_diff = "SamplesDebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug, PartialEq"
+)]
+#[derive(Debug, PartialEq)]
+struct Samples<T, const N: usize>(T, [T; N])
+where
+    T: Float;
+
+#[derive_float_eq(
+    ulps_tol = "TaggedUlps",
+    ulps_tol_derive = "Debug, PartialEq",
+    debug_ulps_diff = "TaggedDebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug, PartialEq"
+)]
+#[derive(Debug, PartialEq)]
+enum Tagged<T> {
+    Value(T),
+    Fixed(f32),
+}
+
+fn vec3<T>(x: T, y: T, z: T) -> Vec3<T> {
+    Vec3 { x, y, z }
+}
+
+fn main() {
+    let a = Pose {
+        pos: vec3(1.0f32, 2.0, 3.0),
+        dir: vec3(0.0, 1.0, 0.0),
+    };
+    let b = Pose {
+        pos: vec3(1.000_000_1, 2.0, 3.0),
+        dir: vec3(0.0, 1.0, 0.000_000_1),
+    };
+
+    assert_float_eq!(a, b, abs_all <= 0.000_000_2);
+    assert_float_ne!(a, b, abs_all <= 0.000_000_01);
+    assert_float_eq!(
+        a,
+        b,
+        ulps <= PoseUlps {
+            pos: Vec3Ulps { x: 1, y: 0, z: 0 },
+            dir: Vec3Ulps {
+                x: 0,
+                y: 0,
+                z: 0.000_000_1f32.to_bits()
+            }
+        }
+    );
+
+    let c = Pose {
+        pos: vec3(1.0f64, 2.0, 3.0),
+        dir: vec3(0.0, 1.0, 0.0),
+    };
+    assert_float_eq!(c, c, ulps_all <= 0);
+    assert_float_eq!(c.pos, vec3(1.0, 2.0, 3.000_000_000_000_000_5), ulps_all <= 1);
+
+    let d = Samples(1.0f64, [1.0, 2.0]);
+    let e = Samples(1.0f64, [1.0, 2.000_000_000_000_000_4]);
+    assert_float_eq!(d, e, abs <= Samples(0.0, [0.0, 0.000_000_000_000_000_5]));
+    assert_float_ne!(d, e, ulps <= SamplesUlps(0, [0, 0]));
+
+    let f = Tagged::Value(Samples(1.0f32, [2.0, 3.0, 4.0]));
+    let g = Tagged::Value(Samples(1.0f32, [2.0, 3.0, 4.000_000_5]));
+    assert_float_eq!(
+        f,
+        g,
+        ulps <= TaggedUlps::Value(SamplesUlps(0, [0, 0, 1]))
+    );
+    assert_float_ne!(
+        f,
+        Tagged::Fixed(1.0),
+        abs <= Tagged::Fixed(f32::INFINITY)
+    );
+}
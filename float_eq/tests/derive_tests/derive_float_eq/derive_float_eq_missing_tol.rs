@@ -0,0 +1,14 @@
+use float_eq::derive_float_eq;
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    debug_ulps_diff = "ReadingDebugUlpsDiff"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Reading {
+    value: f32,
+    #[float_eq(skip)]
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,67 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq, NanEq, NanEqBits};
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ReadingDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Reading {
+    time: f64,
+    value: NanEq<f64>,
+    history: [NanEq<f64>; 2],
+}
+
+#[derive_float_eq(
+    ulps_tol = "TaggedUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "TaggedDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Tagged(NanEqBits<f32>);
+
+fn main() {
+    let a = Reading {
+        time: 1.0,
+        value: NanEq(f64::NAN),
+        history: [NanEq(2.0), NanEq(f64::NAN)],
+    };
+    let b = Reading {
+        time: 1.0,
+        value: NanEq(-f64::NAN),
+        history: [NanEq(2.000_000_000_000_000_4), NanEq(f64::NAN)],
+    };
+
+    assert_ne!(a, b);
+    assert_eq!(a, Reading { ..a });
+    assert_float_eq!(a, b, abs_all <= 0.000_000_000_000_000_5);
+    assert_float_eq!(
+        a,
+        b,
+        ulps <= ReadingUlps {
+            time: 0,
+            value: 0,
+            history: [1, 0]
+        }
+    );
+    assert_float_ne!(a, b, ulps_all <= 0);
+
+    // the time is not wrapped, so it is never equal to NaN
+    let c = Reading {
+        time: f64::NAN,
+        ..a
+    };
+    assert_float_ne!(c, c, abs_all <= f64::INFINITY);
+
+    let d = Tagged(NanEqBits(f32::NAN));
+    assert_float_eq!(d, d, abs <= Tagged(NanEqBits(0.0)));
+    assert_float_eq!(d, d, ulps <= TaggedUlps(0));
+    assert_float_ne!(
+        d,
+        Tagged(NanEqBits(-f32::NAN)),
+        abs <= Tagged(NanEqBits(f32::INFINITY))
+    );
+}
@@ -0,0 +1,74 @@
+use float_eq::{assert_float_eq, derive_float_eq, AssertFloatEq, UlpsTol};
+
+#[derive_float_eq(
+    ulps_tol = "PointUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "PointDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f32",
+    serde
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive_float_eq(
+    ulps_tol = "PairUlps",
+    ulps_tol_derive = "Debug, PartialEq",
+    debug_ulps_diff = "PairDebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug, PartialEq",
+    serde
+)]
+#[derive(Debug, PartialEq)]
+struct Pair<T>(T, [T; 2]);
+
+#[derive_float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug, PartialEq",
+    serde
+)]
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Circle { centre: Point, radius: f64 },
+    Empty,
+}
+
+fn main() {
+    let ulps: PointUlps = serde_json::from_str(r#"{ "x": 1, "y": 2 }"#).unwrap();
+    assert_eq!(ulps, PointUlps { x: 1, y: 2 });
+    assert_eq!(serde_json::to_string(&ulps).unwrap(), r#"{"x":1,"y":2}"#);
+
+    let a = Point { x: 1.0, y: 2.0 };
+    let b = Point {
+        x: 1.000_000_1,
+        y: 2.0,
+    };
+    assert_float_eq!(a, b, ulps <= ulps);
+
+    let diff = a.debug_ulps_diff(&b);
+    assert_eq!(
+        serde_json::to_string(&diff).unwrap(),
+        r#"{"x":1,"y":0}"#
+    );
+
+    let pair: UlpsTol<Pair<f64>> = serde_json::from_str("[4, [0, 1]]").unwrap();
+    assert_eq!(pair, PairUlps(4, [0, 1]));
+    assert_eq!(serde_json::to_string(&pair).unwrap(), "[4,[0,1]]");
+    assert_float_eq!(Pair(1.0, [2.0, 3.0]), Pair(1.0, [2.0, 3.0]), ulps <= pair);
+
+    let shape = ShapeUlps::Circle {
+        centre: PointUlps { x: 1, y: 1 },
+        radius: 8,
+    };
+    let json = serde_json::to_string(&shape).unwrap();
+    assert_eq!(json, r#"{"Circle":{"centre":{"x":1,"y":1},"radius":8}}"#);
+    assert_eq!(serde_json::from_str::<ShapeUlps>(&json).unwrap(), shape);
+    assert_eq!(
+        serde_json::from_str::<ShapeUlps>(r#""Empty""#).unwrap(),
+        ShapeUlps::Empty
+    );
+}
@@ -0,0 +1,41 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq, InfAsMax, Strict};
+
+#[derive_float_eq(
+    ulps_tol = "BranchUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "BranchDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Branch {
+    re: Strict<f64>,
+    im: Strict<f64>,
+    limit: InfAsMax<f64>,
+}
+
+fn main() {
+    let a = Branch {
+        re: Strict(-1.0),
+        im: Strict(0.0),
+        limit: InfAsMax(f64::INFINITY),
+    };
+    let b = Branch {
+        im: Strict(-0.0),
+        limit: InfAsMax(f64::MAX),
+        ..a
+    };
+
+    assert_ne!(a, b);
+    assert_eq!(a, Branch { im: Strict(0.0), ..b });
+    assert_float_eq!(a, a, abs_all <= 0.0);
+    assert_float_ne!(a, b, abs_all <= 1.0);
+    let zero = Branch {
+        re: Strict(0.0),
+        im: Strict(0.0),
+        limit: InfAsMax(0.0),
+    };
+    assert_float_eq!(a, Branch { im: Strict(0.0), ..b }, abs <= zero);
+    assert_float_ne!(a, b, abs <= zero);
+    assert_float_eq!(Branch { im: Strict(-0.0), ..a }, b, ulps_all <= 0);
+}
@@ -0,0 +1,88 @@
+use float_eq::{check_float_eq, derive_float_eq, set_assert_message, AssertMessage};
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ReadingDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "ReadingTol",
+    debug_tol = "ReadingDebugTol",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Reading {
+    pressure: f32,
+    #[float_eq(abs <= 0.5)]
+    temperature: f32,
+    #[float_eq(exact)]
+    sensor: u8,
+    #[float_eq(skip)]
+    timestamp: u64,
+}
+
+#[derive_float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect(f64, f64),
+}
+
+fn main() {
+    set_assert_message(AssertMessage::Concise { limit: 10 });
+
+    let a = Reading {
+        pressure: 1.0,
+        temperature: 20.0,
+        sensor: 1,
+        timestamp: 0,
+    };
+    let b = Reading {
+        pressure: 1.5,
+        temperature: 20.75,
+        sensor: 2,
+        timestamp: 1,
+    };
+
+    let err = check_float_eq!(vec![a], vec![b], abs_all <= 1.0).unwrap_err();
+    assert_eq!(
+        err.diffs(),
+        Some(
+            " [abs_all] 2 failures:
+           [0].temperature: left: `20.0`, right: `20.75`, abs_diff: `0.75`, ulps_diff: `Some(393216)`, t: `0.5`
+           [0].sensor: left: `1`, right: `2`"
+        )
+    );
+
+    let err =
+        check_float_eq!(vec![a], vec![b], ulps <= vec![ReadingUlps { pressure: 4 }]).unwrap_err();
+    assert_eq!(
+        err.diffs(),
+        Some(
+            "    [ulps] 3 failures:
+           [0].pressure: left: `1.0`, right: `1.5`, abs_diff: `0.5`, ulps_diff: `Some(4194304)`, t: `4`
+           [0].temperature: left: `20.0`, right: `20.75`, abs_diff: `0.75`, ulps_diff: `Some(393216)`, t: `0.5`
+           [0].sensor: left: `1`, right: `2`"
+        )
+    );
+
+    let err = check_float_eq!(
+        vec![Shape::Rect(1.0, 2.0), Shape::Circle { radius: 1.0 }],
+        vec![Shape::Rect(1.0, 2.5), Shape::Rect(1.0, 1.0)],
+        rmax_all <= 0.1
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.diffs(),
+        Some(
+            "[rmax_all] 2 failures:
+           [0].1: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(1125899906842624)`, t: `0.25`
+           [1]: variants differ"
+        )
+    );
+}
@@ -0,0 +1,72 @@
+use float_eq::{
+    AssertDiffs, AssertFloatEq, AssertFloatEqAll, AssertFloatEqAllDiffs, AssertFloatEqDiffs,
+    FloatEq, FloatEqAll, FloatEqDebugUlpsDiff, FloatEqUlpsTol, ReportTol,
+};
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    FloatEqUlpsTol,
+    FloatEq,
+    FloatEqAll,
+    FloatEqDebugUlpsDiff,
+    AssertFloatEq,
+    AssertFloatEqAll,
+    AssertFloatEqDiffs,
+    AssertFloatEqAllDiffs,
+)]
+#[float_eq(
+    ulps_tol = "MyComplexUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "MyComplexDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "T"
+)]
+struct MyComplex<T> {
+    re: T,
+    im: T,
+}
+
+fn main() {
+    let a = MyComplex {
+        re: 2.0_f32,
+        im: -1.0,
+    };
+    let b = MyComplex {
+        re: 2.5_f32,
+        im: -1.0,
+    };
+
+    let mut diffs = AssertDiffs::new(10);
+    let tol = MyComplex { re: 0.25, im: 0.25 };
+    assert!(!a.debug_diffs(&b, ReportTol::Abs(&tol), "[3]", &mut diffs));
+    assert_eq!(
+        diffs.iter().collect::<Vec<_>>(),
+        vec!["[3].re: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `0.25`"]
+    );
+
+    let mut diffs = AssertDiffs::new(10);
+    let tol = MyComplexUlps { re: 4, im: 0 };
+    assert!(!a.debug_diffs(&b, ReportTol::Ulps(&tol), "", &mut diffs));
+    assert_eq!(
+        diffs.iter().collect::<Vec<_>>(),
+        vec![".re: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `4`"]
+    );
+
+    let mut diffs = AssertDiffs::new(10);
+    let b = MyComplex {
+        re: 2.5_f32,
+        im: -1.5,
+    };
+    assert!(!a.debug_all_diffs(&b, ReportTol::Rmax(&0.1), "", &mut diffs));
+    assert_eq!(diffs.count(), 2);
+    assert_eq!(
+        diffs.iter().collect::<Vec<_>>(),
+        vec![
+            ".re: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `0.25`",
+            ".im: left: `-1.0`, right: `-1.5`, abs_diff: `0.5`, ulps_diff: `Some(4194304)`, t: `0.15`",
+        ]
+    );
+}
@@ -0,0 +1,39 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq};
+
+#[derive_float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "ShapeTol",
+    tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_tol = "ShapeDebugTol",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+    Circle {
+        #[float_eq(skip)]
+        id: u32,
+        radius: f64,
+    },
+    Rect(#[float_eq(exact)] u32, f64, #[float_eq(abs <= 0.5)] f64),
+}
+
+fn main() {
+    let a = Shape::Circle { id: 1, radius: 1.0 };
+    let b = Shape::Circle {
+        id: 2,
+        radius: 1.000_000_000_000_000_2,
+    };
+    assert_float_eq!(a, b, ulps <= ShapeUlps::Circle { radius: 1 });
+    assert_float_eq!(a, b, abs_all <= 0.000_000_000_000_000_3);
+
+    let c = Shape::Rect(1, 2.0, 3.0);
+    let d = Shape::Rect(1, 2.0, 3.25);
+    assert_float_eq!(c, d, ulps <= ShapeUlps::Rect(0));
+    assert_float_eq!(c, d, abs <= ShapeTol::Rect(0.0));
+    assert_float_eq!(a, b, rmax <= ShapeTol::Circle { radius: 0.000_000_000_000_000_3 });
+    assert_float_ne!(c, Shape::Rect(2, 2.0, 3.0), abs_all <= 1.0);
+    assert_float_ne!(a, c, abs_all <= f64::INFINITY);
+}
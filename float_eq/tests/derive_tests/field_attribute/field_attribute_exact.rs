@@ -0,0 +1,55 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq};
+
+// Exactly compared fields do not need to implement Default
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Unit {
+    Metres,
+    Feet,
+}
+
+#[derive_float_eq(
+    ulps_tol = "LengthUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "LengthDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "LengthTol",
+    tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_tol = "LengthDebugTol",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Length {
+    value: f64,
+    #[float_eq(exact)]
+    unit: Unit,
+    #[float_eq(exact)]
+    origin: f64,
+}
+
+fn main() {
+    let a = Length {
+        value: 1.0,
+        unit: Unit::Metres,
+        origin: 0.0,
+    };
+    let b = Length {
+        value: 1.000_000_000_000_000_2,
+        ..a
+    };
+    assert_float_eq!(a, b, ulps <= LengthUlps { value: 1 });
+    assert_float_eq!(a, b, abs_all <= 0.1);
+    assert_float_eq!(a, b, abs <= LengthTol { value: 0.1 });
+
+    let c = Length {
+        unit: Unit::Feet,
+        ..a
+    };
+    assert_float_ne!(a, c, abs_all <= f64::INFINITY);
+    assert_float_ne!(a, c, ulps <= LengthUlps { value: u64::MAX });
+
+    let d = Length {
+        origin: 0.000_000_000_000_000_1,
+        ..a
+    };
+    assert_float_ne!(a, d, abs_all <= 0.1);
+}
@@ -0,0 +1,76 @@
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq};
+
+const TEMPERATURE_TOL: f32 = 0.5;
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ReadingDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "ReadingTol",
+    tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_tol = "ReadingDebugTol",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Reading {
+    pressure: f32,
+    #[float_eq(abs <= TEMPERATURE_TOL)]
+    temperature: f32,
+    #[float_eq(ulps <= 4)]
+    humidity: f32,
+    #[float_eq(rmax <= 0.1)]
+    altitude: f32,
+    #[float_eq(atol_rtol <= (0.01, 0.1))]
+    depth: f32,
+}
+
+fn main() {
+    let a = Reading {
+        pressure: 1.0,
+        temperature: 20.0,
+        humidity: 0.5,
+        altitude: 100.0,
+        depth: 10.0,
+    };
+    let b = Reading {
+        pressure: 1.000_000_1,
+        temperature: 20.4,
+        humidity: 0.500_000_2,
+        altitude: 105.0,
+        depth: 11.0,
+    };
+    assert_float_eq!(
+        a,
+        b,
+        ulps <= ReadingUlps { pressure: 1 }
+    );
+    assert_float_ne!(a, b, ulps <= ReadingUlps { pressure: 0 });
+    assert_float_eq!(a, b, abs_all <= 0.000_000_2);
+    assert_float_eq!(a, b, r2nd_all <= 0.000_000_2);
+
+    let c = Reading {
+        temperature: 20.6,
+        ..a
+    };
+    assert_float_ne!(a, c, abs_all <= f32::INFINITY);
+
+    let d = Reading {
+        humidity: 0.500_001,
+        ..a
+    };
+    assert_float_ne!(a, d, ulps_all <= u32::MAX);
+
+    let e = Reading {
+        altitude: 120.0,
+        ..a
+    };
+    assert_float_ne!(
+        a,
+        e,
+        rmin <= ReadingTol { pressure: 0.0 }
+    );
+
+    let f = Reading { depth: 8.9, ..a };
+    assert_float_ne!(a, f, abs_all <= f32::INFINITY);
+}
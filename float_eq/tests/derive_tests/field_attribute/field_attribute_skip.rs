@@ -0,0 +1,113 @@
+use core::marker::PhantomData;
+use core::num::NonZeroU32;
+use float_eq::{assert_float_eq, assert_float_ne, derive_float_eq};
+
+#[derive_float_eq(
+    ulps_tol = "SampleUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "SampleDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "SampleTol",
+    tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_tol = "SampleDebugTol",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sample {
+    value: f64,
+    #[float_eq(skip)]
+    timestamp: u64,
+    error: f64,
+}
+
+#[derive_float_eq(
+    ulps_tol = "LabelledUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "LabelledDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "LabelledTol",
+    tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_tol = "LabelledDebugTol",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, PartialEq)]
+struct Labelled(#[float_eq(skip)] String, f32);
+
+// Skipped fields do not need to implement Default or Clone
+#[derive_float_eq(
+    ulps_tol = "HandleUlps",
+    ulps_tol_derive = "Debug",
+    debug_ulps_diff = "HandleDebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug",
+    tol = "HandleTol",
+    debug_tol = "HandleDebugTol"
+)]
+#[derive(Debug, PartialEq)]
+struct Handle {
+    #[float_eq(skip)]
+    id: NonZeroU32,
+    weight: f64,
+}
+
+#[derive_float_eq(
+    ulps_tol = "TaggedUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "TaggedDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "TaggedTol",
+    tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_tol = "TaggedDebugTol",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Tagged<'a, U> {
+    value: f32,
+    #[float_eq(skip)]
+    tag: PhantomData<&'a U>,
+}
+
+fn main() {
+    let a = Sample {
+        value: 1.0,
+        timestamp: 1,
+        error: 0.1,
+    };
+    let b = Sample {
+        value: 1.000_000_000_000_000_2,
+        timestamp: 2,
+        error: 0.1,
+    };
+    assert_float_eq!(a, b, ulps <= SampleUlps { value: 1, error: 0 });
+    assert_float_ne!(a, b, ulps <= SampleUlps { value: 0, error: 0 });
+    assert_float_eq!(a, b, abs_all <= 0.000_000_000_000_000_3);
+    assert_float_eq!(a, b, rmax_all <= 0.000_000_000_000_000_3);
+
+    let c = Labelled("first".to_string(), 2.0);
+    let d = Labelled("second".to_string(), 2.000_000_2);
+    assert_float_eq!(c, d, ulps <= LabelledUlps(1));
+    assert_float_ne!(c, d, ulps_all <= 0);
+    assert_float_eq!(
+        c,
+        d,
+        abs <= LabelledTol(0.000_000_3)
+    );
+
+    let e: Tagged<'static, u8> = Tagged {
+        value: 1.0,
+        tag: PhantomData,
+    };
+    assert_float_eq!(e, e, ulps <= TaggedUlps { value: 0 });
+    assert_float_eq!(e, e, abs_all <= 0.0);
+    assert_float_eq!(e, e, abs <= TaggedTol { value: 0.0 });
+
+    let f = Handle {
+        id: NonZeroU32::new(1).unwrap(),
+        weight: 1.0,
+    };
+    let g = Handle {
+        id: NonZeroU32::new(2).unwrap(),
+        weight: 1.5,
+    };
+    assert_float_eq!(f, g, abs <= HandleTol { weight: 0.5 });
+    assert_float_ne!(f, g, rmax <= HandleTol { weight: 0.1 });
+}
@@ -0,0 +1,11 @@
+use float_eq::FloatEq;
+
+#[derive(Debug, PartialEq, FloatEq)]
+#[float_eq(ulps_tol = "MyComplex32Ulps")]
+struct MyComplex32 {
+    re: f32,
+    #[float_eq(skip, exact)]
+    im: f32,
+}
+
+fn main() {}
@@ -1,9 +1,48 @@
 use float_eq::{FloatEq, FloatEqUlpsTol};
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, FloatEqUlpsTol, FloatEq)]
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq)]
+#[float_eq(ulps_tol = "SomeEnumUlps")]
 enum SomeEnum {
-    Float(f32),
+    Complex { re: f32, im: f32 },
     Double(f64),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let a = SomeEnum::Complex {
+        re: 2.0,
+        im: -1_000_000.0,
+    };
+    let b = SomeEnum::Complex {
+        re: 2.000_000_5,
+        im: -1_000_000.06,
+    };
+    let complex = |re, im| SomeEnum::Complex { re, im };
+
+    assert!(a.eq_abs(&b, &complex(0.000_000_5, 0.07)));
+    assert!(a.ne_abs(&b, &complex(0.000_000_4, 0.07)));
+    assert!(a.ne_abs(&b, &complex(0.000_000_5, 0.06)));
+
+    assert!(a.eq_rel(&b, &complex(0.000_000_25, 0.000_000_1)));
+    assert!(a.ne_rel(&b, &complex(0.000_000_15, 0.000_000_1)));
+    assert!(a.ne_rel(&b, &complex(0.000_000_25, 0.000_000_05)));
+
+    assert!(a.eq_ulps(&b, &SomeEnumUlps::Complex { re: 2, im: 1 }));
+    assert!(a.ne_ulps(&b, &SomeEnumUlps::Complex { re: 1, im: 1 }));
+    assert!(a.ne_ulps(&b, &SomeEnumUlps::Complex { re: 2, im: 0 }));
+
+    let c = SomeEnum::Double(1.0);
+    let d = SomeEnum::Double(1.000_000_000_000_000_2);
+    assert!(c.eq_abs(&d, &SomeEnum::Double(0.000_000_000_000_000_3)));
+    assert!(c.eq_ulps(&d, &SomeEnumUlps::Double(1)));
+    assert!(c.ne_ulps(&d, &SomeEnumUlps::Double(0)));
+
+    assert!(SomeEnum::Empty.eq_abs(&SomeEnum::Empty, &SomeEnum::Empty));
+    assert!(SomeEnum::Empty.eq_ulps(&SomeEnum::Empty, &SomeEnumUlps::Empty));
+
+    // different variants, or a tolerance of a different variant, are never equal
+    assert!(a.ne_abs(&c, &complex(f32::INFINITY, f32::INFINITY)));
+    assert!(c.ne_abs(&c, &complex(f32::INFINITY, f32::INFINITY)));
+    assert!(c.ne_ulps(&c, &SomeEnumUlps::Empty));
+    assert!(a.ne_ulps(&SomeEnum::Empty, &SomeEnumUlps::Empty));
+}
@@ -1,9 +1,36 @@
 use float_eq::{FloatEq, FloatEqUlpsTol};
 
 #[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq)]
-struct MyComplexUlps<T> {
+#[float_eq(ulps_tol = "MyComplexUlps")]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+impl<T> MyComplex<T> {
+    fn new(re: T, im: T) -> MyComplex<T> {
+        MyComplex { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex::<f32>::new(2.0, -1_000_000.0);
+    let b = MyComplex::<f32>::new(2.000_000_5, -1_000_000.06);
+
+    assert!(a.eq_abs(&b, &MyComplex::new(0.000_000_5, 0.07)));
+    assert!(a.ne_abs(&b, &MyComplex::new(0.000_000_4, 0.07)));
+    assert!(a.ne_abs(&b, &MyComplex::new(0.000_000_5, 0.06)));
+
+    assert!(a.eq_rel(&b, &MyComplex::new(0.000_000_25, 0.000_000_1)));
+    assert!(a.ne_rel(&b, &MyComplex::new(0.000_000_15, 0.000_000_1)));
+    assert!(a.ne_rel(&b, &MyComplex::new(0.000_000_25, 0.000_000_05)));
+
+    assert!(a.eq_ulps(&b, &MyComplexUlps { re: 2, im: 1 }));
+    assert!(a.ne_ulps(&b, &MyComplexUlps { re: 1, im: 1 }));
+    assert!(a.ne_ulps(&b, &MyComplexUlps { re: 2, im: 0 }));
+
+    let c = MyComplex::<f64>::new(1.0, 2.0);
+    let d = MyComplex::<f64>::new(1.000_000_000_000_000_2, 2.0);
+    assert!(c.eq_ulps(&d, &MyComplexUlps { re: 1, im: 0 }));
+    assert!(c.ne_ulps(&d, &MyComplexUlps { re: 0, im: 0 }));
+}
@@ -25,6 +25,12 @@ fn main() {
     assert!(a.ne_rel(&b, &MyComplex32::new(0.000_000_15, 0.000_000_1)));
     assert!(a.ne_rel(&b, &MyComplex32::new(0.000_000_25, 0.000_000_05)));
 
+    let atol = MyComplex32::new(0.000_000_5, 0.0);
+    let rtol = MyComplex32::new(0.0, 0.000_000_1);
+    assert!(a.eq_atol_rtol(&b, &atol, &rtol));
+    assert!(a.ne_atol_rtol(&b, &MyComplex32::new(0.000_000_4, 0.0), &rtol));
+    assert!(a.ne_atol_rtol(&b, &atol, &MyComplex32::new(0.0, 0.000_000_05)));
+
     assert!(a.eq_ulps(&b, &MyComplex32Ulps { re: 2, im: 1 }));
     assert!(a.ne_ulps(&b, &MyComplex32Ulps { re: 1, im: 1 }));
     assert!(a.ne_ulps(&b, &MyComplex32Ulps { re: 2, im: 0 }));
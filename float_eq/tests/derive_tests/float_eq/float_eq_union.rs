@@ -0,0 +1,10 @@
+use float_eq::{FloatEq, FloatEqUlpsTol};
+
+#[derive(Clone, Copy, FloatEqUlpsTol, FloatEq)]
+#[float_eq(ulps_tol = "SomeUnionUlps")]
+union SomeUnion {
+    float: f32,
+    double: f64,
+}
+
+fn main() {}
@@ -1,9 +1,36 @@
 use float_eq::{FloatEq, FloatEqAll, FloatEqUlpsTol};
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, FloatEqUlpsTol, FloatEq, FloatEqAll)]
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqAll)]
+#[float_eq(ulps_tol = "SomeEnumUlps", all_tol = "f32")]
 enum SomeEnum {
+    Complex { re: f32, im: f32 },
     Float(f32),
-    Double(f64),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let a = SomeEnum::Complex {
+        re: 2.0,
+        im: -1_000_000.0,
+    };
+    let b = SomeEnum::Complex {
+        re: 2.000_000_5,
+        im: -1_000_000.06,
+    };
+
+    assert!(a.eq_abs_all(&b, &0.07));
+    assert!(a.ne_abs_all(&b, &0.06));
+
+    assert!(a.eq_rel_all(&b, &0.000_000_25));
+    assert!(a.ne_rel_all(&b, &0.000_000_15));
+
+    assert!(a.eq_ulps_all(&b, &2));
+    assert!(a.ne_ulps_all(&b, &1));
+
+    assert!(SomeEnum::Float(1.0).eq_abs_all(&SomeEnum::Float(1.5), &0.5));
+    assert!(SomeEnum::Empty.eq_abs_all(&SomeEnum::Empty, &0.0));
+
+    // different variants are never equal
+    assert!(a.ne_abs_all(&SomeEnum::Float(2.0), &f32::INFINITY));
+    assert!(SomeEnum::Empty.ne_ulps_all(&SomeEnum::Float(2.0), &u32::MAX));
+}
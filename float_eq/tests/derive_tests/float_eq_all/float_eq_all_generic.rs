@@ -1,9 +1,33 @@
 use float_eq::{FloatEq, FloatEqAll, FloatEqUlpsTol};
 
 #[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqAll)]
-struct MyComplexUlps<T> {
+#[float_eq(ulps_tol = "MyComplexUlps", all_tol = "T")]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+impl<T> MyComplex<T> {
+    fn new(re: T, im: T) -> MyComplex<T> {
+        MyComplex { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex::<f32>::new(2.0, -1_000_000.0);
+    let b = MyComplex::<f32>::new(2.000_000_5, -1_000_000.06);
+
+    assert!(a.eq_abs_all(&b, &0.07));
+    assert!(a.ne_abs_all(&b, &0.06));
+
+    assert!(a.eq_rel_all(&b, &0.000_000_25));
+    assert!(a.ne_rel_all(&b, &0.000_000_15));
+
+    assert!(a.eq_ulps_all(&b, &2));
+    assert!(a.ne_ulps_all(&b, &1));
+
+    let c = MyComplex::<f64>::new(1.0, 2.0);
+    let d = MyComplex::<f64>::new(1.000_000_000_000_000_2, 2.0);
+    assert!(c.eq_ulps_all(&d, &1u64));
+    assert!(c.ne_ulps_all(&d, &0u64));
+}
@@ -0,0 +1,63 @@
+use float_eq::{
+    AssertFloatEq, AssertFloatOrd, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol, FloatOrd,
+    FloatOrdOp, UlpsTol,
+};
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    FloatEqUlpsTol,
+    FloatEq,
+    FloatEqDebugUlpsDiff,
+    AssertFloatEq,
+    FloatOrd,
+    AssertFloatOrd,
+)]
+#[float_eq(
+    ulps_tol = "SomeEnumUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "SomeEnumDebugUlpsDiff"
+)]
+enum SomeEnum {
+    Complex { re: f32, im: f32 },
+    Float(f32),
+    Empty,
+}
+
+fn complex(re: f32, im: f32) -> SomeEnum {
+    SomeEnum::Complex { re, im }
+}
+
+fn main() {
+    let a = complex(1.0, 2.0);
+    let b = complex(1.5, 1.75);
+
+    assert!(a.ord_abs(&b, FloatOrdOp::Le, &complex(0.0, 0.25)));
+    assert!(!a.ord_abs(&b, FloatOrdOp::Le, &complex(0.0, 0.125)));
+    assert!(SomeEnum::Float(1.0).ord_rmax(
+        &SomeEnum::Float(2.0),
+        FloatOrdOp::Lt,
+        &SomeEnum::Float(0.25)
+    ));
+    assert!(SomeEnum::Empty.ord_abs(&SomeEnum::Empty, FloatOrdOp::Lt, &SomeEnum::Empty));
+    assert!(a.ord_ulps(
+        &b,
+        FloatOrdOp::Ge,
+        &UlpsTol::<SomeEnum>::Complex {
+            re: 4_194_304,
+            im: 0
+        }
+    ));
+
+    // different variants cannot be ordered
+    assert!(!a.ord_abs(&SomeEnum::Float(2.0), FloatOrdOp::Le, &complex(0.0, 0.0)));
+    assert!(!a.ord_ulps(&b, FloatOrdOp::Le, &SomeEnumUlps::Empty));
+
+    assert_eq!(
+        a.debug_ord_abs(&b, FloatOrdOp::Le, &complex(0.0, 0.125)),
+        Some(complex(-0.5, 0.125))
+    );
+    assert_eq!(a.debug_ord_abs(&b, FloatOrdOp::Le, &SomeEnum::Empty), None);
+}
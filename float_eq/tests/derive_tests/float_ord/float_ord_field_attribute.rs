@@ -0,0 +1,46 @@
+use float_eq::{derive_float_eq, AssertFloatOrd, FloatOrd, FloatOrdOp};
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    debug_ulps_diff = "ReadingDebugUlpsDiff",
+    tol = "ReadingTol",
+    debug_tol = "ReadingDebugTol"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, float_eq::FloatOrd, float_eq::AssertFloatOrd)]
+struct Reading {
+    value: f32,
+    #[float_eq(abs <= 0.5)]
+    offset: f32,
+    #[float_eq(exact)]
+    id: u32,
+    #[float_eq(skip)]
+    label: &'static str,
+}
+
+fn main() {
+    let a = Reading {
+        value: 1.0,
+        offset: 2.25,
+        id: 1,
+        label: "a",
+    };
+    let b = Reading {
+        value: 2.0,
+        offset: 2.0,
+        id: 1,
+        label: "b",
+    };
+    let tol = ReadingTol { value: 0.0 };
+
+    // the fixed check of `offset` ignores the caller's tolerance
+    assert!(a.ord_abs(&b, FloatOrdOp::Le, &tol));
+    assert!(!a.ord_abs(&b, FloatOrdOp::Lt, &tol));
+    assert!(a.ord_rmax(&b, FloatOrdOp::Le, &tol));
+
+    // exact fields must be equal
+    assert!(!a.ord_abs(&Reading { id: 2, ..b }, FloatOrdOp::Le, &tol));
+
+    let past_bound = a.debug_ord_abs(&b, FloatOrdOp::Le, &tol);
+    assert_eq!(past_bound.value, -1.0);
+    assert_eq!(past_bound.offset, -0.25);
+}
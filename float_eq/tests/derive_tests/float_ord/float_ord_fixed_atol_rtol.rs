@@ -0,0 +1,11 @@
+use float_eq::{FloatEq, FloatEqUlpsTol, FloatOrd};
+
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatOrd)]
+#[float_eq(ulps_tol = "ReadingUlps", tol = "ReadingTol", debug_tol = "ReadingDebugTol")]
+struct Reading {
+    value: f32,
+    #[float_eq(atol_rtol <= (0.5, 0.1))]
+    offset: f32,
+}
+
+fn main() {}
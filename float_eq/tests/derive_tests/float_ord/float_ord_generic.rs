@@ -0,0 +1,47 @@
+use float_eq::{
+    assert_float_le, AssertFloatEq, AssertFloatOrd, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol,
+    FloatOrd, FloatOrdOp,
+};
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    FloatEqUlpsTol,
+    FloatEq,
+    FloatEqDebugUlpsDiff,
+    AssertFloatEq,
+    FloatOrd,
+    AssertFloatOrd,
+)]
+#[float_eq(
+    ulps_tol = "MyComplexUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "MyComplexDebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug"
+)]
+struct MyComplex<T> {
+    re: T,
+    im: T,
+}
+
+fn main() {
+    let a = MyComplex {
+        re: 1.0_f64,
+        im: 2.0,
+    };
+    let b = MyComplex {
+        re: 1.5_f64,
+        im: 2.0,
+    };
+
+    assert!(a.ord_abs(&b, FloatOrdOp::Le, &MyComplex { re: 0.0, im: 0.0 }));
+    assert!(!a.ord_abs(&b, FloatOrdOp::Lt, &MyComplex { re: 0.0, im: 0.0 }));
+    assert!(a.ord_ulps(&b, FloatOrdOp::Le, &MyComplexUlps { re: 0, im: 0 }));
+    assert_float_le!(a, b, abs <= MyComplex { re: 0.0, im: 0.0 });
+    assert_eq!(
+        a.debug_ord_abs(&b, FloatOrdOp::Le, &MyComplex { re: 0.0, im: 0.0 }),
+        MyComplex { re: -0.5, im: 0.0 }
+    );
+}
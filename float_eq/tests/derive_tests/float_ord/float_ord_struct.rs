@@ -0,0 +1,78 @@
+use float_eq::{
+    assert_float_ge, assert_float_le, assert_float_lt, float_ge, float_gt, float_le, AssertFloatEq,
+    AssertFloatOrd, FloatEq, FloatEqDebugUlpsDiff, FloatEqUlpsTol, FloatOrd, FloatOrdOp,
+};
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    FloatEqUlpsTol,
+    FloatEq,
+    FloatEqDebugUlpsDiff,
+    AssertFloatEq,
+    FloatOrd,
+    AssertFloatOrd,
+)]
+#[float_eq(
+    ulps_tol = "MyComplex32Ulps",
+    debug_ulps_diff = "MyComplex32DebugUlpsDiff",
+    debug_ulps_diff_derive = "Debug"
+)]
+struct MyComplex32 {
+    re: f32,
+    im: f32,
+}
+
+impl MyComplex32 {
+    fn new(re: f32, im: f32) -> MyComplex32 {
+        MyComplex32 { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex32::new(2.0, 4.0);
+    let b = MyComplex32::new(2.5, 3.75);
+
+    // every field must satisfy the ordering
+    assert!(!a.ord_abs(&b, FloatOrdOp::Le, &MyComplex32::new(0.0, 0.0)));
+    assert!(a.ord_abs(&b, FloatOrdOp::Le, &MyComplex32::new(0.0, 0.25)));
+    assert!(!a.ord_abs(&b, FloatOrdOp::Lt, &MyComplex32::new(0.0, 0.25)));
+    assert!(a.ord_ulps(
+        &b,
+        FloatOrdOp::Le,
+        &MyComplex32Ulps {
+            re: 0,
+            im: 1_048_576
+        }
+    ));
+    assert!(!a.ord_ulps(
+        &b,
+        FloatOrdOp::Le,
+        &MyComplex32Ulps {
+            re: 0,
+            im: 1_048_575
+        }
+    ));
+
+    assert!(float_le!(a, b, abs <= MyComplex32::new(0.0, 0.25)));
+    assert!(float_ge!(b, a, abs <= MyComplex32::new(0.25, 0.25)));
+    assert!(!float_gt!(b, a, abs <= MyComplex32::new(0.25, 0.0)));
+    assert_float_le!(a, b, rmax <= MyComplex32::new(0.0, 0.0625));
+    assert_float_ge!(b, a, r2nd <= MyComplex32::new(0.0, 0.0625));
+    assert_float_lt!(
+        MyComplex32::new(2.0, 3.0),
+        b,
+        abs <= MyComplex32::new(0.25, 0.5)
+    );
+
+    assert_eq!(
+        a.debug_ord_abs(&b, FloatOrdOp::Le, &MyComplex32::new(0.125, 0.125)),
+        MyComplex32::new(-0.625, 0.125)
+    );
+    assert_eq!(
+        a.debug_ord_rmax(&b, FloatOrdOp::Ge, &MyComplex32::new(0.0, 0.0)),
+        MyComplex32::new(0.5, -0.25)
+    );
+}
@@ -0,0 +1,73 @@
+use float_eq::{compare, derive_float_eq, Mismatch, ReportKind};
+
+#[derive_float_eq(
+    ulps_tol = "ReadingUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ReadingDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    tol = "ReadingTol",
+    debug_tol = "ReadingDebugTol",
+    all_tol = "f32"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Reading {
+    pressure: f32,
+    #[float_eq(abs <= 0.5)]
+    temperature: f32,
+    #[float_eq(exact)]
+    sensor: u8,
+    #[float_eq(skip)]
+    timestamp: u64,
+}
+
+#[derive_float_eq(
+    ulps_tol = "ShapeUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq",
+    debug_ulps_diff = "ShapeDebugUlpsDiff",
+    debug_ulps_diff_derive = "Clone, Copy, Debug, PartialEq",
+    all_tol = "f64"
+)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect(f64, f64),
+}
+
+fn main() {
+    let a = Reading {
+        pressure: 1.0,
+        temperature: 20.0,
+        sensor: 1,
+        timestamp: 0,
+    };
+    let b = Reading {
+        pressure: 1.5,
+        temperature: 20.75,
+        sensor: 2,
+        timestamp: 1,
+    };
+
+    let report = compare!(a, b, abs_all <= 1.0);
+    let paths: Vec<_> = report.failures().map(|node| node.path.as_str()).collect();
+    assert_eq!(paths, vec![".temperature", ".sensor"]);
+    assert_eq!(report.nodes().count(), 4);
+
+    let report = compare!(vec![a], vec![b], ulps_all <= 0);
+    let paths: Vec<_> = report.failures().map(|node| node.path.as_str()).collect();
+    assert_eq!(paths, vec!["[0].pressure", "[0].temperature", "[0].sensor"]);
+
+    let report = compare!(
+        Shape::Rect(1.0, 2.0),
+        Shape::Rect(1.0, 2.5),
+        rmax <= Shape::Rect(0.0, 0.1)
+    );
+    let paths: Vec<_> = report.failures().map(|node| node.path.as_str()).collect();
+    assert_eq!(paths, vec![".1"]);
+
+    let report = compare!(
+        Shape::Circle { radius: 1.0 },
+        Shape::Rect(1.0, 1.0),
+        abs_all <= f64::INFINITY
+    );
+    assert_eq!(report.root.kind, ReportKind::Mismatch(Mismatch::Variant));
+}
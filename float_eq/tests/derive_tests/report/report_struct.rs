@@ -0,0 +1,48 @@
+use float_eq::{compare, FloatEq, FloatEqReport, FloatEqUlpsTol, ReportKind, ReportTol};
+
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol, FloatEq, FloatEqReport)]
+#[float_eq(ulps_tol = "MyComplex32Ulps")]
+struct MyComplex32 {
+    re: f32,
+    im: f32,
+}
+
+impl MyComplex32 {
+    fn new(re: f32, im: f32) -> MyComplex32 {
+        MyComplex32 { re, im }
+    }
+}
+
+fn main() {
+    let a = MyComplex32::new(2.0, -1_000_000.0);
+    let b = MyComplex32::new(2.000_000_5, -1_000_000.06);
+
+    let report = compare!(a, b, abs <= MyComplex32::new(0.000_000_5, 0.07));
+    assert!(report.passed());
+    assert_eq!(report.nodes().count(), 3);
+
+    let report = compare!(a, b, ulps <= MyComplex32Ulps { re: 2, im: 0 });
+    let failures: Vec<_> = report.failures().collect();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].path, ".im");
+
+    let atol = MyComplex32::new(0.000_000_5, 0.0);
+    let rtol = MyComplex32::new(0.0, 0.000_000_05);
+    let report = compare!(a, b, atol_rtol <= (atol, rtol));
+    assert_eq!(report.check, "atol_rtol");
+    let failures: Vec<_> = report.failures().collect();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].path, ".im");
+
+    let tol = MyComplex32::new(0.0, 0.1);
+    let node = a.report(&b, ReportTol::Abs(&tol), String::from("a"));
+    assert!(!node.passed);
+    if let ReportKind::Composite(children) = node.kind {
+        assert_eq!(children[0].path, "a.re");
+        assert!(!children[0].passed);
+        assert_eq!(children[1].path, "a.im");
+        assert!(children[1].passed);
+    } else {
+        panic!("expected a composite node");
+    }
+}
@@ -1,9 +1,24 @@
-use float_eq::FloatEqUlpsTol;
+use float_eq::{FloatEqUlpsTol, UlpsTol};
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, FloatEqUlpsTol)]
+#[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol)]
+#[float_eq(
+    ulps_tol = "SomeEnumUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq"
+)]
 enum SomeEnum {
-    Float(f32),
+    Complex { re: f32, im: f32 },
     Double(f64),
+    Empty,
 }
 
-fn main() {}
+fn main() {
+    let a = UlpsTol::<SomeEnum>::Complex { re: 1, im: 2 };
+    let b = a; // Clone, Copy
+
+    // Debug, PartialEq
+    assert_eq!(a, b);
+    assert_ne!(a, SomeEnumUlps::Complex { re: 3, im: 2 });
+    assert_ne!(a, SomeEnumUlps::Double(1));
+    assert_ne!(a, SomeEnumUlps::Empty);
+    assert_eq!(SomeEnumUlps::Double(1), SomeEnumUlps::Double(1));
+}
@@ -1,9 +1,24 @@
-use float_eq::FloatEqUlpsTol;
+use float_eq::{FloatEqUlpsTol, UlpsTol};
 
 #[derive(Debug, Clone, Copy, PartialEq, FloatEqUlpsTol)]
-struct MyComplexUlps<T> {
+#[float_eq(
+    ulps_tol = "MyComplexUlps",
+    ulps_tol_derive = "Clone, Copy, Debug, PartialEq"
+)]
+struct MyComplex<T> {
     re: T,
     im: T,
 }
 
-fn main() {}
+fn main() {
+    let a = UlpsTol::<MyComplex<f32>> { re: 1, im: 2 };
+    let b = a; // Clone, Copy
+
+    // Debug, PartialEq
+    assert_eq!(a, b);
+    assert_ne!(a, MyComplexUlps::<f32> { re: 3, im: 2 });
+    assert_ne!(a, MyComplexUlps::<f32> { re: 1, im: 3 });
+
+    let c: UlpsTol<MyComplex<f64>> = MyComplexUlps { re: 1u64, im: 2 };
+    assert_eq!(c, MyComplexUlps { re: 1, im: 2 });
+}
@@ -106,6 +106,10 @@ impl FloatEq<f32> for MyComplex32 {
         self.re.eq_r2nd(other, tol) && self.im.eq_r2nd(&0.0, tol)
     }
 
+    fn eq_atol_rtol(&self, other: &f32, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.re.eq_atol_rtol(other, atol, rtol) && self.im.eq_atol_rtol(&0.0_f32, atol, rtol)
+    }
+
     fn eq_ulps(&self, other: &f32, tol: &UlpsTol<Self::Tol>) -> bool {
         self.re.eq_ulps(other, tol) && self.im.eq_ulps(&0.0, tol)
     }
@@ -134,6 +138,10 @@ impl FloatEq<MyComplex32> for f32 {
         self.eq_r2nd(&other.re, tol) && 0.0.eq_r2nd(&other.im, tol)
     }
 
+    fn eq_atol_rtol(&self, other: &MyComplex32, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.eq_atol_rtol(&other.re, atol, rtol) && 0.0_f32.eq_atol_rtol(&other.im, atol, rtol)
+    }
+
     fn eq_ulps(&self, other: &MyComplex32, tol: &UlpsTol<Self::Tol>) -> bool {
         other.eq_ulps(self, tol)
     }
@@ -331,6 +339,18 @@ impl AssertFloatEq<f32> for MyComplex32 {
         }
     }
 
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &f32,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        MyComplex32 {
+            re: self.re.debug_atol_rtol_tol(other, atol, rtol),
+            im: self.im.debug_atol_rtol_tol(&0.0_f32, atol, rtol),
+        }
+    }
+
     fn debug_ulps_tol(&self, other: &f32, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol> {
         UlpsTol::<Self::DebugTol> {
             re: self.re.debug_ulps_tol(other, tol),
@@ -377,6 +397,18 @@ impl AssertFloatEq<MyComplex32> for f32 {
         }
     }
 
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &MyComplex32,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        MyComplex32 {
+            re: self.debug_atol_rtol_tol(&other.re, atol, rtol),
+            im: 0.0_f32.debug_atol_rtol_tol(&other.im, atol, rtol),
+        }
+    }
+
     fn debug_ulps_tol(
         &self,
         other: &MyComplex32,
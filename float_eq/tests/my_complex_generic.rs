@@ -123,6 +123,11 @@ where
         self.re.eq_r2nd(&other.re, &tol.re) && self.im.eq_r2nd(&other.im, &tol.im)
     }
 
+    fn eq_atol_rtol(&self, other: &Self, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+        self.re.eq_atol_rtol(&other.re, &atol.re, &rtol.re)
+            && self.im.eq_atol_rtol(&other.im, &atol.im, &rtol.im)
+    }
+
     fn eq_ulps(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> bool {
         self.re.eq_ulps(&other.re, &tol.re) && self.im.eq_ulps(&other.im, &tol.im)
     }
@@ -169,27 +174,32 @@ where
     type AllTol = T::AllTol;
 
     fn eq_abs_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
-        self.re.eq_abs_all(&other.re, &tol) && self.im.eq_abs_all(&other.im, &tol)
+        self.re.eq_abs_all(&other.re, tol) && self.im.eq_abs_all(&other.im, tol)
     }
 
     fn eq_rmax_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
-        self.re.eq_rmax_all(&other.re, &tol) && self.im.eq_rmax_all(&other.im, &tol)
+        self.re.eq_rmax_all(&other.re, tol) && self.im.eq_rmax_all(&other.im, tol)
     }
 
     fn eq_rmin_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
-        self.re.eq_rmin_all(&other.re, &tol) && self.im.eq_rmin_all(&other.im, &tol)
+        self.re.eq_rmin_all(&other.re, tol) && self.im.eq_rmin_all(&other.im, tol)
     }
 
     fn eq_r1st_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
-        self.re.eq_r1st_all(&other.re, &tol) && self.im.eq_r1st_all(&other.im, &tol)
+        self.re.eq_r1st_all(&other.re, tol) && self.im.eq_r1st_all(&other.im, tol)
     }
 
     fn eq_r2nd_all(&self, other: &Self, tol: &Self::AllTol) -> bool {
-        self.re.eq_r2nd_all(&other.re, &tol) && self.im.eq_r2nd_all(&other.im, &tol)
+        self.re.eq_r2nd_all(&other.re, tol) && self.im.eq_r2nd_all(&other.im, tol)
+    }
+
+    fn eq_atol_rtol_all(&self, other: &Self, atol: &Self::AllTol, rtol: &Self::AllTol) -> bool {
+        self.re.eq_atol_rtol_all(&other.re, atol, rtol)
+            && self.im.eq_atol_rtol_all(&other.im, atol, rtol)
     }
 
     fn eq_ulps_all(&self, other: &Self, tol: &UlpsTol<Self::AllTol>) -> bool {
-        self.re.eq_ulps_all(&other.re, &tol) && self.im.eq_ulps_all(&other.im, &tol)
+        self.re.eq_ulps_all(&other.re, tol) && self.im.eq_ulps_all(&other.im, tol)
     }
 }
 
@@ -403,6 +413,18 @@ where
         }
     }
 
+    fn debug_atol_rtol_tol(
+        &self,
+        other: &Self,
+        atol: &Self::Tol,
+        rtol: &Self::Tol,
+    ) -> Self::DebugTol {
+        MyComplex {
+            re: self.re.debug_atol_rtol_tol(&other.re, &atol.re, &rtol.re),
+            im: self.im.debug_atol_rtol_tol(&other.im, &atol.im, &rtol.im),
+        }
+    }
+
     fn debug_ulps_tol(&self, other: &Self, tol: &UlpsTol<Self::Tol>) -> UlpsTol<Self::DebugTol> {
         UlpsTol::<Self::DebugTol> {
             re: self.re.debug_ulps_tol(&other.re, &tol.re),
@@ -517,6 +539,18 @@ where
         }
     }
 
+    fn debug_atol_rtol_all_tol(
+        &self,
+        other: &Self,
+        atol: &Self::AllTol,
+        rtol: &Self::AllTol,
+    ) -> Self::AllDebugTol {
+        Self::AllDebugTol {
+            re: self.re.debug_atol_rtol_all_tol(&other.re, atol, rtol),
+            im: self.im.debug_atol_rtol_all_tol(&other.im, atol, rtol),
+        }
+    }
+
     fn debug_ulps_all_tol(
         &self,
         other: &Self,
@@ -182,21 +182,45 @@ macro_rules! wrapper_tests {
 
 mod unit_tests {
     mod arrays;
+    mod asserts;
+    mod check;
     mod core_types;
     mod macros;
     mod primitives;
     mod tuples;
+    mod wrappers;
 
+    #[cfg(feature = "std")]
+    mod checker;
+    #[cfg(feature = "std")]
+    mod parse;
+    #[cfg(feature = "std")]
+    mod report;
     #[cfg(feature = "std")]
     mod std_types;
 
+    #[cfg(feature = "glam")]
+    mod glam;
+    #[cfg(feature = "half")]
+    mod half;
+    #[cfg(feature = "mint")]
+    mod mint;
+    #[cfg(feature = "nalgebra")]
+    mod nalgebra;
+    #[cfg(feature = "ndarray")]
+    mod ndarray;
     #[cfg(feature = "num")]
     mod num_complex;
+    #[cfg(feature = "proptest")]
+    mod proptest;
+    #[cfg(feature = "serde")]
+    mod serde;
 }
 
 struct Foo(f32, f64);
 
 #[test]
+#[allow(clippy::init_numbered_fields)]
 fn check() {
     let f = Foo { 0: 0.0, 1: 1.0 };
     assert_eq!(f.0, 0.0);
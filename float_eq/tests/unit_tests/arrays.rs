@@ -394,3 +394,31 @@ fn assert_fail_all_message() {
         ulps_all <= 3u64
     );
 }
+
+#[test]
+fn float_ord() {
+    use float_eq::{assert_float_ge, assert_float_lt, float_ge, float_gt, float_lt};
+
+    let a = [1.0f32, 2.0];
+    let b = [1.5f32, 2.5];
+
+    assert!(float_lt!(a, b, abs <= [0.25, 0.25]));
+    assert!(!float_lt!(a, b, abs <= [0.25, 0.5]));
+    assert!(float_ge!(a, b, abs <= [0.5, 0.5]));
+    assert!(!float_ge!(a, b, abs <= [0.5, 0.25]));
+    assert!(float_gt!(b, a, ulps <= [1, 1]));
+
+    assert_float_lt!(a, b, abs <= [0.25, 0.25]);
+    assert_float_ge!(a, b, rmax <= [0.5, 0.2]);
+}
+
+#[test]
+#[should_panic(expected = r#"`float_lt!(left, right, abs <= t)`
+        left: `[1.0, 2.0]`,
+       right: `[1.5, 2.5]`,
+    abs_diff: `[0.5, 0.5]`,
+   ulps_diff: `[Some(4194304), Some(2097152)]`,
+     [abs] t: `[0.25, 0.5]`, past_bound: `[-0.25, 0.0]`"#)]
+fn assert_ord_fail_message() {
+    float_eq::assert_float_lt!([1.0f32, 2.0], [1.5f32, 2.5], abs <= [0.25, 0.5]);
+}
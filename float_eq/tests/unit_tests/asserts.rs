@@ -0,0 +1,204 @@
+use float_eq::*;
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Once;
+
+fn panic_message(f: impl FnOnce()) -> String {
+    let err = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+    match err.downcast::<String>() {
+        Ok(message) => *message,
+        Err(err) => err.downcast::<&str>().unwrap().to_string(),
+    }
+}
+
+thread_local! {
+    static PANIC_LINE: RefCell<Option<(String, u32)>> = const { RefCell::new(None) };
+}
+
+// Records the location of each panic on this thread, then defers to the
+// previous hook so that other tests still report as usual.
+fn panic_location(f: impl FnOnce()) -> (String, u32) {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let prev = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let location = info.location().map(|l| (l.file().to_string(), l.line()));
+            PANIC_LINE.with(|line| *line.borrow_mut() = location);
+            prev(info)
+        }));
+    });
+    let _ = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+    PANIC_LINE.with(|line| line.borrow_mut().take().unwrap())
+}
+
+macro_rules! assert_same_panic {
+    ($func:expr, $macro:expr) => {
+        assert_eq!(panic_message(|| $func), panic_message(|| $macro));
+    };
+}
+
+#[test]
+fn eq() {
+    let a = 1.0_f32;
+    let b = 1.000_000_1_f32;
+
+    assert_eq_abs(&a, &b, &0.000_000_2);
+    assert_eq_rmax(&a, &b, &0.000_000_2);
+    assert_eq_rmin(&a, &b, &0.000_000_2);
+    assert_eq_r1st(&a, &b, &0.000_000_2);
+    assert_eq_r2nd(&a, &b, &0.000_000_2);
+    assert_eq_atol_rtol(&a, &b, &(0.0, 0.000_000_2));
+    assert_eq_ulps(&a, &b, &1);
+
+    assert_ne_abs(&a, &b, &0.0);
+    assert_ne_rmax(&a, &b, &0.0);
+    assert_ne_rmin(&a, &b, &0.0);
+    assert_ne_r1st(&a, &b, &0.0);
+    assert_ne_r2nd(&a, &b, &0.0);
+    assert_ne_atol_rtol(&a, &b, &(0.0, 0.0));
+    assert_ne_ulps(&a, &b, &0);
+}
+
+#[test]
+fn eq_all() {
+    let a = [1.0_f32, 2.0];
+    let b = [1.000_000_1_f32, 2.0];
+
+    assert_eq_abs_all(&a, &b, &0.000_000_2);
+    assert_eq_rmax_all(&a, &b, &0.000_000_2);
+    assert_eq_rmin_all(&a, &b, &0.000_000_2);
+    assert_eq_r1st_all(&a, &b, &0.000_000_2);
+    assert_eq_r2nd_all(&a, &b, &0.000_000_2);
+    assert_eq_atol_rtol_all(&a, &b, &(0.0, 0.000_000_2));
+    assert_eq_ulps_all(&a, &b, &1);
+
+    assert_ne_abs_all(&a, &b, &0.0);
+    assert_ne_rmax_all(&a, &b, &0.0);
+    assert_ne_rmin_all(&a, &b, &0.0);
+    assert_ne_r1st_all(&a, &b, &0.0);
+    assert_ne_r2nd_all(&a, &b, &0.0);
+    assert_ne_atol_rtol_all(&a, &b, &(0.0, 0.0));
+    assert_ne_ulps_all(&a, &b, &0);
+}
+
+#[test]
+fn eq_checks() {
+    let checks = [Check::Abs(0.000_1_f32), Check::Ulps(4)];
+    assert_eq_checks(&1.0_f32, &1.000_05_f32, &checks);
+    assert_eq_checks(&1.0_f32, &1.000_000_5_f32, &checks);
+    assert_ne_checks(&1.0_f32, &1.5_f32, &checks);
+
+    assert_eq_checks_all(&[1.0_f32, 2.0], &[1.000_05_f32, 2.0], &checks);
+    assert_ne_checks_all(&[1.0_f32, 2.0], &[1.5_f32, 2.0], &checks);
+}
+
+#[test]
+fn same_messages() {
+    let a = 1.0_f64;
+    let b = 1.5_f64;
+
+    assert_same_panic!(
+        assert_eq_abs(&a, &b, &0.1),
+        assert_float_eq!(a, b, abs <= 0.1)
+    );
+    assert_same_panic!(
+        assert_eq_rmax(&a, &b, &0.1),
+        assert_float_eq!(a, b, rmax <= 0.1)
+    );
+    assert_same_panic!(
+        assert_eq_rmin(&a, &b, &0.1),
+        assert_float_eq!(a, b, rmin <= 0.1)
+    );
+    assert_same_panic!(
+        assert_eq_r1st(&a, &b, &0.1),
+        assert_float_eq!(a, b, r1st <= 0.1)
+    );
+    assert_same_panic!(
+        assert_eq_r2nd(&a, &b, &0.1),
+        assert_float_eq!(a, b, r2nd <= 0.1)
+    );
+    assert_same_panic!(
+        assert_eq_atol_rtol(&a, &b, &(0.1, 0.1)),
+        assert_float_eq!(a, b, atol_rtol <= (0.1, 0.1))
+    );
+    assert_same_panic!(
+        assert_eq_ulps(&a, &b, &4),
+        assert_float_eq!(a, b, ulps <= 4_u64)
+    );
+    assert_same_panic!(
+        assert_ne_ulps(&a, &a, &4),
+        assert_float_ne!(a, a, ulps <= 4_u64)
+    );
+}
+
+#[test]
+fn same_messages_all() {
+    let a = [1.0_f32, 2.0];
+    let b = [1.5_f32, 2.0];
+
+    assert_same_panic!(
+        assert_eq_abs_all(&a, &b, &0.1),
+        assert_float_eq!(a, b, abs_all <= 0.1)
+    );
+    assert_same_panic!(
+        assert_eq_rmax_all(&a, &b, &0.1),
+        assert_float_eq!(a, b, rmax_all <= 0.1)
+    );
+    assert_same_panic!(
+        assert_eq_atol_rtol_all(&a, &b, &(0.1, 0.1)),
+        assert_float_eq!(a, b, atol_rtol_all <= (0.1, 0.1))
+    );
+    assert_same_panic!(
+        assert_eq_ulps_all(&a, &b, &4),
+        assert_float_eq!(a, b, ulps_all <= 4_u32)
+    );
+    assert_same_panic!(
+        assert_ne_r2nd_all(&a, &a, &0.1),
+        assert_float_ne!(a, a, r2nd_all <= 0.1)
+    );
+}
+
+#[test]
+fn same_messages_checks() {
+    let checks = [Check::Abs(0.1_f64), Check::Ulps(4)];
+
+    assert_same_panic!(
+        assert_eq_checks(&1.0_f64, &1.5_f64, &checks),
+        assert_float_eq!(1.0_f64, 1.5_f64, abs <= 0.1, ulps <= 4)
+    );
+    assert_same_panic!(
+        assert_ne_checks(&1.0_f64, &1.0_f64, &checks),
+        assert_float_ne!(1.0_f64, 1.0_f64, abs <= 0.1, ulps <= 4)
+    );
+    assert_same_panic!(
+        assert_eq_checks_all(&[1.0_f64], &[1.5_f64], &checks),
+        assert_float_eq!([1.0_f64], [1.5_f64], abs_all <= 0.1, ulps_all <= 4)
+    );
+}
+
+#[test]
+#[should_panic(expected = r#"assertion failed: `float_eq!(left, right, rmax <= t)`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+    [rmax] t: `0.15`"#)]
+fn eq_fail() {
+    assert_eq_rmax(&1.0_f32, &1.5_f32, &0.1);
+}
+
+#[test]
+fn caller_location() {
+    let line = line!() + 1;
+    let location = panic_location(|| assert_eq_abs(&1.0_f32, &2.0_f32, &0.5));
+    assert_eq!(location, (file!().to_string(), line));
+
+    let line = line!() + 1;
+    let location = panic_location(|| assert_ne_ulps_all(&[1.0_f64], &[1.0_f64], &0));
+    assert_eq!(location, (file!().to_string(), line));
+
+    let checks = [Check::Rmax(0.1_f32)];
+    let line = line!() + 1;
+    let location = panic_location(|| assert_eq_checks(&1.0_f32, &2.0_f32, &checks));
+    assert_eq!(location, (file!().to_string(), line));
+}
@@ -0,0 +1,238 @@
+use float_eq::{float_eq, Check};
+
+#[test]
+fn name() {
+    assert_eq!(Check::Abs(0.1_f32).name(), "abs");
+    assert_eq!(Check::Rmax(0.1_f32).name(), "rmax");
+    assert_eq!(Check::Rmin(0.1_f32).name(), "rmin");
+    assert_eq!(Check::R1st(0.1_f32).name(), "r1st");
+    assert_eq!(Check::R2nd(0.1_f32).name(), "r2nd");
+    assert_eq!(Check::AtolRtol(0.1_f32, 0.1).name(), "atol_rtol");
+    assert_eq!(Check::<f32>::Ulps(1).name(), "ulps");
+}
+
+#[test]
+fn traits() {
+    let check = Check::AtolRtol(0.1_f64, 0.2);
+    let copy = check;
+    assert_eq!(check, copy);
+    assert_ne!(check, Check::AtolRtol(0.1, 0.3));
+    assert_ne!(Check::Rmax(0.1_f64), Check::Rmin(0.1));
+    assert_eq!(Check::<f64>::Ulps(4), Check::Ulps(4));
+    assert_eq!(format!("{:?}", check), "AtolRtol(0.1, 0.2)");
+    assert_eq!(format!("{:?}", Check::<f32>::Ulps(4)), "Ulps(4)");
+}
+
+macro_rules! impl_tests {
+    ($float:ident) => {
+        mod $float {
+            use super::*;
+            use crate::$float::*;
+
+            #[test]
+            fn eq() {
+                let a: $float = 1.0;
+                let b = next_n(a, 2);
+                let tol = 2.0 * EPSILON;
+
+                let checks = [
+                    (Check::Abs(tol), float_eq!(a, b, abs <= tol)),
+                    (Check::Rmax(tol), float_eq!(a, b, rmax <= tol)),
+                    (Check::Rmin(tol), float_eq!(a, b, rmin <= tol)),
+                    (Check::R1st(tol), float_eq!(a, b, r1st <= tol)),
+                    (Check::R2nd(tol), float_eq!(a, b, r2nd <= tol)),
+                    (
+                        Check::AtolRtol(EPSILON, EPSILON),
+                        float_eq!(a, b, atol_rtol <= (EPSILON, EPSILON)),
+                    ),
+                    (Check::Ulps(2), float_eq!(a, b, ulps <= 2)),
+                    (Check::Ulps(1), float_eq!(a, b, ulps <= 1)),
+                ];
+                for (check, expected) in &checks {
+                    assert_eq!(check.eq(&a, &b), *expected, "{:?}", check);
+                    assert_eq!(check.ne(&a, &b), !*expected, "{:?}", check);
+                    assert_eq!(check.eq_all(&a, &b), *expected, "{:?}", check);
+                    assert_eq!(check.ne_all(&a, &b), !*expected, "{:?}", check);
+                }
+                assert!(!Check::Ulps(1).eq(&a, &b));
+                assert!(Check::Abs(INFINITY).ne(&nan_test_values()[0], &a));
+            }
+
+            #[test]
+            fn eq_all() {
+                let a: [$float; 2] = [1.0, 2.0];
+                let b = [next(1.0), prev(2.0)];
+
+                assert!(Check::Ulps(1).eq_all(&a, &b));
+                assert!(Check::Ulps([1, 1]).eq(&a, &b));
+                assert!(Check::Ulps(0).ne_all(&a, &b));
+                assert!(Check::Abs(EPSILON).eq_all(&a, &b));
+                assert!(Check::Abs([EPSILON, EPSILON]).eq(&a, &b));
+                assert!(Check::Abs([EPSILON, 0.0]).ne(&a, &b));
+                assert!(Check::Rmin(EPSILON).eq_all(&a, &b));
+                assert!(Check::Rmin(EPSILON / 2.0).ne_all(&a, &b));
+            }
+
+            #[test]
+            fn asserts() {
+                let a: $float = 1.0;
+                let b = next(a);
+
+                Check::Ulps(1).assert_eq(&a, &b);
+                Check::Ulps(0).assert_ne(&a, &b);
+                Check::Abs(EPSILON).assert_eq_all(&[a, a], &[b, b]);
+                Check::Abs(0.0).assert_ne_all(&[a, a], &[a, b]);
+            }
+        }
+    };
+}
+
+impl_tests!(f32);
+impl_tests!(f64);
+
+#[cfg(feature = "std")]
+mod check_set {
+    use super::*;
+    use float_eq::{assert_float_eq, assert_float_ne, CheckSet};
+
+    #[test]
+    fn any() {
+        let checks = CheckSet::Any(vec![Check::Abs(0.000_1_f32), Check::Ulps(4)]);
+        assert_eq!(checks.checks().len(), 2);
+
+        // either check may succeed
+        assert!(checks.eq(&1.0_f32, &1.000_05_f32));
+        assert!(checks.eq(&1e10_f32, &1.000_000_3e10_f32));
+        assert!(checks.ne(&1e10_f32, &1.000_1e10_f32));
+        assert!(checks.eq_all(&[1.0_f32, 2.0], &[1.000_05_f32, 2.0]));
+
+        // each check is made of all of the elements together
+        assert!(checks.ne_all(&[1.0_f32, 1e10], &[1.000_05_f32, 1.000_000_3e10]));
+        assert!(checks.ne_all(&[1.0_f32, 1e10], &[1.000_5_f32, 1.000_000_3e10]));
+
+        checks.assert_eq(&1.0_f32, &1.000_05_f32);
+        checks.assert_ne(&1.0_f32, &1.001_f32);
+        checks.assert_eq_all(&[1.0_f32], &[1.000_05_f32]);
+        checks.assert_ne_all(&[1.0_f32], &[1.001_f32]);
+    }
+
+    #[test]
+    fn all() {
+        let checks = CheckSet::All(vec![Check::Abs(0.000_1_f32), Check::Ulps(4)]);
+
+        // both checks must succeed
+        assert!(checks.eq(&1.0_f32, &1.000_000_1_f32));
+        assert!(checks.ne(&1.0_f32, &1.000_05_f32));
+        assert!(checks.ne(&1e10_f32, &1.000_000_3e10_f32));
+        assert!(checks.eq_all(&[1.0_f32, 2.0], &[1.000_000_1_f32, 2.0]));
+        assert!(checks.ne_all(&[1.0_f32, 2.0], &[1.000_05_f32, 2.0]));
+
+        checks.assert_eq(&1.0_f32, &1.000_000_1_f32);
+        checks.assert_ne(&1.0_f32, &1.000_05_f32);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(CheckSet::<f64>::Any(vec![]).ne(&1.0, &1.0));
+        assert!(CheckSet::<f64>::All(vec![]).eq(&1.0, &2.0));
+    }
+
+    #[test]
+    fn traits() {
+        let checks = CheckSet::All(vec![Check::Abs(0.5_f64), Check::Ulps(4)]);
+        assert_eq!(checks.clone(), checks);
+        assert_ne!(checks, CheckSet::Any(vec![Check::Abs(0.5), Check::Ulps(4)]));
+        assert_eq!(format!("{:?}", checks), "All([Abs(0.5), Ulps(4)])");
+        assert_eq!(
+            CheckSet::from(Check::<f64>::Ulps(1)),
+            CheckSet::Any(vec![Check::Ulps(1)])
+        );
+    }
+
+    #[test]
+    fn report() {
+        let a = vec![1.0_f32, 2.0, 3.0];
+        let b = vec![1.0_f32, 2.000_001, 3.5];
+
+        let report = Check::Rmax(vec![0.0, 0.000_001, 0.1]).report(&a, &b);
+        assert_eq!(report.check, "rmax");
+        assert_eq!(
+            report,
+            float_eq::compare!(a, b, rmax <= vec![0.0, 0.000_001, 0.1])
+        );
+
+        let report = Check::Abs(0.25).report_all(&a, &b);
+        assert_eq!(report.check, "abs_all");
+        assert_eq!(report, float_eq::compare!(a, b, abs_all <= 0.25));
+        let failures: Vec<_> = report.failures().map(|node| node.path.as_str()).collect();
+        assert_eq!(failures, ["[2]"]);
+    }
+
+    fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let err = std::panic::catch_unwind(f).unwrap_err();
+        err.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn assert_messages_match_macros() {
+        let (a, b) = (1.0_f32, 1.5_f32);
+        assert_eq!(
+            panic_message(|| Check::Abs(0.1).assert_eq(&a, &b)),
+            panic_message(|| assert_float_eq!(a, b, abs <= 0.1))
+        );
+        assert_eq!(
+            panic_message(|| Check::Ulps(4).assert_eq_all(&a, &b)),
+            panic_message(|| assert_float_eq!(a, b, ulps_all <= 4))
+        );
+        assert_eq!(
+            panic_message(|| Check::AtolRtol(1.0, 0.0).assert_ne(&a, &b)),
+            panic_message(|| assert_float_ne!(a, b, atol_rtol <= (1.0, 0.0)))
+        );
+
+        let any = CheckSet::Any(vec![Check::Abs(0.1), Check::Rmax(0.2)]);
+        assert_eq!(
+            panic_message(|| any.assert_eq(&a, &b)),
+            panic_message(|| assert_float_eq!(a, b, abs <= 0.1, rmax <= 0.2))
+        );
+        let all = CheckSet::All(vec![Check::Abs(1.0), Check::Rmax(0.2)]);
+        assert_eq!(
+            panic_message(|| all.assert_eq(&a, &b)),
+            panic_message(|| assert_float_eq!(a, b, all(abs <= 1.0, rmax <= 0.2)))
+        );
+        assert_eq!(
+            panic_message(|| all.assert_ne_all(&a, &a)),
+            panic_message(|| assert_float_ne!(a, a, all(abs_all <= 1.0, rmax_all <= 0.2)))
+        );
+
+        // collections are itemised in the same way
+        let (a, b) = (vec![1.0_f32, 2.0, 3.0], vec![1.0_f32, 2.5, 3.0]);
+        let all = CheckSet::All(vec![Check::Abs(1.0), Check::Rmax(0.1)]);
+        assert_eq!(
+            panic_message(|| all.assert_eq_all(&a, &b)),
+            panic_message(|| assert_float_eq!(a, b, all(abs_all <= 1.0, rmax_all <= 0.1)))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, all(abs <= t, ulps <= t))`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+     [abs] t: `1.0`,
+    [ulps] t: `4` (failed)"#)]
+    fn assert_all_fail() {
+        CheckSet::All(vec![Check::Abs(1.0_f32), Check::Ulps(4)]).assert_eq(&1.0_f32, &1.5_f32);
+    }
+}
+
+#[test]
+#[should_panic(expected = r#"`float_ne!(left, right, r2nd <= t)`
+        left: `1.0`,
+       right: `1.0`,
+    abs_diff: `0.0`,
+   ulps_diff: `Some(0)`,
+    [r2nd] t: `0.5`"#)]
+fn assert_ne_fail() {
+    Check::R2nd(0.5_f64).assert_ne(&1.0, &1.0);
+}
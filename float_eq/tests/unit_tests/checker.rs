@@ -0,0 +1,118 @@
+use float_eq::{check_float_eq, check_float_ne, FloatEqChecker};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+fn panic_message(f: impl FnOnce()) -> String {
+    let err = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+    match err.downcast::<String>() {
+        Ok(message) => *message,
+        Err(err) => err.downcast::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn pass() {
+    let checker = FloatEqChecker::new();
+    assert!(checker.check(check_float_eq!(1_f32, 1.000_000_1_f32, ulps <= 1)));
+    assert!(checker.check(check_float_ne!(1_f64, 2_f64, abs <= 0.5)));
+    assert_eq!(checker.count(), 2);
+    assert!(checker.failures().is_empty());
+    checker.finish();
+}
+
+#[test]
+fn failures() {
+    let checker = FloatEqChecker::new();
+    let line = line!() + 1;
+    assert!(!checker.check(check_float_eq!(1_f32, 2_f32, abs <= 0.5)));
+    assert!(checker.check(check_float_eq!(1_f32, 1_f32, abs <= 0.5)));
+    assert!(!checker.check(check_float_ne!(1_f64, 1_f64, ulps <= 0, "exact")));
+    assert_eq!(checker.count(), 3);
+
+    let failures = checker.failures();
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].0.file(), file!());
+    assert_eq!(failures[0].0.line(), line);
+    assert_eq!(failures[0].1.check(), "float_eq!(left, right, abs <= t)");
+    assert_eq!(failures[1].0.line(), line + 2);
+    assert_eq!(failures[1].1.message(), Some("exact"));
+
+    let message = panic_message(|| checker.finish());
+    let first = format!("{}:{}:22", file!(), line);
+    let second = format!("{}:{}:22", file!(), line + 2);
+    let width = first.len();
+    assert_eq!(
+        message,
+        format!(
+            r#"2 of 3 float checks failed
+  {:<w$}  check                              left  right
+  {:<w$}  float_eq!(left, right, abs <= t)   1.0   2.0
+  {:<w$}  float_ne!(left, right, ulps <= t)  1.0   1.0
+
+{}: check failed: `float_eq!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `2.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(8388608)`,
+     [abs] t: `0.5`
+
+{}: check failed: `float_ne!(left, right, ulps <= t)`
+        left: `1.0`,
+       right: `1.0`,
+    abs_diff: `0.0`,
+   ulps_diff: `Some(0)`,
+    [ulps] t: `0`: exact
+"#,
+            "location",
+            first,
+            second,
+            first,
+            second,
+            w = width
+        )
+    );
+}
+
+#[test]
+fn threads() {
+    let checker = FloatEqChecker::new();
+    std::thread::scope(|s| {
+        for i in 0..4 {
+            let checker = &checker;
+            s.spawn(move || {
+                for j in 0..100 {
+                    let x = f64::from(i * 100 + j);
+                    let y = if j % 10 == 0 { x + 1.0 } else { x };
+                    checker.check(check_float_eq!(x, y, abs <= 0.5, "{} {}", i, j));
+                }
+            });
+        }
+    });
+    assert_eq!(checker.count(), 400);
+    assert_eq!(checker.failures().len(), 40);
+
+    let message = panic_message(|| checker.finish());
+    assert!(
+        message.starts_with("40 of 400 float checks failed\n"),
+        "{}",
+        message
+    );
+}
+
+#[test]
+fn drop_without_finish() {
+    // only warns, since panicking in drop may abort
+    let checker = FloatEqChecker::new();
+    checker.check(check_float_eq!(1_f32, 1_f32, abs <= 0.5));
+    checker.check(check_float_eq!(1_f32, 2_f32, abs <= 0.5));
+    drop(checker);
+}
+
+#[test]
+fn drop_while_panicking() {
+    let message = panic_message(|| {
+        let checker = FloatEqChecker::new();
+        checker.check(check_float_eq!(1_f32, 2_f32, abs <= 0.5));
+        panic!("test failed");
+    });
+    assert_eq!(message, "test failed");
+}
@@ -488,6 +488,22 @@ mod option {
         assert_eq!(a.debug_r2nd_all_tol(&b, &None), None);
         assert_eq!(a.debug_ulps_all_tol(&b, &None), None);
     }
+
+    #[test]
+    fn float_ord() {
+        use float_eq::{float_ge, float_lt};
+
+        let a = Some(1.0f32);
+        let b = Some(1.5f32);
+        let none: Option<f32> = None;
+
+        assert!(float_lt!(a, b, abs <= Some(0.25)));
+        assert!(!float_lt!(a, b, abs <= Some(0.5)));
+        assert!(float_ge!(a, b, abs <= Some(0.5)));
+        assert!(!float_lt!(a, b, abs <= None));
+        assert!(!float_lt!(a, none, abs <= Some(0.25)));
+        assert!(!float_ge!(none, none, abs <= None));
+    }
 }
 
 mod cell {
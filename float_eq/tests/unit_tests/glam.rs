@@ -0,0 +1,221 @@
+#![allow(clippy::float_cmp)]
+
+use core::f32;
+use float_eq::{
+    assert_float_eq, assert_float_ne, Affine3Ulps, AssertFloatEq, AssertFloatEqAll, Mat2Ulps,
+    QuatUlps, Vec2Ulps, Vec3Ulps,
+};
+use glam::{Affine3A, DQuat, DVec3, Mat2, Mat3A, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+#[test]
+fn float_eq() {
+    let a = Vec2::new(0.999_999_9f32, 4.0);
+    let b = Vec2::new(1.0f32, 3.999_999_5);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs <= Vec2::new(1.0 * eps, 4.0 * eps));
+    assert_float_ne!(a, b, abs <= Vec2::new(0.5 * eps, 4.0 * eps));
+    assert_float_ne!(a, b, abs <= Vec2::new(1.0 * eps, 2.0 * eps));
+
+    assert_float_eq!(a, b, rel <= Vec2::new(1.0 * eps, 1.0 * eps));
+    assert_float_ne!(a, b, rel <= Vec2::new(0.5 * eps, 1.0 * eps));
+    assert_float_ne!(a, b, rel <= Vec2::new(1.0 * eps, 0.5 * eps));
+
+    assert_float_eq!(a, b, rmax <= Vec2::new(1.0 * eps, 1.0 * eps));
+    assert_float_ne!(a, b, rmax <= Vec2::new(0.5 * eps, 1.0 * eps));
+    assert_float_ne!(a, b, rmax <= Vec2::new(1.0 * eps, 0.5 * eps));
+
+    assert_float_eq!(a, b, rmin <= Vec2::new(2.0 * eps, 2.0 * eps));
+    assert_float_ne!(a, b, rmin <= Vec2::new(1.0 * eps, 2.0 * eps));
+    assert_float_ne!(a, b, rmin <= Vec2::new(2.0 * eps, 1.0 * eps));
+
+    assert_float_eq!(a, b, r1st <= Vec2::new(2.0 * eps, 1.0 * eps));
+    assert_float_ne!(a, b, r1st <= Vec2::new(1.0 * eps, 1.0 * eps));
+    assert_float_ne!(a, b, r1st <= Vec2::new(2.0 * eps, 0.5 * eps));
+
+    assert_float_eq!(a, b, r2nd <= Vec2::new(1.0 * eps, 2.0 * eps));
+    assert_float_ne!(a, b, r2nd <= Vec2::new(0.5 * eps, 2.0 * eps));
+    assert_float_ne!(a, b, r2nd <= Vec2::new(1.0 * eps, 1.0 * eps));
+
+    assert_float_eq!(a, b, ulps <= Vec2Ulps::new(2, 2));
+    assert_float_ne!(a, b, ulps <= Vec2Ulps::new(1, 2));
+    assert_float_ne!(a, b, ulps <= Vec2Ulps::new(2, 1));
+}
+
+#[test]
+fn float_eq_all() {
+    let a = Vec2::new(0.999_999_9f32, 4.0);
+    let b = Vec2::new(1.0f32, 3.999_999_5);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs_all <= 4.0 * eps);
+    assert_float_ne!(a, b, abs_all <= 2.0 * eps);
+
+    assert_float_eq!(a, b, rel_all <= 1.0 * eps);
+    assert_float_ne!(a, b, rel_all <= 0.5 * eps);
+
+    assert_float_eq!(a, b, rmax_all <= 1.0 * eps);
+    assert_float_ne!(a, b, rmax_all <= 0.5 * eps);
+
+    assert_float_eq!(a, b, rmin_all <= 2.0 * eps);
+    assert_float_ne!(a, b, rmin_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, r1st_all <= 2.0 * eps);
+    assert_float_ne!(a, b, r1st_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, r2nd_all <= 2.0 * eps);
+    assert_float_ne!(a, b, r2nd_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, ulps_all <= 2);
+    assert_float_ne!(a, b, ulps_all <= 1);
+}
+
+#[test]
+fn debug_diff() {
+    let a = Vec2::new(1.0f32, 2.0);
+    let b = Vec2::new(1.5f32, 2.25);
+
+    assert_eq!(a.debug_abs_diff(&a), Vec2::new(0.0, 0.0));
+    assert_eq!(a.debug_ulps_diff(&a), Vec2Ulps::new(Some(0), Some(0)));
+
+    assert_eq!(a.debug_abs_diff(&b), Vec2::new(0.5, 0.25));
+    assert_eq!(b.debug_abs_diff(&a), Vec2::new(0.5, 0.25));
+
+    let ulps = Vec2Ulps::new(Some(4_194_304), Some(1_048_576));
+    assert_eq!(a.debug_ulps_diff(&b), ulps);
+    assert_eq!(b.debug_ulps_diff(&a), ulps);
+}
+
+#[test]
+fn debug_tol() {
+    let a = Vec2::new(2.0f32, 4.25);
+    let b = Vec2::new(2.5f32, 4.0);
+    let eps = Vec2::new(0.1, 0.2);
+
+    assert_eq!(a.debug_abs_tol(&b, &eps), Vec2::new(0.1, 0.2));
+    assert_eq!(a.debug_rel_tol(&b, &eps), Vec2::new(0.25, 0.85));
+    assert_eq!(a.debug_rmax_tol(&b, &eps), Vec2::new(0.25, 0.85));
+    assert_eq!(a.debug_rmin_tol(&b, &eps), Vec2::new(0.2, 0.8));
+    assert_eq!(a.debug_r1st_tol(&b, &eps), Vec2::new(0.2, 0.85));
+    assert_eq!(a.debug_r2nd_tol(&b, &eps), Vec2::new(0.25, 0.8));
+    assert_eq!(
+        a.debug_ulps_tol(&b, &Vec2Ulps::new(1, 2)),
+        Vec2Ulps::new(1, 2)
+    );
+}
+
+#[test]
+fn debug_all_tol() {
+    let a = Vec2::new(2.0f32, 4.25);
+    let b = Vec2::new(2.5f32, 4.0);
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), Vec2::new(0.2, 0.2));
+    assert_eq!(a.debug_rel_all_tol(&b, &0.2), Vec2::new(0.5, 0.85));
+    assert_eq!(a.debug_rmax_all_tol(&b, &0.2), Vec2::new(0.5, 0.85));
+    assert_eq!(a.debug_rmin_all_tol(&b, &0.2), Vec2::new(0.4, 0.8));
+    assert_eq!(a.debug_r1st_all_tol(&b, &0.2), Vec2::new(0.4, 0.85));
+    assert_eq!(a.debug_r2nd_all_tol(&b, &0.2), Vec2::new(0.5, 0.8));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), Vec2Ulps::new(2, 2));
+}
+
+#[test]
+fn vectors_and_quaternions() {
+    assert_float_eq!(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(1.0, 2.0, 3.5),
+        abs <= Vec3::new(0.0, 0.0, 0.5)
+    );
+    assert_float_ne!(
+        Vec3A::new(1.0, 2.0, 3.0),
+        Vec3A::new(1.0, 2.0, 3.5),
+        ulps <= Vec3Ulps::new(0, 0, 2_097_151)
+    );
+    assert_float_eq!(
+        Vec4::new(1.0, 2.0, 3.0, 4.0),
+        Vec4::new(1.0, 2.0, 3.0, 4.5),
+        abs_all <= 0.5
+    );
+    assert_float_eq!(
+        DVec3::new(1.0, 2.0, 3.0),
+        DVec3::new(1.0, 2.0, 3.0 + 1e-12),
+        abs_all <= 1e-9
+    );
+
+    let a = Quat::from_xyzw(0.0, 0.0, 0.6, 0.8);
+    let b = Quat::from_xyzw(0.0, 0.0, 0.8, 0.6);
+    assert_float_eq!(a, b, abs_all <= 0.2 + f32::EPSILON);
+    assert_float_ne!(a, b, abs_all <= 0.1);
+    assert_eq!(
+        a.debug_ulps_diff(&a),
+        QuatUlps::new(Some(0), Some(0), Some(0), Some(0))
+    );
+    assert_float_eq!(
+        DQuat::from_xyzw(0.0, 0.0, 0.6, 0.8),
+        DQuat::from_xyzw(0.0, 0.0, 0.6, 0.8),
+        ulps_all <= 0
+    );
+}
+
+#[test]
+fn matrices_and_affines() {
+    let a = Mat2::from_cols(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+    let b = Mat2::from_cols(Vec2::new(1.0, 2.0), Vec2::new(3.5, 4.0));
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, abs_all <= 0.25);
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        Mat2Ulps::from_cols(
+            Vec2Ulps::new(Some(0), Some(0)),
+            Vec2Ulps::new(Some(2_097_152), Some(0))
+        )
+    );
+
+    let a = Affine3A::from_mat3_translation(glam::Mat3::IDENTITY, Vec3::new(1.0, 2.0, 3.0));
+    let b = Affine3A::from_mat3_translation(glam::Mat3::IDENTITY, Vec3::new(1.0, 2.0, 3.5));
+    let tol = Affine3A {
+        matrix3: Mat3A::ZERO,
+        translation: Vec3A::new(0.0, 0.0, 0.5),
+    };
+    assert_float_eq!(a, b, abs <= tol);
+    assert_float_ne!(a, b, rmax_all <= 0.1);
+    assert_eq!(
+        a.debug_ulps_all_tol(&b, &1),
+        Affine3Ulps {
+            matrix3: float_eq::Mat3Ulps::from_cols(
+                Vec3Ulps::new(1, 1, 1),
+                Vec3Ulps::new(1, 1, 1),
+                Vec3Ulps::new(1, 1, 1)
+            ),
+            translation: Vec3Ulps::new(1, 1, 1),
+        }
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `Vec3(1.0, 2.0, 3.0)`,
+       right: `Vec3(1.0, 2.0, 3.5)`,
+    abs_diff: `Vec3(0.0, 0.0, 0.5)`,
+   ulps_diff: `Vec3Ulps { x: Some(0), y: Some(0), z: Some(2097152) }`,
+ [abs_all] t: `Vec3(0.25, 0.25, 0.25)`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(1.0, 2.0, 3.5),
+        abs_all <= 0.25
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn report() {
+    use float_eq::compare;
+
+    let a = Mat2::from_cols(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+    let b = Mat2::from_cols(Vec2::new(1.0, 2.0), Vec2::new(3.5, 4.0));
+
+    let report = compare!(a, b, abs_all <= 0.25);
+    let paths: Vec<_> = report.failures().map(|n| n.path.as_str()).collect();
+    assert_eq!(paths, vec![".y_axis.x"]);
+}
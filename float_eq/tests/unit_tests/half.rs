@@ -0,0 +1,197 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, float_eq, AssertFloatEq, AssertFloatEqAll, InfAsMax, NanEq,
+    NanEqBits, Strict,
+};
+use half::{bf16, f16};
+
+macro_rules! impl_tests {
+    ($mod:ident, $float:ident) => {
+        mod $mod {
+            use super::*;
+
+            fn next(value: $float, n: u16) -> $float {
+                $float::from_bits(value.to_bits() + n)
+            }
+
+            #[test]
+            fn float_eq() {
+                let a = $float::ONE;
+                let b = next(a, 2);
+                let eps = $float::EPSILON;
+                let half_eps = eps * $float::from_f32(0.5);
+
+                assert_float_eq!(a, b, abs <= eps * $float::from_f32(2.0));
+                assert_float_ne!(a, b, abs <= eps);
+
+                assert_float_eq!(a, b, rmax <= eps * $float::from_f32(2.0));
+                assert_float_ne!(a, b, rmax <= eps);
+
+                assert_float_eq!(a, b, rmin <= eps * $float::from_f32(2.0));
+                assert_float_ne!(a, b, rmin <= half_eps);
+
+                assert_float_eq!(a, b, r1st <= eps * $float::from_f32(2.0));
+                assert_float_ne!(a, b, r1st <= eps);
+
+                assert_float_eq!(a, b, r2nd <= eps * $float::from_f32(2.0));
+                assert_float_ne!(a, b, r2nd <= half_eps);
+
+                assert_float_eq!(a, b, ulps <= 2u16);
+                assert_float_ne!(a, b, ulps <= 1u16);
+
+                assert_float_eq!(a, b, abs_all <= eps * $float::from_f32(2.0));
+                assert_float_eq!(a, b, ulps_all <= 2u16);
+                assert_float_ne!(a, b, ulps_all <= 1u16);
+            }
+
+            #[test]
+            fn special_values() {
+                let zero = $float::ZERO;
+                let neg_zero = $float::NEG_ZERO;
+                assert!(float_eq!(zero, neg_zero, ulps <= 0));
+                assert!(float_eq!(zero, neg_zero, abs <= zero));
+
+                let inf = $float::INFINITY;
+                assert!(float_eq!(inf, inf, abs <= zero));
+                assert!(float_eq!(inf, inf, ulps <= 0));
+                assert!(!float_eq!(inf, $float::NEG_INFINITY, ulps <= u16::MAX));
+
+                let nan = $float::NAN;
+                assert!(!float_eq!(nan, nan, abs <= inf));
+                assert!(!float_eq!(nan, nan, ulps <= u16::MAX));
+                assert!(!float_eq!(nan, $float::ONE, rmax <= inf));
+            }
+
+            #[test]
+            fn debug() {
+                let a = $float::ONE;
+                let b = next(a, 3);
+                let tol = $float::from_f32(0.5);
+
+                assert_eq!(a.debug_abs_diff(&b), b - a);
+                assert_eq!(a.debug_ulps_diff(&b), Some(3));
+                assert_eq!(b.debug_ulps_diff(&a), Some(3));
+                assert_eq!(a.debug_ulps_diff(&-a), None);
+                assert_eq!(a.debug_ulps_diff(&$float::NAN), None);
+
+                assert_eq!(a.debug_abs_tol(&b, &tol), tol);
+                assert_eq!(a.debug_rmax_tol(&b, &tol), b * tol);
+                assert_eq!(a.debug_rmin_tol(&b, &tol), a * tol);
+                assert_eq!(a.debug_r1st_tol(&b, &tol), a * tol);
+                assert_eq!(a.debug_r2nd_tol(&b, &tol), b * tol);
+                assert_eq!(a.debug_ulps_tol(&b, &7), 7);
+                assert_eq!(a.debug_rmax_all_tol(&b, &tol), b * tol);
+                assert_eq!(a.debug_ulps_all_tol(&b, &7), 7);
+            }
+
+            #[test]
+            fn mixed_width() {
+                let a = $float::from_f32(0.1);
+                let reference = f32::from(a);
+
+                assert_float_eq!(a, reference, ulps <= 0);
+                assert_float_eq!(reference, a, ulps <= 0);
+                assert_float_eq!(a, f64::from(a), ulps <= 0);
+                assert_float_eq!(f64::from(a), a, ulps_all <= 0);
+
+                assert_float_eq!(a, 0.1f32, rmax <= f32::from($float::EPSILON));
+                assert_float_eq!(0.1f64, a, r1st_all <= f64::from($float::EPSILON));
+
+                assert_eq!(a.debug_abs_diff(&0.1f32), (reference - 0.1).abs());
+                assert_eq!(
+                    a.debug_ulps_diff(&reference),
+                    reference.debug_ulps_diff(&reference)
+                );
+            }
+
+            #[test]
+            fn mixed_width_ulps() {
+                let a = $float::from_f32(0.1);
+                let b = next(a, 1);
+
+                // measured in the narrower type, which the wider value is rounded to
+                assert_float_eq!(a, 0.1f32, ulps <= 0);
+                assert_float_eq!(0.1f64, a, ulps_all <= 0);
+
+                assert_float_eq!(a, f32::from(b), ulps <= 1);
+                assert_float_ne!(a, f32::from(b), ulps <= 0);
+                assert_float_eq!(f64::from(b), a, ulps <= 1);
+                assert_float_ne!(f64::from(b), a, ulps <= 0);
+                assert_eq!(a.debug_ulps_diff(&f32::from(b)), Some(1));
+                assert_eq!(f64::from(a).debug_ulps_diff(&b), Some(1));
+            }
+            #[test]
+            fn nan_eq() {
+                let nan = $float::NAN;
+                let one = $float::ONE;
+                assert_float_eq!(NanEq(nan), NanEq(-nan), ulps <= 0);
+                assert_float_eq!(NanEq(nan), NanEq(-nan), abs <= NanEq($float::ZERO));
+                assert_float_ne!(NanEq(nan), NanEq(one), abs_all <= $float::INFINITY);
+                assert_float_eq!(NanEqBits(nan), NanEqBits(nan), ulps <= 0);
+                assert_float_ne!(NanEqBits(nan), NanEqBits(-nan), ulps <= u16::MAX);
+            }
+
+            #[test]
+            fn strict() {
+                let zero = $float::ZERO;
+                let inf = $float::INFINITY;
+                assert_float_ne!(Strict(zero), Strict(-zero), abs <= Strict($float::ONE));
+                assert_float_eq!(Strict(-zero), Strict(-zero), ulps <= 0);
+                assert_float_ne!(
+                    Strict(inf),
+                    Strict($float::MAX),
+                    rmax <= Strict($float::ONE)
+                );
+                assert_float_eq!(Strict(inf), Strict(inf), abs_all <= zero);
+            }
+
+            #[test]
+            fn inf_as_max() {
+                let inf = InfAsMax($float::INFINITY);
+                assert_float_eq!(inf, InfAsMax($float::MAX), ulps <= 0);
+                assert_float_eq!(
+                    InfAsMax(-$float::INFINITY),
+                    InfAsMax($float::MIN),
+                    ulps_all <= 0
+                );
+                assert_float_ne!(inf, InfAsMax(-$float::INFINITY), ulps <= u16::MAX);
+            }
+        }
+    };
+}
+
+impl_tests!(f16_tests, f16);
+impl_tests!(bf16_tests, bf16);
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, ulps <= t)`
+        left: `1.0`,
+       right: `1.0019531`,
+    abs_diff: `0.001953125`,
+   ulps_diff: `Some(2)`,
+    [ulps] t: `1`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(f16::ONE, f16::from_bits(f16::ONE.to_bits() + 2), ulps <= 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_check_set() {
+    use float_eq::{Check, CheckSet};
+
+    let checks: CheckSet<f16> = "abs <= 0x1p-10, ulps <= 2".parse().unwrap();
+    assert_eq!(
+        checks,
+        CheckSet::Any(vec![
+            Check::Abs(f16::from_f32(0.000_976_562_5)),
+            Check::Ulps(2)
+        ])
+    );
+    assert!(checks.eq(&f16::ONE, &f16::from_bits(f16::ONE.to_bits() + 1)));
+
+    let err = "ulps <= 65536".parse::<CheckSet<bf16>>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "tolerance `65536` for `ulps` is too large for `u16`"
+    );
+}
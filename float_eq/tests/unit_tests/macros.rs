@@ -1,6 +1,6 @@
 use float_eq::{
-    assert_float_eq, assert_float_ne, debug_assert_float_eq, debug_assert_float_ne, float_eq,
-    float_ne,
+    assert_float_eq, assert_float_ne, check_float_eq, check_float_ne, debug_assert_float_eq,
+    debug_assert_float_ne, float_eq, float_ne,
 };
 
 #[test]
@@ -11,6 +11,88 @@ fn trailing_commas() {
     assert_float_ne!(1.0, 1.5, abs <= 0.4);
 }
 
+#[test]
+fn all_checks() {
+    // 1.000_000_2_f32 is within 0.000_000_3 but two ULPs away from 1.0
+    assert!(float_eq!(
+        1_f32,
+        1.000_000_2_f32,
+        all(abs <= 0.000_000_3, ulps <= 2)
+    ));
+    assert!(!float_eq!(
+        1_f32,
+        1.000_000_2_f32,
+        all(abs <= 0.000_000_3, ulps <= 1)
+    ));
+    assert!(!float_eq!(
+        1_f32,
+        1.000_000_2_f32,
+        all(abs <= 0.000_000_1, ulps <= 2)
+    ));
+    assert!(float_ne!(
+        1_f32,
+        1.000_000_2_f32,
+        all(abs <= 0.000_000_3, ulps <= 1)
+    ));
+    assert!(!float_ne!(
+        1_f32,
+        1.000_000_2_f32,
+        all(abs <= 0.000_000_3, ulps <= 2,),
+    ));
+
+    // groups may be combined with other checks, which are still applied left to right
+    assert!(float_eq!(
+        1_f32,
+        1.000_000_2_f32,
+        all(abs <= 0.000_000_3, ulps <= 1),
+        rmax <= 0.000_000_3
+    ));
+    assert!(float_eq!(
+        1_f32,
+        1.000_000_2_f32,
+        ulps <= 1,
+        all(abs <= 0.000_000_3, rmax <= 0.000_000_3)
+    ));
+    assert!(float_ne!(
+        1_f32,
+        1.000_000_2_f32,
+        ulps <= 1,
+        all(abs <= 0.000_000_3, rmax <= 0.000_000_1)
+    ));
+
+    assert_float_eq!(1_f32, 1.000_000_2_f32, all(abs <= 0.000_000_3, ulps <= 2));
+    assert_float_eq!(1_f32, 1.000_000_2_f32, all(abs <= 0.000_000_3, ulps <= 2,),);
+    assert_float_eq!(1_f32, 1.000_000_2_f32, all(abs <= 0.000_000_3), "testing");
+    assert_float_ne!(1_f32, 1.000_000_2_f32, all(abs <= 0.000_000_3, ulps <= 1));
+    assert_float_ne!(
+        1_f32,
+        1.000_000_2_f32,
+        all(abs <= 0.000_000_1, ulps <= 2),
+        "testing",
+    );
+
+    // as may the checks of an assert
+    assert_float_eq!(
+        1_f32,
+        1.000_000_2_f32,
+        ulps <= 1,
+        all(abs <= 0.000_000_3, rmax <= 0.000_000_3)
+    );
+    assert_float_eq!(
+        1_f32,
+        1.000_000_2_f32,
+        all(abs <= 0.000_000_3, ulps <= 1),
+        rmax <= 0.000_000_3,
+        "testing"
+    );
+    assert_float_ne!(
+        1_f32,
+        1.000_000_2_f32,
+        ulps <= 1,
+        all(abs <= 0.000_000_3, rmax <= 0.000_000_1),
+    );
+}
+
 mod assert_float_eq {
     use super::*;
 
@@ -190,6 +272,61 @@ mod assert_float_eq {
         assert_float_eq!(0_f32, 1., abs <= 0.1, "testing: {} != {}", 0_f32, 1_f32,);
     }
 
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, all(abs <= t, ulps <= t))`
+        left: `1.0`,
+       right: `1.0000002`,
+    abs_diff: `2.3841858e-7`,
+   ulps_diff: `Some(2)`,
+     [abs] t: `3e-7`,
+    [ulps] t: `1` (failed)"#)]
+    fn all_fail() {
+        assert_float_eq!(1_f32, 1.000_000_2_f32, all(abs <= 0.000_000_3, ulps <= 1));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"`float_eq!(left, right, ulps <= t, all(abs <= t, rmax <= t))`
+        left: `1.0`,
+       right: `1.0000002`,
+    abs_diff: `2.3841858e-7`,
+   ulps_diff: `Some(2)`,
+    [ulps] t: `1`,
+     [abs] t: `3e-7`,
+    [rmax] t: `1.0000002e-7` (failed): testing"#
+    )]
+    fn mixed_fail_with_message() {
+        assert_float_eq!(
+            1_f32,
+            1.000_000_2_f32,
+            ulps <= 1,
+            all(abs <= 0.000_000_3, rmax <= 0.000_000_1),
+            "testing"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"`float_eq!(left, right, all(abs <= t, rmax <= t, ulps <= t))`
+        left: `0.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `0.1` (failed),
+    [rmax] t: `0.1` (failed),
+    [ulps] t: `1` (failed): testing: 0 != 1"#
+    )]
+    fn all_fail_with_message() {
+        assert_float_eq!(
+            0_f32,
+            1_f32,
+            all(abs <= 0.1, rmax <= 0.1, ulps <= 1),
+            "testing: {} != {}",
+            0_f32,
+            1_f32,
+        );
+    }
+
     #[test]
     #[should_panic(expected = r#"`float_eq!(left, right, abs <= t, ulps <= t)`
         left: `0.0`,
@@ -230,6 +367,34 @@ mod assert_float_eq {
         );
     }
 
+    #[test]
+    #[should_panic(
+        expected = r#"`float_eq!(left, right, abs <= t, rmax <= t, r2nd <= t, ulps <= t, abs_all <= t)`
+        left: `0.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `0.1`,
+    [rmax] t: `0.1`,
+    [r2nd] t: `0.1`,
+    [ulps] t: `1`,
+ [abs_all] t: `0.2`: testing: 0 != 1"#
+    )]
+    fn chaining_many_fail_with_message() {
+        assert_float_eq!(
+            0_f32,
+            1_f32,
+            abs <= 0.1,
+            rmax <= 0.1,
+            r2nd <= 0.1,
+            ulps <= 1,
+            abs_all <= 0.2,
+            "testing: {} != {}",
+            0_f32,
+            1_f32,
+        );
+    }
+
     #[test]
     fn chaining() {
         // first succeeds
@@ -260,6 +425,17 @@ mod assert_float_eq {
             rel <= 0.000_000_1,
             ulps <= 2
         );
+
+        // fifth succeeds
+        assert_float_eq!(
+            1_f32,
+            1.000_000_2_f32,
+            abs <= 0.000_000_1,
+            rel <= 0.000_000_1,
+            ulps <= 1,
+            abs_all <= 0.000_000_1,
+            ulps_all <= 2
+        );
     }
 
     #[test]
@@ -320,6 +496,138 @@ mod assert_float_eq {
     }
 }
 
+#[cfg(feature = "std")]
+mod assert_float_eq_concise {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+ [abs_all] 2 failures:
+           [1]: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `0.25`
+           [2]: left: `3.0`, right: `3.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `0.25`"#)]
+    fn vec_fail() {
+        assert_float_eq!(vec![1_f32, 2., 3.], vec![1_f32, 2.5, 3.5], abs_all <= 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, rmax <= t)`
+    [rmax] 1 failure:
+           [1]: left: `2.0`, right: `3.0`, abs_diff: `1.0`, ulps_diff: `Some(2251799813685248)`, t: `0.30000000000000004`"#)]
+    fn slice_fail() {
+        let a = [1_f64, 2.];
+        let b = [1_f64, 3.];
+        assert_float_eq!(a[..], b[..], rmax <= [0.1, 0.1][..]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, ulps <= t)`
+    [ulps] 1 failure:
+           [0][1]: left: `2.0`, right: `2.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `1`"#)]
+    fn nested_fail() {
+        assert_float_eq!(vec![[1_f32, 2.]], vec![[1_f32, 2.5]], ulps <= vec![[0, 1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+ [abs_all] 20 failures, showing the first 10:
+           [0]: left: `0.0`, right: `1.0`"#)]
+    fn limit_fail() {
+        let a: Vec<f32> = (0..20).map(|i| i as f32).collect();
+        let b: Vec<f32> = (1..21).map(|i| i as f32).collect();
+        assert_float_eq!(a, b, abs_all <= 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+ [abs_all] 1 failure:
+           <root>: lengths differ (left: 1, right: 2)"#)]
+    fn len_fail() {
+        assert_float_eq!(vec![1_f32], vec![1_f32, 2.], abs_all <= 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+     [abs] 3 failures:
+           ["a"]: missing from right
+           ["b"]: missing from tolerance
+           ["c"]: missing from left"#)]
+    fn btree_map_fail() {
+        let a: BTreeMap<_, _> = vec![("a", 1_f32), ("b", 2.)].into_iter().collect();
+        let b: BTreeMap<_, _> = vec![("b", 2_f32), ("c", 3.)].into_iter().collect();
+        let tol: BTreeMap<_, _> = vec![("a", 0.1_f32), ("c", 0.1)].into_iter().collect();
+        assert_float_eq!(a, b, abs <= tol);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, rmax_all <= t, abs_all <= t)`
+[rmax_all] 1 failure:
+           ["a"]: left: `1.0`, right: `2.0`, abs_diff: `1.0`, ulps_diff: `Some(4503599627370496)`, t: `0.2`,
+ [abs_all] 1 failure:
+           ["a"]: left: `1.0`, right: `2.0`, abs_diff: `1.0`, ulps_diff: `Some(4503599627370496)`, t: `0.1`: testing"#)]
+    fn hash_map_fail_with_message() {
+        let a: HashMap<_, _> = vec![("a", 1_f64)].into_iter().collect();
+        let b: HashMap<_, _> = vec![("a", 2_f64)].into_iter().collect();
+        assert_float_eq!(a, b, rmax_all <= 0.1, abs_all <= 0.1, "testing");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"`float_eq!(left, right, abs_all <= t, rmax_all <= t, r2nd_all <= t, ulps_all <= t)`
+ [abs_all] 1 failure:
+           [0]: left: `1.0`, right: `2.0`, abs_diff: `1.0`, ulps_diff: `Some(8388608)`, t: `0.5`,
+[rmax_all] 1 failure:
+           [0]: left: `1.0`, right: `2.0`, abs_diff: `1.0`, ulps_diff: `Some(8388608)`, t: `0.2`,
+[r2nd_all] 1 failure:
+           [0]: left: `1.0`, right: `2.0`, abs_diff: `1.0`, ulps_diff: `Some(8388608)`, t: `0.2`,
+[ulps_all] 1 failure:
+           [0]: left: `1.0`, right: `2.0`, abs_diff: `1.0`, ulps_diff: `Some(8388608)`, t: `4`"#
+    )]
+    fn vec_many_fail() {
+        assert_float_eq!(
+            vec![1_f32],
+            vec![2_f32],
+            abs_all <= 0.5,
+            rmax_all <= 0.1,
+            r2nd_all <= 0.1,
+            ulps_all <= 4
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"`float_eq!(left, right, all(abs_all <= t, ulps_all <= t))`
+[ulps_all] 1 failure:
+           [1]: left: `2.0`, right: `2.0000005`, abs_diff: `4.7683716e-7`, ulps_diff: `Some(2)`, t: `1`: testing"#
+    )]
+    fn all_vec_fail_with_message() {
+        let a = vec![1_f32, 2.];
+        let b = vec![1_f32, 2.000_000_5];
+        assert_float_eq!(a, b, all(abs_all <= 0.000_001, ulps_all <= 1), "testing");
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+ [abs_all] 1 failure:
+           [1]: left: `2.0`, right: `3.0`"#)]
+    fn option_fail() {
+        assert_float_eq!(
+            Some(vec![1_f32, 2.]),
+            Some(vec![1_f32, 3.]),
+            abs_all <= Some(0.5)
+        );
+    }
+
+    // Only collections are itemised, so other types keep the full message
+    #[test]
+    #[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `[[1.0, 2.0]]`,
+       right: `[[1.0, 3.0]]`,"#)]
+    fn array_fail() {
+        assert_float_eq!([[1_f32, 2.]], [[1_f32, 3.]], abs_all <= 0.5);
+    }
+}
+
 mod assert_float_ne {
     use super::*;
 
@@ -488,6 +796,47 @@ mod assert_float_ne {
         assert_float_ne!(0_f32, 1., abs <= 1., "testing: {} != {}", 0_f32, 1_f32,);
     }
 
+    #[test]
+    #[should_panic(
+        expected = r#"`float_ne!(left, right, abs <= t, rmax <= t, r2nd <= t, ulps <= t)`
+        left: `0.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `0.1`,
+    [rmax] t: `0.1`,
+    [r2nd] t: `0.1`,
+    [ulps] t: `1065353216`: testing"#
+    )]
+    fn chaining_many_fail_with_message() {
+        assert_float_ne!(
+            0_f32,
+            1_f32,
+            abs <= 0.1,
+            rmax <= 0.1,
+            r2nd <= 0.1,
+            ulps <= 1_065_353_216,
+            "testing"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_ne!(left, right, all(abs <= t, ulps <= t))`
+        left: `1.0`,
+       right: `1.0000002`,
+    abs_diff: `2.3841858e-7`,
+   ulps_diff: `Some(2)`,
+     [abs] t: `3e-7`,
+    [ulps] t: `2`: testing"#)]
+    fn all_fail_with_message() {
+        assert_float_ne!(
+            1_f32,
+            1.000_000_2_f32,
+            all(abs <= 0.000_000_3, ulps <= 2),
+            "testing"
+        );
+    }
+
     #[test]
     #[should_panic(expected = r#"`float_ne!(left, right, abs <= t, ulps <= t)`
         left: `0.0`,
@@ -538,6 +887,15 @@ mod assert_float_ne {
             rel <= 0.000_000_1,
             ulps <= 1
         );
+        assert_float_ne!(
+            1_f32,
+            1.000_000_2_f32,
+            abs <= 0.000_000_1,
+            rel <= 0.000_000_1,
+            ulps <= 1,
+            abs_all <= 0.000_000_1,
+            ulps_all <= 1
+        );
     }
 
     #[test]
@@ -941,3 +1299,273 @@ mod debug_assert_float_ne {
         );
     }
 }
+
+mod check_float_eq {
+    use super::*;
+
+    #[test]
+    fn pass() {
+        assert_eq!(check_float_eq!(1_f32, 1.000_000_1_f32, ulps <= 1), Ok(()));
+        assert_eq!(
+            check_float_eq!(1_f32, 1.5, abs <= 0.1, rmax <= 0.5,),
+            Ok(())
+        );
+        assert_eq!(
+            check_float_eq!(1_f32, 1.000_000_2_f32, all(abs <= 0.000_000_3, ulps <= 2)),
+            Ok(())
+        );
+        assert_eq!(
+            check_float_eq!([1_f32, 2.], [1_f32, 2.], abs_all <= 0.0, "unused {}", 0),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail() {
+        let err = check_float_eq!(0_f32, 1_f32, abs <= 0.1, ulps <= 1).unwrap_err();
+        assert_eq!(err.check(), "float_eq!(left, right, abs <= t, ulps <= t)");
+        assert_eq!(err.left(), "0.0");
+        assert_eq!(err.right(), "1.0");
+        assert_eq!(err.abs_diff(), "1.0");
+        assert_eq!(err.ulps_diff(), "Some(1065353216)");
+        assert_eq!(err.tols(), "     [abs] t: `0.1`,\n    [ulps] t: `1`");
+        assert_eq!(err.message(), None);
+        assert_eq!(
+            err.to_string(),
+            r#"check failed: `float_eq!(left, right, abs <= t, ulps <= t)`
+        left: `0.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `0.1`,
+    [ulps] t: `1`"#
+        );
+    }
+
+    #[test]
+    fn all_fail() {
+        let err = check_float_eq!(
+            1_f32,
+            1.000_000_2_f32,
+            all(abs <= 0.000_000_3, ulps <= 1),
+            "testing: {} != {}",
+            1_f32,
+            1.000_000_2_f32,
+        )
+        .unwrap_err();
+        assert_eq!(err.message(), Some("testing: 1 != 1.0000002"));
+        assert_eq!(
+            err.to_string(),
+            r#"check failed: `float_eq!(left, right, all(abs <= t, ulps <= t))`
+        left: `1.0`,
+       right: `1.0000002`,
+    abs_diff: `2.3841858e-7`,
+   ulps_diff: `Some(2)`,
+     [abs] t: `3e-7`,
+    [ulps] t: `1` (failed): testing: 1 != 1.0000002"#
+        );
+    }
+
+    #[test]
+    fn array_fail() {
+        let err = check_float_eq!([[1_f32, 2.]], [[1_f32, 3.]], abs_all <= 0.5).unwrap_err();
+        assert_eq!(err.left(), "[[1.0, 2.0]]");
+        assert_eq!(err.abs_diff(), "[[0.0, 1.0]]");
+        assert_eq!(err.tols(), " [abs_all] t: `[[0.5, 0.5]]`");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn question_mark() {
+        fn check(a: f64) -> Result<f64, Box<dyn std::error::Error>> {
+            check_float_eq!(a, 1.0, rmax <= 0.01)?;
+            Ok(a)
+        }
+        assert_eq!(check(1.001).unwrap(), 1.001);
+        assert!(check(1.1)
+            .unwrap_err()
+            .to_string()
+            .starts_with("check failed: `float_eq!(left, right, rmax <= t)`"));
+    }
+}
+
+mod check_float_ne {
+    use super::*;
+
+    #[test]
+    fn pass() {
+        assert_eq!(check_float_ne!(0_f32, 1., abs <= 0.5), Ok(()));
+        assert_eq!(
+            check_float_ne!(0_f32, 1_f32, all(abs <= 2.0, ulps <= 1)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail() {
+        let err = check_float_ne!(0_f32, 1., abs <= 1., "testing: {}", 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"check failed: `float_ne!(left, right, abs <= t)`
+        left: `0.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `1.0`: testing: 0"#
+        );
+
+        let err = check_float_ne!(1_f64, 1_f64, all(abs <= 0.0, ulps <= 0)).unwrap_err();
+        assert_eq!(
+            err.check(),
+            "float_ne!(left, right, all(abs <= t, ulps <= t))"
+        );
+        assert_eq!(err.tols(), "     [abs] t: `0.0`,\n    [ulps] t: `0`");
+    }
+}
+
+mod float_ord {
+    use float_eq::{
+        assert_float_ge, assert_float_gt, assert_float_le, assert_float_lt, debug_assert_float_ge,
+        debug_assert_float_gt, debug_assert_float_le, debug_assert_float_lt, float_ge, float_gt,
+        float_le, float_lt,
+    };
+
+    #[test]
+    fn trailing_commas() {
+        assert!(float_le!(1.5_f32, 1.0, abs <= 0.5,));
+        assert!(float_lt!(1.0_f32, 1.5, abs <= 0.4,));
+        assert!(float_ge!(1.0_f32, 1.5, abs <= 0.5,));
+        assert!(float_gt!(1.5_f32, 1.0, abs <= 0.4,));
+        assert_float_le!(1.5_f32, 1.0, abs <= 0.5,);
+        assert_float_lt!(1.0_f32, 1.5, abs <= 0.4,);
+        assert_float_ge!(1.0_f32, 1.5, abs <= 0.5,);
+        assert_float_gt!(1.5_f32, 1.0, abs <= 0.4,);
+    }
+
+    #[test]
+    fn chaining() {
+        // 1.000_000_2_f32 is more than 0.000_000_1 but only two ULPs above 1.0
+        assert!(float_ge!(1_f32, 1.000_000_2, abs <= 0.000_000_1, ulps <= 2));
+        assert!(!float_ge!(
+            1_f32,
+            1.000_000_2,
+            abs <= 0.000_000_1,
+            ulps <= 1
+        ));
+        assert!(float_lt!(1_f32, 1.000_000_2, ulps <= 2, abs <= 0.000_000_1));
+        assert!(!float_lt!(
+            1_f32,
+            1.000_000_2,
+            ulps <= 2,
+            abs <= 0.000_000_3
+        ));
+
+        assert!(float_ge!(
+            1_f32,
+            1.000_000_2,
+            all(abs <= 0.000_000_3, ulps <= 2)
+        ));
+        assert!(!float_ge!(
+            1_f32,
+            1.000_000_2,
+            all(abs <= 0.000_000_3, ulps <= 1)
+        ));
+        assert!(float_ge!(
+            1_f32,
+            1.000_000_2,
+            all(abs <= 0.000_000_3, ulps <= 1),
+            ulps <= 2,
+        ));
+
+        assert_float_ge!(1_f32, 1.000_000_2, abs <= 0.000_000_1, ulps <= 2);
+        assert_float_lt!(1_f32, 1.000_000_2, all(abs <= 0.000_000_1, ulps <= 1));
+        assert_float_ge!(
+            1_f32,
+            1.000_000_2,
+            all(abs <= 0.000_000_3, ulps <= 1),
+            ulps <= 2,
+            "testing"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_le!(left, right, abs <= t)`
+        left: `1.5`,
+       right: `1.0`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+     [abs] t: `0.25`, past_bound: `0.25`"#)]
+    fn le_fail() {
+        assert_float_le!(1.5_f32, 1.0, abs <= 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_lt!(left, right, abs <= t)`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+     [abs] t: `0.5`, past_bound: `0.0`"#)]
+    fn lt_fail() {
+        assert_float_lt!(1.0_f32, 1.5, abs <= 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_ge!(left, right, ulps <= t)`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+    [ulps] t: `4194303`, past_bound: `1.1920929e-7`"#)]
+    fn ge_fail() {
+        assert_float_ge!(1.0_f32, 1.5, ulps <= 4_194_303);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_gt!(left, right, rmax <= t)`
+        left: `1.5`,
+       right: `1.0`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+    [rmax] t: `0.75`, past_bound: `0.25`"#)]
+    fn gt_fail() {
+        assert_float_gt!(1.5_f32, 1.0, rmax <= 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"`float_le!(left, right, abs <= t, ulps <= t)`
+        left: `1.5`,
+       right: `1.0`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(4194304)`,
+     [abs] t: `0.25`, past_bound: `0.25`,
+    [ulps] t: `1`, past_bound: `0.49999988`: testing: 1.5 > 1"#)]
+    fn chaining_fail_with_message() {
+        assert_float_le!(
+            1.5_f32,
+            1.0,
+            abs <= 0.25,
+            ulps <= 1,
+            "testing: {} > {}",
+            1.5_f32,
+            1_f32,
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = r#"`float_lt!(left, right, abs <= t)`"#)
+    )]
+    fn debug_fail() {
+        debug_assert_float_lt!(1.0_f32, 1.5, abs <= 0.5);
+    }
+
+    #[test]
+    fn debug() {
+        debug_assert_float_le!(1.5_f32, 1.0, abs <= 0.5);
+        debug_assert_float_lt!(1.0_f32, 1.5, abs <= 0.4);
+        debug_assert_float_ge!(1.0_f32, 1.5, abs <= 0.5);
+        debug_assert_float_gt!(1.5_f32, 1.0, abs <= 0.4);
+    }
+}
@@ -0,0 +1,126 @@
+#![allow(clippy::float_cmp)]
+
+use core::f32;
+use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll};
+use mint::{ColumnMatrix2, Point3, Quaternion, RowMatrix2x3, Vector2, Vector3};
+
+#[test]
+fn float_eq() {
+    let a = Vector2::from([0.999_999_9f32, 4.0]);
+    let b = Vector2::from([1.0f32, 3.999_999_5]);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs <= Vector2::from([1.0 * eps, 4.0 * eps]));
+    assert_float_ne!(a, b, abs <= Vector2::from([0.5 * eps, 4.0 * eps]));
+    assert_float_ne!(a, b, abs <= Vector2::from([1.0 * eps, 2.0 * eps]));
+
+    assert_float_eq!(a, b, rel <= Vector2::from([1.0 * eps, 1.0 * eps]));
+    assert_float_ne!(a, b, rel <= Vector2::from([0.5 * eps, 1.0 * eps]));
+
+    assert_float_eq!(a, b, rmax <= Vector2::from([1.0 * eps, 1.0 * eps]));
+    assert_float_ne!(a, b, rmax <= Vector2::from([1.0 * eps, 0.5 * eps]));
+
+    assert_float_eq!(a, b, rmin <= Vector2::from([2.0 * eps, 2.0 * eps]));
+    assert_float_ne!(a, b, rmin <= Vector2::from([1.0 * eps, 2.0 * eps]));
+
+    assert_float_eq!(a, b, r1st <= Vector2::from([2.0 * eps, 1.0 * eps]));
+    assert_float_ne!(a, b, r1st <= Vector2::from([1.0 * eps, 1.0 * eps]));
+
+    assert_float_eq!(a, b, r2nd <= Vector2::from([1.0 * eps, 2.0 * eps]));
+    assert_float_ne!(a, b, r2nd <= Vector2::from([1.0 * eps, 1.0 * eps]));
+
+    assert_float_eq!(a, b, ulps <= Vector2 { x: 2, y: 2 });
+    assert_float_ne!(a, b, ulps <= Vector2 { x: 1, y: 2 });
+    assert_float_ne!(a, b, ulps <= Vector2 { x: 2, y: 1 });
+}
+
+#[test]
+fn float_eq_all() {
+    let a = Vector2::from([0.999_999_9f32, 4.0]);
+    let b = Vector2::from([1.0f32, 3.999_999_5]);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs_all <= 4.0 * eps);
+    assert_float_ne!(a, b, abs_all <= 2.0 * eps);
+
+    assert_float_eq!(a, b, rel_all <= 1.0 * eps);
+    assert_float_ne!(a, b, rel_all <= 0.5 * eps);
+
+    assert_float_eq!(a, b, rmax_all <= 1.0 * eps);
+    assert_float_ne!(a, b, rmax_all <= 0.5 * eps);
+
+    assert_float_eq!(a, b, rmin_all <= 2.0 * eps);
+    assert_float_ne!(a, b, rmin_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, r1st_all <= 2.0 * eps);
+    assert_float_ne!(a, b, r1st_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, r2nd_all <= 2.0 * eps);
+    assert_float_ne!(a, b, r2nd_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, ulps_all <= 2);
+    assert_float_ne!(a, b, ulps_all <= 1);
+}
+
+#[test]
+fn debug_diff() {
+    let a = Vector2::from([1.0f32, 2.0]);
+    let b = Vector2::from([1.5f32, 2.25]);
+
+    assert_eq!(a.debug_abs_diff(&b), Vector2::from([0.5, 0.25]));
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        Vector2::from([Some(4_194_304), Some(1_048_576)])
+    );
+    assert_eq!(
+        a.debug_rmin_tol(&b, &Vector2::from([0.1, 0.2])),
+        Vector2::from([0.1, 0.4])
+    );
+    assert_eq!(a.debug_r2nd_all_tol(&b, &0.5), Vector2::from([0.75, 1.125]));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), Vector2::from([2, 2]));
+}
+
+#[test]
+fn points_quaternions_and_matrices() {
+    assert_float_eq!(
+        Point3::from([1.0f64, 2.0, 3.0]),
+        Point3::from([1.0f64, 2.0, 3.0 + 1e-12]),
+        abs_all <= 1e-9
+    );
+
+    let a = Quaternion {
+        v: Vector3::from([0.0f32, 0.0, 0.6]),
+        s: 0.8,
+    };
+    let b = Quaternion {
+        v: Vector3::from([0.0f32, 0.0, 0.8]),
+        s: 0.6,
+    };
+    assert_float_eq!(a, b, abs_all <= 0.2 + f32::EPSILON);
+    assert_float_ne!(a, b, abs_all <= 0.1);
+    assert_eq!(a.debug_abs_diff(&a).v, Vector3::from([0.0, 0.0, 0.0]));
+
+    let a = ColumnMatrix2::from([[1.0f32, 2.0], [3.0, 4.0]]);
+    let b = ColumnMatrix2::from([[1.0f32, 2.0], [3.5, 4.0]]);
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, abs_all <= 0.25);
+
+    let a = RowMatrix2x3::from([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_float_eq!(a, a, ulps_all <= 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+        left: `Vector3 { x: 1.0, y: 2.0, z: 3.0 }`,
+       right: `Vector3 { x: 1.0, y: 2.0, z: 3.5 }`,
+    abs_diff: `Vector3 { x: 0.0, y: 0.0, z: 0.5 }`,
+   ulps_diff: `Vector3 { x: Some(0), y: Some(0), z: Some(2097152) }`,
+ [abs_all] t: `Vector3 { x: 0.25, y: 0.25, z: 0.25 }`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(
+        Vector3::from([1.0f32, 2.0, 3.0]),
+        Vector3::from([1.0f32, 2.0, 3.5]),
+        abs_all <= 0.25
+    );
+}
@@ -0,0 +1,255 @@
+#![allow(clippy::float_cmp)]
+
+use core::f32;
+use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll};
+use nalgebra::{
+    Isometry, Isometry3, Matrix2, Point2, Quaternion, Translation2, Translation3, UnitQuaternion,
+    Vector2, Vector3,
+};
+
+#[test]
+fn float_eq() {
+    let a = Matrix2::new(0.999_999_9f32, 4.0, 1.0, 2.0);
+    let b = Matrix2::new(1.0f32, 3.999_999_5, 1.0, 2.0);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs <= Matrix2::new(1.0 * eps, 4.0 * eps, 0.0, 0.0));
+    assert_float_ne!(a, b, abs <= Matrix2::new(0.5 * eps, 4.0 * eps, 0.0, 0.0));
+    assert_float_ne!(a, b, abs <= Matrix2::new(1.0 * eps, 2.0 * eps, 0.0, 0.0));
+
+    assert_float_eq!(a, b, rel <= Matrix2::new(1.0 * eps, 1.0 * eps, 0.0, 0.0));
+    assert_float_ne!(a, b, rel <= Matrix2::new(0.5 * eps, 1.0 * eps, 0.0, 0.0));
+
+    assert_float_eq!(a, b, rmax <= Matrix2::new(1.0 * eps, 1.0 * eps, 0.0, 0.0));
+    assert_float_ne!(a, b, rmax <= Matrix2::new(1.0 * eps, 0.5 * eps, 0.0, 0.0));
+
+    assert_float_eq!(a, b, rmin <= Matrix2::new(2.0 * eps, 2.0 * eps, 0.0, 0.0));
+    assert_float_ne!(a, b, rmin <= Matrix2::new(1.0 * eps, 2.0 * eps, 0.0, 0.0));
+
+    assert_float_eq!(a, b, r1st <= Matrix2::new(2.0 * eps, 1.0 * eps, 0.0, 0.0));
+    assert_float_ne!(a, b, r1st <= Matrix2::new(1.0 * eps, 1.0 * eps, 0.0, 0.0));
+
+    assert_float_eq!(a, b, r2nd <= Matrix2::new(1.0 * eps, 2.0 * eps, 0.0, 0.0));
+    assert_float_ne!(a, b, r2nd <= Matrix2::new(1.0 * eps, 1.0 * eps, 0.0, 0.0));
+
+    assert_float_eq!(a, b, ulps <= Matrix2::new(2, 2, 0, 0));
+    assert_float_ne!(a, b, ulps <= Matrix2::new(1, 2, 0, 0));
+    assert_float_ne!(a, b, ulps <= Matrix2::new(2, 1, 0, 0));
+}
+
+#[test]
+fn float_eq_all() {
+    let a = Matrix2::new(0.999_999_9f32, 4.0, 1.0, 2.0);
+    let b = Matrix2::new(1.0f32, 3.999_999_5, 1.0, 2.0);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs_all <= 4.0 * eps);
+    assert_float_ne!(a, b, abs_all <= 2.0 * eps);
+
+    assert_float_eq!(a, b, rel_all <= 1.0 * eps);
+    assert_float_ne!(a, b, rel_all <= 0.5 * eps);
+
+    assert_float_eq!(a, b, rmax_all <= 1.0 * eps);
+    assert_float_ne!(a, b, rmax_all <= 0.5 * eps);
+
+    assert_float_eq!(a, b, rmin_all <= 2.0 * eps);
+    assert_float_ne!(a, b, rmin_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, r1st_all <= 2.0 * eps);
+    assert_float_ne!(a, b, r1st_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, r2nd_all <= 2.0 * eps);
+    assert_float_ne!(a, b, r2nd_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, ulps_all <= 2);
+    assert_float_ne!(a, b, ulps_all <= 1);
+}
+
+#[test]
+fn views() {
+    let a = Matrix2::new(1.0f64, 2.0, 3.0, 4.0);
+    let b = a.transpose();
+
+    assert_float_eq!(a.row(1), b.column(1).transpose(), abs_all <= 0.0);
+    assert_float_eq!(
+        a.column(0),
+        Vector2::new(1.0, 3.0),
+        ulps <= Vector2::new(0, 0)
+    );
+    assert_float_ne!(a.column(0), Vector2::new(1.0, 3.5), abs_all <= 0.25);
+}
+
+#[test]
+fn debug_diff() {
+    let a = Vector2::new(1.0f32, 2.0);
+    let b = Vector2::new(1.5f32, 2.25);
+
+    assert_eq!(a.debug_abs_diff(&a), Some(Vector2::new(0.0, 0.0)));
+    assert_eq!(a.debug_ulps_diff(&a), Some(Vector2::new(Some(0), Some(0))));
+
+    assert_eq!(a.debug_abs_diff(&b), Some(Vector2::new(0.5, 0.25)));
+    assert_eq!(b.debug_abs_diff(&a), Some(Vector2::new(0.5, 0.25)));
+
+    let ulps = Some(Vector2::new(Some(4_194_304), Some(1_048_576)));
+    assert_eq!(a.debug_ulps_diff(&b), ulps);
+    assert_eq!(b.debug_ulps_diff(&a), ulps);
+}
+
+#[test]
+fn debug_tol() {
+    let a = Vector2::new(2.0f32, 4.25);
+    let b = Vector2::new(2.5f32, 4.0);
+    let eps = Vector2::new(0.1, 0.2);
+
+    assert_eq!(a.debug_abs_tol(&b, &eps), Some(Vector2::new(0.1, 0.2)));
+    assert_eq!(a.debug_rel_tol(&b, &eps), Some(Vector2::new(0.25, 0.85)));
+    assert_eq!(a.debug_rmax_tol(&b, &eps), Some(Vector2::new(0.25, 0.85)));
+    assert_eq!(a.debug_rmin_tol(&b, &eps), Some(Vector2::new(0.2, 0.8)));
+    assert_eq!(a.debug_r1st_tol(&b, &eps), Some(Vector2::new(0.2, 0.85)));
+    assert_eq!(a.debug_r2nd_tol(&b, &eps), Some(Vector2::new(0.25, 0.8)));
+    assert_eq!(
+        a.debug_ulps_tol(&b, &Vector2::new(1, 2)),
+        Some(Vector2::new(1, 2))
+    );
+}
+
+#[test]
+fn debug_all_tol() {
+    let a = Vector2::new(2.0f32, 4.25);
+    let b = Vector2::new(2.5f32, 4.0);
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), Some(Vector2::new(0.2, 0.2)));
+    assert_eq!(a.debug_rel_all_tol(&b, &0.2), Some(Vector2::new(0.5, 0.85)));
+    assert_eq!(
+        a.debug_rmax_all_tol(&b, &0.2),
+        Some(Vector2::new(0.5, 0.85))
+    );
+    assert_eq!(a.debug_rmin_all_tol(&b, &0.2), Some(Vector2::new(0.4, 0.8)));
+    assert_eq!(
+        a.debug_r1st_all_tol(&b, &0.2),
+        Some(Vector2::new(0.4, 0.85))
+    );
+    assert_eq!(a.debug_r2nd_all_tol(&b, &0.2), Some(Vector2::new(0.5, 0.8)));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), Some(Vector2::new(2, 2)));
+}
+
+#[test]
+fn quaternion() {
+    let a = Quaternion::new(1.0f32, 2.0, 3.0, 4.0);
+    let b = Quaternion::new(1.0f32, 2.0, 3.5, 4.0);
+
+    assert_float_eq!(a, b, abs <= Quaternion::new(0.0, 0.0, 0.5, 0.0));
+    assert_float_ne!(a, b, abs <= Quaternion::new(0.5, 0.5, 0.25, 0.5));
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, abs_all <= 0.25);
+
+    assert_eq!(a.debug_abs_diff(&b), Quaternion::new(0.0, 0.0, 0.5, 0.0));
+    assert_eq!(
+        a.debug_ulps_diff(&b),
+        Quaternion::new(Some(0), Some(0), Some(2_097_152), Some(0))
+    );
+    assert_eq!(
+        a.debug_rmax_all_tol(&b, &0.1),
+        Quaternion::new(0.1, 0.2, 0.35, 0.4)
+    );
+}
+
+#[test]
+fn unit_quaternion() {
+    let a = UnitQuaternion::new_unchecked(Quaternion::new(0.0f64, 0.0, 0.0, 1.0));
+    let b = UnitQuaternion::new_unchecked(Quaternion::new(0.0f64, 0.0, 0.6, 0.8));
+
+    assert_float_eq!(a, b, abs_all <= 0.6);
+    assert_float_ne!(a, b, abs_all <= 0.5);
+    assert_float_eq!(a, b, abs <= Quaternion::new(0.0, 0.0, 0.6, 0.2));
+
+    assert_eq!(a.debug_abs_diff(&b).j, 0.6);
+    assert_eq!(
+        a.debug_abs_all_tol(&b, &0.1),
+        Quaternion::new(0.1, 0.1, 0.1, 0.1)
+    );
+}
+
+#[test]
+fn translation_and_point() {
+    let a = Translation2::new(1.0f32, 2.0);
+    let b = Translation2::new(1.0f32, 2.5);
+
+    assert_float_eq!(a, b, abs <= Translation2::new(0.0, 0.5));
+    assert_float_ne!(a, b, abs <= Translation2::new(0.5, 0.25));
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_eq!(a.debug_abs_diff(&b), Translation2::new(0.0, 0.5));
+
+    let a = Point2::new(1.0f32, 2.0);
+    let b = Point2::new(1.0f32, 2.5);
+
+    assert_float_eq!(a, b, ulps <= Point2::new(0, 2_097_152));
+    assert_float_ne!(a, b, ulps <= Point2::new(0, 2_097_151));
+    assert_float_eq!(a, b, rmax_all <= 0.2);
+    assert_eq!(a.debug_ulps_diff(&b), Point2::new(Some(0), Some(2_097_152)));
+}
+
+#[test]
+fn isometry() {
+    let rotation = UnitQuaternion::new_unchecked(Quaternion::new(0.0f64, 0.0, 0.6, 0.8));
+    let pose_a = Isometry3 {
+        rotation,
+        translation: Translation3::new(1.0, 2.0, 3.0),
+    };
+    let pose_b = Isometry3 {
+        rotation,
+        translation: Translation3::new(1.0, 2.0, 3.0 + 1e-10),
+    };
+
+    assert_float_eq!(pose_a, pose_b, abs_all <= 1e-9);
+    assert_float_ne!(pose_a, pose_b, abs_all <= 1e-11);
+    assert_float_eq!(pose_a, pose_b, ulps_all <= 225_180);
+    assert_float_ne!(pose_a, pose_b, ulps_all <= 225_179);
+
+    let tol = Isometry {
+        rotation: Quaternion::new(0.0, 0.0, 0.0, 0.0),
+        translation: Translation3::new(0.0, 0.0, 1e-9),
+    };
+    assert_float_eq!(pose_a, pose_b, abs <= tol);
+
+    let diff = pose_a.debug_abs_diff(&pose_b);
+    assert_eq!(diff.rotation, Quaternion::new(0.0, 0.0, 0.0, 0.0));
+    assert_eq!(diff.translation.vector.xy(), Vector2::new(0.0, 0.0));
+
+    let all_tol = pose_a.debug_abs_all_tol(&pose_b, &0.5);
+    assert_eq!(all_tol.rotation, Quaternion::new(0.5, 0.5, 0.5, 0.5));
+    assert_eq!(all_tol.translation.vector, Vector3::new(0.5, 0.5, 0.5));
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+ [abs_all] 1 failure:
+           [(1, 0)]: left: `3.0`, right: `3.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `0.25`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(
+        Matrix2::new(1.0f32, 2.0, 3.0, 4.0),
+        Matrix2::new(1.0f32, 2.0, 3.5, 4.0),
+        abs_all <= 0.25
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn report() {
+    use float_eq::compare;
+
+    let rotation = UnitQuaternion::new_unchecked(Quaternion::new(0.0f64, 0.0, 0.6, 0.8));
+    let pose_a = Isometry3 {
+        rotation,
+        translation: Translation3::new(1.0, 2.0, 3.0),
+    };
+    let pose_b = Isometry3 {
+        rotation,
+        translation: Translation3::new(1.0, 2.5, 3.0),
+    };
+
+    let report = compare!(pose_a, pose_b, abs_all <= 0.25);
+    let paths: Vec<_> = report.failures().map(|n| n.path.as_str()).collect();
+    assert_eq!(paths, vec![".translation.vector[1]"]);
+}
@@ -0,0 +1,187 @@
+#![allow(clippy::float_cmp)]
+
+use core::f32;
+use float_eq::{assert_float_eq, assert_float_ne, AssertFloatEq, AssertFloatEqAll};
+use ndarray::{arr1, arr2, Array2, ArrayD, IxDyn};
+
+#[test]
+fn float_eq() {
+    let a = arr2(&[[0.999_999_9f32, 4.0], [1.0, 2.0]]);
+    let b = arr2(&[[1.0f32, 3.999_999_5], [1.0, 2.0]]);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs <= arr2(&[[1.0 * eps, 4.0 * eps], [0.0, 0.0]]));
+    assert_float_ne!(a, b, abs <= arr2(&[[0.5 * eps, 4.0 * eps], [0.0, 0.0]]));
+    assert_float_ne!(a, b, abs <= arr2(&[[1.0 * eps, 2.0 * eps], [0.0, 0.0]]));
+
+    assert_float_eq!(a, b, rel <= arr2(&[[1.0 * eps, 1.0 * eps], [0.0, 0.0]]));
+    assert_float_ne!(a, b, rel <= arr2(&[[0.5 * eps, 1.0 * eps], [0.0, 0.0]]));
+
+    assert_float_eq!(a, b, rmax <= arr2(&[[1.0 * eps, 1.0 * eps], [0.0, 0.0]]));
+    assert_float_ne!(a, b, rmax <= arr2(&[[1.0 * eps, 0.5 * eps], [0.0, 0.0]]));
+
+    assert_float_eq!(a, b, rmin <= arr2(&[[2.0 * eps, 2.0 * eps], [0.0, 0.0]]));
+    assert_float_ne!(a, b, rmin <= arr2(&[[1.0 * eps, 2.0 * eps], [0.0, 0.0]]));
+
+    assert_float_eq!(a, b, r1st <= arr2(&[[2.0 * eps, 1.0 * eps], [0.0, 0.0]]));
+    assert_float_ne!(a, b, r1st <= arr2(&[[1.0 * eps, 1.0 * eps], [0.0, 0.0]]));
+
+    assert_float_eq!(a, b, r2nd <= arr2(&[[1.0 * eps, 2.0 * eps], [0.0, 0.0]]));
+    assert_float_ne!(a, b, r2nd <= arr2(&[[1.0 * eps, 1.0 * eps], [0.0, 0.0]]));
+
+    assert_float_eq!(a, b, ulps <= arr2(&[[2, 2], [0, 0]]));
+    assert_float_ne!(a, b, ulps <= arr2(&[[1, 2], [0, 0]]));
+    assert_float_ne!(a, b, ulps <= arr2(&[[2, 1], [0, 0]]));
+}
+
+#[test]
+fn float_eq_all() {
+    let a = arr2(&[[0.999_999_9f32, 4.0], [1.0, 2.0]]);
+    let b = arr2(&[[1.0f32, 3.999_999_5], [1.0, 2.0]]);
+    let eps = f32::EPSILON;
+
+    assert_float_eq!(a, b, abs_all <= 4.0 * eps);
+    assert_float_ne!(a, b, abs_all <= 2.0 * eps);
+
+    assert_float_eq!(a, b, rel_all <= 1.0 * eps);
+    assert_float_ne!(a, b, rel_all <= 0.5 * eps);
+
+    assert_float_eq!(a, b, rmax_all <= 1.0 * eps);
+    assert_float_ne!(a, b, rmax_all <= 0.5 * eps);
+
+    assert_float_eq!(a, b, rmin_all <= 2.0 * eps);
+    assert_float_ne!(a, b, rmin_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, r1st_all <= 2.0 * eps);
+    assert_float_ne!(a, b, r1st_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, r2nd_all <= 2.0 * eps);
+    assert_float_ne!(a, b, r2nd_all <= 1.0 * eps);
+
+    assert_float_eq!(a, b, ulps_all <= 2);
+    assert_float_ne!(a, b, ulps_all <= 1);
+}
+
+#[test]
+fn views_and_dynamic_dimensions() {
+    let a = arr2(&[[1.0f64, 2.0], [3.0, 4.0]]);
+    let b = a.t().to_owned();
+
+    assert_float_eq!(a.view(), b.t(), abs_all <= 0.0);
+    assert_float_ne!(a.view(), b.view(), abs_all <= 0.5);
+    assert_float_eq!(a.row(1), arr1(&[3.0, 4.0]), ulps <= arr1(&[0, 0]));
+
+    let c = a.clone().into_dyn();
+    let d = ArrayD::from_elem(IxDyn(&[2, 2]), 2.5);
+    assert_float_eq!(c, d, abs_all <= 1.5);
+    assert_float_ne!(c, d, abs_all <= 1.0);
+}
+
+#[test]
+fn different_shapes() {
+    let a = Array2::<f32>::zeros((2, 3));
+    let b = Array2::<f32>::zeros((3, 2));
+
+    assert_float_ne!(a, b, abs_all <= 1.0);
+    assert_float_ne!(a, b, abs <= Array2::ones((2, 3)));
+    assert_float_ne!(a, a, abs <= Array2::ones((3, 2)));
+    assert_float_ne!(a, a, ulps <= Array2::zeros((3, 2)));
+
+    assert_eq!(a.debug_abs_diff(&b), None);
+    assert_eq!(a.debug_ulps_diff(&b), None);
+    assert_eq!(a.debug_abs_all_tol(&b, &1.0), None);
+    assert_eq!(a.debug_abs_tol(&a, &Array2::ones((3, 2))), None);
+}
+
+#[test]
+fn debug_diff() {
+    let a = arr1(&[1.0f32, 2.0]);
+    let b = arr1(&[1.5f32, 2.25]);
+
+    assert_eq!(a.debug_abs_diff(&a), Some(arr1(&[0.0, 0.0])));
+    assert_eq!(a.debug_ulps_diff(&a), Some(arr1(&[Some(0), Some(0)])));
+
+    assert_eq!(a.debug_abs_diff(&b), Some(arr1(&[0.5, 0.25])));
+    assert_eq!(b.debug_abs_diff(&a), Some(arr1(&[0.5, 0.25])));
+
+    let ulps = Some(arr1(&[Some(4_194_304), Some(1_048_576)]));
+    assert_eq!(a.debug_ulps_diff(&b), ulps);
+    assert_eq!(b.debug_ulps_diff(&a), ulps);
+}
+
+#[test]
+fn debug_tol() {
+    let a = arr1(&[2.0f32, 4.25]);
+    let b = arr1(&[2.5f32, 4.0]);
+    let eps = arr1(&[0.1, 0.2]);
+
+    assert_eq!(a.debug_abs_tol(&b, &eps), Some(arr1(&[0.1, 0.2])));
+    assert_eq!(a.debug_rel_tol(&b, &eps), Some(arr1(&[0.25, 0.85])));
+    assert_eq!(a.debug_rmax_tol(&b, &eps), Some(arr1(&[0.25, 0.85])));
+    assert_eq!(a.debug_rmin_tol(&b, &eps), Some(arr1(&[0.2, 0.8])));
+    assert_eq!(a.debug_r1st_tol(&b, &eps), Some(arr1(&[0.2, 0.85])));
+    assert_eq!(a.debug_r2nd_tol(&b, &eps), Some(arr1(&[0.25, 0.8])));
+    assert_eq!(a.debug_ulps_tol(&b, &arr1(&[1, 2])), Some(arr1(&[1, 2])));
+}
+
+#[test]
+fn debug_all_tol() {
+    let a = arr1(&[2.0f32, 4.25]);
+    let b = arr1(&[2.5f32, 4.0]);
+
+    assert_eq!(a.debug_abs_all_tol(&b, &0.2), Some(arr1(&[0.2, 0.2])));
+    assert_eq!(a.debug_rel_all_tol(&b, &0.2), Some(arr1(&[0.5, 0.85])));
+    assert_eq!(a.debug_rmax_all_tol(&b, &0.2), Some(arr1(&[0.5, 0.85])));
+    assert_eq!(a.debug_rmin_all_tol(&b, &0.2), Some(arr1(&[0.4, 0.8])));
+    assert_eq!(a.debug_r1st_all_tol(&b, &0.2), Some(arr1(&[0.4, 0.85])));
+    assert_eq!(a.debug_r2nd_all_tol(&b, &0.2), Some(arr1(&[0.5, 0.8])));
+    assert_eq!(a.debug_ulps_all_tol(&b, &2), Some(arr1(&[2, 2])));
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+ [abs_all] 1 failure:
+           [1, 0]: left: `3.0`, right: `3.5`, abs_diff: `0.5`, ulps_diff: `Some(2097152)`, t: `0.25`"#)]
+fn assert_fail_message() {
+    assert_float_eq!(
+        arr2(&[[1.0f32, 2.0], [3.0, 4.0]]),
+        arr2(&[[1.0f32, 2.0], [3.5, 4.0]]),
+        abs_all <= 0.25
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs <= t)`
+     [abs] 1 failure:
+           <root>: shapes differ (left: [2, 3], right: [3, 2])"#)]
+fn assert_fail_shape_message() {
+    assert_float_eq!(
+        Array2::<f32>::zeros((2, 3)),
+        Array2::<f32>::zeros((3, 2)),
+        abs <= Array2::zeros((2, 3))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn report() {
+    use float_eq::{compare, Mismatch, ReportKind};
+
+    let a = arr2(&[[1.0f32, 2.0], [3.0, 4.0]]);
+    let b = arr2(&[[1.0f32, 2.5], [3.0, 4.0]]);
+
+    let report = compare!(a, b, abs <= Array2::from_elem((2, 2), 0.25));
+    let paths: Vec<_> = report.failures().map(|n| n.path.as_str()).collect();
+    assert_eq!(paths, vec!["[0, 1]"]);
+
+    let report = compare!(a, b, rmax <= Array2::from_elem((1, 2), 0.25));
+    assert_eq!(
+        report.root.kind,
+        ReportKind::Mismatch(Mismatch::TolShape {
+            shape: vec![2, 2],
+            tol: vec![1, 2],
+        })
+    );
+}
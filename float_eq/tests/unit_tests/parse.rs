@@ -0,0 +1,191 @@
+use float_eq::{Check, CheckSet, ParseCheckError, ParseTol};
+
+fn parse<T: ParseTol>(s: &str) -> Result<CheckSet<T>, ParseCheckError>
+where
+    float_eq::UlpsTol<T>: Sized + std::convert::TryFrom<u64>,
+{
+    s.parse()
+}
+
+fn error(s: &str) -> String {
+    parse::<f32>(s).unwrap_err().to_string()
+}
+
+#[test]
+fn checks() {
+    let checks = [
+        ("abs <= 0.5", Check::Abs(0.5_f64)),
+        ("rel <= 0.5", Check::Rmax(0.5)),
+        ("rmax <= 0.5", Check::Rmax(0.5)),
+        ("rmin <= 0.5", Check::Rmin(0.5)),
+        ("r1st <= 0.5", Check::R1st(0.5)),
+        ("r2nd <= 0.5", Check::R2nd(0.5)),
+        ("atol_rtol <= (0.5, 0.25)", Check::AtolRtol(0.5, 0.25)),
+        ("ulps <= 4", Check::Ulps(4)),
+    ];
+    for (s, check) in &checks {
+        assert_eq!(parse(s), Ok(CheckSet::Any(vec![*check])), "{}", s);
+
+        // the _all variants parse to the same check
+        let all = s.replacen(" <=", "_all <=", 1);
+        assert_eq!(parse(&all), Ok(CheckSet::Any(vec![*check])), "{}", all);
+    }
+}
+
+#[test]
+fn any() {
+    assert_eq!(
+        parse("rmax <= 1e-6, ulps <= 4"),
+        Ok(CheckSet::Any(vec![Check::Rmax(1e-6_f32), Check::Ulps(4)]))
+    );
+    assert_eq!(
+        parse("  abs<=0.1,rmin_all<=0.2 ,  "),
+        Ok(CheckSet::Any(vec![Check::Abs(0.1_f64), Check::Rmin(0.2)]))
+    );
+}
+
+#[test]
+fn all() {
+    assert_eq!(
+        parse("all(abs <= 0.1, ulps_all <= 2)"),
+        Ok(CheckSet::All(vec![Check::Abs(0.1_f64), Check::Ulps(2)]))
+    );
+    assert_eq!(
+        parse(" all ( atol_rtol <= (0.1, 0.2,), ) , "),
+        Ok(CheckSet::All(vec![Check::AtolRtol(0.1_f32, 0.2)]))
+    );
+}
+
+#[test]
+fn tolerances() {
+    let tols = [
+        ("1", 1.0),
+        ("-0.5", -0.5),
+        ("+2.5e-3", 0.0025),
+        ("1_000.000_1", 1000.0001),
+        ("0x1p-20", 1.0 / 1_048_576.0),
+        ("0x1.8p1", 3.0),
+        ("-0X.8", -0.5),
+        ("0x10", 16.0),
+        ("0xA_BC.Dp+4", 43_981.0),
+        ("0x1p-1074", 5e-324),
+        ("0x1p1024", <f64>::INFINITY),
+        ("inf", <f64>::INFINITY),
+    ];
+    for (s, tol) in &tols {
+        assert_eq!(f64::parse_tol(s), Some(*tol), "{}", s);
+    }
+    assert!(f64::parse_tol("NaN").unwrap().is_nan());
+
+    // rounds to nearest, including digits beyond the precision of the mantissa
+    assert_eq!(f32::parse_tol("0x1.000001p0"), Some(1.0));
+    assert_eq!(f32::parse_tol("0x1.0000011p0"), Some(1.000_000_1));
+    assert_eq!(
+        f64::parse_tol("0x1.00000000000008000000000000001p0"),
+        Some(1.000_000_000_000_000_2)
+    );
+
+    for s in &[
+        "", "-", "_1", "1_", "1e", "0x", "0x.p1", "0x1q", "0x1p", "1.0f32",
+    ] {
+        assert_eq!(f64::parse_tol(s), None, "{}", s);
+    }
+}
+
+#[test]
+fn ulps_tolerances() {
+    assert_eq!(
+        parse("ulps <= 1_000"),
+        Ok(CheckSet::Any(vec![Check::<f32>::Ulps(1000)]))
+    );
+    assert_eq!(
+        parse("ulps <= 0xFF"),
+        Ok(CheckSet::Any(vec![Check::<f32>::Ulps(255)]))
+    );
+    assert_eq!(
+        parse("ulps <= 4294967295"),
+        Ok(CheckSet::Any(vec![Check::<f32>::Ulps(u32::MAX)]))
+    );
+    assert_eq!(
+        parse("ulps <= 4294967296"),
+        Ok(CheckSet::Any(vec![Check::<f64>::Ulps(4_294_967_296)]))
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        parse::<f32>("rmx <= 0.1"),
+        Err(ParseCheckError::UnknownAlgorithm("rmx".to_owned()))
+    );
+    assert_eq!(
+        error("rmx <= 0.1"),
+        "unknown check `rmx`, expected one of abs, rel, rmax, rmin, r1st, r2nd, \
+         atol_rtol or ulps, or their _all variants"
+    );
+    assert_eq!(
+        error("rmax <= 1e-"),
+        "invalid tolerance `1e-` for `rmax`, expected a floating point number"
+    );
+    assert_eq!(
+        error("abs_all <= 0.1.2"),
+        "invalid tolerance `0.1.2` for `abs_all`, expected a floating point number"
+    );
+    assert_eq!(
+        error("ulps <= 4.5"),
+        "invalid tolerance `4.5` for `ulps`, expected an unsigned integer"
+    );
+    assert_eq!(
+        error("ulps_all <= -1"),
+        "invalid tolerance `-1` for `ulps_all`, expected an unsigned integer"
+    );
+    assert_eq!(
+        parse::<f32>("ulps <= 5_000_000_000"),
+        Err(ParseCheckError::UlpsTolOutOfRange {
+            check: "ulps",
+            tol: "5_000_000_000".to_owned(),
+            ulps_tol: "u32",
+        })
+    );
+    assert_eq!(
+        parse::<f64>("ulps_all <= 0x1_0000_0000_0000_0000")
+            .unwrap_err()
+            .to_string(),
+        "tolerance `0x1_0000_0000_0000_0000` for `ulps_all` is too large for `u64`"
+    );
+
+    assert_eq!(error(""), "expected a check, found end of input");
+    assert_eq!(error("rmax"), "expected `<=`, found end of input");
+    assert_eq!(error("rmax < 0.1"), "expected `<=`, found `<`");
+    assert_eq!(error("rmax <="), "expected a tolerance, found end of input");
+    assert_eq!(
+        error("abs <= 0.1 rmax <= 0.2"),
+        "expected `,` or end of input, found `rmax`"
+    );
+    assert_eq!(
+        error("abs <= 0.1,, rmax <= 0.2"),
+        "expected a check, found `,`"
+    );
+    assert_eq!(error("atol_rtol <= 0.1"), "expected `(`, found `0.1`");
+    assert_eq!(error("atol_rtol <= (0.1)"), "expected `,`, found `)`");
+    assert_eq!(error("all(abs <= 0.1"), "expected `)`, found end of input");
+
+    let mixed = "an `all(...)` group cannot be combined with other checks";
+    assert_eq!(error("abs <= 0.1, all(rmax <= 0.2)"), mixed);
+    assert_eq!(error("all(rmax <= 0.2), abs <= 0.1"), mixed);
+    assert_eq!(error("all(all(rmax <= 0.2))"), mixed);
+}
+
+#[test]
+fn parsed_checks() {
+    let a = [1.0_f32, 2.0];
+    let b = [1.000_000_1_f32, 2.0];
+
+    let checks: CheckSet<f32> = "rmax_all <= 0x1p-24, ulps_all <= 1".parse().unwrap();
+    assert!(checks.eq_all(&a, &b));
+    checks.assert_eq_all(&a, &b);
+
+    let checks: CheckSet<f32> = "all(abs <= 1e-6, ulps <= 0)".parse().unwrap();
+    assert!(checks.ne(&a[0], &b[0]));
+    checks.assert_ne_all(&a, &b);
+}
@@ -1,5 +1,7 @@
 mod assert_float_eq;
 mod eq_abs;
+mod eq_atol_rtol;
 mod eq_rmax;
 mod eq_rmin;
 mod eq_ulps;
+mod ord;
@@ -0,0 +1,142 @@
+//! Systematic tests of eq_atol_rtol/eq_atol_rtol_all behaviour over primitives.
+
+macro_rules! impl_tests {
+    ($float:ident) => {
+        mod $float {
+            use crate::$float::*;
+            use float_eq::{
+                assert_float_eq, assert_float_ne, float_eq, float_ne, AssertFloatEq,
+                AssertFloatEqAll, FloatEq, FloatEqAll,
+            };
+
+            fn check_eq_atol_rtol(a: $float, b: $float, atol: $float, rtol: $float) {
+                assert_float_eq!(a, b, atol_rtol <= (atol, rtol));
+                assert_float_eq!(a, b, atol_rtol_all <= (atol, rtol));
+
+                assert!(float_eq!(a, b, atol_rtol <= (atol, rtol)));
+                assert!(float_eq!(a, b, atol_rtol_all <= (atol, rtol)));
+                assert!(!float_ne!(a, b, atol_rtol <= (atol, rtol)));
+                assert!(!float_ne!(a, b, atol_rtol_all <= (atol, rtol)));
+
+                assert!(a.eq_atol_rtol(&b, &atol, &rtol));
+                assert!(a.eq_atol_rtol_all(&b, &atol, &rtol));
+                assert!(!a.ne_atol_rtol(&b, &atol, &rtol));
+                assert!(!a.ne_atol_rtol_all(&b, &atol, &rtol));
+            }
+
+            fn check_ne_atol_rtol(a: $float, b: $float, atol: $float, rtol: $float) {
+                assert_float_ne!(a, b, atol_rtol <= (atol, rtol));
+                assert_float_ne!(a, b, atol_rtol_all <= (atol, rtol));
+
+                assert!(!float_eq!(a, b, atol_rtol <= (atol, rtol)));
+                assert!(!float_eq!(a, b, atol_rtol_all <= (atol, rtol)));
+                assert!(float_ne!(a, b, atol_rtol <= (atol, rtol)));
+                assert!(float_ne!(a, b, atol_rtol_all <= (atol, rtol)));
+
+                assert!(!a.eq_atol_rtol(&b, &atol, &rtol));
+                assert!(!a.eq_atol_rtol_all(&b, &atol, &rtol));
+                assert!(a.ne_atol_rtol(&b, &atol, &rtol));
+                assert!(a.ne_atol_rtol_all(&b, &atol, &rtol));
+            }
+
+            // also covers negative values
+            fn check_eq(a: $float, b: $float, atol: $float, rtol: $float) {
+                check_eq_atol_rtol(a, b, atol, rtol);
+                check_eq_atol_rtol(-a, -b, atol, rtol);
+            }
+
+            // also covers negative values
+            fn check_ne(a: $float, b: $float, atol: $float, rtol: $float) {
+                check_ne_atol_rtol(a, b, atol, rtol);
+                check_ne_atol_rtol(-a, -b, atol, rtol);
+            }
+
+            #[test]
+            fn zero() {
+                check_eq(0.0, 0.0, 0.0, 0.0);
+                check_eq(0.0, -0.0, 0.0, 0.0);
+                check_eq(-0.0, 0.0, 0.0, 0.0);
+
+                // relative tolerance alone cannot match anything to zero
+                check_ne(next(0.0), 0.0, 0.0, 1.0);
+                check_eq(next(0.0), 0.0, next(0.0), 0.0);
+            }
+
+            #[test]
+            fn absolute_only() {
+                check_eq(1.0, next(1.0), EPSILON, 0.0);
+                check_ne(1.0, next(1.0), 0.5 * EPSILON, 0.0);
+
+                check_eq(2.0, next(2.0), 2.0 * EPSILON, 0.0);
+                check_ne(2.0, next(2.0), 1.0 * EPSILON, 0.0);
+            }
+
+            #[test]
+            fn relative_only() {
+                // scaled to the second operand, as with r2nd
+                check_eq(1.0, next(1.0), 0.0, EPSILON);
+                check_ne(1.0, next_n(1.0, 2), 0.0, EPSILON);
+
+                check_eq(2.0, prev(2.0), 0.0, EPSILON);
+                check_ne(2.0, prev(2.0), 0.0, 0.5 * EPSILON);
+                check_eq(prev(2.0), 2.0, 0.0, 0.5 * EPSILON);
+            }
+
+            #[test]
+            fn combined() {
+                // the tolerances are summed, so neither alone is enough
+                let b = next_n(1.0, 4);
+                check_ne(1.0, b, 2.0 * EPSILON, 0.0);
+                check_ne(1.0, b, 0.0, 2.0 * EPSILON);
+                check_eq(1.0, b, 2.0 * EPSILON, 2.0 * EPSILON);
+                check_ne(1.0, b, 2.0 * EPSILON, EPSILON);
+
+                // numpy's default tolerances
+                check_eq(1.0, 1.000_001, 1e-8, 1e-5);
+                check_ne(1.0, 1.001, 1e-8, 1e-5);
+                check_eq(0.0, 1e-9, 1e-8, 1e-5);
+                check_ne(0.0, 1e-7, 1e-8, 1e-5);
+            }
+
+            #[test]
+            fn debug_tol() {
+                let a: $float = 2.0;
+                let b: $float = -4.0;
+                assert_eq!(a.debug_atol_rtol_tol(&b, &0.5, &0.25), 1.5);
+                assert_eq!(b.debug_atol_rtol_tol(&a, &0.5, &0.25), 1.0);
+                assert_eq!(a.debug_atol_rtol_all_tol(&b, &0.5, &0.25), 1.5);
+            }
+
+            #[test]
+            fn infinities() {
+                check_eq(INFINITY, INFINITY, 0.0, 0.0);
+
+                // as in NumPy, infinities are only equal to themselves
+                check_ne(INFINITY, MAX_NORMAL, INFINITY, INFINITY);
+                check_ne(MAX_NORMAL, INFINITY, INFINITY, INFINITY);
+                check_ne(INFINITY, -INFINITY, INFINITY, INFINITY);
+
+                // but the difference of finite values may still overflow
+                check_eq(MAX_NORMAL, -MAX_NORMAL, 0.0, 2.0);
+            }
+
+            #[test]
+            fn nans() {
+                let nans = nan_test_values();
+                for &a in &nans {
+                    check_ne_atol_rtol(a, a, 0.0, 0.0);
+
+                    check_ne_atol_rtol(1.0, a, 1.0, 1.0);
+                    check_ne_atol_rtol(a, 1.0, 1.0, 1.0);
+
+                    for &b in &nans {
+                        check_ne_atol_rtol(a, b, INFINITY, INFINITY);
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_tests!(f32);
+impl_tests!(f64);
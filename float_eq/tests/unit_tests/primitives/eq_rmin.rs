@@ -1,5 +1,5 @@
-/// Systematic tests of rmin/rmin_all behaviour over primitives. Also tests the
-/// relevant combinations of r1st/r1st_all and r2nd/r2nd_all.
+//! Systematic tests of rmin/rmin_all behaviour over primitives. Also tests the
+//! relevant combinations of r1st/r1st_all and r2nd/r2nd_all.
 
 macro_rules! impl_tests {
     ($float:ident) => {
@@ -1,4 +1,4 @@
-/// Systematic tests of eq_ulps/eq_ulps_all behaviour over primitives.
+//! Systematic tests of eq_ulps/eq_ulps_all behaviour over primitives.
 
 macro_rules! impl_tests {
     ($float:ident, $uint:ident) => {
@@ -0,0 +1,242 @@
+//! Systematic tests of FloatOrd/AssertFloatOrd behaviour over primitives.
+
+// Checks each of the ordering macros, expecting the results of [lt, le, gt, ge].
+macro_rules! check_ord {
+    ($a:expr, $b:expr, $check:ident <= $tol:expr, $expected:expr) => {{
+        let (a, b, expected) = ($a, $b, $expected);
+        let actual = [
+            float_lt!(a, b, $check <= $tol),
+            float_le!(a, b, $check <= $tol),
+            float_gt!(a, b, $check <= $tol),
+            float_ge!(a, b, $check <= $tol),
+        ];
+        assert_eq!(
+            actual,
+            expected,
+            "{:?} vs {:?} with {} <= {:?}",
+            a,
+            b,
+            stringify!($check),
+            $tol
+        );
+    }};
+}
+
+macro_rules! impl_tests {
+    ($float:ident, $uint:ident) => {
+        mod $float {
+            use crate::$float::*;
+            use float_eq::{
+                assert_float_ge, assert_float_gt, assert_float_le, assert_float_lt, float_ge,
+                float_gt, float_le, float_lt, AssertFloatOrd, FloatOrd, FloatOrdOp,
+            };
+
+            const NONE: [bool; 4] = [false, false, false, false];
+
+            // the smallest positive subnormal value
+            fn tiny() -> $float {
+                $float::from_bits(1)
+            }
+
+            #[test]
+            fn zero_tol_is_operator() {
+                let values: [$float; 9] = [
+                    -INFINITY,
+                    -MAX_NORMAL,
+                    -1.0,
+                    -tiny(),
+                    -0.0,
+                    0.0,
+                    MIN_NORMAL,
+                    1.0,
+                    INFINITY,
+                ];
+                for &a in &values {
+                    for &b in &values {
+                        let expected = [a < b, a <= b, a > b, a >= b];
+                        check_ord!(a, b, abs <= 0.0, expected);
+                        check_ord!(a, b, rel <= 0.0, expected);
+                        check_ord!(a, b, rmax <= 0.0, expected);
+                        check_ord!(a, b, rmin <= 0.0, expected);
+                        check_ord!(a, b, r1st <= 0.0, expected);
+                        check_ord!(a, b, r2nd <= 0.0, expected);
+                        check_ord!(a, b, ulps <= 0, expected);
+                    }
+                }
+            }
+
+            #[test]
+            fn abs() {
+                let a: $float = 1.0;
+                let b: $float = 1.5;
+
+                check_ord!(a, b, abs <= 0.25, [true, true, false, false]);
+                check_ord!(a, b, abs <= 0.5, [false, true, false, true]);
+                check_ord!(b, a, abs <= 0.25, [false, false, true, true]);
+                check_ord!(b, a, abs <= 0.5, [false, true, false, true]);
+                check_ord!(-b, -a, abs <= 0.25, [true, true, false, false]);
+                check_ord!(-b, -a, abs <= 0.5, [false, true, false, true]);
+
+                assert_float_lt!(a, b, abs <= 0.25);
+                assert_float_le!(b, a, abs <= 0.5);
+                assert_float_gt!(b, a, abs <= 0.25);
+                assert_float_ge!(a, b, abs <= 0.5);
+            }
+
+            #[test]
+            fn rel() {
+                let a: $float = 2.0;
+                let b: $float = 4.0;
+
+                // rmax and r2nd scale to 4.0, rmin and r1st to 2.0
+                check_ord!(a, b, rel <= 0.5, [false, true, false, true]);
+                check_ord!(a, b, rmax <= 0.5, [false, true, false, true]);
+                check_ord!(a, b, r2nd <= 0.5, [false, true, false, true]);
+                check_ord!(a, b, rmin <= 0.5, [true, true, false, false]);
+                check_ord!(a, b, r1st <= 0.5, [true, true, false, false]);
+
+                check_ord!(b, a, rmax <= 0.5, [false, true, false, true]);
+                check_ord!(b, a, r1st <= 0.5, [false, true, false, true]);
+                check_ord!(b, a, rmin <= 0.5, [false, false, true, true]);
+                check_ord!(b, a, r2nd <= 0.5, [false, false, true, true]);
+
+                assert_float_ge!(a, b, rmax <= 0.5);
+                assert_float_lt!(a, b, rmin <= 0.5);
+                assert_float_gt!(b, a, r2nd <= 0.5);
+                assert_float_le!(b, a, r1st <= 0.5);
+            }
+
+            #[test]
+            fn ulps() {
+                let a: $float = 1.0;
+                let b = next_n(a, 4);
+
+                check_ord!(a, b, ulps <= 3, [true, true, false, false]);
+                check_ord!(a, b, ulps <= 4, [false, true, false, true]);
+                check_ord!(b, a, ulps <= 3, [false, false, true, true]);
+                check_ord!(b, a, ulps <= 4, [false, true, false, true]);
+                check_ord!(-b, -a, ulps <= 3, [true, true, false, false]);
+                check_ord!(-b, -a, ulps <= 4, [false, true, false, true]);
+
+                assert_float_lt!(a, b, ulps <= 3);
+                assert_float_ge!(a, b, ulps <= 4);
+            }
+
+            #[test]
+            fn ulps_across_zero() {
+                let zero: $float = 0.0;
+                // both zeroes are the same step away from the smallest values
+                check_ord!(-tiny(), tiny(), ulps <= 1, [true, true, false, false]);
+                check_ord!(-tiny(), tiny(), ulps <= 2, [false, true, false, true]);
+                check_ord!(-tiny(), zero, ulps <= 1, [false, true, false, true]);
+                check_ord!(-tiny(), -zero, ulps <= 1, [false, true, false, true]);
+                check_ord!(tiny(), -zero, ulps <= 1, [false, true, false, true]);
+                check_ord!(tiny(), -tiny(), ulps <= 1, [false, false, true, true]);
+                check_ord!(tiny(), -tiny(), ulps <= 2, [false, true, false, true]);
+            }
+
+            #[test]
+            fn ulps_stop_at_infinity() {
+                let one: $float = 1.0;
+                check_ord!(INFINITY, MAX_NORMAL, ulps <= 0, [false, false, true, true]);
+                check_ord!(INFINITY, MAX_NORMAL, ulps <= 1, [false, true, false, true]);
+                check_ord!(
+                    INFINITY,
+                    MAX_NORMAL,
+                    ulps <= MAX_ULPS,
+                    [false, true, false, true]
+                );
+                check_ord!(
+                    -INFINITY,
+                    -MAX_NORMAL,
+                    ulps <= MAX_ULPS,
+                    [false, true, false, true]
+                );
+                check_ord!(one, -INFINITY, ulps <= MAX_ULPS, [false, true, false, true]);
+            }
+
+            #[test]
+            fn infinities() {
+                let one: $float = 1.0;
+                check_ord!(INFINITY, INFINITY, abs <= 1.0, [false, true, false, true]);
+                check_ord!(INFINITY, INFINITY, rmax <= 0.1, [false, true, false, true]);
+                check_ord!(
+                    -INFINITY,
+                    -INFINITY,
+                    rmin <= 0.1,
+                    [false, true, false, true]
+                );
+                check_ord!(one, INFINITY, abs <= MAX_NORMAL, [true, true, false, false]);
+                check_ord!(INFINITY, one, r2nd <= 0.1, [false, false, true, true]);
+                check_ord!(-INFINITY, one, rmax <= 0.0, [true, true, false, false]);
+                check_ord!(INFINITY, one, abs <= INFINITY, [false, true, false, true]);
+            }
+
+            #[test]
+            fn nan() {
+                let one: $float = 1.0;
+                let two: $float = 2.0;
+                for &nan in &nan_test_values() {
+                    check_ord!(nan, one, abs <= INFINITY, NONE);
+                    check_ord!(one, nan, abs <= INFINITY, NONE);
+                    check_ord!(nan, nan, abs <= INFINITY, NONE);
+                    check_ord!(nan, one, rmax <= 1.0, NONE);
+                    check_ord!(one, nan, rmin <= 1.0, NONE);
+                    check_ord!(nan, INFINITY, r1st <= 1.0, NONE);
+                    check_ord!(-INFINITY, nan, r2nd <= 1.0, NONE);
+                    check_ord!(nan, one, ulps <= MAX_ULPS, NONE);
+                    check_ord!(one, nan, ulps <= MAX_ULPS, NONE);
+
+                    // a NaN tolerance leaves le and ge as the plain operators,
+                    // and can never show that lt or gt hold
+                    check_ord!(one, one, abs <= nan, [false, true, false, true]);
+                    check_ord!(one, two, abs <= nan, [false, true, false, false]);
+                }
+            }
+
+            #[test]
+            fn trait_methods() {
+                let a: $float = 1.0;
+                let b: $float = 1.5;
+
+                assert!(a.ord_abs(&b, FloatOrdOp::Lt, &0.25));
+                assert!(!a.ord_abs(&b, FloatOrdOp::Lt, &0.5));
+                assert!(a.ord_rel(&b, FloatOrdOp::Ge, &(1.0 / 3.0)));
+                assert!(!a.ord_rmax(&b, FloatOrdOp::Ge, &0.25));
+                assert!(a.ord_rmin(&b, FloatOrdOp::Lt, &0.25));
+                assert!(a.ord_r1st(&b, FloatOrdOp::Le, &0.0));
+                assert!(b.ord_r2nd(&a, FloatOrdOp::Gt, &0.25));
+                assert!(b.ord_ulps(&a, FloatOrdOp::Ge, &0));
+            }
+
+            #[test]
+            fn debug_past_bound() {
+                let a: $float = 1.0;
+                let b: $float = 1.5;
+
+                assert_eq!(a.debug_ord_abs(&b, FloatOrdOp::Lt, &0.25), -0.25);
+                assert_eq!(a.debug_ord_abs(&b, FloatOrdOp::Le, &0.25), -0.75);
+                assert_eq!(a.debug_ord_abs(&b, FloatOrdOp::Gt, &0.25), 0.75);
+                assert_eq!(a.debug_ord_abs(&b, FloatOrdOp::Ge, &0.25), 0.25);
+
+                let a: $float = 2.0;
+                let b: $float = 4.0;
+                assert_eq!(a.debug_ord_rel(&b, FloatOrdOp::Ge, &0.5), 0.0);
+                assert_eq!(a.debug_ord_rmax(&b, FloatOrdOp::Ge, &0.25), 1.0);
+                assert_eq!(a.debug_ord_rmin(&b, FloatOrdOp::Ge, &0.5), 1.0);
+                assert_eq!(a.debug_ord_r1st(&b, FloatOrdOp::Lt, &0.5), -1.0);
+                assert_eq!(a.debug_ord_r2nd(&b, FloatOrdOp::Le, &0.5), -4.0);
+
+                // measured in the units of the values rather than in ULPs
+                let a: $float = 1.0;
+                let b = next_n(a, 4);
+                assert_eq!(a.debug_ord_ulps(&b, FloatOrdOp::Ge, &3), EPSILON);
+                assert_eq!(a.debug_ord_ulps(&b, FloatOrdOp::Lt, &3), -EPSILON);
+                assert_eq!(a.debug_ord_ulps(&b, FloatOrdOp::Le, &0), -4.0 * EPSILON);
+            }
+        }
+    };
+}
+
+impl_tests!(f32, u32);
+impl_tests!(f64, u64);
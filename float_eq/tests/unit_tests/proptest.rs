@@ -0,0 +1,177 @@
+use float_eq::{prop_assert_float_eq, prop_assert_float_ne};
+use proptest::test_runner::TestCaseError;
+
+macro_rules! strategy_tests {
+    ($float:ident, $uint:ident) => {
+        mod $float {
+            use float_eq::proptest::$float::{boundaries, eq_pairs, ne_pairs, values};
+            use float_eq::Check;
+            use proptest::prelude::*;
+            use proptest::sample::select;
+            use proptest::test_runner::TestRunner;
+
+            const SIGN: $uint = 1 << ($uint::BITS - 1);
+
+            fn checks() -> Vec<Check<$float>> {
+                let eps = $float::EPSILON;
+                vec![
+                    Check::Abs(0.5),
+                    Check::Abs($float::MIN_POSITIVE),
+                    Check::Abs(0.0),
+                    Check::Rmax(4.0 * eps),
+                    Check::Rmax(0.75),
+                    Check::Rmin(4.0 * eps),
+                    Check::R1st(4.0 * eps),
+                    Check::R2nd(0.25),
+                    Check::AtolRtol(eps, 4.0 * eps),
+                    Check::Ulps(4),
+                    Check::Ulps(0),
+                    Check::Ulps($uint::MAX / 2),
+                ]
+            }
+
+            fn with_check<S: Strategy + 'static>(
+                f: fn(Check<$float>) -> S,
+            ) -> impl Strategy<Value = (Check<$float>, S::Value)> {
+                select(checks()).prop_flat_map(move |check| (Just(check), f(check)))
+            }
+
+            // whether there are no values between x and y, treating zeroes as one value
+            fn adjacent(x: $float, y: $float) -> bool {
+                let key = |v: $float| {
+                    let bits = v.to_bits();
+                    if bits & SIGN == 0 {
+                        i128::from(bits)
+                    } else {
+                        -i128::from(bits & !SIGN)
+                    }
+                };
+                (key(x) - key(y)).abs() == 1
+            }
+
+            proptest! {
+                #[test]
+                fn eq_pairs_are_eq((check, (a, b)) in with_check(eq_pairs)) {
+                    prop_assert!(check.eq(&a, &b), "{:?} {:?} {:?}", check, a, b);
+                }
+
+                #[test]
+                fn ne_pairs_are_ne((check, (a, b)) in with_check(ne_pairs)) {
+                    prop_assert!(check.ne(&a, &b), "{:?} {:?} {:?}", check, a, b);
+                }
+
+                #[test]
+                fn boundaries_straddle_tol((check, (a, inside, outside)) in with_check(boundaries)) {
+                    prop_assert!(check.eq(&a, &inside), "{:?} {:?} {:?}", check, a, inside);
+                    prop_assert!(check.ne(&a, &outside), "{:?} {:?} {:?}", check, a, outside);
+                    prop_assert!(adjacent(inside, outside), "{:?} {:?}", inside, outside);
+                }
+            }
+
+            #[test]
+            fn edge_cases() {
+                let mut runner = TestRunner::deterministic();
+                let strategy = values();
+                let values: Vec<$float> = (0..1000)
+                    .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+                    .collect();
+
+                let found = |f: fn(&$float) -> bool| values.iter().any(f);
+                assert!(found(|v| v.to_bits() == 0));
+                assert!(found(|v| v.to_bits() == SIGN));
+                assert!(found(|v| v.is_subnormal() && v.is_sign_positive()));
+                assert!(found(|v| v.is_subnormal() && v.is_sign_negative()));
+                assert!(found(|v| *v == $float::INFINITY));
+                assert!(found(|v| *v == $float::NEG_INFINITY));
+                assert!(!found(|v| v.is_nan()));
+
+                let strategy = ne_pairs(Check::Ulps(4));
+                let pairs: Vec<($float, $float)> = (0..1000)
+                    .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+                    .collect();
+                assert!(pairs.iter().any(|(a, _)| a.is_nan()));
+                assert!(pairs.iter().any(|(_, b)| b.is_nan()));
+            }
+
+            #[test]
+            fn no_boundary() {
+                // infinity compares equal to everything but NaN
+                let mut runner = TestRunner::deterministic();
+                let strategy = ne_pairs(Check::Abs($float::INFINITY));
+                for _ in 0..100 {
+                    let (a, b) = strategy.new_tree(&mut runner).unwrap().current();
+                    assert!(a.is_nan() || b.is_nan());
+                }
+
+                let strategy = eq_pairs(Check::Abs($float::INFINITY));
+                for _ in 0..100 {
+                    let (a, b) = strategy.new_tree(&mut runner).unwrap().current();
+                    assert!(Check::Abs($float::INFINITY).eq(&a, &b));
+                }
+            }
+        }
+    };
+}
+
+strategy_tests!(f32, u32);
+strategy_tests!(f64, u64);
+
+fn message<T>(result: Result<T, TestCaseError>) -> String {
+    match result {
+        Err(TestCaseError::Fail(reason)) => reason.message().to_owned(),
+        _ => panic!("expected a failure"),
+    }
+}
+
+#[test]
+fn prop_assert_float_eq() {
+    fn eq(a: f32, b: f32) -> Result<(), TestCaseError> {
+        prop_assert_float_eq!(a, b, abs <= 0.1);
+        Ok(())
+    }
+    assert!(eq(0.0, 0.05).is_ok());
+    assert_eq!(
+        message(eq(0.0, 1.0)),
+        r#"assertion failed: `float_eq!(left, right, abs <= t)`
+        left: `0.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `0.1`"#
+    );
+
+    fn eq_all(a: f32, b: f32) -> Result<(), TestCaseError> {
+        prop_assert_float_eq!(a, b, all(abs <= 0.1, ulps <= 1), "testing: {}", a);
+        Ok(())
+    }
+    assert!(eq_all(1.0, 1.0).is_ok());
+    assert_eq!(
+        message(eq_all(0.0, 1.0)),
+        r#"assertion failed: `float_eq!(left, right, all(abs <= t, ulps <= t))`
+        left: `0.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(1065353216)`,
+     [abs] t: `0.1` (failed),
+    [ulps] t: `1` (failed): testing: 0"#
+    );
+}
+
+#[test]
+fn prop_assert_float_ne() {
+    fn ne(a: f64, b: f64) -> Result<(), TestCaseError> {
+        prop_assert_float_ne!(a, b, rmax <= 0.5, ulps <= 1, "testing: {} != {}", a, b);
+        Ok(())
+    }
+    assert!(ne(1.0, 4.0).is_ok());
+    assert_eq!(
+        message(ne(1.0, 1.5)),
+        r#"assertion failed: `float_ne!(left, right, rmax <= t, ulps <= t)`
+        left: `1.0`,
+       right: `1.5`,
+    abs_diff: `0.5`,
+   ulps_diff: `Some(2251799813685248)`,
+    [rmax] t: `0.75`,
+    [ulps] t: `1`: testing: 1 != 1.5"#
+    );
+}
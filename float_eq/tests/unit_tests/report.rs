@@ -0,0 +1,220 @@
+use float_eq::{compare, EffectiveTol, FloatReport, Mismatch, Report, ReportKind, ReportNode};
+use std::collections::{BTreeMap, HashMap};
+
+fn failure_paths(report: &Report) -> Vec<&str> {
+    report.failures().map(|node| node.path.as_str()).collect()
+}
+
+fn float_report(node: &ReportNode) -> &FloatReport {
+    match &node.kind {
+        ReportKind::Float(float) => float,
+        kind => panic!("expected a float node, found {:?}", kind),
+    }
+}
+
+fn mismatch(node: &ReportNode) -> &Mismatch {
+    match &node.kind {
+        ReportKind::Mismatch(mismatch) => mismatch,
+        kind => panic!("expected a mismatch node, found {:?}", kind),
+    }
+}
+
+#[test]
+fn primitive() {
+    let report = compare!(1.0_f32, 1.5, abs <= 0.25);
+    assert_eq!(report.check, "abs");
+    assert!(!report.passed());
+    assert_eq!(
+        report.root,
+        ReportNode::float(
+            String::new(),
+            false,
+            FloatReport {
+                left: 1.0,
+                right: 1.5,
+                abs_diff: 0.5,
+                ulps_diff: Some(4_194_304),
+                tol: EffectiveTol::Abs(0.25),
+            }
+        )
+    );
+
+    let report = compare!(2.0_f64, 2.5, rmax <= 0.2);
+    assert!(report.passed());
+    assert_eq!(float_report(&report.root).tol, EffectiveTol::Abs(0.5));
+
+    let report = compare!(1.0_f32, 1.000_000_1, ulps <= 1);
+    assert_eq!(report.check, "ulps");
+    assert!(report.passed());
+    assert_eq!(float_report(&report.root).tol, EffectiveTol::Ulps(1));
+}
+
+#[test]
+fn rel_is_rmax() {
+    assert_eq!(compare!(1.0_f32, 1.0, rel <= 0.1).check, "rmax");
+    assert_eq!(compare!([1.0_f32], [1.0], rel_all <= 0.1).check, "rmax_all");
+}
+
+#[test]
+fn array() {
+    let a = [1.0_f32, 2.0, 3.0];
+    let b = [1.0_f32, 2.5, 4.0];
+
+    let report = compare!(a, b, abs <= [0.0, 0.5, 0.5]);
+    assert_eq!(failure_paths(&report), vec!["[2]"]);
+    assert_eq!(
+        float_report(report.failures().next().unwrap()).abs_diff,
+        1.0
+    );
+
+    let report = compare!(a, b, abs_all <= 1.0);
+    assert!(report.passed());
+    assert_eq!(report.nodes().count(), 4);
+
+    let report = compare!(a, b, ulps_all <= 0);
+    assert_eq!(failure_paths(&report), vec!["[1]", "[2]"]);
+}
+
+#[test]
+fn tuple() {
+    let a = (1.0_f32, 2.0_f64);
+    let b = (1.5_f32, 2.0_f64);
+
+    let report = compare!(a, b, abs <= (0.1, 0.1));
+    assert_eq!(failure_paths(&report), vec![".0"]);
+
+    let report = compare!(a, b, ulps <= (4_194_304, 0));
+    assert!(report.passed());
+
+    let report = compare!((), (), abs <= ());
+    assert!(report.passed());
+    assert_eq!(
+        report.root,
+        ReportNode::composite(String::new(), Vec::new())
+    );
+}
+
+#[test]
+fn option() {
+    let report = compare!(Some(1.0_f32), Some(1.5), abs <= Some(0.5));
+    assert!(report.passed());
+
+    let report = compare!(Some(1.0_f32), None, abs <= Some(0.5));
+    assert_eq!(mismatch(&report.root), &Mismatch::Variant);
+    assert!(!report.passed());
+
+    let report = compare!(Some(1.0_f32), Some(1.0), abs <= None);
+    assert_eq!(mismatch(&report.root), &Mismatch::Variant);
+
+    // consistent with float_eq!, None is never equal to None
+    let report = compare!(None::<f32>, None, abs <= Some(0.5));
+    assert_eq!(mismatch(&report.root), &Mismatch::Variant);
+}
+
+#[test]
+fn vec() {
+    let a = vec![1.0_f64, 2.0];
+
+    let report = compare!(a, vec![1.0_f64, 3.0], r2nd <= vec![0.0, 0.25]);
+    assert_eq!(failure_paths(&report), vec!["[1]"]);
+    let failure = float_report(report.failures().next().unwrap());
+    assert_eq!(failure.tol, EffectiveTol::Abs(0.75));
+
+    let report = compare!(a, vec![1.0_f64], abs_all <= 0.1);
+    assert_eq!(mismatch(&report.root), &Mismatch::Len { left: 2, right: 1 });
+
+    let report = compare!(a, a, abs <= vec![0.1]);
+    assert_eq!(mismatch(&report.root), &Mismatch::TolLen { len: 2, tol: 1 });
+}
+
+#[test]
+fn slice() {
+    let a = [1.0_f32, 2.0, 3.0];
+    let b = [1.0_f32, 2.0, 3.5];
+
+    let report = compare!(a[..], b[..], rmin_all <= 0.1);
+    assert_eq!(failure_paths(&report), vec!["[2]"]);
+
+    let report = compare!(a[1..], b[..], rmin_all <= 0.1);
+    assert_eq!(mismatch(&report.root), &Mismatch::Len { left: 2, right: 3 });
+}
+
+#[test]
+fn hash_map() {
+    let a: HashMap<_, _> = vec![("a", 1.0_f32), ("b", 2.0)].into_iter().collect();
+    let b: HashMap<_, _> = vec![("a", 1.0_f32), ("c", 2.0)].into_iter().collect();
+    let tol: HashMap<_, _> = vec![("a", 0.1_f32), ("b", 0.1)].into_iter().collect();
+
+    let report = compare!(a, a, abs <= tol);
+    assert!(report.passed());
+
+    let report = compare!(a, b, abs <= tol);
+    let mut failures: Vec<_> = report.failures().collect();
+    failures.sort_by(|x, y| x.path.cmp(&y.path));
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].path, "[\"b\"]");
+    assert_eq!(mismatch(failures[0]), &Mismatch::MissingRight);
+    assert_eq!(failures[1].path, "[\"c\"]");
+    assert_eq!(mismatch(failures[1]), &Mismatch::MissingLeft);
+}
+
+#[test]
+fn btree_map() {
+    let a: BTreeMap<_, _> = vec![(1, 1.0_f64), (2, 2.0)].into_iter().collect();
+    let b: BTreeMap<_, _> = vec![(1, 1.5_f64), (2, 2.0)].into_iter().collect();
+
+    let report = compare!(a, b, abs_all <= 0.25);
+    assert_eq!(failure_paths(&report), vec!["[1]"]);
+
+    let tol: BTreeMap<_, _> = vec![(1, 0.5_f64), (3, 0.5)].into_iter().collect();
+    let report = compare!(a, b, abs <= tol);
+    assert_eq!(failure_paths(&report), vec!["[2]"]);
+    assert_eq!(
+        mismatch(report.failures().next().unwrap()),
+        &Mismatch::MissingTol
+    );
+}
+
+#[test]
+fn nested() {
+    let a = vec![Some([1.0_f32, 2.0]), Some([3.0, 4.0])];
+    let b = vec![Some([1.0_f32, 2.5]), Some([3.0, 4.0])];
+
+    let report = compare!(a, b, abs_all <= Some(0.25));
+    assert_eq!(failure_paths(&report), vec!["[0][1]"]);
+    assert_eq!(
+        report.to_string(),
+        "abs_all: 1 failure\n    [0][1]: left = 2.0, right = 2.5, abs_diff = 0.5, \
+         ulps_diff = Some(2097152), tol = 0.25"
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        compare!(1.0_f32, 1.0, abs <= 0.0).to_string(),
+        "abs: passed"
+    );
+
+    let report = compare!(vec![1.0_f32], vec![1.0, 2.0], abs_all <= 0.0);
+    assert_eq!(
+        report.to_string(),
+        "abs_all: 1 failure\n    <root>: lengths differ (left: 1, right: 2)"
+    );
+}
+
+#[cfg(feature = "num")]
+#[test]
+fn complex() {
+    use float_eq::ComplexUlps32;
+    use num_complex::Complex32;
+
+    let a = Complex32::new(1.0, 2.0);
+    let b = Complex32::new(1.0, 2.5);
+
+    let report = compare!(a, b, abs <= Complex32::new(0.1, 0.1));
+    assert_eq!(failure_paths(&report), vec![".im"]);
+
+    let report = compare!(a, b, ulps <= ComplexUlps32::new(0, 2_097_152));
+    assert!(report.passed());
+}
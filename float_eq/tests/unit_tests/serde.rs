@@ -0,0 +1,136 @@
+use float_eq::{Check, FloatOrdOp, InfAsMax, NanEq, NanEqBits, Strict};
+use serde_json::{from_str, to_string};
+
+#[test]
+fn check() {
+    let checks = [
+        (Check::Abs(0.5_f64), r#"{"abs":0.5}"#),
+        (Check::Rmax(0.5), r#"{"rmax":0.5}"#),
+        (Check::Rmin(0.5), r#"{"rmin":0.5}"#),
+        (Check::R1st(0.5), r#"{"r1st":0.5}"#),
+        (Check::R2nd(0.5), r#"{"r2nd":0.5}"#),
+        (Check::AtolRtol(0.5, 0.25), r#"{"atol_rtol":[0.5,0.25]}"#),
+        (Check::Ulps(4), r#"{"ulps":4}"#),
+    ];
+    for (check, json) in &checks {
+        assert_eq!(to_string(check).unwrap(), *json);
+        assert_eq!(from_str::<Check<f64>>(json).unwrap(), *check);
+    }
+
+    let err = from_str::<Check<f32>>(r#"{"ulps":-1}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid value"), "{}", err);
+}
+
+#[test]
+fn wrappers() {
+    assert_eq!(to_string(&NanEq(1.5_f32)).unwrap(), "1.5");
+    assert_eq!(to_string(&NanEqBits(-1.5_f64)).unwrap(), "-1.5");
+    assert_eq!(to_string(&Strict(0.25_f32)).unwrap(), "0.25");
+    assert_eq!(to_string(&InfAsMax(2.0_f64)).unwrap(), "2.0");
+
+    assert_eq!(from_str::<NanEq<f32>>("1.5").unwrap().0, 1.5);
+    assert_eq!(
+        from_str::<Strict<[f64; 2]>>("[1.0, -0.0]").unwrap().0,
+        [1.0, -0.0]
+    );
+}
+
+#[test]
+fn float_ord_op() {
+    assert_eq!(to_string(&FloatOrdOp::Le).unwrap(), r#""Le""#);
+    assert_eq!(from_str::<FloatOrdOp>(r#""Gt""#).unwrap(), FloatOrdOp::Gt);
+}
+
+#[cfg(feature = "std")]
+mod std_types {
+    use super::*;
+    use float_eq::{compare, AssertDiffs, AssertMessage, CheckSet, Report};
+
+    #[test]
+    fn check_set() {
+        let checks: CheckSet<f32> = "all(abs <= 0.5, ulps <= 4)".parse().unwrap();
+        let json = r#"{"all":[{"abs":0.5},{"ulps":4}]}"#;
+        assert_eq!(to_string(&checks).unwrap(), json);
+        assert_eq!(from_str::<CheckSet<f32>>(json).unwrap(), checks);
+        assert_eq!(
+            from_str::<CheckSet<f32>>(r#"{"any":[]}"#).unwrap(),
+            CheckSet::Any(vec![])
+        );
+    }
+
+    #[test]
+    fn report() {
+        let a = vec![1.0_f64, 2.0];
+        let b = vec![1.0_f64, 2.5];
+        let report = compare!(a, b, abs_all <= 0.25);
+
+        let json = to_string(&report).unwrap();
+        assert!(
+            json.starts_with(r#"{"check":"abs_all","root":{"path":"","passed":false,"#),
+            "{}",
+            json
+        );
+        assert_eq!(from_str::<Report>(&json).unwrap(), report);
+
+        let report = compare!(vec![1.0_f32], vec![1.0_f32, 2.0], ulps_all <= 1);
+        assert_eq!(
+            from_str::<Report>(&to_string(&report).unwrap()).unwrap(),
+            report
+        );
+
+        let err = from_str::<Report>(&json.replace("abs_all", "cheese")).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("unknown variant `cheese`, expected one of `abs`"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn assert_message() {
+        let messages = [
+            (AssertMessage::Full, r#""Full""#),
+            (
+                AssertMessage::Concise { limit: 3 },
+                r#"{"Concise":{"limit":3}}"#,
+            ),
+        ];
+        for (message, json) in &messages {
+            assert_eq!(to_string(message).unwrap(), *json);
+            assert_eq!(from_str::<AssertMessage>(json).unwrap(), *message);
+        }
+
+        let mut diffs = AssertDiffs::new(1);
+        diffs.push("[0]", format_args!("a"));
+        diffs.push("[1]", format_args!("b"));
+        let json = to_string(&diffs).unwrap();
+        assert_eq!(from_str::<AssertDiffs>(&json).unwrap(), diffs);
+    }
+}
+
+#[cfg(feature = "num")]
+#[test]
+fn complex_ulps() {
+    use float_eq::ComplexUlps32;
+
+    let ulps = ComplexUlps32::new(1, 2);
+    assert_eq!(to_string(&ulps).unwrap(), r#"{"re":1,"im":2}"#);
+    assert_eq!(
+        from_str::<ComplexUlps32>(r#"{"re":1,"im":2}"#).unwrap(),
+        ulps
+    );
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_ulps() {
+    use float_eq::Vec3Ulps;
+
+    let ulps = Vec3Ulps::new(1_u32, 2, 3);
+    assert_eq!(to_string(&ulps).unwrap(), r#"{"x":1,"y":2,"z":3}"#);
+    assert_eq!(
+        from_str::<Vec3Ulps<u32>>(r#"{"x":1,"y":2,"z":3}"#).unwrap(),
+        ulps
+    );
+}
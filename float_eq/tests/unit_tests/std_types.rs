@@ -218,6 +218,25 @@ macro_rules! impl_linear_collection_tests {
                 assert_float_ne!(a, b, ulps <= $c![u32::MAX; 3]);
             }
 
+            #[test]
+            fn float_ord() {
+                use float_eq::{float_ge, float_lt};
+
+                let a = $c![1.0f32, 2.0];
+                let b = $c![1.5f32, 2.5];
+                let c = $c![1.0f32];
+
+                assert!(float_lt!(a, b, abs <= $c![0.25, 0.25]));
+                assert!(!float_lt!(a, b, abs <= $c![0.25, 0.5]));
+                assert!(float_ge!(a, b, abs <= $c![0.5, 0.5]));
+                assert!(!float_ge!(a, b, abs <= $c![0.5, 0.25]));
+
+                // Different shape a/b/tol
+                assert!(!float_ge!(a, c, abs <= $c![f32::INFINITY; 2]));
+                assert!(!float_ge!(c, b, abs <= $c![f32::INFINITY; 2]));
+                assert!(!float_ge!(a, b, abs <= $c![f32::INFINITY]));
+            }
+
             #[test]
             fn float_eq_all() {
                 let a = $c![0.999_999_9f32, 4.0];
@@ -544,6 +563,25 @@ macro_rules! impl_map_tests {
                 assert_float_ne!(a, b, ulps <= map13(u32::MAX, u32::MAX));
             }
 
+            #[test]
+            fn float_ord() {
+                use float_eq::{float_ge, float_lt};
+
+                let a = map12(1.0f32, 2.0);
+                let b = map12(1.5f32, 2.5);
+                let inf = f32::INFINITY;
+
+                assert!(float_lt!(a, b, abs <= map12(0.25, 0.25)));
+                assert!(!float_lt!(a, b, abs <= map12(0.25, 0.5)));
+                assert!(float_ge!(a, b, abs <= map12(0.5, 0.5)));
+                assert!(!float_ge!(a, b, abs <= map12(0.5, 0.25)));
+
+                // Different keys a/b/tol
+                assert!(!float_ge!(a, map13(1.5, 2.5), abs <= map12(inf, inf)));
+                assert!(!float_ge!(a, b, abs <= map13(inf, inf)));
+                assert!(!float_ge!(map1(1.0), b, abs <= map12(inf, inf)));
+            }
+
             #[test]
             fn float_eq_all() {
                 let a = map12(0.999_999_9f32, 4.0);
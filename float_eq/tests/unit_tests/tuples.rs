@@ -511,3 +511,20 @@ fn debug_tol() {
 fn test_assert_fail_message() {
     assert_float_eq!((1.0f32, 2.0f64), (1.5f32, -2.5f64), abs <= (0.1, 0.2))
 }
+
+#[test]
+fn float_ord() {
+    use float_eq::{assert_float_gt, assert_float_le, float_gt, float_le};
+
+    let a = (1.0f32, 2.0f64);
+    let b = (1.5f32, 2.5f64);
+
+    assert!(float_gt!(b, a, abs <= (0.25, 0.25)));
+    assert!(!float_gt!(b, a, abs <= (0.5, 0.25)));
+    assert!(float_le!(b, a, abs <= (0.5, 0.5)));
+    assert!(!float_le!(b, a, abs <= (0.25, 0.5)));
+    assert!(float_le!((), (), abs <= ()));
+
+    assert_float_gt!(b, a, abs <= (0.25, 0.25));
+    assert_float_le!(b, a, r2nd <= (0.5, 0.5));
+}
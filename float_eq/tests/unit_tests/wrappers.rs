@@ -0,0 +1,319 @@
+use float_eq::{
+    assert_float_eq, assert_float_ne, float_eq, float_ge, float_gt, float_le, float_lt, float_ne,
+    InfAsMax, NanEq, NanEqBits, Strict,
+};
+
+macro_rules! impl_tests {
+    ($float:ident) => {
+        mod $float {
+            use super::*;
+            use crate::$float::*;
+
+            #[test]
+            fn nan_eq() {
+                for &a in &nan_test_values() {
+                    for &b in &nan_test_values() {
+                        let (a, b) = (NanEq(a), NanEq(b));
+                        assert!(a == b);
+                        assert_float_eq!(a, b, abs <= NanEq(0.0));
+                        assert_float_eq!(a, b, rmax <= NanEq(0.0));
+                        assert_float_eq!(a, b, rmin <= NanEq(0.0));
+                        assert_float_eq!(a, b, r1st <= NanEq(0.0));
+                        assert_float_eq!(a, b, r2nd <= NanEq(0.0));
+                        assert_float_eq!(a, b, atol_rtol <= (NanEq(0.0), NanEq(0.0)));
+                        assert_float_eq!(a, b, ulps <= 0);
+                        assert_float_eq!(a, b, abs_all <= 0.0);
+                        assert_float_eq!(a, b, ulps_all <= 0);
+                    }
+                }
+            }
+
+            #[test]
+            fn nan_eq_bits() {
+                for &a in &nan_test_values() {
+                    for &b in &nan_test_values() {
+                        let same = a.to_bits() == b.to_bits();
+                        let (a, b) = (NanEqBits(a), NanEqBits(b));
+                        assert_eq!(a == b, same);
+                        assert_eq!(float_eq!(a, b, abs <= NanEqBits(0.0)), same);
+                        assert_eq!(float_eq!(a, b, rmax <= NanEqBits(INFINITY)), same);
+                        assert_eq!(float_eq!(a, b, ulps <= MAX_ULPS), same);
+                        assert_eq!(float_eq!(a, b, abs_all <= INFINITY), same);
+                    }
+                }
+            }
+
+            #[test]
+            fn nan_ne_value() {
+                let one: $float = 1.0;
+                for &nan in &nan_test_values() {
+                    assert!(NanEq(nan) != NanEq(one));
+                    assert_float_ne!(NanEq(nan), NanEq(one), abs <= NanEq(INFINITY));
+                    assert_float_ne!(NanEq(one), NanEq(nan), rmax <= NanEq(INFINITY));
+                    assert_float_ne!(NanEq(nan), NanEq(INFINITY), ulps <= MAX_ULPS);
+                    assert_float_ne!(NanEqBits(nan), NanEqBits(one), abs <= NanEqBits(INFINITY));
+                }
+            }
+
+            #[test]
+            fn other_values() {
+                // values that are not NaN are compared as the wrapped type would be
+                let a: $float = 1.0;
+                let b = next_n(a, 2);
+                assert!(NanEq(a) == NanEq(a));
+                assert!(NanEq(a) != NanEq(b));
+                assert!(NanEq(0.0 as $float) == NanEq(-0.0));
+                assert_float_eq!(NanEq(a), NanEq(b), ulps <= 2);
+                assert_float_ne!(NanEq(a), NanEq(b), ulps <= 1);
+                assert_float_eq!(NanEqBits(a), NanEqBits(b), abs <= NanEqBits(2.0 * EPSILON));
+                assert_float_ne!(NanEqBits(a), NanEqBits(b), abs <= NanEqBits(EPSILON));
+            }
+
+            #[test]
+            fn ordering() {
+                let one: $float = 1.0;
+                for &nan in &nan_test_values() {
+                    let a = NanEq(nan);
+                    let b = NanEq(-nan);
+                    assert!(float_le!(a, b, abs <= NanEq(0.0)));
+                    assert!(float_ge!(a, b, ulps <= 0));
+                    assert!(!float_gt!(a, b, abs <= NanEq(0.0)));
+                    assert!(!float_le!(a, NanEq(one), abs <= NanEq(INFINITY)));
+                }
+                assert!(float_le!(NanEq(one), NanEq(one + 0.5), abs <= NanEq(0.0)));
+            }
+
+            #[test]
+            fn debug_diff() {
+                use float_eq::AssertFloatEq;
+
+                let nan = nan_test_values()[0];
+                let a: $float = 1.0;
+                assert_eq!(NanEq(nan).debug_abs_diff(&NanEq(-nan)), NanEq(0.0));
+                assert_eq!(NanEq(nan).debug_ulps_diff(&NanEq(-nan)), Some(0));
+                assert!(NanEqBits(nan).debug_abs_diff(&NanEqBits(-nan)).0.is_nan());
+                assert_eq!(NanEqBits(nan).debug_ulps_diff(&NanEqBits(-nan)), None);
+                assert_eq!(NanEq(a).debug_abs_diff(&NanEq(2.0)), NanEq(1.0));
+                assert_eq!(
+                    NanEq(a).debug_rmax_tol(&NanEq(2.0), &NanEq(0.5)),
+                    NanEq(1.0)
+                );
+                assert_eq!(
+                    NanEq(a).debug_ulps_diff(&NanEq(next(a))),
+                    a.debug_ulps_diff(&next(a))
+                );
+            }
+
+            #[test]
+            fn strict_signed_zero() {
+                let zero: $float = 0.0;
+                let (pos, neg) = (Strict(zero), Strict(-zero));
+                assert!(pos != neg);
+                assert!(neg == neg);
+                assert_float_ne!(pos, neg, abs <= Strict(INFINITY));
+                assert_float_ne!(neg, pos, rmax <= Strict(1.0));
+                assert_float_ne!(pos, neg, atol_rtol <= (Strict(1.0), Strict(1.0)));
+                assert_float_ne!(pos, neg, ulps <= MAX_ULPS);
+                assert_float_ne!(pos, neg, abs_all <= INFINITY);
+                assert_float_eq!(neg, neg, abs <= Strict(0.0));
+                assert_float_eq!(pos, pos, ulps_all <= 0);
+
+                // a zero is compared as usual with a nonzero value of either sign
+                let tiny = Strict(next(zero));
+                assert_float_eq!(pos, tiny, abs <= Strict(EPSILON));
+                assert_float_eq!(neg, tiny, abs <= Strict(EPSILON));
+                assert_float_eq!(Strict(-tiny.0), pos, abs <= Strict(EPSILON));
+                assert_float_ne!(neg, tiny, abs <= Strict(0.0));
+            }
+
+            #[test]
+            fn strict_infinity() {
+                let (inf, max) = (Strict(INFINITY), Strict(MAX_NORMAL));
+                assert!(inf == inf);
+                assert!(inf != Strict(-INFINITY));
+                assert_float_eq!(inf, inf, abs <= Strict(0.0));
+                assert_float_ne!(inf, max, abs <= Strict(INFINITY));
+                assert_float_ne!(inf, max, rmax <= Strict(1.0));
+                assert_float_ne!(inf, max, ulps <= MAX_ULPS);
+                assert_float_ne!(max, inf, rmin_all <= INFINITY);
+                assert!(Strict(nan_test_values()[0]) != Strict(INFINITY));
+
+                // other values are compared as the wrapped type would be
+                let a: $float = 1.0;
+                assert_float_eq!(Strict(a), Strict(next_n(a, 2)), ulps <= 2);
+                assert_float_ne!(Strict(a), Strict(next_n(a, 2)), ulps <= 1);
+            }
+
+            #[test]
+            fn strict_ordering() {
+                let zero: $float = 0.0;
+                let (pos, neg) = (Strict(zero), Strict(-zero));
+                assert!(float_lt!(neg, pos, abs <= Strict(0.0)));
+                assert!(float_le!(neg, pos, ulps <= 0));
+                assert!(float_gt!(pos, neg, abs <= Strict(0.0)));
+                assert!(float_ge!(pos, neg, rmax <= Strict(0.0)));
+                assert!(!float_lt!(pos, neg, abs <= Strict(1.0)));
+                assert!(!float_ge!(neg, pos, abs <= Strict(1.0)));
+                assert!(float_le!(neg, neg, abs <= Strict(0.0)));
+                assert!(!float_lt!(neg, neg, abs <= Strict(0.0)));
+                assert!(float_lt!(Strict(-1.0), pos, abs <= Strict(0.5)));
+            }
+
+            #[test]
+            fn strict_debug_diff() {
+                use float_eq::AssertFloatEq;
+
+                let zero: $float = 0.0;
+                let (pos, neg) = (Strict(zero), Strict(-zero));
+                assert_eq!(pos.debug_abs_diff(&neg), Strict(0.0));
+                assert_eq!(pos.debug_ulps_diff(&neg), None);
+                assert_eq!(neg.debug_ulps_diff(&neg), Some(0));
+                assert_eq!(Strict(INFINITY).debug_ulps_diff(&Strict(MAX_NORMAL)), None);
+                assert_eq!(
+                    Strict(1.0 as $float).debug_abs_tol(&Strict(2.0), &Strict(0.5)),
+                    Strict(0.5)
+                );
+                assert_eq!(format!("{:?}", neg), "-0.0");
+            }
+
+            #[test]
+            fn inf_as_max() {
+                let (inf, max) = (InfAsMax(INFINITY), InfAsMax(MAX_NORMAL));
+                assert!(inf == max);
+                assert!(InfAsMax(-INFINITY) == InfAsMax(-MAX_NORMAL));
+                assert!(inf != InfAsMax(-INFINITY));
+                assert_float_eq!(inf, max, ulps <= 0);
+                assert_float_eq!(inf, InfAsMax(prev_n(MAX_NORMAL, 2)), ulps <= 2);
+                assert_float_ne!(inf, InfAsMax(prev_n(MAX_NORMAL, 2)), ulps <= 1);
+                assert_float_eq!(inf, InfAsMax(MAX_NORMAL / 2.0), rmax <= InfAsMax(0.5));
+                assert_float_ne!(inf, InfAsMax(MAX_NORMAL / 2.0), rmax <= InfAsMax(0.25));
+                assert_float_eq!(InfAsMax(-INFINITY), InfAsMax(-MAX_NORMAL), abs_all <= 0.0);
+                assert_float_ne!(inf, InfAsMax(-INFINITY), ulps <= MAX_ULPS);
+                assert!(float_lt!(InfAsMax(-INFINITY), inf, ulps <= 0));
+                assert!(float_ge!(inf, max, abs <= InfAsMax(0.0)));
+
+                // NaN and finite values are unaffected
+                assert!(InfAsMax(nan_test_values()[0]) != InfAsMax(nan_test_values()[0]));
+                assert_float_eq!(InfAsMax(1.0 as $float), InfAsMax(next(1.0)), ulps <= 1);
+            }
+
+            #[test]
+            fn inf_as_max_debug_diff() {
+                use float_eq::AssertFloatEq;
+
+                let (inf, max) = (InfAsMax(INFINITY), InfAsMax(MAX_NORMAL));
+                assert_eq!(inf.debug_abs_diff(&max), InfAsMax(0.0));
+                assert_eq!(inf.debug_ulps_diff(&InfAsMax(prev(MAX_NORMAL))), Some(1));
+                assert_eq!(
+                    inf.debug_rmax_tol(&InfAsMax(1.0), &InfAsMax(0.5)),
+                    InfAsMax(MAX_NORMAL * 0.5)
+                );
+                assert_eq!(format!("{:?}", inf), format!("{:?}", INFINITY));
+            }
+        }
+    };
+}
+
+impl_tests!(f32);
+impl_tests!(f64);
+
+#[test]
+fn composite_types() {
+    let nan = f32::NAN;
+    let a = [NanEq(1.0_f32), NanEq(nan)];
+    let b = [NanEq(1.000_000_1_f32), NanEq(-nan)];
+    assert_float_eq!(a, b, ulps <= [1, 0]);
+    assert_float_eq!(a, b, abs_all <= 0.000_000_2);
+    assert_float_ne!(a, b, ulps <= [0, 0]);
+
+    assert!(float_eq!(
+        Some(NanEq(nan)),
+        Some(NanEq(nan)),
+        abs <= Some(NanEq(0.0))
+    ));
+    assert!(float_eq!(
+        (NanEq(nan), NanEq(2.0_f64)),
+        (NanEq(nan), NanEq(2.0_f64)),
+        abs <= (NanEq(0.0), NanEq(0.0))
+    ));
+    assert!(float_ne!(
+        (NanEqBits(nan), NanEqBits(2.0_f64)),
+        (NanEqBits(-nan), NanEqBits(2.0_f64)),
+        abs <= (NanEqBits(0.0), NanEqBits(0.0))
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn collections() {
+    let a = vec![NanEq(1.0_f64), NanEq(f64::NAN), NanEq(3.0)];
+    let b = vec![NanEq(1.0_f64), NanEq(f64::NAN), NanEq(3.5)];
+    assert_float_eq!(a, b, abs_all <= 0.5);
+    assert_float_ne!(a, b, abs_all <= 0.25);
+
+    let report = float_eq::compare!(a, b, abs_all <= 0.25);
+    let failures: Vec<_> = report.failures().map(|node| node.path.as_str()).collect();
+    assert_eq!(failures, ["[2]"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+ [abs_all] 1 failure:
+           [2]: left: `3.0`, right: `3.5`, abs_diff: `0.5`, ulps_diff: `Some(1125899906842624)`, t: `0.25`"#)]
+fn assert_fail_message() {
+    let a = vec![NanEq(1.0_f64), NanEq(f64::NAN), NanEq(3.0)];
+    let b = vec![NanEq(1.0_f64), NanEq(f64::NAN), NanEq(3.5)];
+    assert_float_eq!(a, b, abs_all <= 0.25);
+}
+
+#[test]
+fn strict_composite_types() {
+    let a = [Strict(1.0_f64), Strict(0.0)];
+    let b = [Strict(1.0_f64), Strict(-0.0)];
+    assert_float_ne!(a, b, abs <= [Strict(0.1), Strict(0.1)]);
+    assert_float_eq!(a, a, abs_all <= 0.0);
+    assert!(float_ne!(
+        (Strict(<f32>::INFINITY), Strict(2.0_f64)),
+        (Strict(f32::MAX), Strict(2.0_f64)),
+        rmax <= (Strict(1.0), Strict(0.0))
+    ));
+    assert!(float_eq!(
+        Some(InfAsMax(<f64>::INFINITY)),
+        Some(InfAsMax(f64::MAX)),
+        ulps <= Some(0)
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn strict_report() {
+    let a = vec![Strict(1.0_f64), Strict(0.0)];
+    let b = vec![Strict(1.0_f64), Strict(-0.0)];
+    let report = float_eq::compare!(a, b, abs_all <= 0.5);
+    let failures: Vec<_> = report.failures().map(|node| node.path.as_str()).collect();
+    assert_eq!(failures, ["[1]"]);
+
+    let report = float_eq::compare!(InfAsMax(<f64>::INFINITY), InfAsMax(f64::MAX), ulps <= 0);
+    assert!(report.passed());
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = r#"`float_eq!(left, right, abs_all <= t)`
+ [abs_all] 1 failure:
+           [1]: left: `0.0`, right: `-0.0`, abs_diff: `0.0`, ulps_diff: `None`, t: `0.5`"#)]
+fn strict_assert_fail_message() {
+    let a = vec![Strict(1.0_f64), Strict(0.0)];
+    let b = vec![Strict(1.0_f64), Strict(-0.0)];
+    assert_float_eq!(a, b, abs_all <= 0.5);
+}
+
+#[test]
+fn debug_is_transparent() {
+    assert_eq!(format!("{:?}", NanEq(1.5_f32)), "1.5");
+    assert_eq!(format!("{:?}", [NanEqBits(f64::NAN)]), "[NaN]");
+    assert_eq!(format!("{}", NanEq(0.25_f64)), "0.25");
+    assert_eq!(format!("{:?}", Strict(-0.0_f32)), "-0.0");
+    assert_eq!(format!("{:?}", InfAsMax(f64::NEG_INFINITY)), "-inf");
+}
@@ -6,7 +6,7 @@ extern crate proc_macro;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, TokenStreamExt};
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, DeriveInput, Type};
 
 mod read;
 
@@ -24,6 +24,30 @@ mod read;
 /// used by `FloatEqAll::AllTol` then [`FloatEqAll`] and [`AssertFloatEqAll`]
 /// are also derived.
 ///
+/// When float_eq's `std` feature is enabled, [`FloatEqReport`] and
+/// [`AssertFloatEqDiffs`] are also derived, along with [`FloatEqAllReport`] and
+/// [`AssertFloatEqAllDiffs`] if `all_tol` is provided.
+///
+/// If the optional `serde` parameter is provided, serde's `Serialize` and
+/// `Deserialize` are derived for both generated types, which requires
+/// float_eq's `serde` feature.
+///
+/// [`FloatOrd`] and [`AssertFloatOrd`] are not derived by default, but may be
+/// added with `#[derive(float_eq::FloatOrd, float_eq::AssertFloatOrd)]`. They
+/// cannot be derived for a field with a fixed `atol_rtol` check.
+///
+/// May be applied to structs, tuple structs and enums. For an enum, the generated
+/// types are enums with the same variants, and values of different variants
+/// are never considered equal.
+///
+/// Individual fields may be annotated with `#[float_eq(skip)]` to ignore them,
+/// `#[float_eq(exact)]` to compare them using `PartialEq`, or with a fixed check
+/// such as `#[float_eq(abs <= 0.5)]` that is always used for that field. Such
+/// a type requires two more parameters: `tol` names the generated `Tol` type,
+/// which only has the fields that use the caller's tolerance and whose derived
+/// traits may be listed with `tol_derive`, and `debug_tol` names the type used
+/// to display debug information about the fields compared by a check.
+///
 /// See [How to derive the traits] for more information and example usage.
 ///
 /// [`FloatEqUlpsTol`]: trait.FloatEqUlpsTol.html
@@ -32,6 +56,12 @@ mod read;
 /// [`FloatEqAll`]: trait.FloatEqAll.html
 /// [`AssertFloatEq`]: trait.AssertFloatEq.html
 /// [`AssertFloatEqAll`]: trait.AssertFloatEqAll.html
+/// [`FloatEqReport`]: trait.FloatEqReport.html
+/// [`FloatEqAllReport`]: trait.FloatEqAllReport.html
+/// [`AssertFloatEqDiffs`]: trait.AssertFloatEqDiffs.html
+/// [`AssertFloatEqAllDiffs`]: trait.AssertFloatEqAllDiffs.html
+/// [`FloatOrd`]: trait.FloatOrd.html
+/// [`AssertFloatOrd`]: trait.AssertFloatOrd.html
 /// [How to derive the traits]: https://jtempest.github.io/float_eq-rs/book/how_to/derive_the_traits.html
 #[proc_macro_attribute]
 pub fn derive_float_eq(
@@ -81,15 +111,45 @@ help: try specifying `debug_ulps_diff = "{}DebugUlpsDiff"` in `derive_float_eq`.
         return Err(syn::Error::new(Span::call_site(), msg));
     }
 
+    if read::type_info("derive_float_eq", &item)?.has_field_attributes() {
+        if !has_arg("tol") {
+            let msg = format!(
+                r#"Missing tolerance type name required by fields with a float_eq attribute.
+
+help: try specifying `tol = "{}Tol"` in `derive_float_eq`."#,
+                item.ident
+            );
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+
+        if !has_arg("debug_tol") {
+            let msg = format!(
+                r#"Missing debug tolerance type name required by fields with a float_eq attribute.
+
+help: try specifying `debug_tol = "{}DebugTol"` in `derive_float_eq`."#,
+                item.ident
+            );
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+    }
+
     let mut trait_names = vec![
         "FloatEqUlpsTol",
         "FloatEq",
         "FloatEqDebugUlpsDiff",
         "AssertFloatEq",
     ];
+    if cfg!(feature = "std") {
+        trait_names.push("FloatEqReport");
+        trait_names.push("AssertFloatEqDiffs");
+    }
     if has_arg("all_tol") {
         trait_names.push("FloatEqAll");
         trait_names.push("AssertFloatEqAll");
+        if cfg!(feature = "std") {
+            trait_names.push("FloatEqAllReport");
+            trait_names.push("AssertFloatEqAllDiffs");
+        }
     }
 
     let mut traits = TokenStream::new();
@@ -115,40 +175,38 @@ pub fn derive_float_eq_ulps_tol(input: proc_macro::TokenStream) -> proc_macro::T
 }
 
 fn expand_float_eq_ulps_tol(input: DeriveInput) -> Result<TokenStream, syn::Error> {
-    let vis = &input.vis;
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("FloatEqUlpsTol", &input)?;
+    let info = read::type_info("FloatEqUlpsTol", &input)?;
     let params = read::float_eq_attr(&input)?;
     let ulps_name = params.ulps_tol_type()?;
 
-    let ulps_type = match fields.ty {
-        read::FieldListType::Named => {
-            let ulps_fields = fields.expand(|field| {
-                let name = &field.name;
-                let ty = &field.ty;
-                quote! { #vis #name: float_eq::UlpsTol<#ty> }
-            });
-            quote! {
-                #vis struct #ulps_name {
-                    #(#ulps_fields,)*
-                }
-            }
-        }
-        read::FieldListType::Tuple => {
-            let ulps_fields = fields.expand(|field| {
-                let ty = &field.ty;
-                quote! { #vis float_eq::UlpsTol<#ty> }
-            });
-            quote! {
-                #vis struct #ulps_name( #(#ulps_fields,)* );
-            }
-        }
-        read::FieldListType::Unit => quote! {
-            #vis struct #ulps_name;
-        },
-    };
+    let ulps_info = info.subset(read::FieldInfo::uses_tol);
+    let ulps_generics = ulps_info.used_generics(&input.generics);
+    let field_bounds = |field: &read::FieldInfo| ulps_tol_bounds(&params, field.ty);
+    let ulps_type = expand_companion_type(
+        &input,
+        ulps_name,
+        &ulps_info,
+        &ulps_generics,
+        true,
+        |ty| quote! { float_eq::UlpsTol<#ty> },
+        field_bounds,
+    );
 
     let derive_types = params.ulps_tol_derive_types();
+    let serde_derive = expand_serde_derive(&params, &ulps_info, &ulps_generics, |ty| {
+        quote! { float_eq::UlpsTol<#ty> }
+    });
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (_, ulps_ty_generics, _) = ulps_generics.split_for_impl();
+    let where_clause = expand_where_clause(&input.generics, &ulps_info, quote! {}, field_bounds);
+
+    let tol_type = if info.has_field_attributes() {
+        expand_tol_type(&input, &params, &ulps_info, &ulps_generics)?
+    } else {
+        quote! {}
+    };
 
     let doc = format!(
         "Floating point ULPs tolerance representation derived from {}, used by float_eq.",
@@ -157,10 +215,62 @@ fn expand_float_eq_ulps_tol(input: DeriveInput) -> Result<TokenStream, syn::Erro
     Ok(quote! {
         #[doc = #doc]
         #[derive(#(#derive_types,)*)]
+        #serde_derive
         #ulps_type
 
-        impl float_eq::FloatEqUlpsTol for #struct_name {
-            type UlpsTol = #ulps_name;
+        impl #impl_generics float_eq::FloatEqUlpsTol for #struct_name #ty_generics #where_clause {
+            type UlpsTol = #ulps_name #ulps_ty_generics;
+        }
+
+        #tol_type
+    })
+}
+
+/// Declares the `tol` type of a derived type with field attributes, which has
+/// the same fields as its `ulps_tol` type, given by `tol_info`.
+fn expand_tol_type(
+    input: &DeriveInput,
+    params: &read::FloatEqAttr,
+    tol_info: &read::TypeInfo,
+    tol_generics: &syn::Generics,
+) -> Result<TokenStream, syn::Error> {
+    let tol_name = params.tol_type()?;
+    let ulps_name = params.ulps_tol_type()?;
+    let tol_type = expand_companion_type(
+        input,
+        tol_name,
+        tol_info,
+        tol_generics,
+        true,
+        |ty| quote! { #ty },
+        |field| tol_bounds(params, field.ty),
+    );
+
+    let derive_types = params.tol_derive_types();
+    let serde_derive = expand_serde_derive(params, tol_info, tol_generics, |ty| quote! { #ty });
+
+    let (impl_generics, ty_generics, _) = tol_generics.split_for_impl();
+    let where_clause = expand_where_clause(tol_generics, tol_info, quote! {}, |field| {
+        let tol_bounds = tol_bounds(params, field.ty);
+        let ulps_tol_bounds = ulps_tol_bounds(params, field.ty);
+        quote! {
+            #tol_bounds
+            #ulps_tol_bounds
+        }
+    });
+
+    let doc = format!(
+        "Floating point tolerance representation derived from {}, used by float_eq.",
+        input.ident
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(#(#derive_types,)*)]
+        #serde_derive
+        #tol_type
+
+        impl #impl_generics float_eq::FloatEqUlpsTol for #tol_name #ty_generics #where_clause {
+            type UlpsTol = #ulps_name #ty_generics;
         }
     })
 }
@@ -175,48 +285,41 @@ pub fn derive_float_eq_debug_ulps_diff(input: proc_macro::TokenStream) -> proc_m
 }
 
 fn expand_float_eq_debug_ulps_diff(input: DeriveInput) -> Result<TokenStream, syn::Error> {
-    let vis = &input.vis;
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("FloatEqDebugUlpsDiff", &input)?;
+    let info = read::type_info("FloatEqDebugUlpsDiff", &input)?;
     let params = read::float_eq_attr(&input)?;
-    let ulps_name = params.debug_ulps_diff()?;
-
-    let ulps_type = match fields.ty {
-        read::FieldListType::Named => {
-            let ulps_fields = fields.expand(|field| {
-                let name = &field.name;
-                let ty = &field.ty;
-                quote! { #name: float_eq::DebugUlpsDiff<#ty> }
-            });
-            quote! {
-                #vis struct #ulps_name {
-                    #(#ulps_fields,)*
-                }
-            }
-        }
-        read::FieldListType::Tuple => {
-            let ulps_fields = fields.expand(|field| {
-                let ty = &field.ty;
-                quote! { float_eq::DebugUlpsDiff<#ty> }
-            });
-            quote! {
-                #vis struct #ulps_name( #(#ulps_fields,)* );
-            }
-        }
-        read::FieldListType::Unit => quote! {
-            #vis struct #ulps_name;
-        },
-    };
+    let diff_name = params.debug_ulps_diff()?;
+
+    let diff_info = info.subset(read::FieldInfo::is_float);
+    let diff_generics = diff_info.used_generics(&input.generics);
+    let field_bounds = |field: &read::FieldInfo| debug_ulps_diff_bounds(&params, field.ty);
+    let diff_type = expand_companion_type(
+        &input,
+        diff_name,
+        &diff_info,
+        &diff_generics,
+        false,
+        |ty| quote! { float_eq::DebugUlpsDiff<#ty> },
+        field_bounds,
+    );
 
     let derive_types = params.debug_ulps_diff_derive_types();
+    let serde_derive = expand_serde_derive(&params, &diff_info, &diff_generics, |ty| {
+        quote! { float_eq::DebugUlpsDiff<#ty> }
+    });
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let (_, diff_ty_generics, _) = diff_generics.split_for_impl();
+    let where_clause = expand_where_clause(&input.generics, &diff_info, quote! {}, field_bounds);
 
     Ok(quote! {
         #[doc(hidden)]
         #[derive(#(#derive_types,)*)]
-        #ulps_type
+        #serde_derive
+        #diff_type
 
-        impl float_eq::FloatEqDebugUlpsDiff for #struct_name {
-            type DebugUlpsDiff = #ulps_name;
+        impl #impl_generics float_eq::FloatEqDebugUlpsDiff for #struct_name #ty_generics #where_clause {
+            type DebugUlpsDiff = #diff_name #diff_ty_generics;
         }
     })
 }
@@ -232,61 +335,91 @@ pub fn derive_float_eq_attribute(input: proc_macro::TokenStream) -> proc_macro::
 
 fn expand_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("FloatEq", &input)?;
+    let info = read::type_info("FloatEq", &input)?;
     let params = read::float_eq_attr(&input)?;
     let ulps_name = params.ulps_tol_type()?;
-
-    let expand_exprs = |method| {
-        let mut expanded = fields.expand(|field| {
-            let name = &field.name;
-            let method = Ident::new(method, Span::call_site());
-            quote! { self.#name.#method(&other.#name, &tol.#name) }
-        });
-        if expanded.is_empty() {
-            expanded.push(quote! { true });
-        }
-        expanded
+    let tol_type = TolType::tol(&input, &info, &params)?;
+    let tol_name = tol_type.name;
+
+    let expand_exprs = |method, tols: Vec<Operand>| {
+        let method = Ident::new(method, Span::call_site());
+        let names: Vec<_> = tols.iter().map(|tol| tol.name).collect();
+        let mut operands = vec![
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+        ];
+        operands.extend(tols);
+        expand_match(
+            &info,
+            &operands,
+            |variant| {
+                expand_eq_fields(&variant.fields, &method, |field| {
+                    let tols = names.iter().map(|name| field.binding(name));
+                    quote! { #(#tols),* }
+                })
+            },
+            quote! { false },
+        )
     };
 
-    let eq_abs = expand_exprs("eq_abs");
-    let eq_rmax = expand_exprs("eq_rmax");
-    let eq_rmin = expand_exprs("eq_rmin");
-    let eq_r1st = expand_exprs("eq_r1st");
-    let eq_r2nd = expand_exprs("eq_r2nd");
-    let eq_ulps = expand_exprs("eq_ulps");
+    let tol = || vec![Operand::tol("tol", tol_name)];
+    let eq_abs = expand_exprs("eq_abs", tol());
+    let eq_rmax = expand_exprs("eq_rmax", tol());
+    let eq_rmin = expand_exprs("eq_rmin", tol());
+    let eq_r1st = expand_exprs("eq_r1st", tol());
+    let eq_r2nd = expand_exprs("eq_r2nd", tol());
+    let eq_atol_rtol = expand_exprs(
+        "eq_atol_rtol",
+        vec![
+            Operand::tol("atol", tol_name),
+            Operand::tol("rtol", tol_name),
+        ],
+    );
+    let eq_ulps = expand_exprs("eq_ulps", vec![Operand::tol("tol", ulps_name)]);
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(&input.generics, &info, quote! {}, |field| {
+        float_eq_bounds(&params, field)
+    });
 
+    let tol_type = &tol_type.ty;
     Ok(quote! {
-        impl float_eq::FloatEq for #struct_name {
-            type Tol = Self;
+        impl #impl_generics float_eq::FloatEq for #struct_name #ty_generics #where_clause {
+            type Tol = #tol_type;
 
             #[inline]
-            fn eq_abs(&self, other: &Self, tol: &Self) -> bool {
-                #(#eq_abs)&&*
+            fn eq_abs(&self, other: &Self, tol: &Self::Tol) -> bool {
+                #eq_abs
             }
 
             #[inline]
-            fn eq_rmax(&self, other: &Self, tol: &Self) -> bool {
-                #(#eq_rmax)&&*
+            fn eq_rmax(&self, other: &Self, tol: &Self::Tol) -> bool {
+                #eq_rmax
             }
 
             #[inline]
-            fn eq_rmin(&self, other: &Self, tol: &Self) -> bool {
-                #(#eq_rmin)&&*
+            fn eq_rmin(&self, other: &Self, tol: &Self::Tol) -> bool {
+                #eq_rmin
             }
 
             #[inline]
-            fn eq_r1st(&self, other: &Self, tol: &Self) -> bool {
-                #(#eq_r1st)&&*
+            fn eq_r1st(&self, other: &Self, tol: &Self::Tol) -> bool {
+                #eq_r1st
             }
 
             #[inline]
-            fn eq_r2nd(&self, other: &Self, tol: &Self) -> bool {
-                #(#eq_r2nd)&&*
+            fn eq_r2nd(&self, other: &Self, tol: &Self::Tol) -> bool {
+                #eq_r2nd
             }
 
             #[inline]
-            fn eq_ulps(&self, other: &Self, tol: &#ulps_name) -> bool {
-                #(#eq_ulps)&&*
+            fn eq_atol_rtol(&self, other: &Self, atol: &Self::Tol, rtol: &Self::Tol) -> bool {
+                #eq_atol_rtol
+            }
+
+            #[inline]
+            fn eq_ulps(&self, other: &Self, tol: &float_eq::UlpsTol<Self::Tol>) -> bool {
+                #eq_ulps
             }
         }
     })
@@ -303,96 +436,471 @@ pub fn derive_assert_float_eq(input: proc_macro::TokenStream) -> proc_macro::Tok
 
 fn expand_assert_float_eq(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("AssertFloatEq", &input)?;
+    let info = read::type_info("AssertFloatEq", &input)?;
     let params = read::float_eq_attr(&input)?;
     let ulps_name = params.ulps_tol_type()?;
     let diff_name = params.debug_ulps_diff()?;
+    let tol_name = TolType::tol(&input, &info, &params)?.name;
+    let debug_tol = TolType::debug(&input, &info, &params)?;
+    let debug_name = debug_tol.name;
+
+    // Enums may not be compared if their variants differ, so their debug
+    // output is optional in the same way as collections of differing lengths.
+    let debug_type = |ty: TokenStream| {
+        if info.is_enum {
+            quote! { ::core::option::Option<#ty> }
+        } else {
+            ty
+        }
+    };
+    let debug_value = |value: TokenStream| {
+        if info.is_enum {
+            quote! { ::core::option::Option::Some(#value) }
+        } else {
+            value
+        }
+    };
 
-    let expand_diff_fields = |method| {
-        fields.expand(|field| {
-            let name = &field.name;
-            let method = Ident::new(method, Span::call_site());
-            quote! { #name: self.#name.#method(&other.#name) }
-        })
+    let self_and_other = [
+        Operand::new("self", struct_name),
+        Operand::new("other", struct_name),
+    ];
+    let abs_diff = expand_match(
+        &info,
+        &self_and_other,
+        |variant| {
+            let path = variant.path(debug_name);
+            let fields = variant.fields.subset(read::FieldInfo::is_float);
+            debug_value(fields.construct(&path, |field| {
+                let a = field.binding("self");
+                let b = field.binding("other");
+                quote! { #a.debug_abs_diff(#b) }
+            }))
+        },
+        quote! { ::core::option::Option::None },
+    );
+    let ulps_diff = expand_match(
+        &info,
+        &self_and_other,
+        |variant| {
+            let path = variant.path(diff_name);
+            let fields = variant.fields.subset(read::FieldInfo::is_float);
+            debug_value(fields.construct(&path, |field| {
+                let a = field.binding("self");
+                let b = field.binding("other");
+                quote! { #a.debug_ulps_diff(#b) }
+            }))
+        },
+        quote! { ::core::option::Option::None },
+    );
+
+    let expand_tol = |method: &str, names: &[&'static str]| {
+        let mut operands = vec![
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+        ];
+        operands.extend(names.iter().map(|name| Operand::tol(name, tol_name)));
+        expand_match(
+            &info,
+            &operands,
+            |variant| {
+                let path = variant.path(debug_name);
+                let fields = variant.fields.subset(read::FieldInfo::is_float);
+                debug_value(fields.construct(&path, |field| {
+                    let tols = names.iter().map(|name| field.binding(name));
+                    expand_debug_field_tol(field, method, quote! { #(#tols),* })
+                }))
+            },
+            quote! { ::core::option::Option::None },
+        )
     };
 
-    let abs_diff_fields = expand_diff_fields("debug_abs_diff");
-    let ulps_diff_fields = expand_diff_fields("debug_ulps_diff");
+    let abs_tol = expand_tol("abs", &["tol"]);
+    let rmax_tol = expand_tol("rmax", &["tol"]);
+    let rmin_tol = expand_tol("rmin", &["tol"]);
+    let r1st_tol = expand_tol("r1st", &["tol"]);
+    let r2nd_tol = expand_tol("r2nd", &["tol"]);
+    let atol_rtol_tol = expand_tol("atol_rtol", &["atol", "rtol"]);
+    let ulps_tol = expand_match(
+        &info,
+        &[
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+            Operand::tol("tol", ulps_name),
+        ],
+        |variant| {
+            let path = variant.path(ulps_name);
+            let fields = variant.fields.subset(read::FieldInfo::uses_tol);
+            debug_value(fields.construct(&path, |field| {
+                let a = field.binding("self");
+                let b = field.binding("other");
+                let t = field.binding("tol");
+                quote! { #a.debug_ulps_tol(#b, #t) }
+            }))
+        },
+        quote! { ::core::option::Option::None },
+    );
+
+    let debug_self = debug_type(debug_tol.ty.clone());
+    let debug_ulps_diff = debug_type(quote! { float_eq::DebugUlpsDiff<Self> });
+    let debug_ulps_tol = debug_type(quote! { float_eq::UlpsTol<Self> });
 
-    let expand_eps_fields = |method| {
-        fields.expand(|field| {
-            let name = &field.name;
-            let method = Ident::new(method, Span::call_site());
-            quote! { #name: self.#name.#method(&other.#name, &tol.#name) }
-        })
+    let debug_tol_type = if info.has_field_attributes() {
+        expand_debug_tol_type(&input, &params, &info)?
+    } else {
+        quote! {}
     };
 
-    let abs_eps_fields = expand_eps_fields("debug_abs_tol");
-    let rmax_eps_fields = expand_eps_fields("debug_rmax_tol");
-    let rmin_eps_fields = expand_eps_fields("debug_rmin_tol");
-    let r1st_eps_fields = expand_eps_fields("debug_r1st_tol");
-    let r2nd_eps_fields = expand_eps_fields("debug_r2nd_tol");
-    let ulps_eps_fields = expand_eps_fields("debug_ulps_tol");
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(
+        &input.generics,
+        &info,
+        quote! { #struct_name #ty_generics: ::core::fmt::Debug, },
+        |field| assert_float_eq_bounds(&params, field),
+    );
 
     Ok(quote! {
-        impl float_eq::AssertFloatEq for #struct_name {
-            type DebugAbsDiff = Self;
-            type DebugTol = Self;
+        #debug_tol_type
+
+        impl #impl_generics float_eq::AssertFloatEq for #struct_name #ty_generics #where_clause {
+            type DebugAbsDiff = #debug_self;
+            type DebugTol = #debug_self;
 
             #[inline]
-            fn debug_abs_diff(&self, other: &Self) -> Self {
-                Self {
-                    #(#abs_diff_fields,)*
-                }
+            fn debug_abs_diff(&self, other: &Self) -> #debug_self {
+                #abs_diff
             }
 
             #[inline]
-            fn debug_ulps_diff(&self, other: &Self) -> #diff_name {
-                #diff_name {
-                    #(#ulps_diff_fields,)*
-                }
+            fn debug_ulps_diff(&self, other: &Self) -> #debug_ulps_diff {
+                #ulps_diff
             }
 
             #[inline]
-            fn debug_abs_tol(&self, other: &Self, tol: &Self) -> Self {
-                Self {
-                    #(#abs_eps_fields,)*
-                }
+            fn debug_abs_tol(&self, other: &Self, tol: &Self::Tol) -> #debug_self {
+                #abs_tol
             }
 
             #[inline]
-            fn debug_rmax_tol(&self, other: &Self, tol: &Self) -> Self {
-                Self {
-                    #(#rmax_eps_fields,)*
-                }
+            fn debug_rmax_tol(&self, other: &Self, tol: &Self::Tol) -> #debug_self {
+                #rmax_tol
             }
 
             #[inline]
-            fn debug_rmin_tol(&self, other: &Self, tol: &Self) -> Self {
-                Self {
-                    #(#rmin_eps_fields,)*
-                }
+            fn debug_rmin_tol(&self, other: &Self, tol: &Self::Tol) -> #debug_self {
+                #rmin_tol
             }
 
             #[inline]
-            fn debug_r1st_tol(&self, other: &Self, tol: &Self) -> Self {
-                Self {
-                    #(#r1st_eps_fields,)*
-                }
+            fn debug_r1st_tol(&self, other: &Self, tol: &Self::Tol) -> #debug_self {
+                #r1st_tol
             }
 
             #[inline]
-            fn debug_r2nd_tol(&self, other: &Self, tol: &Self) -> Self {
-                Self {
-                    #(#r2nd_eps_fields,)*
-                }
+            fn debug_r2nd_tol(&self, other: &Self, tol: &Self::Tol) -> #debug_self {
+                #r2nd_tol
+            }
+
+            #[inline]
+            fn debug_atol_rtol_tol(
+                &self,
+                other: &Self,
+                atol: &Self::Tol,
+                rtol: &Self::Tol
+            ) -> #debug_self {
+                #atol_rtol_tol
+            }
+
+            #[inline]
+            fn debug_ulps_tol(
+                &self,
+                other: &Self,
+                tol: &float_eq::UlpsTol<Self::Tol>
+            ) -> #debug_ulps_tol {
+                #ulps_tol
+            }
+        }
+    })
+}
+
+/// Declares the `debug_tol` type of a derived type with field attributes, which
+/// has the same fields as its `debug_ulps_diff` type.
+fn expand_debug_tol_type(
+    input: &DeriveInput,
+    params: &read::FloatEqAttr,
+    info: &read::TypeInfo,
+) -> Result<TokenStream, syn::Error> {
+    let debug_name = params.debug_tol_type()?;
+    let ulps_name = params.ulps_tol_type()?;
+    let diff_name = params.debug_ulps_diff()?;
+
+    let debug_info = info.subset(read::FieldInfo::is_float);
+    let debug_generics = debug_info.used_generics(&input.generics);
+    let ulps_generics = info
+        .subset(read::FieldInfo::uses_tol)
+        .used_generics(&input.generics);
+    let debug_bounds = |field: &read::FieldInfo| {
+        let ty = field.ty;
+        quote! { #ty: ::core::fmt::Debug, }
+    };
+    let debug_type = expand_companion_type(
+        input,
+        debug_name,
+        &debug_info,
+        &debug_generics,
+        true,
+        |ty| quote! { #ty },
+        debug_bounds,
+    );
+
+    let (impl_generics, ty_generics, _) = debug_generics.split_for_impl();
+    let (_, ulps_ty_generics, _) = ulps_generics.split_for_impl();
+    let ulps_where_clause = expand_where_clause(&debug_generics, &debug_info, quote! {}, |field| {
+        let debug_bounds = debug_bounds(field);
+        let ulps_tol_bounds = if field.uses_tol() {
+            ulps_tol_bounds(params, field.ty)
+        } else {
+            quote! {}
+        };
+        quote! {
+            #debug_bounds
+            #ulps_tol_bounds
+        }
+    });
+    let diff_where_clause = expand_where_clause(&debug_generics, &debug_info, quote! {}, |field| {
+        let debug_bounds = debug_bounds(field);
+        let debug_ulps_diff_bounds = debug_ulps_diff_bounds(params, field.ty);
+        quote! {
+            #debug_bounds
+            #debug_ulps_diff_bounds
+        }
+    });
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        #debug_type
+
+        impl #impl_generics float_eq::FloatEqUlpsTol for #debug_name #ty_generics #ulps_where_clause {
+            type UlpsTol = #ulps_name #ulps_ty_generics;
+        }
+
+        impl #impl_generics float_eq::FloatEqDebugUlpsDiff for #debug_name #ty_generics #diff_where_clause {
+            type DebugUlpsDiff = #diff_name #ty_generics;
+        }
+    })
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(FloatOrd, attributes(float_eq))]
+pub fn derive_float_ord(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_float_ord(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_float_ord(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let info = read::type_info("FloatOrd", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let ulps_name = params.ulps_tol_type()?;
+    let tol_name = TolType::tol(&input, &info, &params)?.name;
+    check_ord_fields("FloatOrd", &info)?;
+
+    let expand_exprs = |method, tol: Operand| {
+        let method = Ident::new(method, Span::call_site());
+        let operands = [
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+            tol,
+        ];
+        expand_match(
+            &info,
+            &operands,
+            |variant| expand_ord_fields(&variant.fields, &method),
+            quote! { false },
+        )
+    };
+
+    let ord_abs = expand_exprs("ord_abs", Operand::tol("tol", tol_name));
+    let ord_rmax = expand_exprs("ord_rmax", Operand::tol("tol", tol_name));
+    let ord_rmin = expand_exprs("ord_rmin", Operand::tol("tol", tol_name));
+    let ord_r1st = expand_exprs("ord_r1st", Operand::tol("tol", tol_name));
+    let ord_r2nd = expand_exprs("ord_r2nd", Operand::tol("tol", tol_name));
+    let ord_ulps = expand_exprs("ord_ulps", Operand::tol("tol", ulps_name));
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(&input.generics, &info, quote! {}, |field| {
+        float_ord_bounds(&params, field)
+    });
+
+    Ok(quote! {
+        impl #impl_generics float_eq::FloatOrd for #struct_name #ty_generics #where_clause {
+            #[inline]
+            fn ord_abs(&self, other: &Self, op: float_eq::FloatOrdOp, tol: &Self::Tol) -> bool {
+                #ord_abs
+            }
+
+            #[inline]
+            fn ord_rmax(&self, other: &Self, op: float_eq::FloatOrdOp, tol: &Self::Tol) -> bool {
+                #ord_rmax
+            }
+
+            #[inline]
+            fn ord_rmin(&self, other: &Self, op: float_eq::FloatOrdOp, tol: &Self::Tol) -> bool {
+                #ord_rmin
+            }
+
+            #[inline]
+            fn ord_r1st(&self, other: &Self, op: float_eq::FloatOrdOp, tol: &Self::Tol) -> bool {
+                #ord_r1st
             }
 
             #[inline]
-            fn debug_ulps_tol(&self, other: &Self, tol: &#ulps_name) -> #ulps_name {
-                #ulps_name {
-                    #(#ulps_eps_fields,)*
+            fn ord_r2nd(&self, other: &Self, op: float_eq::FloatOrdOp, tol: &Self::Tol) -> bool {
+                #ord_r2nd
+            }
+
+            #[inline]
+            fn ord_ulps(
+                &self,
+                other: &Self,
+                op: float_eq::FloatOrdOp,
+                tol: &float_eq::UlpsTol<Self::Tol>
+            ) -> bool {
+                #ord_ulps
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(AssertFloatOrd, attributes(float_eq))]
+pub fn derive_assert_float_ord(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_assert_float_ord(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_assert_float_ord(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let info = read::type_info("AssertFloatOrd", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let ulps_name = params.ulps_tol_type()?;
+    let tol_name = TolType::tol(&input, &info, &params)?.name;
+    let debug_tol = TolType::debug(&input, &info, &params)?;
+    check_ord_fields("AssertFloatOrd", &info)?;
+
+    let debug_self = if info.is_enum {
+        let debug_type = &debug_tol.ty;
+        quote! { ::core::option::Option<#debug_type> }
+    } else {
+        debug_tol.ty.clone()
+    };
+
+    let expand_past_bound = |check: &str, tol: Operand| {
+        let operands = [
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+            tol,
+        ];
+        expand_match(
+            &info,
+            &operands,
+            |variant| {
+                let path = variant.path(debug_tol.name);
+                let value = variant
+                    .fields
+                    .subset(read::FieldInfo::is_float)
+                    .construct(&path, |field| expand_debug_field_ord(field, check));
+                if info.is_enum {
+                    quote! { ::core::option::Option::Some(#value) }
+                } else {
+                    value
                 }
+            },
+            quote! { ::core::option::Option::None },
+        )
+    };
+
+    let abs = expand_past_bound("abs", Operand::tol("tol", tol_name));
+    let rmax = expand_past_bound("rmax", Operand::tol("tol", tol_name));
+    let rmin = expand_past_bound("rmin", Operand::tol("tol", tol_name));
+    let r1st = expand_past_bound("r1st", Operand::tol("tol", tol_name));
+    let r2nd = expand_past_bound("r2nd", Operand::tol("tol", tol_name));
+    let ulps = expand_past_bound("ulps", Operand::tol("tol", ulps_name));
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(
+        &input.generics,
+        &info,
+        quote! { #struct_name #ty_generics: ::core::fmt::Debug, },
+        |field| assert_float_ord_bounds(&params, field),
+    );
+
+    Ok(quote! {
+        impl #impl_generics float_eq::AssertFloatOrd for #struct_name #ty_generics #where_clause {
+            #[inline]
+            fn debug_ord_abs(
+                &self,
+                other: &Self,
+                op: float_eq::FloatOrdOp,
+                tol: &Self::Tol
+            ) -> #debug_self {
+                #abs
+            }
+
+            #[inline]
+            fn debug_ord_rmax(
+                &self,
+                other: &Self,
+                op: float_eq::FloatOrdOp,
+                tol: &Self::Tol
+            ) -> #debug_self {
+                #rmax
+            }
+
+            #[inline]
+            fn debug_ord_rmin(
+                &self,
+                other: &Self,
+                op: float_eq::FloatOrdOp,
+                tol: &Self::Tol
+            ) -> #debug_self {
+                #rmin
+            }
+
+            #[inline]
+            fn debug_ord_r1st(
+                &self,
+                other: &Self,
+                op: float_eq::FloatOrdOp,
+                tol: &Self::Tol
+            ) -> #debug_self {
+                #r1st
+            }
+
+            #[inline]
+            fn debug_ord_r2nd(
+                &self,
+                other: &Self,
+                op: float_eq::FloatOrdOp,
+                tol: &Self::Tol
+            ) -> #debug_self {
+                #r2nd
+            }
+
+            #[inline]
+            fn debug_ord_ulps(
+                &self,
+                other: &Self,
+                op: float_eq::FloatOrdOp,
+                tol: &float_eq::UlpsTol<Self::Tol>
+            ) -> #debug_self {
+                #ulps
             }
         }
     })
@@ -409,61 +917,76 @@ pub fn derive_float_eq_all(input: proc_macro::TokenStream) -> proc_macro::TokenS
 
 fn expand_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("FloatEqAll", &input)?;
+    let info = read::type_info("FloatEqAll", &input)?;
     let params = read::float_eq_attr(&input)?;
     let all_tol = params.all_tol_type()?;
 
-    let expand_exprs = |method| {
-        let mut expanded = fields.expand(|field| {
-            let name = &field.name;
-            let method = Ident::new(method, Span::call_site());
-            quote! { self.#name.#method(&other.#name, tol) }
-        });
-        if expanded.is_empty() {
-            expanded.push(quote! { true });
-        }
-        expanded
+    let expand_exprs = |method, tol: TokenStream| {
+        let method = Ident::new(method, Span::call_site());
+        expand_match(
+            &info,
+            &[
+                Operand::new("self", struct_name),
+                Operand::new("other", struct_name),
+            ],
+            |variant| expand_eq_fields(&variant.fields, &method, |_| tol.clone()),
+            quote! { false },
+        )
     };
 
-    let eq_abs = expand_exprs("eq_abs_all");
-    let eq_rmax = expand_exprs("eq_rmax_all");
-    let eq_rmin = expand_exprs("eq_rmin_all");
-    let eq_r1st = expand_exprs("eq_r1st_all");
-    let eq_r2nd = expand_exprs("eq_r2nd_all");
-    let eq_ulps = expand_exprs("eq_ulps_all");
+    let eq_abs = expand_exprs("eq_abs_all", quote! { tol });
+    let eq_rmax = expand_exprs("eq_rmax_all", quote! { tol });
+    let eq_rmin = expand_exprs("eq_rmin_all", quote! { tol });
+    let eq_r1st = expand_exprs("eq_r1st_all", quote! { tol });
+    let eq_r2nd = expand_exprs("eq_r2nd_all", quote! { tol });
+    let eq_atol_rtol = expand_exprs("eq_atol_rtol_all", quote! { atol, rtol });
+    let eq_ulps = expand_exprs("eq_ulps_all", quote! { tol });
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(
+        &input.generics,
+        &info,
+        quote! { #all_tol: float_eq::FloatEqUlpsTol, },
+        |field| float_eq_all_bounds(all_tol, field),
+    );
 
     Ok(quote! {
-        impl float_eq::FloatEqAll for #struct_name {
+        impl #impl_generics float_eq::FloatEqAll for #struct_name #ty_generics #where_clause {
             type AllTol = #all_tol;
 
             #[inline]
             fn eq_abs_all(&self, other: &Self, tol: &#all_tol) -> bool {
-                #(#eq_abs)&&*
+                #eq_abs
             }
 
             #[inline]
             fn eq_rmax_all(&self, other: &Self, tol: &#all_tol) -> bool {
-                #(#eq_rmax)&&*
+                #eq_rmax
             }
 
             #[inline]
             fn eq_rmin_all(&self, other: &Self, tol: &#all_tol) -> bool {
-                #(#eq_rmin)&&*
+                #eq_rmin
             }
 
             #[inline]
             fn eq_r1st_all(&self, other: &Self, tol: &#all_tol) -> bool {
-                #(#eq_r1st)&&*
+                #eq_r1st
             }
 
             #[inline]
             fn eq_r2nd_all(&self, other: &Self, tol: &#all_tol) -> bool {
-                #(#eq_r2nd)&&*
+                #eq_r2nd
+            }
+
+            #[inline]
+            fn eq_atol_rtol_all(&self, other: &Self, atol: &#all_tol, rtol: &#all_tol) -> bool {
+                #eq_atol_rtol
             }
 
             #[inline]
             fn eq_ulps_all(&self, other: &Self, tol: &::float_eq::UlpsTol<Self::AllTol>) -> bool {
-                #(#eq_ulps)&&*
+                #eq_ulps
             }
         }
     })
@@ -480,62 +1003,124 @@ pub fn derive_assert_float_eq_all(input: proc_macro::TokenStream) -> proc_macro:
 
 fn expand_assert_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Error> {
     let struct_name = &input.ident;
-    let fields = read::all_fields_info("AssertFloatEqAll", &input)?;
+    let info = read::type_info("AssertFloatEqAll", &input)?;
     let params = read::float_eq_attr(&input)?;
     let all_tol = params.all_tol_type()?;
+    let debug_tol = TolType::debug(&input, &info, &params)?;
+    let debug_type = &debug_tol.ty;
+
+    // Structs may name their ULPs type via UlpsTol, but enum variants must be
+    // reached through the generated type's name.
+    let (debug_self, debug_ulps_tol, ulps_name) = if info.is_enum {
+        let ulps_name = params.ulps_tol_type()?;
+        (
+            quote! { ::core::option::Option<#debug_type> },
+            quote! { ::core::option::Option<float_eq::UlpsTol<Self>> },
+            quote! { #ulps_name },
+        )
+    } else {
+        (
+            quote! { #debug_type },
+            quote! { ::float_eq::UlpsTol<Self::AllDebugTol> },
+            quote! { ::float_eq::UlpsTol::<Self::AllDebugTol> },
+        )
+    };
+    let debug_value = |value: TokenStream| {
+        if info.is_enum {
+            quote! { ::core::option::Option::Some(#value) }
+        } else {
+            value
+        }
+    };
 
-    let expand_fields = |method| {
-        fields.expand(|field| {
-            let name = &field.name;
-            let method = Ident::new(method, Span::call_site());
-            quote! { #name: self.#name.#method(&other.#name, tol) }
-        })
+    let self_and_other = [
+        Operand::new("self", struct_name),
+        Operand::new("other", struct_name),
+    ];
+    let expand_tol = |method: &str, tol: TokenStream| {
+        expand_match(
+            &info,
+            &self_and_other,
+            |variant| {
+                let path = variant.path(debug_tol.name);
+                let fields = variant.fields.subset(read::FieldInfo::is_float);
+                debug_value(fields.construct(&path, |field| {
+                    expand_debug_field_tol(field, method, tol.clone())
+                }))
+            },
+            quote! { ::core::option::Option::None },
+        )
     };
 
-    let abs_eps_fields = expand_fields("debug_abs_all_tol");
-    let rmax_eps_fields = expand_fields("debug_rmax_all_tol");
-    let rmin_eps_fields = expand_fields("debug_rmin_all_tol");
-    let r1st_eps_fields = expand_fields("debug_r1st_all_tol");
-    let r2nd_eps_fields = expand_fields("debug_r2nd_all_tol");
-    let ulps_eps_fields = expand_fields("debug_ulps_all_tol");
+    let abs_tol = expand_tol("abs_all", quote! { tol });
+    let rmax_tol = expand_tol("rmax_all", quote! { tol });
+    let rmin_tol = expand_tol("rmin_all", quote! { tol });
+    let r1st_tol = expand_tol("r1st_all", quote! { tol });
+    let r2nd_tol = expand_tol("r2nd_all", quote! { tol });
+    let atol_rtol_tol = expand_tol("atol_rtol_all", quote! { atol, rtol });
+    let ulps_tol = expand_match(
+        &info,
+        &self_and_other,
+        |variant| {
+            let path = variant.path(&ulps_name);
+            let fields = variant.fields.subset(read::FieldInfo::uses_tol);
+            debug_value(fields.construct(&path, |field| {
+                let a = field.binding("self");
+                let b = field.binding("other");
+                quote! { #a.debug_ulps_all_tol(#b, tol) }
+            }))
+        },
+        quote! { ::core::option::Option::None },
+    );
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(
+        &input.generics,
+        &info,
+        quote! {
+            #struct_name #ty_generics: ::core::fmt::Debug,
+            #all_tol: float_eq::FloatEqUlpsTol,
+        },
+        |field| assert_float_eq_all_bounds(&params, all_tol, field),
+    );
 
     Ok(quote! {
-        impl float_eq::AssertFloatEqAll for #struct_name {
-            type AllDebugTol = Self;
+        impl #impl_generics float_eq::AssertFloatEqAll for #struct_name #ty_generics #where_clause {
+            type AllDebugTol = #debug_self;
 
             #[inline]
-            fn debug_abs_all_tol(&self, other: &Self, tol: &#all_tol) -> Self {
-                Self {
-                    #(#abs_eps_fields,)*
-                }
+            fn debug_abs_all_tol(&self, other: &Self, tol: &#all_tol) -> #debug_self {
+                #abs_tol
             }
 
             #[inline]
-            fn debug_rmax_all_tol(&self, other: &Self, tol: &#all_tol) -> Self {
-                Self {
-                    #(#rmax_eps_fields,)*
-                }
+            fn debug_rmax_all_tol(&self, other: &Self, tol: &#all_tol) -> #debug_self {
+                #rmax_tol
             }
 
             #[inline]
-            fn debug_rmin_all_tol(&self, other: &Self, tol: &#all_tol) -> Self {
-                Self {
-                    #(#rmin_eps_fields,)*
-                }
+            fn debug_rmin_all_tol(&self, other: &Self, tol: &#all_tol) -> #debug_self {
+                #rmin_tol
             }
 
             #[inline]
-            fn debug_r1st_all_tol(&self, other: &Self, tol: &#all_tol) -> Self {
-                Self {
-                    #(#r1st_eps_fields,)*
-                }
+            fn debug_r1st_all_tol(&self, other: &Self, tol: &#all_tol) -> #debug_self {
+                #r1st_tol
             }
 
             #[inline]
-            fn debug_r2nd_all_tol(&self, other: &Self, tol: &#all_tol) -> Self {
-                Self {
-                    #(#r2nd_eps_fields,)*
-                }
+            fn debug_r2nd_all_tol(&self, other: &Self, tol: &#all_tol) -> #debug_self {
+                #r2nd_tol
+            }
+
+            #[inline]
+            fn debug_atol_rtol_all_tol(
+                &self,
+                other: &Self,
+                atol: &#all_tol,
+                rtol: &#all_tol
+            ) -> #debug_self {
+                #atol_rtol_tol
             }
 
             #[inline]
@@ -543,11 +1128,1058 @@ fn expand_assert_float_eq_all(input: DeriveInput) -> Result<TokenStream, syn::Er
                 &self,
                 other: &Self,
                 tol: &::float_eq::UlpsTol<Self::AllTol>
-            ) -> ::float_eq::UlpsTol<Self::AllDebugTol> {
-                ::float_eq::UlpsTol::<Self::AllDebugTol> {
-                    #(#ulps_eps_fields,)*
-                }
+            ) -> #debug_ulps_tol {
+                #ulps_tol
             }
         }
     })
 }
+
+#[doc(hidden)]
+#[proc_macro_derive(FloatEqReport, attributes(float_eq))]
+pub fn derive_float_eq_report(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_float_eq_report(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_float_eq_report(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let info = read::type_info("FloatEqReport", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let ulps_name = params.ulps_tol_type()?;
+    let tol_name = TolType::tol(&input, &info, &params)?.name;
+
+    let checks: &[(&str, &[&'static str])] = &[
+        ("Abs", &["tol"]),
+        ("Rmax", &["tol"]),
+        ("Rmin", &["tol"]),
+        ("R1st", &["tol"]),
+        ("R2nd", &["tol"]),
+        ("AtolRtol", &["atol", "rtol"]),
+        ("Ulps", &["tol"]),
+    ];
+    let arms = checks.iter().map(|(check, names)| {
+        let check = Ident::new(check, Span::call_site());
+        let mut operands = vec![
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+        ];
+        operands.extend(names.iter().map(|name| {
+            if check == "Ulps" {
+                Operand::tol(name, ulps_name)
+            } else {
+                Operand::tol(name, tol_name)
+            }
+        }));
+        let report = expand_match(
+            &info,
+            &operands,
+            |variant| {
+                expand_report_fields(&variant.fields, |field| {
+                    let a = field.binding("self");
+                    let b = field.binding("other");
+                    let tols = names.iter().map(|name| field.binding(name));
+                    let path = field.path_segment();
+                    quote! {
+                        float_eq::FloatEqReport::report(
+                            #a,
+                            #b,
+                            float_eq::ReportTol::#check(#(#tols),*),
+                            ::std::format!("{}{}", path, #path),
+                        )
+                    }
+                })
+            },
+            quote! { float_eq::ReportNode::mismatch(path, float_eq::Mismatch::Variant) },
+        );
+        let names = names.iter().map(|name| Ident::new(name, Span::call_site()));
+        quote! { float_eq::ReportTol::#check(#(#names),*) => { #report } }
+    });
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(&input.generics, &info, quote! {}, |field| {
+        float_eq_report_bounds(&params, field)
+    });
+
+    Ok(quote! {
+        impl #impl_generics float_eq::FloatEqReport for #struct_name #ty_generics #where_clause {
+            fn report(
+                &self,
+                other: &Self,
+                tol: float_eq::ReportTol<'_, Self::Tol>,
+                path: ::std::string::String,
+            ) -> float_eq::ReportNode {
+                match tol {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(FloatEqAllReport, attributes(float_eq))]
+pub fn derive_float_eq_all_report(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_float_eq_all_report(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_float_eq_all_report(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let info = read::type_info("FloatEqAllReport", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let all_tol = params.all_tol_type()?;
+
+    let report = expand_match(
+        &info,
+        &[
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+        ],
+        |variant| {
+            expand_report_fields(&variant.fields, |field| {
+                let a = field.binding("self");
+                let b = field.binding("other");
+                let path = field.path_segment();
+                quote! {
+                    float_eq::FloatEqAllReport::report_all(
+                        #a,
+                        #b,
+                        tol,
+                        ::std::format!("{}{}", path, #path),
+                    )
+                }
+            })
+        },
+        quote! { float_eq::ReportNode::mismatch(path, float_eq::Mismatch::Variant) },
+    );
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(
+        &input.generics,
+        &info,
+        quote! { #all_tol: float_eq::FloatEqUlpsTol, },
+        |field| float_eq_all_report_bounds(all_tol, field),
+    );
+
+    Ok(quote! {
+        impl #impl_generics float_eq::FloatEqAllReport for #struct_name #ty_generics #where_clause {
+            fn report_all(
+                &self,
+                other: &Self,
+                tol: float_eq::ReportTol<'_, #all_tol>,
+                path: ::std::string::String,
+            ) -> float_eq::ReportNode {
+                #report
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(AssertFloatEqDiffs, attributes(float_eq))]
+pub fn derive_assert_float_eq_diffs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_assert_float_eq_diffs(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_assert_float_eq_diffs(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let info = read::type_info("AssertFloatEqDiffs", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let ulps_name = params.ulps_tol_type()?;
+    let tol_name = TolType::tol(&input, &info, &params)?.name;
+
+    let checks: &[(&str, &[&'static str])] = &[
+        ("Abs", &["tol"]),
+        ("Rmax", &["tol"]),
+        ("Rmin", &["tol"]),
+        ("R1st", &["tol"]),
+        ("R2nd", &["tol"]),
+        ("AtolRtol", &["atol", "rtol"]),
+        ("Ulps", &["tol"]),
+    ];
+    let arms = checks.iter().map(|(check, names)| {
+        let check = Ident::new(check, Span::call_site());
+        let mut operands = vec![
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+        ];
+        operands.extend(names.iter().map(|name| {
+            if check == "Ulps" {
+                Operand::tol(name, ulps_name)
+            } else {
+                Operand::tol(name, tol_name)
+            }
+        }));
+        let push = expand_match(
+            &info,
+            &operands,
+            |variant| {
+                expand_diffs_fields(&variant.fields, |field| {
+                    let a = field.binding("self");
+                    let b = field.binding("other");
+                    let tols = names.iter().map(|name| field.binding(name));
+                    let path = field.path_segment();
+                    quote! {
+                        float_eq::AssertFloatEqDiffs::debug_diffs(
+                            #a,
+                            #b,
+                            float_eq::ReportTol::#check(#(#tols),*),
+                            &::std::format!("{}{}", path, #path),
+                            diffs,
+                        );
+                    }
+                })
+            },
+            expand_variant_diffs(),
+        );
+        let names = names.iter().map(|name| Ident::new(name, Span::call_site()));
+        quote! { float_eq::ReportTol::#check(#(#names),*) => { #push } }
+    });
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(
+        &input.generics,
+        &info,
+        quote! { #struct_name #ty_generics: ::core::fmt::Debug, },
+        |field| assert_float_eq_diffs_bounds(&params, field),
+    );
+
+    Ok(quote! {
+        impl #impl_generics float_eq::AssertFloatEqDiffs for #struct_name #ty_generics #where_clause {
+            fn debug_diffs(
+                &self,
+                other: &Self,
+                tol: float_eq::ReportTol<'_, Self::Tol>,
+                path: &str,
+                diffs: &mut float_eq::AssertDiffs,
+            ) -> bool {
+                match tol {
+                    #(#arms)*
+                }
+                false
+            }
+        }
+    })
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(AssertFloatEqAllDiffs, attributes(float_eq))]
+pub fn derive_assert_float_eq_all_diffs(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_assert_float_eq_all_diffs(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_assert_float_eq_all_diffs(input: DeriveInput) -> Result<TokenStream, syn::Error> {
+    let struct_name = &input.ident;
+    let info = read::type_info("AssertFloatEqAllDiffs", &input)?;
+    let params = read::float_eq_attr(&input)?;
+    let all_tol = params.all_tol_type()?;
+
+    let push = expand_match(
+        &info,
+        &[
+            Operand::new("self", struct_name),
+            Operand::new("other", struct_name),
+        ],
+        |variant| {
+            expand_diffs_fields(&variant.fields, |field| {
+                let a = field.binding("self");
+                let b = field.binding("other");
+                let path = field.path_segment();
+                quote! {
+                    float_eq::AssertFloatEqAllDiffs::debug_all_diffs(
+                        #a,
+                        #b,
+                        tol,
+                        &::std::format!("{}{}", path, #path),
+                        diffs,
+                    );
+                }
+            })
+        },
+        expand_variant_diffs(),
+    );
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = expand_where_clause(
+        &input.generics,
+        &info,
+        quote! {
+            #struct_name #ty_generics: ::core::fmt::Debug,
+            #all_tol: float_eq::FloatEqUlpsTol,
+        },
+        |field| assert_float_eq_all_diffs_bounds(&params, all_tol, field),
+    );
+
+    Ok(quote! {
+        impl #impl_generics float_eq::AssertFloatEqAllDiffs for #struct_name #ty_generics #where_clause {
+            fn debug_all_diffs(
+                &self,
+                other: &Self,
+                tol: float_eq::ReportTol<'_, #all_tol>,
+                path: &str,
+                diffs: &mut float_eq::AssertDiffs,
+            ) -> bool {
+                #push
+                false
+            }
+        }
+    })
+}
+
+/// A value destructured by [`expand_match`].
+struct Operand<'a> {
+    /// The name of the value, also used as the prefix of its field bindings.
+    name: &'static str,
+    /// The type the value has the same shape as.
+    type_name: &'a Ident,
+    /// Which of the derived type's fields the value's type contains.
+    fields: fn(&read::FieldInfo) -> bool,
+}
+
+impl<'a> Operand<'a> {
+    /// A value of the derived type, or of one with all of the same fields.
+    fn new(name: &'static str, type_name: &'a Ident) -> Self {
+        Operand {
+            name,
+            type_name,
+            fields: |_| true,
+        }
+    }
+
+    /// A value of one of the derived type's tolerance types, which only have
+    /// the fields compared using the caller's tolerance.
+    fn tol(name: &'static str, type_name: &'a Ident) -> Self {
+        Operand {
+            name,
+            type_name,
+            fields: |field| field.uses_tol(),
+        }
+    }
+}
+
+/// A type used for the tolerances of a derived type, or for their debug output.
+///
+/// This is the derived type itself, unless any of its fields has a
+/// `#[float_eq(...)]` attribute. In that case it is a generated type without
+/// the fields that do not need it.
+struct TolType<'a> {
+    name: &'a Ident,
+    /// The type including its generic arguments.
+    ty: TokenStream,
+}
+
+impl<'a> TolType<'a> {
+    /// The `Tol` type, which has the fields that use the caller's tolerance.
+    fn tol(
+        input: &'a DeriveInput,
+        info: &read::TypeInfo,
+        params: &'a read::FloatEqAttr,
+    ) -> Result<Self, syn::Error> {
+        if !info.has_field_attributes() {
+            return Ok(TolType::derived(input));
+        }
+        let name = params.tol_type()?;
+        Ok(TolType::generated(
+            input,
+            info,
+            name,
+            read::FieldInfo::uses_tol,
+        ))
+    }
+
+    /// The `DebugAbsDiff` and `DebugTol` type, which has the fields compared as
+    /// floating point values.
+    fn debug(
+        input: &'a DeriveInput,
+        info: &read::TypeInfo,
+        params: &'a read::FloatEqAttr,
+    ) -> Result<Self, syn::Error> {
+        if !info.has_field_attributes() {
+            return Ok(TolType::derived(input));
+        }
+        let name = params.debug_tol_type()?;
+        Ok(TolType::generated(
+            input,
+            info,
+            name,
+            read::FieldInfo::is_float,
+        ))
+    }
+
+    fn derived(input: &'a DeriveInput) -> Self {
+        TolType {
+            name: &input.ident,
+            ty: quote! { Self },
+        }
+    }
+
+    fn generated<'b, F: Fn(&read::FieldInfo<'b>) -> bool>(
+        input: &'a DeriveInput,
+        info: &read::TypeInfo<'b>,
+        name: &'a Ident,
+        fields: F,
+    ) -> Self {
+        let generics = info.subset(fields).used_generics(&input.generics);
+        let (_, ty_generics, _) = generics.split_for_impl();
+        TolType {
+            name,
+            ty: quote! { #name #ty_generics },
+        }
+    }
+}
+
+/// Expands to a match over the same variant of each operand. Each arm
+/// destructures the operands and evaluates the expression returned by `func`,
+/// and for enums variants that do not match evaluate to `otherwise`.
+fn expand_match<F: Fn(&read::VariantInfo) -> TokenStream>(
+    info: &read::TypeInfo,
+    operands: &[Operand],
+    func: F,
+    otherwise: TokenStream,
+) -> TokenStream {
+    let exprs = operands
+        .iter()
+        .map(|operand| Ident::new(operand.name, Span::call_site()));
+    let arms = info.variants.iter().map(|variant| {
+        let patterns = operands.iter().map(|operand| {
+            let path = variant.path(operand.type_name);
+            variant
+                .fields
+                .subset(operand.fields)
+                .pattern(&path, operand.name)
+        });
+        let expr = func(variant);
+        quote! { (#(#patterns,)*) => #expr, }
+    });
+
+    if info.is_enum {
+        quote! {
+            #[allow(unreachable_patterns)]
+            match (#(#exprs,)*) {
+                #(#arms)*
+                _ => #otherwise,
+            }
+        }
+    } else {
+        quote! {
+            match (#(#exprs,)*) {
+                #(#arms)*
+            }
+        }
+    }
+}
+
+/// Expands to the conjunction of the checks of each field bound by a `self`
+/// and `other` pattern, where `tol` provides the caller's tolerance for a field
+/// to be compared using `method`.
+fn expand_eq_fields<F: Fn(&read::FieldInfo) -> TokenStream>(
+    fields: &read::FieldInfoList,
+    method: &Ident,
+    tol: F,
+) -> TokenStream {
+    let checks: Vec<TokenStream> = fields
+        .iter()
+        .filter_map(|field| {
+            let a = field.binding("self");
+            let b = field.binding("other");
+            match &field.compare {
+                read::FieldCompare::Tol => {
+                    let t = tol(field);
+                    Some(quote! { #a.#method(#b, #t) })
+                }
+                read::FieldCompare::Skip => None,
+                read::FieldCompare::Exact => Some(quote! { #a == #b }),
+                read::FieldCompare::Fixed { check, tol } => {
+                    let method = Ident::new(&format!("eq_{}", check), Span::call_site());
+                    let tol = expand_fixed_tol(check, tol);
+                    Some(quote! { float_eq::FloatEq::#method(#a, #b, #tol) })
+                }
+            }
+        })
+        .collect();
+
+    if checks.is_empty() {
+        quote! { true }
+    } else {
+        quote! { #(#checks)&&* }
+    }
+}
+
+/// Expands to a composite report node of the fields bound by a `self` and
+/// `other` pattern, where `report` provides the node of a field compared using
+/// the caller's tolerance.
+fn expand_report_fields<F: Fn(&read::FieldInfo) -> TokenStream>(
+    fields: &read::FieldInfoList,
+    report: F,
+) -> TokenStream {
+    let children = fields.iter().filter_map(|field| {
+        let a = field.binding("self");
+        let b = field.binding("other");
+        let path = field.path_segment();
+        match &field.compare {
+            read::FieldCompare::Tol => Some(report(field)),
+            read::FieldCompare::Skip => None,
+            read::FieldCompare::Exact => Some(quote! {
+                float_eq::ReportNode::exact(::std::format!("{}{}", path, #path), #a == #b)
+            }),
+            read::FieldCompare::Fixed { check, tol } => {
+                let tol = expand_fixed_tol(check, tol);
+                let check = Ident::new(&report_tol_variant(check), Span::call_site());
+                Some(quote! {
+                    float_eq::FloatEqReport::report(
+                        #a,
+                        #b,
+                        float_eq::ReportTol::#check(#tol),
+                        ::std::format!("{}{}", path, #path),
+                    )
+                })
+            }
+        }
+    });
+    quote! {{
+        let children = ::std::vec![#(#children),*];
+        float_eq::ReportNode::composite(path, children)
+    }}
+}
+
+/// Expands to statements that record the failures of each field bound by a
+/// `self` and `other` pattern, where `push` records those of a field compared
+/// using the caller's tolerance.
+fn expand_diffs_fields<F: Fn(&read::FieldInfo) -> TokenStream>(
+    fields: &read::FieldInfoList,
+    push: F,
+) -> TokenStream {
+    let pushes = fields.iter().filter_map(|field| {
+        let a = field.binding("self");
+        let b = field.binding("other");
+        let path = field.path_segment();
+        match &field.compare {
+            read::FieldCompare::Tol => Some(push(field)),
+            read::FieldCompare::Skip => None,
+            read::FieldCompare::Exact => Some(quote! {
+                if #a != #b {
+                    diffs.push(
+                        &::std::format!("{}{}", path, #path),
+                        ::core::format_args!("left: `{:?}`, right: `{:?}`", #a, #b),
+                    );
+                }
+            }),
+            read::FieldCompare::Fixed { check, tol } => {
+                let tol = expand_fixed_tol(check, tol);
+                let check = Ident::new(&report_tol_variant(check), Span::call_site());
+                Some(quote! {
+                    float_eq::AssertFloatEqDiffs::debug_diffs(
+                        #a,
+                        #b,
+                        float_eq::ReportTol::#check(#tol),
+                        &::std::format!("{}{}", path, #path),
+                        diffs,
+                    );
+                })
+            }
+        }
+    });
+    quote! {{ #(#pushes)* }}
+}
+
+/// Expands to a statement that records values of different enum variants as a
+/// single failure.
+fn expand_variant_diffs() -> TokenStream {
+    quote! {
+        diffs.push(path, ::core::format_args!("{}", float_eq::Mismatch::Variant))
+    }
+}
+
+/// The name of the `ReportTol` variant for a check, such as `Rmax` for `rmax`
+/// or `AtolRtol` for `atol_rtol`.
+fn report_tol_variant(check: &Ident) -> String {
+    check
+        .to_string()
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .concat()
+}
+
+/// Expands to the tolerance arguments of a field with a fixed check, which for
+/// `atol_rtol` is an `(atol, rtol)` pair passed as two arguments.
+fn expand_fixed_tol(check: &Ident, tol: &TokenStream) -> TokenStream {
+    if check == "atol_rtol" {
+        quote! { &(#tol).0, &(#tol).1 }
+    } else {
+        quote! { &(#tol) }
+    }
+}
+
+/// Expands to the conjunction of the ordering checks of each field bound by a
+/// `self` and `other` pattern, using `method` with the caller's `op` and `tol`.
+/// Exact fields must be equal, since they have no tolerance to order by.
+fn expand_ord_fields(fields: &read::FieldInfoList, method: &Ident) -> TokenStream {
+    let checks: Vec<TokenStream> = fields
+        .iter()
+        .filter_map(|field| {
+            let a = field.binding("self");
+            let b = field.binding("other");
+            match &field.compare {
+                read::FieldCompare::Tol => {
+                    let t = field.binding("tol");
+                    Some(quote! { #a.#method(#b, op, #t) })
+                }
+                read::FieldCompare::Skip => None,
+                read::FieldCompare::Exact => Some(quote! { #a == #b }),
+                read::FieldCompare::Fixed { check, tol } => {
+                    let method = Ident::new(&format!("ord_{}", check), Span::call_site());
+                    Some(quote! { float_eq::FloatOrd::#method(#a, #b, op, &(#tol)) })
+                }
+            }
+        })
+        .collect();
+
+    if checks.is_empty() {
+        quote! { true }
+    } else {
+        quote! { #(#checks)&&* }
+    }
+}
+
+/// Expands to the distance past the bound of an ordering check of a field
+/// bound by a `self` and `other` pattern, as displayed by `debug_ord_{check}`.
+/// Fields with a fixed `ulps` check use their default value, since their
+/// tolerance is not of the field's type.
+fn expand_debug_field_ord(field: &read::FieldInfo, check: &str) -> TokenStream {
+    let a = field.binding("self");
+    let b = field.binding("other");
+    match &field.compare {
+        read::FieldCompare::Tol => {
+            let method = Ident::new(&format!("debug_ord_{}", check), Span::call_site());
+            let t = field.binding("tol");
+            quote! { #a.#method(#b, op, #t) }
+        }
+        read::FieldCompare::Fixed { check, tol } if check != "ulps" => {
+            let method = Ident::new(&format!("debug_ord_{}", check), Span::call_site());
+            quote! { float_eq::AssertFloatOrd::#method(#a, #b, op, &(#tol)) }
+        }
+        _ => quote! { ::core::default::Default::default() },
+    }
+}
+
+/// Fails if any field has a fixed check that has no ordering equivalent.
+fn check_ord_fields(trait_name: &str, info: &read::TypeInfo) -> Result<(), syn::Error> {
+    for field in info.fields() {
+        if let read::FieldCompare::Fixed { check, .. } = &field.compare {
+            if check == "atol_rtol" {
+                let msg = format!(
+                    "{} may not be derived for a field with a fixed `atol_rtol` check.",
+                    trait_name
+                );
+                return Err(syn::Error::new(check.span(), msg));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Expands to the tolerance of a field bound by a `self` and `other` pattern,
+/// as displayed by `debug_{check}_tol`, where `tol` is the caller's tolerance.
+/// Fields with a fixed `ulps` check use their default value, since their
+/// tolerance is not of the field's type.
+fn expand_debug_field_tol(field: &read::FieldInfo, check: &str, tol: TokenStream) -> TokenStream {
+    let a = field.binding("self");
+    let b = field.binding("other");
+    match &field.compare {
+        read::FieldCompare::Tol => {
+            let method = Ident::new(&format!("debug_{}_tol", check), Span::call_site());
+            quote! { #a.#method(#b, #tol) }
+        }
+        read::FieldCompare::Fixed { check, tol } if check != "ulps" => {
+            let method = Ident::new(&format!("debug_{}_tol", check), Span::call_site());
+            let tol = expand_fixed_tol(check, tol);
+            quote! { float_eq::AssertFloatEq::#method(#a, #b, #tol) }
+        }
+        _ => quote! { ::core::default::Default::default() },
+    }
+}
+
+/// Declares a type named `name` with the same shape as the derived type and
+/// the fields and generic parameters of `info`, where `field_type` provides the
+/// type of each field.
+fn expand_companion_type<T, B>(
+    input: &DeriveInput,
+    name: &Ident,
+    info: &read::TypeInfo,
+    generics: &syn::Generics,
+    pub_fields: bool,
+    field_type: T,
+    field_bounds: B,
+) -> TokenStream
+where
+    T: Fn(&Type) -> TokenStream,
+    B: Fn(&read::FieldInfo) -> TokenStream,
+{
+    let vis = &input.vis;
+    let where_clause = expand_where_clause(generics, info, quote! {}, field_bounds);
+
+    if info.is_enum {
+        let variants = info.variants.iter().map(|variant| {
+            let name = variant.name;
+            let fields = variant
+                .fields
+                .declare(|field| field.declaration(field_type(field.ty)));
+            quote! { #name #fields }
+        });
+        return quote! {
+            #vis enum #name #generics #where_clause {
+                #(#variants,)*
+            }
+        };
+    }
+
+    let fields = &info.variants[0].fields;
+    let declared = fields.declare(|field| {
+        let declaration = field.declaration(field_type(field.ty));
+        if pub_fields {
+            quote! { #vis #declaration }
+        } else {
+            declaration
+        }
+    });
+    match fields.ty {
+        read::FieldListType::Named => quote! {
+            #vis struct #name #generics #where_clause #declared
+        },
+        read::FieldListType::Tuple => quote! {
+            #vis struct #name #generics #declared #where_clause;
+        },
+        read::FieldListType::Unit => quote! {
+            #vis struct #name #generics #where_clause;
+        },
+    }
+}
+
+/// The where clause of an item with `generics` for the fields in `info`. If it
+/// is generic, this is extended with `self_bounds` and with `field_bounds` for
+/// each field whose type depends on a generic parameter.
+fn expand_where_clause<F: Fn(&read::FieldInfo) -> TokenStream>(
+    generics: &syn::Generics,
+    info: &read::TypeInfo,
+    self_bounds: TokenStream,
+    field_bounds: F,
+) -> TokenStream {
+    if generics.params.is_empty() {
+        let where_clause = &generics.where_clause;
+        return quote! { #where_clause };
+    }
+
+    let predicates = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter());
+
+    let mut bounds: Vec<TokenStream> = Vec::new();
+    for field in info.fields() {
+        if field.is_generic(generics) {
+            let field_bounds = field_bounds(field);
+            let key = field_bounds.to_string();
+            if !bounds.iter().any(|b| b.to_string() == key) {
+                bounds.push(field_bounds);
+            }
+        }
+    }
+
+    quote! {
+        where
+            #(#predicates,)*
+            #self_bounds
+            #(#bounds)*
+    }
+}
+
+/// The serde derives of a companion type if `#[float_eq(serde)]` is present,
+/// using the serde re-exported by float_eq. Serde would bound each generic
+/// parameter rather than the field types, which are `field_type` of the
+/// original field types, so the bounds are given explicitly.
+fn expand_serde_derive<T: Fn(&Type) -> TokenStream>(
+    params: &read::FloatEqAttr,
+    info: &read::TypeInfo,
+    generics: &syn::Generics,
+    field_type: T,
+) -> TokenStream {
+    if !params.serde() {
+        return quote! {};
+    }
+
+    let mut field_types: Vec<String> = Vec::new();
+    for field in info.fields() {
+        if field.is_generic(generics) {
+            let ty = field_type(field.ty).to_string();
+            if !field_types.contains(&ty) {
+                field_types.push(ty);
+            }
+        }
+    }
+    let bounds = |bound: &str| {
+        field_types
+            .iter()
+            .map(|ty| format!("{}: {}", ty, bound))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let serialize = bounds("float_eq::__serde::Serialize");
+    let deserialize = bounds("float_eq::__serde::Deserialize<'de>");
+
+    quote! {
+        #[derive(float_eq::__serde::Serialize, float_eq::__serde::Deserialize)]
+        #[serde(
+            crate = "float_eq::__serde",
+            bound(serialize = #serialize, deserialize = #deserialize)
+        )]
+    }
+}
+
+/// Bounds required to declare and name the `ulps_tol` type of a generic field.
+fn ulps_tol_bounds(params: &read::FloatEqAttr, ty: &Type) -> TokenStream {
+    let derives = derive_bounds(&params.ulps_tol_derive_types());
+    quote! {
+        #ty: float_eq::FloatEqUlpsTol,
+        float_eq::UlpsTol<#ty>: Sized #(+ #derives)*,
+    }
+}
+
+/// Bounds required to declare and name the `tol` type of a generic field.
+fn tol_bounds(params: &read::FloatEqAttr, ty: &Type) -> TokenStream {
+    let derives = derive_bounds(&params.tol_derive_types());
+    quote! {
+        #ty: Sized #(+ #derives)*,
+    }
+}
+
+/// Bounds required to declare and name the `debug_ulps_diff` type of a generic
+/// field.
+fn debug_ulps_diff_bounds(params: &read::FloatEqAttr, ty: &Type) -> TokenStream {
+    let derives = derive_bounds(&params.debug_ulps_diff_derive_types());
+    quote! {
+        #ty: float_eq::FloatEqDebugUlpsDiff,
+        float_eq::DebugUlpsDiff<#ty>: Sized #(+ #derives)*,
+    }
+}
+
+/// Whether a field's debug tolerance uses its default value.
+fn has_default_debug_tol(field: &read::FieldInfo) -> bool {
+    match &field.compare {
+        read::FieldCompare::Fixed { check, .. } => check == "ulps",
+        _ => false,
+    }
+}
+
+fn float_eq_bounds(params: &read::FloatEqAttr, field: &read::FieldInfo) -> TokenStream {
+    let ty = field.ty;
+    match &field.compare {
+        read::FieldCompare::Tol => {
+            let ulps_tol_bounds = ulps_tol_bounds(params, ty);
+            let tol_bounds = tol_bounds(params, ty);
+            quote! {
+                #ulps_tol_bounds
+                #tol_bounds
+                #ty: float_eq::FloatEq<Tol = #ty>,
+            }
+        }
+        read::FieldCompare::Fixed { .. } => quote! { #ty: float_eq::FloatEq, },
+        read::FieldCompare::Exact => quote! { #ty: ::core::cmp::PartialEq, },
+        read::FieldCompare::Skip => quote! {},
+    }
+}
+
+fn assert_float_eq_bounds(params: &read::FloatEqAttr, field: &read::FieldInfo) -> TokenStream {
+    let ty = field.ty;
+    let float_eq_bounds = float_eq_bounds(params, field);
+    let debug_bounds = if field.is_float() {
+        let debug_ulps_diff_bounds = debug_ulps_diff_bounds(params, ty);
+        quote! {
+            #debug_ulps_diff_bounds
+            #ty: ::core::fmt::Debug + float_eq::AssertFloatEq<DebugAbsDiff = #ty, DebugTol = #ty>,
+        }
+    } else {
+        quote! {}
+    };
+    let default_bounds = if has_default_debug_tol(field) {
+        quote! { #ty: ::core::default::Default, }
+    } else {
+        quote! {}
+    };
+    quote! {
+        #float_eq_bounds
+        #debug_bounds
+        #default_bounds
+    }
+}
+
+fn float_ord_bounds(params: &read::FloatEqAttr, field: &read::FieldInfo) -> TokenStream {
+    let ty = field.ty;
+    let float_eq_bounds = float_eq_bounds(params, field);
+    let ord_bounds = if field.is_float() {
+        quote! { #ty: float_eq::FloatOrd, }
+    } else {
+        quote! {}
+    };
+    quote! {
+        #float_eq_bounds
+        #ord_bounds
+    }
+}
+
+fn assert_float_ord_bounds(params: &read::FloatEqAttr, field: &read::FieldInfo) -> TokenStream {
+    let ty = field.ty;
+    let assert_float_eq_bounds = assert_float_eq_bounds(params, field);
+    let ord_bounds = if field.is_float() {
+        quote! { #ty: float_eq::AssertFloatOrd, }
+    } else {
+        quote! {}
+    };
+    quote! {
+        #assert_float_eq_bounds
+        #ord_bounds
+    }
+}
+
+fn float_eq_all_bounds(all_tol: &Ident, field: &read::FieldInfo) -> TokenStream {
+    let ty = field.ty;
+    match &field.compare {
+        read::FieldCompare::Tol => quote! { #ty: float_eq::FloatEqAll<AllTol = #all_tol>, },
+        read::FieldCompare::Fixed { .. } => quote! { #ty: float_eq::FloatEq, },
+        read::FieldCompare::Exact => quote! { #ty: ::core::cmp::PartialEq, },
+        read::FieldCompare::Skip => quote! {},
+    }
+}
+
+fn assert_float_eq_all_bounds(
+    params: &read::FloatEqAttr,
+    all_tol: &Ident,
+    field: &read::FieldInfo,
+) -> TokenStream {
+    let ty = field.ty;
+    let float_eq_all_bounds = float_eq_all_bounds(all_tol, field);
+    let debug_bounds = match &field.compare {
+        read::FieldCompare::Tol => {
+            let ulps_tol_bounds = ulps_tol_bounds(params, ty);
+            quote! {
+                #ulps_tol_bounds
+                #ty: ::core::fmt::Debug + float_eq::AssertFloatEqAll<AllDebugTol = #ty>,
+            }
+        }
+        read::FieldCompare::Fixed { .. } if has_default_debug_tol(field) => {
+            quote! { #ty: ::core::fmt::Debug + ::core::default::Default, }
+        }
+        read::FieldCompare::Fixed { .. } => {
+            quote! { #ty: ::core::fmt::Debug + float_eq::AssertFloatEq<DebugTol = #ty>, }
+        }
+        read::FieldCompare::Skip | read::FieldCompare::Exact => quote! {},
+    };
+    quote! {
+        #float_eq_all_bounds
+        #debug_bounds
+    }
+}
+
+fn float_eq_report_bounds(params: &read::FloatEqAttr, field: &read::FieldInfo) -> TokenStream {
+    let ty = field.ty;
+    let float_eq_bounds = float_eq_bounds(params, field);
+    let report_bounds = if field.is_float() {
+        quote! { #ty: float_eq::FloatEqReport, }
+    } else {
+        quote! {}
+    };
+    quote! {
+        #float_eq_bounds
+        #report_bounds
+    }
+}
+
+fn float_eq_all_report_bounds(all_tol: &Ident, field: &read::FieldInfo) -> TokenStream {
+    let ty = field.ty;
+    let float_eq_all_bounds = float_eq_all_bounds(all_tol, field);
+    let report_bounds = match &field.compare {
+        read::FieldCompare::Tol => quote! { #ty: float_eq::FloatEqAllReport, },
+        read::FieldCompare::Fixed { .. } => quote! { #ty: float_eq::FloatEqReport, },
+        read::FieldCompare::Exact | read::FieldCompare::Skip => quote! {},
+    };
+    quote! {
+        #float_eq_all_bounds
+        #report_bounds
+    }
+}
+
+fn assert_float_eq_diffs_bounds(
+    params: &read::FloatEqAttr,
+    field: &read::FieldInfo,
+) -> TokenStream {
+    let ty = field.ty;
+    let assert_float_eq_bounds = assert_float_eq_bounds(params, field);
+    let diffs_bounds = match &field.compare {
+        read::FieldCompare::Tol | read::FieldCompare::Fixed { .. } => {
+            quote! { #ty: float_eq::AssertFloatEqDiffs, }
+        }
+        read::FieldCompare::Exact => quote! { #ty: ::core::fmt::Debug, },
+        read::FieldCompare::Skip => quote! {},
+    };
+    quote! {
+        #assert_float_eq_bounds
+        #diffs_bounds
+    }
+}
+
+fn assert_float_eq_all_diffs_bounds(
+    params: &read::FloatEqAttr,
+    all_tol: &Ident,
+    field: &read::FieldInfo,
+) -> TokenStream {
+    let ty = field.ty;
+    let assert_float_eq_all_bounds = assert_float_eq_all_bounds(params, all_tol, field);
+    let diffs_bounds = match &field.compare {
+        read::FieldCompare::Tol => quote! { #ty: float_eq::AssertFloatEqAllDiffs, },
+        read::FieldCompare::Fixed { .. } => quote! { #ty: float_eq::AssertFloatEqDiffs, },
+        read::FieldCompare::Exact => quote! { #ty: ::core::fmt::Debug, },
+        read::FieldCompare::Skip => quote! {},
+    };
+    quote! {
+        #assert_float_eq_all_bounds
+        #diffs_bounds
+    }
+}
+
+/// Paths to the standard library traits named in a list of derives, which a
+/// generated type's generic fields must implement for those derives to work.
+/// Other derives are assumed to take care of their own bounds.
+fn derive_bounds(derive_types: &[Ident]) -> Vec<TokenStream> {
+    derive_types
+        .iter()
+        .filter_map(|name| match name.to_string().as_str() {
+            "Clone" => Some(quote! { ::core::clone::Clone }),
+            "Copy" => Some(quote! { ::core::marker::Copy }),
+            "Debug" => Some(quote! { ::core::fmt::Debug }),
+            "Default" => Some(quote! { ::core::default::Default }),
+            "Hash" => Some(quote! { ::core::hash::Hash }),
+            "PartialEq" => Some(quote! { ::core::cmp::PartialEq }),
+            "Eq" => Some(quote! { ::core::cmp::Eq }),
+            "PartialOrd" => Some(quote! { ::core::cmp::PartialOrd }),
+            "Ord" => Some(quote! { ::core::cmp::Ord }),
+            _ => None,
+        })
+        .collect()
+}
@@ -1,10 +1,11 @@
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::ToTokens;
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
 use syn::{
-    spanned::Spanned, Attribute, Data, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Lit,
-    LitInt, LitStr, Meta, NestedMeta, Type, Visibility,
+    parse::ParseStream, spanned::Spanned, Attribute, Data, DeriveInput, Fields, FieldsNamed,
+    FieldsUnnamed, GenericParam, Generics, Lit, LitInt, LitStr, Meta, NestedMeta, Token, Type,
 };
 
+#[derive(Clone)]
 pub enum FieldName<'a> {
     Ident(&'a Ident),
     Num(Lit),
@@ -19,12 +20,86 @@ impl ToTokens for FieldName<'_> {
     }
 }
 
+/// How a field is compared, as specified by its `#[float_eq(...)]` attribute.
+#[derive(Clone)]
+pub enum FieldCompare {
+    /// Compared using the check and tolerance provided by the caller.
+    Tol,
+    /// Ignored by comparisons.
+    Skip,
+    /// Compared using `PartialEq`.
+    Exact,
+    /// Compared using a fixed check and tolerance, ignoring those provided by
+    /// the caller.
+    Fixed { check: Ident, tol: TokenStream },
+}
+
+#[derive(Clone)]
 pub struct FieldInfo<'a> {
     pub name: FieldName<'a>,
     pub ty: &'a Type,
-    pub vis: &'a Visibility,
+    pub compare: FieldCompare,
+    index: usize,
 }
 
+impl FieldInfo<'_> {
+    /// Whether this field is compared using the tolerance provided by the caller,
+    /// and so is part of the generated `ulps_tol` type.
+    pub fn uses_tol(&self) -> bool {
+        matches!(self.compare, FieldCompare::Tol)
+    }
+
+    /// Whether this field is compared as a floating point value, and so is part
+    /// of the generated `debug_ulps_diff` type.
+    pub fn is_float(&self) -> bool {
+        matches!(self.compare, FieldCompare::Tol | FieldCompare::Fixed { .. })
+    }
+
+    /// Whether the type of this field depends on any of the type or const
+    /// parameters in `generics`.
+    pub fn is_generic(&self, generics: &Generics) -> bool {
+        let params: Vec<&Ident> = generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => Some(&param.ident),
+                GenericParam::Const(param) => Some(&param.ident),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect();
+        self.mentions(&params)
+    }
+
+    /// Whether the type of this field mentions any of the given identifiers.
+    pub fn mentions(&self, idents: &[&Ident]) -> bool {
+        mentions_any(self.ty.to_token_stream(), idents)
+    }
+
+    /// The declaration of a field with the same name as this one but of type `ty`.
+    pub fn declaration(&self, ty: TokenStream) -> TokenStream {
+        match &self.name {
+            FieldName::Ident(name) => quote! { #name: #ty },
+            FieldName::Num(_) => ty,
+        }
+    }
+
+    /// The segment of a report path that refers to this field, such as `.x`
+    /// or `.0`.
+    pub fn path_segment(&self) -> String {
+        match &self.name {
+            FieldName::Ident(name) => format!(".{}", name),
+            FieldName::Num(_) => format!(".{}", self.index),
+        }
+    }
+
+    /// Name of a local variable bound to this field when destructuring, which
+    /// is unique within a pattern for a given prefix.
+    pub fn binding(&self, prefix: &str) -> Ident {
+        Ident::new(&format!("__{}_{}", prefix, self.index), Span::call_site())
+    }
+}
+
+#[derive(Clone, Copy)]
 pub enum FieldListType {
     Named,
     Tuple,
@@ -36,59 +111,285 @@ pub struct FieldInfoList<'a> {
     fields: Vec<FieldInfo<'a>>,
 }
 
-impl FieldInfoList<'_> {
+impl<'a> FieldInfoList<'a> {
+    pub fn iter(&self) -> impl Iterator<Item = &FieldInfo<'a>> {
+        self.fields.iter()
+    }
+
     pub fn expand<F: std::ops::Fn(&FieldInfo) -> TokenStream>(&self, func: F) -> Vec<TokenStream> {
         self.fields.iter().map(func).collect()
     }
+
+    /// The fields for which `pred` is true, named as they would be in a type
+    /// declared with only those fields. Bindings are the same as for the
+    /// original fields.
+    pub fn subset<F: std::ops::Fn(&FieldInfo<'a>) -> bool>(&self, pred: F) -> FieldInfoList<'a> {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|field| pred(field))
+            .enumerate()
+            .map(|(n, field)| {
+                let mut field = field.clone();
+                if let FieldListType::Tuple = self.ty {
+                    field.name =
+                        FieldName::Num(Lit::Int(LitInt::new(&format!("{}", n), Span::call_site())));
+                }
+                field
+            })
+            .collect();
+        FieldInfoList {
+            ty: self.ty,
+            fields,
+        }
+    }
+
+    /// A pattern destructuring the struct or variant at `path`, binding each
+    /// field to a local variable named using `prefix`.
+    pub fn pattern(&self, path: &TokenStream, prefix: &str) -> TokenStream {
+        let fields = self.expand(|field| {
+            let name = &field.name;
+            let binding = field.binding(prefix);
+            quote! { #name: #binding }
+        });
+        quote! { #path { #(#fields,)* } }
+    }
+
+    /// An expression constructing the struct or variant at `path`, with the
+    /// value of each field provided by `func`.
+    pub fn construct<F: std::ops::Fn(&FieldInfo) -> TokenStream>(
+        &self,
+        path: &TokenStream,
+        func: F,
+    ) -> TokenStream {
+        let fields = self.expand(|field| {
+            let name = &field.name;
+            let value = func(field);
+            quote! { #name: #value }
+        });
+        quote! { #path { #(#fields,)* } }
+    }
+
+    /// The body of a struct or variant declaration with the same shape as this
+    /// list, with the definition of each field provided by `func`.
+    pub fn declare<F: std::ops::Fn(&FieldInfo) -> TokenStream>(&self, func: F) -> TokenStream {
+        let fields = self.expand(func);
+        match self.ty {
+            FieldListType::Named => quote! { { #(#fields,)* } },
+            FieldListType::Tuple => quote! { ( #(#fields,)* ) },
+            FieldListType::Unit => quote! {},
+        }
+    }
 }
 
-pub fn all_fields_info<'a>(
-    trait_name: &str,
-    input: &'a DeriveInput,
-) -> Result<FieldInfoList<'a>, syn::Error> {
-    if !input.generics.params.is_empty() {
-        return Err(syn::Error::new(
-            Span::call_site(),
-            "This trait does not yet support derive for generic types.",
-        ));
+/// A variant of an enum, or the single "variant" of a struct, which has no name.
+pub struct VariantInfo<'a> {
+    pub name: Option<&'a Ident>,
+    pub fields: FieldInfoList<'a>,
+}
+
+impl VariantInfo<'_> {
+    /// The path to the variant with the same name as this one in type `type_name`.
+    pub fn path<T: ToTokens>(&self, type_name: &T) -> TokenStream {
+        match self.name {
+            Some(name) => quote! { #type_name::#name },
+            None => quote! { #type_name },
+        }
     }
+}
 
+pub struct TypeInfo<'a> {
+    pub is_enum: bool,
+    pub variants: Vec<VariantInfo<'a>>,
+}
+
+impl<'a> TypeInfo<'a> {
+    /// Every field of the type, across all variants if it is an enum.
+    pub fn fields(&self) -> Vec<&FieldInfo<'a>> {
+        self.variants
+            .iter()
+            .flat_map(|variant| variant.fields.fields.iter())
+            .collect()
+    }
+
+    /// The subset of `generics` used by the fields of this type, along with the
+    /// where clause predicates that do not depend on any unused parameters.
+    pub fn used_generics(&self, generics: &Generics) -> Generics {
+        let param_ident = |param: &GenericParam| match param {
+            GenericParam::Type(param) => param.ident.clone(),
+            GenericParam::Const(param) => param.ident.clone(),
+            GenericParam::Lifetime(param) => param.lifetime.ident.clone(),
+        };
+        let fields = self.fields();
+        let (used, unused): (Vec<&GenericParam>, Vec<&GenericParam>) =
+            generics.params.iter().partition(|param| {
+                let ident = param_ident(param);
+                fields.iter().any(|field| field.mentions(&[&ident]))
+            });
+        let unused: Vec<Ident> = unused.into_iter().map(param_ident).collect();
+        let unused: Vec<&Ident> = unused.iter().collect();
+
+        let mut result = generics.clone();
+        result.params = used.into_iter().cloned().collect();
+        if let Some(where_clause) = &mut result.where_clause {
+            where_clause.predicates = where_clause
+                .predicates
+                .iter()
+                .filter(|predicate| !mentions_any(predicate.to_token_stream(), &unused))
+                .cloned()
+                .collect();
+        }
+        result
+    }
+
+    /// Whether any field has a `#[float_eq(...)]` attribute, and so does not
+    /// use the caller's tolerance.
+    pub fn has_field_attributes(&self) -> bool {
+        self.fields().iter().any(|field| !field.uses_tol())
+    }
+
+    /// A type of the same shape as this one, with only the fields for which
+    /// `pred` is true.
+    pub fn subset<F: std::ops::Fn(&FieldInfo<'a>) -> bool>(&self, pred: F) -> TypeInfo<'a> {
+        TypeInfo {
+            is_enum: self.is_enum,
+            variants: self
+                .variants
+                .iter()
+                .map(|variant| VariantInfo {
+                    name: variant.name,
+                    fields: variant.fields.subset(&pred),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn type_info<'a>(trait_name: &str, input: &'a DeriveInput) -> Result<TypeInfo<'a>, syn::Error> {
     match &input.data {
-        Data::Struct(data) => match &data.fields {
-            Fields::Named(FieldsNamed { named, .. }) => Ok(FieldInfoList {
-                ty: FieldListType::Named,
-                fields: named.iter().map(named_field_info).collect(),
-            }),
-            Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => Ok(FieldInfoList {
-                ty: FieldListType::Tuple,
-                fields: unnamed.iter().enumerate().map(unnamed_field_info).collect(),
-            }),
-            Fields::Unit => Ok(FieldInfoList {
-                ty: FieldListType::Unit,
-                fields: Vec::new(),
-            }),
-        },
-        _ => Err(syn::Error::new(
+        Data::Struct(data) => Ok(TypeInfo {
+            is_enum: false,
+            variants: vec![VariantInfo {
+                name: None,
+                fields: field_info_list(&data.fields)?,
+            }],
+        }),
+        Data::Enum(data) => Ok(TypeInfo {
+            is_enum: true,
+            variants: data
+                .variants
+                .iter()
+                .map(|variant| {
+                    Ok(VariantInfo {
+                        name: Some(&variant.ident),
+                        fields: field_info_list(&variant.fields)?,
+                    })
+                })
+                .collect::<Result<_, syn::Error>>()?,
+        }),
+        Data::Union(_) => Err(syn::Error::new(
             input.ident.span(),
-            format!("{} may only be derived for structs.", trait_name),
+            format!("{} may only be derived for structs and enums.", trait_name),
         )),
     }
 }
 
-fn named_field_info(field: &syn::Field) -> FieldInfo {
-    FieldInfo {
+fn mentions_any(tokens: TokenStream, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&&ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
+
+fn field_info_list(fields: &Fields) -> Result<FieldInfoList<'_>, syn::Error> {
+    Ok(match fields {
+        Fields::Named(FieldsNamed { named, .. }) => FieldInfoList {
+            ty: FieldListType::Named,
+            fields: named
+                .iter()
+                .enumerate()
+                .map(named_field_info)
+                .collect::<Result<_, _>>()?,
+        },
+        Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => FieldInfoList {
+            ty: FieldListType::Tuple,
+            fields: unnamed
+                .iter()
+                .enumerate()
+                .map(unnamed_field_info)
+                .collect::<Result<_, _>>()?,
+        },
+        Fields::Unit => FieldInfoList {
+            ty: FieldListType::Unit,
+            fields: Vec::new(),
+        },
+    })
+}
+
+fn named_field_info((n, field): (usize, &syn::Field)) -> Result<FieldInfo<'_>, syn::Error> {
+    Ok(FieldInfo {
         name: FieldName::Ident(field.ident.as_ref().expect("Expected named field")),
         ty: &field.ty,
-        vis: &field.vis,
-    }
+        compare: field_compare(field)?,
+        index: n,
+    })
 }
 
-fn unnamed_field_info((n, field): (usize, &syn::Field)) -> FieldInfo {
-    FieldInfo {
+fn unnamed_field_info((n, field): (usize, &syn::Field)) -> Result<FieldInfo<'_>, syn::Error> {
+    Ok(FieldInfo {
         name: FieldName::Num(Lit::Int(LitInt::new(&format!("{}", n), Span::call_site()))),
         ty: &field.ty,
-        vis: &field.vis,
+        compare: field_compare(field)?,
+        index: n,
+    })
+}
+
+fn field_compare(field: &syn::Field) -> Result<FieldCompare, syn::Error> {
+    let mut compare = None;
+    for attr in field.attrs.iter().filter(|a| a.path.is_ident("float_eq")) {
+        if compare.is_some() {
+            let msg = "Duplicate float_eq field attribute.";
+            return Err(syn::Error::new(attr.path.span(), msg));
+        }
+        compare = Some(attr.parse_args_with(parse_field_compare)?);
     }
+    Ok(compare.unwrap_or(FieldCompare::Tol))
+}
+
+fn parse_field_compare(input: ParseStream) -> Result<FieldCompare, syn::Error> {
+    let name: Ident = input.parse()?;
+    let compare = match name.to_string().as_str() {
+        "skip" => FieldCompare::Skip,
+        "exact" => FieldCompare::Exact,
+        "abs" | "rel" | "rmax" | "rmin" | "r1st" | "r2nd" | "atol_rtol" | "ulps" => {
+            input.parse::<Token![<=]>()?;
+            let tol: TokenStream = input.parse()?;
+            if tol.is_empty() {
+                let msg = format!("Expected a tolerance, for example `{} <= 4`.", name);
+                return Err(syn::Error::new(name.span(), msg));
+            }
+            // `rel` is a legacy alias, normalised so that generated code only
+            // needs to handle the precise algorithm names
+            let check = if name == "rel" {
+                Ident::new("rmax", name.span())
+            } else {
+                name
+            };
+            FieldCompare::Fixed { check, tol }
+        }
+        _ => {
+            let msg = format!(
+                r"'{}' is not a valid float_eq field option, expected `skip`, `exact` or a check such as `abs <= tol`.",
+                name
+            );
+            return Err(syn::Error::new(name.span(), msg));
+        }
+    };
+    if !input.is_empty() {
+        return Err(input.error("Unexpected tokens in float_eq field attribute."));
+    }
+    Ok(compare)
 }
 
 #[derive(Default)]
@@ -98,7 +399,11 @@ pub struct FloatEqAttr {
     ulps_tol_derive_types: Option<Vec<Ident>>,
     debug_ulps_diff_type_name: Option<Ident>,
     debug_ulps_diff_derive_types: Option<Vec<Ident>>,
+    tol_type_name: Option<Ident>,
+    tol_derive_types: Option<Vec<Ident>>,
+    debug_tol_type_name: Option<Ident>,
     all_tol_type_name: Option<Ident>,
+    serde: bool,
 }
 
 impl FloatEqAttr {
@@ -126,6 +431,10 @@ help: try adding `#[float_eq(ulps_tol = "{}Ulps")]` to your type."#,
             .map_or_else(Vec::new, |v| v.clone())
     }
 
+    pub fn serde(&self) -> bool {
+        self.serde
+    }
+
     pub fn debug_ulps_diff(&self) -> Result<&Ident, syn::Error> {
         self.debug_ulps_diff_type_name.as_ref().ok_or({
             let msg = format!(
@@ -138,6 +447,36 @@ help: try adding `#[float_eq(debug_ulps_diff = "{}DebugUlpsDiff")]` to your type
         })
     }
 
+    pub fn tol_type(&self) -> Result<&Ident, syn::Error> {
+        self.tol_type_name.as_ref().ok_or({
+            let msg = format!(
+                r#"Missing tolerance type name required by fields with a float_eq attribute.
+
+help: try adding `#[float_eq(tol = "{}Tol")]` to your type."#,
+                self.struct_name
+            );
+            syn::Error::new(Span::call_site(), msg)
+        })
+    }
+
+    pub fn tol_derive_types(&self) -> Vec<Ident> {
+        self.tol_derive_types
+            .as_ref()
+            .map_or_else(Vec::new, |v| v.clone())
+    }
+
+    pub fn debug_tol_type(&self) -> Result<&Ident, syn::Error> {
+        self.debug_tol_type_name.as_ref().ok_or({
+            let msg = format!(
+                r#"Missing debug tolerance type name required by fields with a float_eq attribute.
+
+help: try adding `#[float_eq(debug_tol = "{}DebugTol")]` to your type."#,
+                self.struct_name
+            );
+            syn::Error::new(Span::call_site(), msg)
+        })
+    }
+
     pub fn all_tol_type(&self) -> Result<&Ident, syn::Error> {
         self.all_tol_type_name.as_ref().ok_or({
             let msg = r#"Missing Tol type name required to derive trait.
@@ -149,11 +488,11 @@ help: try adding `#[float_eq(all_tol = "T")]` to your type, where T is commonly
 }
 
 pub fn float_eq_attr(input: &DeriveInput) -> Result<FloatEqAttr, syn::Error> {
-    let nv_pair_lists: Vec<Vec<NameValuePair>> = input
+    let arg_lists: Vec<Vec<AttrArg>> = input
         .attrs
         .iter()
         .filter(|a| a.path.is_ident("float_eq"))
-        .map(|a| name_value_pair_list(&input.ident, a))
+        .map(|a| attr_arg_list(&input.ident, a))
         .collect::<Result<_, _>>()?;
 
     let mut attr_values = FloatEqAttr {
@@ -161,7 +500,18 @@ pub fn float_eq_attr(input: &DeriveInput) -> Result<FloatEqAttr, syn::Error> {
         ..Default::default()
     };
 
-    for nv in nv_pair_lists.into_iter().flatten() {
+    for arg in arg_lists.into_iter().flatten() {
+        let nv = match arg {
+            AttrArg::Serde(span) => {
+                if attr_values.serde {
+                    return Err(syn::Error::new(span, "Duplicate `serde` argument"));
+                }
+                attr_values.serde = true;
+                continue;
+            }
+            AttrArg::NameValue(nv) => nv,
+        };
+
         let name = nv.name.to_string();
         if name == "ulps_tol" {
             set_float_eq_attr(&mut attr_values.ulps_tol_type_name, &nv, &parse_ident)?;
@@ -171,6 +521,12 @@ pub fn float_eq_attr(input: &DeriveInput) -> Result<FloatEqAttr, syn::Error> {
                 &nv,
                 &parse_ident,
             )?;
+        } else if name == "tol" {
+            set_float_eq_attr(&mut attr_values.tol_type_name, &nv, &parse_ident)?;
+        } else if name == "tol_derive" {
+            set_float_eq_attr(&mut attr_values.tol_derive_types, &nv, &parse_ident_list)?;
+        } else if name == "debug_tol" {
+            set_float_eq_attr(&mut attr_values.debug_tol_type_name, &nv, &parse_ident)?;
         } else if name == "all_tol" {
             set_float_eq_attr(&mut attr_values.all_tol_type_name, &nv, &parse_ident)?;
         } else if name == "ulps_tol_derive" {
@@ -230,12 +586,9 @@ fn parse_ident_list(value: &LitStr) -> Result<Vec<Ident>, syn::Error> {
         .collect())
 }
 
-fn name_value_pair_list(
-    struct_name: &Ident,
-    attr: &Attribute,
-) -> Result<Vec<NameValuePair>, syn::Error> {
+fn attr_arg_list(struct_name: &Ident, attr: &Attribute) -> Result<Vec<AttrArg>, syn::Error> {
     if let Meta::List(list) = attr.parse_meta()? {
-        list.nested.iter().map(name_value_pair).collect()
+        list.nested.iter().map(attr_arg).collect()
     } else {
         let msg = format!(
             r#"float_eq attribute must be a list of options, for example `#[float_eq(ulps_tol = "{}Ulps")]`"#,
@@ -245,6 +598,22 @@ fn name_value_pair_list(
     }
 }
 
+/// An option of a `#[float_eq(...)]` attribute on a type.
+enum AttrArg {
+    /// `serde`, which derives serde's traits for the generated types.
+    Serde(Span),
+    NameValue(NameValuePair),
+}
+
+fn attr_arg(meta: &NestedMeta) -> Result<AttrArg, syn::Error> {
+    if let NestedMeta::Meta(Meta::Path(path)) = meta {
+        if path.is_ident("serde") {
+            return Ok(AttrArg::Serde(path.span()));
+        }
+    }
+    name_value_pair(meta).map(AttrArg::NameValue)
+}
+
 pub struct NameValuePair {
     pub name: Ident,
     pub value: LitStr,